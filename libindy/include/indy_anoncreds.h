@@ -17,6 +17,18 @@ extern "C" {
                                                                        const char*   schema_json)
                                                   );
 
+    extern indy_error_t indy_issuer_create_schema_with_types(indy_handle_t command_handle,
+                                                             const char *  issuer_did,
+                                                             const char *  name,
+                                                             const char *  version,
+                                                             const char *  attr_types_json,
+
+                                                             void           (*cb)(indy_handle_t command_handle_,
+                                                                                  indy_error_t  err,
+                                                                                  const char*   id,
+                                                                                  const char*   schema_json)
+                                                             );
+
     extern indy_error_t indy_issuer_create_and_store_credential_def(indy_handle_t command_handle,
                                                                     indy_handle_t wallet_handle,
                                                                     const char *  issuer_did,
@@ -30,6 +42,20 @@ extern "C" {
                                                                                          const char*   cred_def_id,
                                                                                          const char*   cred_def_json)
                                                                     );
+
+    extern indy_error_t indy_issuer_rotate_credential_def(indy_handle_t command_handle,
+                                                          indy_handle_t wallet_handle,
+                                                          const char *  issuer_did,
+                                                          const char *  cred_def_id,
+                                                          const char *  schema_json,
+                                                          const char *  tag,
+                                                          const char *  config_json,
+
+                                                          void           (*cb)(indy_handle_t command_handle_,
+                                                                               indy_error_t  err,
+                                                                               const char*   cred_def_id,
+                                                                               const char*   cred_def_json)
+                                                          );
     
     extern indy_error_t indy_issuer_create_and_store_revoc_reg(indy_handle_t command_handle,
                                                                indy_handle_t wallet_handle,
@@ -47,6 +73,18 @@ extern "C" {
                                                                                     const char*   revoc_reg_entry_json)
                                                                );
 
+    extern indy_error_t indy_issuer_rotate_revoc_reg(indy_handle_t command_handle,
+                                                     indy_handle_t wallet_handle,
+                                                     const char *  issuer_did,
+                                                     const char *  cred_def_id,
+                                                     const char *  config_json,
+                                                     indy_handle_t tails_writer_handle,
+
+                                                     void           (*cb)(indy_handle_t command_handle_,
+                                                                          indy_error_t  err,
+                                                                          const char*   rotation_json)
+                                                     );
+
     extern indy_error_t indy_issuer_create_credential_offer(indy_handle_t command_handle,
                                                             indy_handle_t wallet_handle,
                                                             const char *  cred_def_id,
@@ -70,6 +108,48 @@ extern "C" {
                                                                            const char*   cred_revoc_id,
                                                                            const char*   revoc_reg_delta_json)
                                                       );
+
+    extern indy_error_t indy_issuer_create_credential_with_log(indy_handle_t command_handle,
+                                                               indy_handle_t wallet_handle,
+                                                               const char *  cred_offer_json,
+                                                               const char *  cred_req_json,
+                                                               const char *  cred_values_json,
+                                                               const char *  rev_reg_id,
+                                                               indy_handle_t blob_storage_reader_handle,
+                                                               const char *  tags_json,
+
+                                                               void           (*cb)(indy_handle_t command_handle_,
+                                                                                    indy_error_t  err,
+                                                                                    const char*   cred_json,
+                                                                                    const char*   cred_revoc_id,
+                                                                                    const char*   revoc_reg_delta_json)
+                                                               );
+
+    extern indy_error_t indy_issuer_search_issued_credentials(indy_handle_t command_handle,
+                                                              indy_handle_t wallet_handle,
+                                                              const char *  query_json,
+
+                                                              void           (*cb)(indy_handle_t command_handle_,
+                                                                                   indy_error_t  err,
+                                                                                   indy_handle_t search_handle,
+                                                                                   indy_u32_t    total_count)
+                                                              );
+
+    extern indy_error_t indy_issuer_fetch_issued_credentials(indy_handle_t command_handle,
+                                                             indy_handle_t search_handle,
+                                                             indy_u32_t    count,
+
+                                                             void           (*cb)(indy_handle_t command_handle_,
+                                                                                  indy_error_t  err,
+                                                                                  const char*   issued_credentials_json)
+                                                             );
+
+    extern indy_error_t indy_issuer_close_issued_credentials_search(indy_handle_t command_handle,
+                                                                    indy_handle_t search_handle,
+
+                                                                    void           (*cb)(indy_handle_t command_handle_,
+                                                                                         indy_error_t  err)
+                                                                    );
     
     extern indy_error_t indy_issuer_revoke_credential(indy_handle_t command_handle,
                                                       indy_handle_t wallet_handle,
@@ -82,6 +162,17 @@ extern "C" {
                                                                            const char*   revoc_reg_delta_json)
                                                       );
 
+    extern indy_error_t indy_issuer_revoke_credentials(indy_handle_t command_handle,
+                                                       indy_handle_t wallet_handle,
+                                                       indy_handle_t blob_storage_reader_handle,
+                                                       const char *  rev_reg_id,
+                                                       const char *  cred_revoc_ids_json,
+
+                                                       void           (*cb)(indy_handle_t command_handle_,
+                                                                            indy_error_t  err,
+                                                                            const char*   revoc_reg_delta_json)
+                                                       );
+
 /*    extern indy_error_t indy_issuer_recover_credential(indy_handle_t command_handle,
                                                        indy_handle_t wallet_handle,
                                                        indy_handle_t blob_storage_reader_handle,
@@ -138,6 +229,19 @@ extern "C" {
                                                                           indy_error_t  err,
                                                                           const char*   out_cred_id)
                                                      );
+
+    extern indy_error_t indy_prover_verify_credential(indy_handle_t command_handle,
+                                                      indy_handle_t wallet_handle,
+                                                      const char *  cred_req_metadata_json,
+                                                      const char *  cred_json,
+                                                      const char *  schema_json,
+                                                      const char *  cred_def_json,
+                                                      const char *  rev_reg_def_json,
+
+                                                      void           (*cb)(indy_handle_t command_handle_,
+                                                                           indy_error_t  err,
+                                                                           const char*   report_json)
+                                                      );
     
     extern indy_error_t indy_prover_get_credentials(indy_handle_t command_handle,
                                                     indy_handle_t wallet_handle,
@@ -157,6 +261,32 @@ extern "C" {
                                                                         const char*   credential_json)
                                                    );
 
+    extern indy_error_t indy_prover_delete_credential(indy_handle_t command_handle,
+                                                      indy_handle_t wallet_handle,
+                                                      const char *  cred_id,
+
+                                                      void           (*cb)(indy_handle_t command_handle_,
+                                                                           indy_error_t  err)
+                                                      );
+
+    extern indy_error_t indy_prover_add_credential_tags(indy_handle_t command_handle,
+                                                        indy_handle_t wallet_handle,
+                                                        const char *  cred_id,
+                                                        const char *  tags_json,
+
+                                                        void           (*cb)(indy_handle_t command_handle_,
+                                                                             indy_error_t  err)
+                                                        );
+
+    extern indy_error_t indy_prover_delete_credential_tags(indy_handle_t command_handle,
+                                                           indy_handle_t wallet_handle,
+                                                           const char *  cred_id,
+                                                           const char *  tag_names_json,
+
+                                                           void           (*cb)(indy_handle_t command_handle_,
+                                                                                indy_error_t  err)
+                                                           );
+
     extern indy_error_t indy_prover_search_credentials(indy_handle_t command_handle,
                                                        indy_handle_t wallet_handle,
                                                        const char *  query_json,
@@ -249,6 +379,20 @@ extern "C" {
                                                    );
 
 
+    extern indy_error_t indy_verifier_verify_proof_verbose(indy_handle_t command_handle,
+                                                           const char *  proof_request_json,
+                                                           const char *  proof_json,
+                                                           const char *  schemas_json,
+                                                           const char *  credential_defs_jsons,
+                                                           const char *  rev_reg_defs_json,
+                                                           const char *  rev_regs_json,
+
+                                                           void           (*cb)(indy_handle_t command_handle_,
+                                                                                indy_error_t  err,
+                                                                                const char*   report_json)
+                                                           );
+
+
     extern indy_error_t indy_create_revocation_state(indy_handle_t command_handle,
                                                      indy_handle_t blob_storage_reader_handle,
                                                      const char *  rev_reg_def_json,
@@ -275,6 +419,103 @@ extern "C" {
                                                                           const char*   updated_rev_state_json)
                                                      );
 
+    extern indy_error_t indy_prover_update_credential_revocation_state(indy_handle_t command_handle,
+                                                                       indy_handle_t wallet_handle,
+                                                                       indy_handle_t blob_storage_reader_handle,
+                                                                       const char *  cred_id,
+                                                                       const char *  rev_reg_def_json,
+                                                                       const char *  rev_reg_delta_json,
+                                                                       indy_u64_t    timestamp,
+
+                                                                       void           (*cb)(indy_handle_t command_handle_,
+                                                                                            indy_error_t  err,
+                                                                                            const char*   rev_state_json)
+                                                                       );
+
+    extern indy_error_t indy_validate_proof_request(indy_handle_t command_handle,
+                                                    const char *  proof_request_json,
+
+                                                    void           (*cb)(indy_handle_t command_handle_,
+                                                                         indy_error_t  err,
+                                                                         const char*   problems_json)
+                                                    );
+
+    /// Get unqualified form (short form without method) of a fully qualified entity like DID.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context
+    /// entity: target entity to get unqualified form. Can be one of:
+    ///             DID
+    ///             SchemaId
+    ///             CredentialDefinitionId
+    ///             RevocationRegistryId
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// res: entity either in unqualified form or original if casting isn't possible
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_to_unqualified(indy_handle_t command_handle,
+                                            const char *  entity,
+
+                                            void           (*cb)(indy_handle_t command_handle_,
+                                                                 indy_error_t  err,
+                                                                 const char*   res)
+                                            );
+
+    /// Get fully qualified form of an unqualified entity like DID.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context
+    /// entity: target entity to get fully qualified form. Can be one of:
+    ///             DID
+    ///             SchemaId
+    ///             CredentialDefinitionId
+    ///             RevocationRegistryId
+    /// method: DID method to qualify entity with, e.g. "sov".
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// res: entity in fully qualified form. Already qualified entity is returned as is.
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_to_fully_qualified(indy_handle_t command_handle,
+                                                const char *  entity,
+                                                const char *  method,
+
+                                                void           (*cb)(indy_handle_t command_handle_,
+                                                                     indy_error_t  err,
+                                                                     const char*   res)
+                                                );
+
+    /// Encode raw value of credential attribute the standard way.
+    ///
+    /// A value which is the canonical decimal form of 32-bit integer is encoded as itself,
+    /// any other value is encoded as decimal representation of big-endian SHA-256 hash of its UTF-8 bytes.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context
+    /// raw_value: raw value of credential attribute
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// encoded_value: encoded value of credential attribute
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_encode_credential_attribute(indy_handle_t command_handle,
+                                                         const char *  raw_value,
+
+                                                         void           (*cb)(indy_handle_t command_handle_,
+                                                                              indy_error_t  err,
+                                                                              const char*   encoded_value)
+                                                         );
+
 #ifdef __cplusplus
 }
 #endif
@@ -18,6 +18,67 @@ extern "C" {
                                                       void           (*fn)(indy_handle_t command_handle_, indy_error_t err, indy_handle_t handle)
                                                      );
 
+    /// Registers custom blob storage implementation.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// type_: Blob storage type name.
+    /// open_writer: BlobStorage open writer operation handler
+    /// create_blob: BlobStorage create blob operation handler
+    /// append: BlobStorage append operation handler
+    /// finalize: BlobStorage finalize operation handler
+    /// close_writer: BlobStorage close writer operation handler
+    /// open_reader: BlobStorage open reader operation handler
+    /// open_blob: BlobStorage open blob operation handler
+    /// read: BlobStorage read operation handler
+    /// close_blob: BlobStorage close blob operation handler
+    /// close_reader: BlobStorage close reader operation handler
+    ///
+    /// #Returns
+    /// Error code
+
+    extern indy_error_t indy_register_blob_storage(indy_handle_t  command_handle,
+                                                   const char*    type_,
+                                                   indy_error_t (*openWriterFn)(const char* config,
+                                                                                indy_handle_t* writer_handle),
+
+                                                   indy_error_t (*createBlobFn)(indy_handle_t writer_handle,
+                                                                                indy_handle_t* blob_handle),
+
+                                                   indy_error_t (*appendFn)(indy_handle_t blob_handle,
+                                                                            const indy_u8_t* bytes,
+                                                                            size_t bytes_len,
+                                                                            size_t* written_len),
+
+                                                   indy_error_t (*finalizeFn)(indy_handle_t blob_handle,
+                                                                              const indy_u8_t* hash,
+                                                                              size_t hash_len,
+                                                                              const char** location),
+
+                                                   indy_error_t (*closeWriterFn)(indy_handle_t writer_handle),
+
+                                                   indy_error_t (*openReaderFn)(const char* config,
+                                                                                indy_handle_t* reader_handle),
+
+                                                   indy_error_t (*openBlobFn)(indy_handle_t reader_handle,
+                                                                              const indy_u8_t* hash,
+                                                                              size_t hash_len,
+                                                                              const char* location,
+                                                                              indy_handle_t* blob_handle),
+
+                                                   indy_error_t (*readFn)(indy_handle_t blob_handle,
+                                                                          size_t size,
+                                                                          size_t offset,
+                                                                          const indy_u8_t** bytes,
+                                                                          size_t* bytes_len),
+
+                                                   indy_error_t (*closeBlobFn)(indy_handle_t blob_handle),
+
+                                                   indy_error_t (*closeReaderFn)(indy_handle_t reader_handle),
+
+                                                   void           (*fn)(indy_handle_t command_handle_, indy_error_t err)
+                                                  );
+
 
 #ifdef __cplusplus
 }
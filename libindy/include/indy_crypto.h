@@ -17,6 +17,7 @@ extern "C" {
     ///     "seed": string, (optional) Seed that allows deterministic key creation (if not set random one will be created).
     ///                                Can be UTF-8, base64 or hex string.
     ///     "crypto_type": string, // Optional (if not set then ed25519 curve is used); Currently only 'ed25519' value is supported for this field.
+    ///     "exportable": bool, (optional) Allows export of the key with indy_export_key. false by default.
     /// }
     /// cb: Callback that takes command result as parameter.
     ///
@@ -96,6 +97,93 @@ extern "C" {
                                                                       const char *const metadata)
                                              );
 
+    /// Exports the key with its metadata from the wallet as password encrypted JWK or PKCS#8.
+    /// Only keys created with "exportable" flag can be exported.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// verkey - The key (verkey, key id) to export.
+    /// export_config_json: Export configuration json:
+    /// {
+    ///     "key": string, Passphrase used to derive export encryption key
+    ///     "key_derivation_method": optional<string> algorithm to use for export key derivation:
+    ///                              ARGON2I_MOD - derive secured export key (used by default)
+    ///                              ARGON2I_INT - derive secured export key (less secured but faster)
+    ///     "format": optional<string> format of exported key:
+    ///                              jwk - Ed25519 private key as JWK (RFC 8037) (used by default)
+    ///                              pkcs8 - Ed25519 private key as PEM encoded PKCS#8 (RFC 8410)
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - exported_key_json - Encrypted key json that can be passed to indy_import_key.
+    ///   It is libindy specific envelope (not a JWE). Version "1.0" is the JSON serialization
+    ///   {"key": <JWK json or PEM>, "metadata": <key metadata>} encrypted with ChaCha20-Poly1305-IETF
+    ///   under a key derived from the passphrase and salt by Argon2i with parameters of key_derivation_method:
+    /// {
+    ///     "ver": string, envelope version, currently "1.0"
+    ///     "format": string, "jwk" or "pkcs8"
+    ///     "key_derivation_method": string,
+    ///     "salt": string, base64url encoded salt of key derivation
+    ///     "nonce": string, base64url encoded nonce
+    ///     "ciphertext": string, base64url encoded encrypted key and metadata
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+
+    extern indy_error_t indy_export_key(indy_handle_t     command_handle,
+                                        indy_handle_t     wallet_handle,
+                                        const char *const verkey,
+                                        const char *const export_config_json,
+
+                                        void              (*cb)(indy_handle_t     command_handle,
+                                                                indy_error_t      err,
+                                                                const char *const exported_key_json)
+                                       );
+
+    /// Imports the key with its metadata exported by indy_export_key into the wallet.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// exported_key_json - Encrypted key json returned by indy_export_key.
+    /// import_config_json: Import configuration json:
+    /// {
+    ///     "key": string, Passphrase used on export
+    ///     "exportable": optional<bool>, Allows further export of imported key. false by default.
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - verkey: Ver key of imported key pair, also used as key identifier
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+
+    extern indy_error_t indy_import_key(indy_handle_t     command_handle,
+                                        indy_handle_t     wallet_handle,
+                                        const char *const exported_key_json,
+                                        const char *const import_config_json,
+
+                                        void              (*cb)(indy_handle_t     command_handle,
+                                                                indy_error_t      err,
+                                                                const char *const verkey)
+                                       );
+
 
 
 
@@ -439,6 +527,267 @@ extern "C" {
                                                                  const indy_u8_t*  res_json_raw,
                                                                  indy_u32_t        res_json_len)
                                             );
+
+    /// Packs the content of a file by encrypting it in fixed-size segments (Experimental)
+    ///
+    /// Unlike indy_pack_message the message is never loaded into memory as a whole, so this function
+    /// is suitable for large payloads like attachments. Every segment is encrypted with its own
+    /// authentication tag, so corrupted, reordered or truncated output is detected on unpacking.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// input_path: path to the file with the message to be packed
+    /// output_path: path to the file where packed message will be written. File must not exist.
+    /// receivers: a string in the format of a json list which will contain the list of receiver's keys
+    ///                the message is being encrypted for.
+    ///                Example:
+    ///                "[<receiver edge_agent_1 verkey>, <receiver edge_agent_2 verkey>]"
+    /// sender: the sender's verkey as a string When null pointer is used in this parameter, anoncrypt is used
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_pack_file(indy_handle_t      command_handle,
+                                       indy_handle_t      wallet_handle,
+                                       const char *       input_path,
+                                       const char *       output_path,
+                                       const char *       receiver_keys,
+                                       const char *       sender,
+
+                                       void           (*cb)(indy_handle_t     command_handle_,
+                                                            indy_error_t      err)
+                                       );
+
+    /// Unpacks a file produced by indy_pack_file (Experimental)
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// input_path: path to the file produced by indy_pack_file
+    /// output_path: path to the file where unpacked message will be written. File must not exist.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// {
+    ///     sender_verkey: <sender_verkey>, (only if authcrypt was used)
+    ///     recipient_verkey: <recipient_verkey>
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_unpack_file(indy_handle_t      command_handle,
+                                         indy_handle_t      wallet_handle,
+                                         const char *       input_path,
+                                         const char *       output_path,
+
+                                         void           (*cb)(indy_handle_t     command_handle_,
+                                                              indy_error_t      err,
+                                                              const char *const res_json)
+                                         );
+
+    /// Starts packing of a message passed by caller-provided buffers (Experimental)
+    ///
+    /// The same as indy_pack_file but message and packed output are passed by parts through
+    /// indy_pack_stream_update and indy_pack_stream_finish. Concatenation of returned header and
+    /// all returned parts has the same layout as indy_pack_file output.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// receivers: json list of receiver's keys the message is being encrypted for.
+    /// sender: the sender's verkey as a string When null pointer is used in this parameter, anoncrypt is used
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// stream_handle: handle of the stream to pass to indy_pack_stream_update and indy_pack_stream_finish
+    /// header_raw: header of packed message to be written before packed parts
+    /// header_len: header length
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_pack_stream_start(indy_handle_t      command_handle,
+                                               indy_handle_t      wallet_handle,
+                                               const char *       receiver_keys,
+                                               const char *       sender,
+
+                                               void           (*cb)(indy_handle_t     command_handle_,
+                                                                    indy_error_t      err,
+                                                                    indy_handle_t     stream_handle,
+                                                                    const indy_u8_t*  header_raw,
+                                                                    indy_u32_t        header_len)
+                                               );
+
+    /// Packs the next part of a message started by indy_pack_stream_start (Experimental)
+    ///
+    /// Returned packed part can be empty if there is not enough data for a segment yet.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// stream_handle: stream handle returned by indy_pack_stream_start.
+    /// data_raw: a pointer to the first byte of the message part
+    /// data_len: the length of the message part in bytes
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// packed_raw: packed part to be written after previously returned parts
+    /// packed_len: packed part length
+    ///
+    /// #Errors
+    /// Common*
+    /// Crypto*
+    extern indy_error_t indy_pack_stream_update(indy_handle_t      command_handle,
+                                                indy_handle_t      stream_handle,
+                                                const indy_u8_t*   data_raw,
+                                                indy_u32_t         data_len,
+
+                                                void           (*cb)(indy_handle_t     command_handle_,
+                                                                     indy_error_t      err,
+                                                                     const indy_u8_t*  packed_raw,
+                                                                     indy_u32_t        packed_len)
+                                                );
+
+    /// Finishes packing of a message started by indy_pack_stream_start (Experimental)
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// stream_handle: stream handle returned by indy_pack_stream_start.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// packed_raw: the last packed part to be written after previously returned parts
+    /// packed_len: packed part length
+    ///
+    /// #Errors
+    /// Common*
+    /// Crypto*
+    extern indy_error_t indy_pack_stream_finish(indy_handle_t      command_handle,
+                                                indy_handle_t      stream_handle,
+
+                                                void           (*cb)(indy_handle_t     command_handle_,
+                                                                     indy_error_t      err,
+                                                                     const indy_u8_t*  packed_raw,
+                                                                     indy_u32_t        packed_len)
+                                                );
+
+    /// Starts unpacking of a packed message passed by caller-provided buffers (Experimental)
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// stream_handle: handle of the stream to pass to indy_unpack_stream_update and indy_unpack_stream_finish
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    extern indy_error_t indy_unpack_stream_start(indy_handle_t      command_handle,
+                                                 indy_handle_t      wallet_handle,
+
+                                                 void           (*cb)(indy_handle_t     command_handle_,
+                                                                      indy_error_t      err,
+                                                                      indy_handle_t     stream_handle)
+                                                 );
+
+    /// Unpacks the next part of a packed message (Experimental)
+    ///
+    /// Returned message part can be empty if there is not enough data for a segment yet.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// stream_handle: stream handle returned by indy_unpack_stream_start.
+    /// data_raw: a pointer to the first byte of the packed part
+    /// data_len: the length of the packed part in bytes
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// message_raw: unpacked message part
+    /// message_len: unpacked message part length
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_unpack_stream_update(indy_handle_t      command_handle,
+                                                  indy_handle_t      stream_handle,
+                                                  const indy_u8_t*   data_raw,
+                                                  indy_u32_t         data_len,
+
+                                                  void           (*cb)(indy_handle_t     command_handle_,
+                                                                       indy_error_t      err,
+                                                                       const indy_u8_t*  message_raw,
+                                                                       indy_u32_t        message_len)
+                                                  );
+
+    /// Finishes unpacking of a packed message (Experimental)
+    ///
+    /// Fails if the final segment hasn't been received.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// stream_handle: stream handle returned by indy_unpack_stream_start.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// the same json as indy_unpack_file returns
+    ///
+    /// #Errors
+    /// Common*
+    /// Crypto*
+    extern indy_error_t indy_unpack_stream_finish(indy_handle_t      command_handle,
+                                                  indy_handle_t      stream_handle,
+
+                                                  void           (*cb)(indy_handle_t     command_handle_,
+                                                                       indy_error_t      err,
+                                                                       const char *const res_json)
+                                                  );
+
+    /// Creates master seed for deterministic (hierarchical) key derivation and stores it in the wallet.
+    ///
+    /// Keys and DIDs can be derived from this seed by passing "hd_path" to indy_create_key,
+    /// indy_create_and_store_my_did and indy_replace_keys_start. Derived keys can be restored
+    /// with indy_recover_hd_keys. Only one master seed can be stored in the wallet.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// config_json: Master seed configuration as json. Example:
+    /// {
+    ///     "mnemonic": string, (optional) BIP-39 english mnemonic to restore master seed from.
+    ///     "passphrase": string, (optional) BIP-39 passphrase to use together with mnemonic.
+    ///     "seed": string, (optional) Raw master seed (16-64 bytes) as hex string. Can't be used together with mnemonic.
+    /// }
+    /// If neither mnemonic nor seed is set then new 24 words mnemonic will be generated.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - mnemonic: BIP-39 mnemonic of the master seed (empty string if raw seed was used).
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_create_hd_master_seed(indy_handle_t     command_handle,
+                                                   indy_handle_t     wallet_handle,
+                                                   const char *const config_json,
+
+                                                   void              (*cb)(indy_handle_t     command_handle_,
+                                                                           indy_error_t      err,
+                                                                           const char *const mnemonic)
+                                                  );
+
 #ifdef __cplusplus
 }
 #endif
@@ -25,6 +25,11 @@ extern "C" {
     ///     "crypto_type": string, (optional; if not set then ed25519 curve is used;
     ///               currently only 'ed25519' value is supported for this field)
     ///     "cid": bool, (optional; if not set then false is used;)
+    ///     "hd_path": string, (optional) SLIP-0010 derivation path (for example "m/44'/0'/1'") to derive keys from
+    ///                                   the wallet master seed (see indy_create_hd_master_seed). Can't be used together with seed.
+    ///     "method_name": string, (optional) Method of DID to create: "sov" for fully qualified did:sov, "key" for did:key or "peer" for did:peer.
+    ///                                       If not set unqualified Indy DID is created. Can't be used together with did and cid.
+    ///     "numalgo": int, (optional) did:peer numalgo: 0 (inception key) or 2 (default).
     /// }
     /// cb: Callback that takes command result as parameter.
     ///
@@ -116,6 +121,78 @@ extern "C" {
                                                                      indy_error_t  err)
                                                );
 
+    /// Creates key rotation notice for an existing DID (owned by the caller of the library)
+    /// with started key replacement (see indy_replace_keys_start).
+    ///
+    /// Notice is signed by the current key of DID and can be sent to the other side of pairwise
+    /// relationships to let them verify and apply the new key (see indy_apply_key_rotation_notice).
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// did: DID stored in the wallet
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - notice_json: Key rotation notice json:
+    ///     {
+    ///         "did": string, - DID which key is rotated
+    ///         "verkey": string, - current verkey of DID
+    ///         "new_verkey": string, - verkey replacing the current one
+    ///         "timestamp": int, - time of notice creation (seconds since epoch)
+    ///         "signature": string, - base58 encoded signature of "did|verkey|new_verkey|timestamp" by current key
+    ///     }
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+
+    extern indy_error_t indy_create_key_rotation_notice(indy_handle_t command_handle,
+                                                        indy_handle_t wallet_handle,
+                                                        const char *  did,
+
+                                                        void           (*cb)(indy_handle_t command_handle_,
+                                                                             indy_error_t  err,
+                                                                             const char *const   notice_json)
+                                                       );
+
+    /// Verifies key rotation notice received from the other side of pairwise relationship
+    /// and replaces their verkey stored in the wallet.
+    ///
+    /// Notice must be signed by the currently stored verkey of their DID and be newer than
+    /// the last applied notice. Pairwise must exist for their DID. Both their DID record
+    /// and pairwise (its "their_key_rotated_at" field) are updated.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// notice_json: Key rotation notice json (see indy_create_key_rotation_notice).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+
+    extern indy_error_t indy_apply_key_rotation_notice(indy_handle_t command_handle,
+                                                       indy_handle_t wallet_handle,
+                                                       const char *  notice_json,
+
+                                                       void           (*cb)(indy_handle_t command_handle_,
+                                                                            indy_error_t  err)
+                                                      );
+
     /// Saves their DID for a pairwise connection in a secured Wallet,
     /// so that it can be used to verify transaction.
     ///
@@ -225,6 +302,77 @@ extern "C" {
                                                                      const char *const key)
                                              );
 
+    /// Returns ver key (key id) which was active for the given DID at the given time.
+    ///
+    /// Lookup is performed in the local wallet only using the history of DID keys.
+    /// History is tracked for DIDs created by "indy_create_and_store_my_did" and stored by
+    /// "indy_store_their_did" and is updated by "indy_replace_keys_apply" and "indy_apply_key_rotation_notice".
+    /// For DIDs stored before the history was introduced the current key is treated as active since epoch.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// did - The DID to resolve key.
+    /// timestamp - Time (seconds since epoch) to resolve key at.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - key - The DIDs ver key (key id) active at the given time.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+
+    extern indy_error_t indy_key_for_local_did_at(indy_handle_t     command_handle,
+                                                 indy_handle_t     wallet_handle,
+                                                 const char *const did,
+                                                 indy_u64_t        timestamp,
+
+                                                 void              (*cb)(indy_handle_t     command_handle,
+                                                                         indy_error_t      err,
+                                                                         const char *const key)
+                                                );
+
+    /// Returns the history of ver keys for the given DID stored in the local wallet.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// did - The DID to list keys.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - keys_json - The DIDs keys in order of creation:
+    ///   [{
+    ///       "verkey": string, - ver key (key id)
+    ///       "activated_at": Optional<int>, - time (seconds since epoch) key became active.
+    ///                                        Absent for temporary key created by indy_replace_keys_start.
+    ///       "deactivated_at": Optional<int>, - time (seconds since epoch) key was replaced. Absent for current key.
+    ///   }]
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+
+    extern indy_error_t indy_list_did_keys(indy_handle_t     command_handle,
+                                          indy_handle_t     wallet_handle,
+                                          const char *const did,
+
+                                          void              (*cb)(indy_handle_t     command_handle,
+                                                                  indy_error_t      err,
+                                                                  const char *const keys_json)
+                                         );
+
     /// Set/replaces endpoint information for the given DID.
     ///
     /// #Params
@@ -424,6 +572,43 @@ extern "C" {
                                                                  const char *const verkey)
                                             );
 
+    /// Re-derives keys and DIDs from the wallet master seed (see indy_create_hd_master_seed)
+    /// and stores them in the wallet. Already existing keys and DIDs are kept untouched.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// recovery_json: derivation paths to recover:
+    /// {
+    ///     "keys": [string], (optional) derivation paths of keys created by indy_create_key
+    ///     "dids": [string], (optional) derivation paths of DIDs created by indy_create_and_store_my_did
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - recovered_json: recovered keys and DIDs in the same order as paths:
+    /// {
+    ///     "keys": [<verkey>],
+    ///     "dids": [{"did": <did>, "verkey": <verkey>}],
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_recover_hd_keys(indy_handle_t command_handle,
+                                             indy_handle_t wallet_handle,
+                                             const char *  recovery_json,
+
+                                             void           (*cb)(indy_handle_t     xcommand_handle,
+                                                                  indy_error_t      err,
+                                                                  const char *const recovered_json)
+                                            );
+
 #ifdef __cplusplus
 }
 #endif
@@ -167,6 +167,64 @@ extern "C" {
                                                                      const char*   signed_request_json)
                                                 );
 
+    /// Verifies signatures of the request.
+    ///
+    /// Signatures are checked against the request serialized the same way as for signing
+    /// (see indy_sign_request and indy_multi_sign_request). Verkey of each signer is taken from
+    /// verkeys_json if provided, otherwise from my or their DID stored in the wallet.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// request_json: Signed request data json.
+    /// verkeys_json: (Optional) map of signer DIDs to their verkeys:
+    ///     {
+    ///         "<did>": "<verkey>", // abbreviated verkeys are supported
+    ///         ...
+    ///     }
+    /// quorum_json: (Optional) required signatures:
+    ///     {
+    ///         "signers": Optional<[string]>, // DIDs whose valid signatures are counted (any signer if not set)
+    ///         "required": Optional<[string]>, // DIDs whose valid signatures must be present
+    ///         "threshold": Optional<int>, // minimal number of counted valid signatures (1 by default)
+    ///     }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Signatures report json:
+    ///     {
+    ///         "signatures": {
+    ///             "<did>": {
+    ///                 "valid": bool,
+    ///                 "verkey": Optional<string>, // resolved verkey of the signer
+    ///                 "error": Optional<string>, // reason signature can't be verified
+    ///             },
+    ///             ...
+    ///         },
+    ///         "valid_signers": [string],
+    ///         "invalid_signers": [string],
+    ///         "missing_required": [string],
+    ///         "threshold": int,
+    ///         "quorum_met": bool
+    ///     }
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Ledger*
+    /// Crypto*
+
+    extern indy_error_t indy_verify_request_signatures(indy_handle_t command_handle,
+                                                       indy_handle_t  wallet_handle,
+                                                       const char *   request_json,
+                                                       const char *   verkeys_json,
+                                                       const char *   quorum_json,
+
+                                                       void           (*cb)(indy_handle_t command_handle_,
+                                                                            indy_error_t  err,
+                                                                            const char*   report_json)
+                                                       );
+
     /// Builds a request to get a DDO.
     ///
     /// #Params
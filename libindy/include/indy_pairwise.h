@@ -134,6 +134,119 @@ extern "C" {
                                                                        indy_error_t   err)
                                                   );
 
+    /// Replaces searchable tags of pairwise associated with Did.
+    /// Tags can be used to search pairwise with indy_search_pairwise.
+    ///
+    /// #Params
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// command_handle: command handle to map callback to user context.
+    /// their_did: encoded Did
+    /// tags_json: tags of pairwise as json, for example label, state or last contact time:
+    ///     {
+    ///         "tagName1": <str>, // string tag (will be stored encrypted)
+    ///         "~tagName2": <str>, // string tag (will be stored un-encrypted)
+    ///     }
+    ///     Note that only un-encrypted tags can be used in range ($gt, $lt ...) queries.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_set_pairwise_tags(indy_handle_t command_handle,
+                                               indy_handle_t wallet_handle,
+                                               const char *  their_did,
+                                               const char *  tags_json,
+
+                                               void          (*cb)(indy_handle_t  command_handle_,
+                                                                   indy_error_t   err)
+                                              );
+
+    /// Search for pairwise stored in the wallet.
+    /// Pairwise can be filtered by tags set with indy_set_pairwise_tags.
+    ///
+    /// Instead of immediately returning of fetched pairwise
+    /// this call returns search_handle that can be used later
+    /// to fetch records by small batches (with indy_fetch_pairwise_search).
+    ///
+    /// #Params
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// command_handle: command handle to map callback to user context.
+    /// query_json: Wql query filter for pairwise searching based on tags.
+    /// where query: indy-sdk/doc/design/011-wallet-query-language/README.md
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// search_handle: Search handle that can be used later to fetch records by small batches (with indy_fetch_pairwise_search)
+    /// total_count: Total count of records
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_search_pairwise(indy_handle_t command_handle,
+                                             indy_handle_t wallet_handle,
+                                             const char *  query_json,
+
+                                             void          (*cb)(indy_handle_t  command_handle_,
+                                                                 indy_error_t   err,
+                                                                 indy_handle_t  search_handle,
+                                                                 indy_u32_t     total_count)
+                                            );
+
+    /// Fetch next pairwise for search.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// search_handle: Search handle (created by indy_search_pairwise)
+    /// count: Count of pairwise to fetch
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// pairwise_list_json: List of pairwise:
+    ///     [{
+    ///         "their_did": string,
+    ///         "my_did": string,
+    ///         "metadata": Optional<string>,
+    ///         "their_key_rotated_at": Optional<int>, - timestamp of the last applied key rotation notice
+    ///         "tags": {"tagName1": string, ...}
+    ///     }]
+    /// NOTE: The list of length less than the requested count means pairwise search iterator is completed.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_fetch_pairwise_search(indy_handle_t command_handle,
+                                                   indy_handle_t search_handle,
+                                                   indy_u32_t    count,
+
+                                                   void          (*cb)(indy_handle_t  command_handle_,
+                                                                       indy_error_t   err,
+                                                                       const char*    pairwise_list_json)
+                                                  );
+
+    /// Close pairwise search (make search handle invalid)
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// search_handle: Search handle (created by indy_search_pairwise)
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_close_pairwise_search(indy_handle_t command_handle,
+                                                   indy_handle_t search_handle,
+
+                                                   void          (*cb)(indy_handle_t  command_handle_,
+                                                                       indy_error_t   err)
+                                                  );
+
 
 #ifdef __cplusplus
 }
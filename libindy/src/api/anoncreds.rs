@@ -8,18 +8,19 @@ use commands::anoncreds::AnoncredsCommand;
 use commands::anoncreds::issuer::IssuerCommand;
 use commands::anoncreds::prover::ProverCommand;
 use commands::anoncreds::verifier::VerifierCommand;
-use domain::anoncreds::schema::{Schema, AttributeNames};
+use domain::anoncreds::schema::{Schema, AttributeNames, AttributeTypes};
 use domain::anoncreds::credential_definition::{CredentialDefinition, CredentialDefinitionConfig};
 use domain::anoncreds::credential_offer::CredentialOffer;
 use domain::anoncreds::credential_request::{CredentialRequest, CredentialRequestMetadata};
 use domain::anoncreds::credential::{Credential, AttributeValues};
-use domain::anoncreds::revocation_registry_definition::{RevocationRegistryConfig, RevocationRegistryDefinition};
+use domain::anoncreds::revocation_registry_definition::{RevocationRegistryConfig, RevocationRegistryDefinition, RevocationRegistryRotationConfig};
 use domain::anoncreds::revocation_registry_delta::RevocationRegistryDelta;
 use domain::anoncreds::proof::Proof;
 use domain::anoncreds::proof_request::{ProofRequest, ProofRequestExtraQuery};
 use domain::anoncreds::requested_credential::RequestedCredentials;
 use domain::anoncreds::revocation_registry::RevocationRegistry;
 use domain::anoncreds::revocation_state::RevocationState;
+use domain::wallet::Tags;
 use utils::ctypes;
 
 use self::libc::c_char;
@@ -82,6 +83,7 @@ pub extern fn indy_issuer_create_schema(command_handle: IndyHandle,
                     name,
                     version,
                     attrs,
+                    None,
                     Box::new(move |result| {
                         let (err, id, schema_json) = prepare_result_2!(result, String::new(), String::new());
                         trace!("indy_crypto_cl_credential_public_key_to_json: id: {:?}, schema_json: {:?}", id, schema_json);
@@ -98,6 +100,90 @@ pub extern fn indy_issuer_create_schema(command_handle: IndyHandle,
     res
 }
 
+/// Create credential schema entity with type hints of its attributes.
+///
+/// Works as indy_issuer_create_schema, but attribute types are kept in schema json and published on the ledger
+/// with SCHEMA transaction (see indy_build_schema_request), so verifiers check revealed values by them.
+/// Schema without types requires standard encoding of all values. indy_issuer_create_and_store_credential_def stores types of the schema
+/// in the wallet and indy_issuer_create_credential uses them to encode attribute values automatically:
+///     int - 32-bit integer encoded as itself, can be used in predicates
+///     date - date in "YYYY-MM-DD" format encoded as number of days since Unix Epoch, can be used in predicates
+///            with "p_value" specified either as the number of days or as date in the same format
+///     string - value with standard encoding
+///     enum - value that must be one of the allowed values, has standard encoding
+///
+/// #Params
+/// command_handle: command handle to map callback to user context
+/// issuer_did: DID of schema issuer
+/// name: a name the schema
+/// version: a version of the schema
+/// attr_types_json: schema attributes with their types (the number of attributes should be less or equal than 125)
+///     {
+///         "<attr_name>": {"type": "int"},
+///         "<attr_name>": {"type": "date"},
+///         "<attr_name>": {"type": "string"},
+///         "<attr_name>": {"type": "enum", "values": [string, ...]},
+///         ...
+///     }
+/// cb: Callback that takes command result as parameter
+///
+/// #Returns
+/// schema_id: identifier of created schema
+/// schema_json: schema as json with "attrTypes" field
+///
+/// #Errors
+/// Common*
+/// Anoncreds*
+#[no_mangle]
+pub extern fn indy_issuer_create_schema_with_types(command_handle: IndyHandle,
+                                                   issuer_did: *const c_char,
+                                                   name: *const c_char,
+                                                   version: *const c_char,
+                                                   attr_types_json: *const c_char,
+                                                   cb: Option<extern fn(command_handle_: IndyHandle, err: ErrorCode,
+                                                                        schema_id: *const c_char, schema_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_create_schema_with_types: >>> issuer_did: {:?}, name: {:?}, version: {:?}, attr_types_json: {:?}", issuer_did, name, version, attr_types_json);
+
+    check_useful_c_str!(issuer_did, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(name, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(version, ErrorCode::CommonInvalidParam4);
+    check_useful_json!(attr_types_json, ErrorCode::CommonInvalidParam5, AttributeTypes);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_issuer_create_schema_with_types: entity >>> issuer_did: {:?}, name: {:?}, version: {:?}, attr_types_json: {:?}", issuer_did, name, version, attr_types_json);
+
+    if attr_types_json.is_empty() {
+        set_current_error(&err_msg(IndyErrorKind::InvalidStructure, "Empty list of Schema attributes has been passed"));
+        return ErrorCode::CommonInvalidStructure;
+    }
+
+    let attrs: AttributeNames = attr_types_json.keys().cloned().collect();
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::CreateSchema(
+                    issuer_did,
+                    name,
+                    version,
+                    attrs,
+                    Some(attr_types_json),
+                    Box::new(move |result| {
+                        let (err, id, schema_json) = prepare_result_2!(result, String::new(), String::new());
+                        trace!("indy_issuer_create_schema_with_types: id: {:?}, schema_json: {:?}", id, schema_json);
+                        let id = ctypes::string_to_cstring(id);
+                        let schema_json = ctypes::string_to_cstring(schema_json);
+                        cb(command_handle, err, id.as_ptr(), schema_json.as_ptr())
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_create_schema_with_types: <<< res: {:?}", res);
+
+    res
+}
+
 /// Create credential definition entity that encapsulates credentials issuer DID, credential schema, secrets used for signing credentials
 /// and secrets used for credentials revocation.
 ///
@@ -178,6 +264,84 @@ pub extern fn indy_issuer_create_and_store_credential_def(command_handle: IndyHa
     res
 }
 
+/// Rotate keys of credential definition: create successor credential definition for the same issuer and schema
+/// under a new tag and link it with predecessor in the wallet.
+///
+/// Predecessor credential definition becomes retired: indy_issuer_create_credential_offer, indy_issuer_create_and_store_revoc_reg
+/// and indy_issuer_rotate_revoc_reg called with predecessor id use the active (latest) successor automatically.
+/// Credentials issued with predecessor stay verifiable and revocable.
+///
+/// Public part of successor is returned as json intended to be published by CRED_DEF transaction as usual.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// issuer_did: a DID of the issuer signing cred_def transaction to the Ledger
+/// cred_def_id: id of credential definition to rotate (must be stored in the wallet and not rotated yet)
+/// schema_json: credential schema the rotated credential definition was created for as a json
+/// tag: tag of successor credential definition
+/// config_json: (optional) type-specific configuration of successor credential definition as json (see indy_issuer_create_and_store_credential_def).
+///     By default revocation is supported if rotated credential definition supports it.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// cred_def_id: identifier of successor credential definition
+/// cred_def_json: public part of successor credential definition
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Anoncreds*
+#[no_mangle]
+pub extern fn indy_issuer_rotate_credential_def(command_handle: IndyHandle,
+                                                wallet_handle: IndyHandle,
+                                                issuer_did: *const c_char,
+                                                cred_def_id: *const c_char,
+                                                schema_json: *const c_char,
+                                                tag: *const c_char,
+                                                config_json: *const c_char,
+                                                cb: Option<extern fn(command_handle_: IndyHandle, err: ErrorCode,
+                                                                     cred_def_id: *const c_char,
+                                                                     cred_def_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_rotate_credential_def: >>> wallet_handle: {:?}, issuer_did: {:?}, cred_def_id: {:?}, schema_json: {:?}, tag: {:?}, \
+    config_json: {:?}", wallet_handle, issuer_did, cred_def_id, schema_json, tag, config_json);
+
+    check_useful_c_str!(issuer_did, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(cred_def_id, ErrorCode::CommonInvalidParam4);
+    check_useful_json!(schema_json, ErrorCode::CommonInvalidParam5, Schema);
+    check_useful_c_str!(tag, ErrorCode::CommonInvalidParam6);
+    check_useful_opt_json!(config_json, ErrorCode::CommonInvalidParam7, CredentialDefinitionConfig);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+
+    trace!("indy_issuer_rotate_credential_def: entities >>> wallet_handle: {:?}, issuer_did: {:?}, cred_def_id: {:?}, schema_json: {:?}, tag: {:?}, \
+    config_json: {:?}", wallet_handle, issuer_did, cred_def_id, schema_json, tag, config_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::RotateCredentialDefinition(
+                    wallet_handle,
+                    issuer_did,
+                    cred_def_id,
+                    schema_json,
+                    tag,
+                    config_json,
+                    Box::new(move |result| {
+                        let (err, cred_def_id, cred_def_json) = prepare_result_2!(result, String::new(), String::new());
+                        trace!("indy_issuer_rotate_credential_def: cred_def_id: {:?}, cred_def_json: {:?}", cred_def_id, cred_def_json);
+                        let cred_def_id = ctypes::string_to_cstring(cred_def_id);
+                        let cred_def_json = ctypes::string_to_cstring(cred_def_json);
+                        cb(command_handle, err, cred_def_id.as_ptr(), cred_def_json.as_ptr())
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_rotate_credential_def: <<< res: {:?}", res);
+
+    res
+}
+
 /// Create a new revocation registry for the given credential definition as tuple of entities
 /// - Revocation registry definition that encapsulates credentials definition reference, revocation type specific configuration and
 ///   secrets used for credentials revocation
@@ -278,6 +442,97 @@ pub extern fn indy_issuer_create_and_store_revoc_reg(command_handle: IndyHandle,
     res
 }
 
+/// Check the state of the active revocation registry of the given credential definition and pre-create
+/// the next one when the active registry reaches the issuance threshold.
+///
+/// The wallet keeps bookkeeping of revocation registries created for each credential definition by
+/// indy_issuer_create_and_store_revoc_reg: the list of registries, the active one, the pre-created next one
+/// and the total number of issued credentials. When the active registry becomes full the next registry
+/// becomes active (on credential issuance or on this call).
+///
+/// The next registry is created with the same type as the active one and its tails are written
+/// with provided blob storage writer. The returned ledger requests (REVOC_REG_DEF and REVOC_REG_ENTRY)
+/// must be signed and published before credentials are issued from the next registry.
+///
+/// The next registry is stored before it is recorded in the bookkeeping with a single write, so if this call fails
+/// the bookkeeping stays unchanged and the call can be repeated.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// issuer_did: a DID of the issuer signing transactions to the Ledger
+/// cred_def_id: id of stored in ledger credential definition
+/// config_json: rotation configuration as json:
+/// {
+///     "threshold": (optional) number of credentials issued from the active registry after which the next registry is created
+///                  (default max_cred_num of the active registry),
+///     "tag": (optional) tag of the next registry; must not be used by other registry of the credential definition
+///            (default number of registries created for the credential definition, or the next free number),
+///     "issuance_type": (optional) type of issuance of the next registry (default issuance type of the active registry),
+///     "max_cred_num": (optional) maximum number of credentials the next registry can process (default max_cred_num of the active registry)
+/// }
+/// tails_writer_handle: handle of blob storage to store tails of the next registry
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// rotation_json: state of revocation registries of the credential definition
+/// {
+///     "active_rev_reg_id": string, - identifier of the registry to issue credentials from
+///     "active_issued": int, - number of credentials issued from the active registry
+///     "active_max_cred_num": int, - capacity of the active registry
+///     "next_rev_reg_id": Optional<string>, - identifier of the pre-created next registry
+///     "rev_reg_def": Optional<object>, - public part of the registry definition created by this call
+///     "ledger_requests": [string] - REVOC_REG_DEF and REVOC_REG_ENTRY requests for the registry created by this call
+/// }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Anoncreds*
+#[no_mangle]
+pub extern fn indy_issuer_rotate_revoc_reg(command_handle: IndyHandle,
+                                           wallet_handle: IndyHandle,
+                                           issuer_did: *const c_char,
+                                           cred_def_id: *const c_char,
+                                           config_json: *const c_char,
+                                           tails_writer_handle: IndyHandle,
+                                           cb: Option<extern fn(command_handle_: IndyHandle, err: ErrorCode,
+                                                                rotation_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_rotate_revoc_reg: >>> wallet_handle: {:?}, issuer_did: {:?}, cred_def_id: {:?}, config_json: {:?}, tails_writer_handle: {:?}",
+           wallet_handle, issuer_did, cred_def_id, config_json, tails_writer_handle);
+
+    check_useful_c_str!(issuer_did, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(cred_def_id, ErrorCode::CommonInvalidParam4);
+    check_useful_json!(config_json, ErrorCode::CommonInvalidParam5, RevocationRegistryRotationConfig);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_issuer_rotate_revoc_reg: entities >>> wallet_handle: {:?}, issuer_did: {:?}, cred_def_id: {:?}, config_json: {:?}, tails_writer_handle: {:?}",
+           wallet_handle, issuer_did, cred_def_id, config_json, tails_writer_handle);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::RotateRevocationRegistry(
+                    wallet_handle,
+                    issuer_did,
+                    cred_def_id,
+                    config_json,
+                    tails_writer_handle,
+                    Box::new(move |result| {
+                        let (err, rotation_json) = prepare_result_1!(result, String::new());
+                        trace!("indy_issuer_rotate_revoc_reg: rotation_json: {:?}", rotation_json);
+                        let rotation_json = ctypes::string_to_cstring(rotation_json);
+                        cb(command_handle, err, rotation_json.as_ptr())
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_rotate_revoc_reg: <<< res: {:?}", res);
+
+    res
+}
+
 /// Create credential offer that will be used by Prover for
 /// credential request creation. Offer includes nonce and key correctness proof
 /// for authentication between protocol steps and integrity checking.
@@ -358,6 +613,9 @@ pub extern fn indy_issuer_create_credential_offer(command_handle: IndyHandle,
 ///      "attr1" : {"raw": "value1", "encoded": "value1_as_int" },
 ///      "attr2" : {"raw": "value1", "encoded": "value1_as_int" }
 ///     }
+///     "encoded" values must be built by indy_encode_credential_attribute, otherwise proofs will not be verified.
+///     "encoded" can be omitted: then it is built automatically according to the attribute type
+///     (see indy_issuer_create_schema_with_types) or by standard encoding for untyped attributes.
 /// rev_reg_id: id of revocation registry stored in the wallet
 /// blob_storage_reader_handle: configuration of blob storage reader handle that will allow to read revocation tails
 /// cb: Callback that takes command result as parameter.
@@ -416,6 +674,7 @@ pub extern fn indy_issuer_create_credential(command_handle: IndyHandle,
                     cred_values_json,
                     rev_reg_id,
                     blob_storage_reader_handle,
+                    None,
                     Box::new(move |result| {
                         let (err, cred_json, revoc_id, revoc_reg_delta_json) = prepare_result_3!(result, String::new(), None, None);
                         trace!("indy_issuer_create_credential: cred_json: {:?}, revoc_id: {:?}, revoc_reg_delta_json: {:?}",
@@ -436,126 +695,453 @@ pub extern fn indy_issuer_create_credential(command_handle: IndyHandle,
     res
 }
 
-/// Revoke a credential identified by a cred_revoc_id (returned by indy_issuer_create_credential).
+/// Issue Credential as indy_issuer_create_credential does and record it into the issuance log of the wallet.
 ///
-/// The corresponding credential definition and revocation registry must be already
-/// created an stored into the wallet.
-///
-/// This call returns revoc registry delta as json file intended to be shared as REVOC_REG_ENTRY transaction.
-/// Note that it is possible to accumulate deltas to reduce ledger load.
+/// The issuance log entry keeps identifiers of the credential, cred_revoc_id, a keyed hash of attribute raw values
+/// and caller's correlation tags. Revocation status of the entry is maintained by indy_issuer_revoke_credential
+/// and indy_issuer_revoke_credentials. Entries can be found by indy_issuer_search_issued_credentials.
 ///
 /// #Params
-/// command_handle: command handle to map callback to user context.
 /// wallet_handle: wallet handler (created by open_wallet).
-/// blob_storage_reader_cfg_handle: configuration of blob storage reader handle that will allow to read revocation tails
-/// rev_reg_id: id of revocation registry stored in wallet
-/// cred_revoc_id: local id for revocation info
+/// command_handle: command handle to map callback to user context.
+/// cred_offer_json: a cred offer created by indy_issuer_create_credential_offer
+/// cred_req_json: a credential request created by indy_prover_create_credential_req
+/// cred_values_json: a credential containing attribute values for each of requested attribute names (see indy_issuer_create_credential)
+/// rev_reg_id: id of revocation registry stored in the wallet
+/// blob_storage_reader_handle: configuration of blob storage reader handle that will allow to read revocation tails
+/// tags_json: (optional) caller's correlation tags of the issuance log entry as json:
+///     {
+///         "tagName1": <str>, // string tag (will be stored encrypted)
+///         "~tagName2": <str>, // string tag (will be stored un-encrypted)
+///     }
+///     Tags "schema_id", "cred_def_id", "rev_reg_id", "cred_rev_id", "attrs_hash" and "revoked" are reserved.
 /// cb: Callback that takes command result as parameter.
 ///
 /// #Returns
-/// revoc_reg_delta_json: Revocation registry delta json with a revoked credential
+/// cred_json: Credential json containing signed credential values (see indy_issuer_create_credential)
+/// cred_revoc_id: local id for revocation info (Can be used for revocation of this credential)
+/// revoc_reg_delta_json: Revocation registry delta json with a newly issued credential
 ///
 /// #Errors
 /// Annoncreds*
 /// Common*
 /// Wallet*
 #[no_mangle]
-pub extern fn indy_issuer_revoke_credential(command_handle: IndyHandle,
-                                            wallet_handle: IndyHandle,
-                                            blob_storage_reader_cfg_handle: IndyHandle,
-                                            rev_reg_id: *const c_char,
-                                            cred_revoc_id: *const c_char,
-                                            cb: Option<extern fn(command_handle_: IndyHandle, err: ErrorCode,
-                                                                 revoc_reg_delta_json: *const c_char)>) -> ErrorCode {
-    trace!("indy_issuer_revoke_credential: >>> wallet_handle: {:?}, blob_storage_reader_cfg_handle: {:?}, rev_reg_id: {:?}, cred_revoc_id: {:?}",
-           wallet_handle, blob_storage_reader_cfg_handle, rev_reg_id, cred_revoc_id);
+pub extern fn indy_issuer_create_credential_with_log(command_handle: IndyHandle,
+                                                     wallet_handle: IndyHandle,
+                                                     cred_offer_json: *const c_char,
+                                                     cred_req_json: *const c_char,
+                                                     cred_values_json: *const c_char,
+                                                     rev_reg_id: *const c_char,
+                                                     blob_storage_reader_handle: IndyHandle,
+                                                     tags_json: *const c_char,
+                                                     cb: Option<extern fn(command_handle_: IndyHandle, err: ErrorCode,
+                                                                          cred_json: *const c_char,
+                                                                          cred_revoc_id: *const c_char,
+                                                                          revoc_reg_delta_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_create_credential_with_log: >>> wallet_handle: {:?}, cred_offer_json: {:?}, cred_req_json: {:?}, cred_values_json: {:?}, rev_reg_id: {:?}, \
+    blob_storage_reader_handle: {:?}, tags_json: {:?}", wallet_handle, cred_offer_json, cred_req_json, cred_values_json, rev_reg_id, blob_storage_reader_handle, tags_json);
 
-    check_useful_c_str!(rev_reg_id, ErrorCode::CommonInvalidParam4);
-    check_useful_c_str!(cred_revoc_id, ErrorCode::CommonInvalidParam5);
-    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+    check_useful_json!(cred_offer_json, ErrorCode::CommonInvalidParam3, CredentialOffer);
+    check_useful_json!(cred_req_json, ErrorCode::CommonInvalidParam4, CredentialRequest);
+    check_useful_json!(cred_values_json, ErrorCode::CommonInvalidParam5, HashMap<String, AttributeValues>);
+    check_useful_opt_c_str!(rev_reg_id, ErrorCode::CommonInvalidParam6);
+    check_useful_opt_json!(tags_json, ErrorCode::CommonInvalidParam8, Tags);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam9);
 
-    trace!("indy_issuer_revoke_credential: entities >>> wallet_handle: {:?}, blob_storage_reader_cfg_handle: {:?}, rev_reg_id: {:?}, cred_revoc_id: {:?}",
-           wallet_handle, blob_storage_reader_cfg_handle, rev_reg_id, secret!(cred_revoc_id.as_str()));
+    let blob_storage_reader_handle = if blob_storage_reader_handle != -1 { Some(blob_storage_reader_handle) } else { None };
+
+    trace!("indy_issuer_create_credential_with_log: entities >>> wallet_handle: {:?}, cred_offer_json: {:?}, cred_req_json: {:?}, cred_values_json: {:?}, rev_reg_id: {:?}, \
+    blob_storage_reader_handle: {:?}, tags_json: {:?}", wallet_handle, cred_offer_json, secret!(&cred_req_json), secret!(&cred_values_json), secret!(&rev_reg_id),
+           blob_storage_reader_handle, tags_json);
 
     let result = CommandExecutor::instance()
         .send(Command::Anoncreds(
             AnoncredsCommand::Issuer(
-                IssuerCommand::RevokeCredential(
+                IssuerCommand::CreateCredential(
                     wallet_handle,
-                    blob_storage_reader_cfg_handle,
+                    cred_offer_json,
+                    cred_req_json,
+                    cred_values_json,
                     rev_reg_id,
-                    cred_revoc_id,
+                    blob_storage_reader_handle,
+                    Some(tags_json.unwrap_or(Tags::new())),
                     Box::new(move |result| {
-                        let (err, revoc_reg_update_json) = prepare_result_1!(result, String::new());
-                        trace!("indy_issuer_revoke_credential: revoc_reg_update_json: {:?}", revoc_reg_update_json);
-                        let revoc_reg_update_json = ctypes::string_to_cstring(revoc_reg_update_json);
-                        cb(command_handle, err, revoc_reg_update_json.as_ptr())
+                        let (err, cred_json, revoc_id, revoc_reg_delta_json) = prepare_result_3!(result, String::new(), None, None);
+                        trace!("indy_issuer_create_credential_with_log: cred_json: {:?}, revoc_id: {:?}, revoc_reg_delta_json: {:?}",
+                               secret!(cred_json.as_str()), secret!(&revoc_id), revoc_reg_delta_json);
+                        let cred_json = ctypes::string_to_cstring(cred_json);
+                        let revoc_id = revoc_id.map(ctypes::string_to_cstring);
+                        let revoc_reg_delta_json = revoc_reg_delta_json.map(ctypes::string_to_cstring);
+                        cb(command_handle, err, cred_json.as_ptr(),
+                           revoc_id.as_ref().map(|id| id.as_ptr()).unwrap_or(ptr::null()),
+                           revoc_reg_delta_json.as_ref().map(|delta| delta.as_ptr()).unwrap_or(ptr::null()))
                     })
                 ))));
 
     let res = prepare_result!(result);
 
-    trace!("indy_issuer_revoke_credential: <<< res: {:?}", res);
+    trace!("indy_issuer_create_credential_with_log: <<< res: {:?}", res);
 
     res
 }
 
-/*/// Recover a credential identified by a cred_revoc_id (returned by indy_issuer_create_credential).
+/// Search for issuance log entries of credentials issued by indy_issuer_create_credential_with_log.
 ///
-/// The corresponding credential definition and revocation registry must be already
-/// created an stored into the wallet.
-///
-/// This call returns revoc registry delta as json file intended to be shared as REVOC_REG_ENTRY transaction.
-/// Note that it is possible to accumulate deltas to reduce ledger load.
+/// Instead of immediately returning of fetched entries
+/// this call returns search_handle that can be used later
+/// to fetch entries by small batches (with indy_issuer_fetch_issued_credentials).
 ///
 /// #Params
 /// command_handle: command handle to map callback to user context.
 /// wallet_handle: wallet handler (created by open_wallet).
-/// blob_storage_reader_cfg_handle: configuration of blob storage reader handle that will allow to read revocation tails
-/// rev_reg_id: id of revocation registry stored in wallet
-/// cred_revoc_id: local id for revocation info
+/// query_json: (optional) Wql query filter for issuance log entries searching based on tags:
+///     {
+///         "schema_id": string,
+///         "cred_def_id": string,
+///         "rev_reg_id": string, ("None" for non-revocable credentials)
+///         "cred_rev_id": string, ("None" for non-revocable credentials)
+///         "attrs_hash": string,
+///         "revoked": "true" | "false",
+///         <caller's tag name>: string
+///     }
+///     See query syntax in indy_prover_search_credentials
 /// cb: Callback that takes command result as parameter.
 ///
 /// #Returns
-/// revoc_reg_delta_json: Revocation registry delta json with a recovered credential
+/// search_handle: Search handle that can be used later to fetch entries by small batches (with indy_issuer_fetch_issued_credentials)
+/// total_count: Total count of entries
 ///
 /// #Errors
 /// Annoncreds*
 /// Common*
 /// Wallet*
 #[no_mangle]
-pub extern fn indy_issuer_recover_credential(command_handle: IndyHandle,
-                                             wallet_handle: IndyHandle,
-                                             blob_storage_reader_cfg_handle: IndyHandle,
-                                             rev_reg_id: *const c_char,
-                                             cred_revoc_id: *const c_char,
-                                             cb: Option<extern fn(command_handle_: IndyHandle, err: ErrorCode,
-                                                                  revoc_reg_delta_json: *const c_char,
-                                             )>) -> ErrorCode {
-    check_useful_c_str!(rev_reg_id, ErrorCode::CommonInvalidParam4);
-    check_useful_c_str!(cred_revoc_id, ErrorCode::CommonInvalidParam5);
-    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+pub extern fn indy_issuer_search_issued_credentials(command_handle: IndyHandle,
+                                                    wallet_handle: IndyHandle,
+                                                    query_json: *const c_char,
+                                                    cb: Option<extern fn(
+                                                        command_handle_: IndyHandle, err: ErrorCode,
+                                                        search_handle: IndyHandle,
+                                                        total_count: usize)>) -> ErrorCode {
+    trace!("indy_issuer_search_issued_credentials: >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
+
+    check_useful_opt_c_str!(query_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_issuer_search_issued_credentials: entities >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
 
     let result = CommandExecutor::instance()
         .send(Command::Anoncreds(
             AnoncredsCommand::Issuer(
-                IssuerCommand::RecoverCredential(
+                IssuerCommand::SearchIssuedCredentials(
                     wallet_handle,
-                    blob_storage_reader_cfg_handle,
-                    rev_reg_id,
-                    cred_revoc_id,
+                    query_json,
                     Box::new(move |result| {
-                        let (err, revoc_reg_update_json) = prepare_result_1!(result, String::new());
-                        let revoc_reg_update_json = ctypes::string_to_cstring(revoc_reg_update_json);
-                        cb(command_handle, err, revoc_reg_update_json.as_ptr())
+                        let (err, handle, total_count) = prepare_result_2!(result, 0, 0);
+                        cb(command_handle, err, handle, total_count)
                     })
                 ))));
 
-    prepare_result!(result)
-}*/
+    let res = prepare_result!(result);
 
-/// Merge two revocation registry deltas (returned by indy_issuer_create_credential or indy_issuer_revoke_credential) to accumulate common delta.
-/// Send common delta to ledger to reduce the load.
+    trace!("indy_issuer_search_issued_credentials: <<< res: {:?}", res);
+
+    res
+}
+
+/// Fetch next issuance log entries for search.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// search_handle: Search handle (created by indy_issuer_search_issued_credentials)
+/// count: Count of entries to fetch
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// issued_credentials_json: issuance log entries
+///     [{
+///         "referent": string, // id of the entry in the wallet
+///         "schema_id": string,
+///         "cred_def_id": string,
+///         "rev_reg_id": Optional<string>,
+///         "cred_rev_id": Optional<string>,
+///         "attrs_hash": string, // hex encoded HMAC-SHA256 of attribute raw values keyed by a secret of the wallet
+///         "revoked": bool,
+///         "tags": {"tag_name": string}
+///     }]
+/// NOTE: The list of length less than the requested count means entries search iterator is completed.
+/// Equal raw values give equal attrs_hash within one wallet, so attrs_hash of an entry can be used to query the same values.
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_fetch_issued_credentials(command_handle: IndyHandle,
+                                                   search_handle: IndyHandle,
+                                                   count: usize,
+                                                   cb: Option<extern fn(command_handle_: IndyHandle, err: ErrorCode,
+                                                                        issued_credentials_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_fetch_issued_credentials: >>> search_handle: {:?}, count: {:?}", search_handle, count);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_issuer_fetch_issued_credentials: entities >>> search_handle: {:?}, count: {:?}", search_handle, count);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::FetchIssuedCredentials(
+                    search_handle,
+                    count,
+                    Box::new(move |result| {
+                        let (err, issued_credentials_json) = prepare_result_1!(result, String::new());
+                        trace!("indy_issuer_fetch_issued_credentials: issued_credentials_json: {:?}", issued_credentials_json);
+                        let issued_credentials_json = ctypes::string_to_cstring(issued_credentials_json);
+                        cb(command_handle, err, issued_credentials_json.as_ptr())
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_fetch_issued_credentials: <<< res: {:?}", res);
+
+    res
+}
+
+/// Close issuance log search (make search handle invalid)
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// search_handle: Search handle (created by indy_issuer_search_issued_credentials)
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_close_issued_credentials_search(command_handle: IndyHandle,
+                                                          search_handle: IndyHandle,
+                                                          cb: Option<extern fn(command_handle_: IndyHandle, err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_issuer_close_issued_credentials_search: >>> search_handle: {:?}", search_handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_issuer_close_issued_credentials_search: entities >>> search_handle: {:?}", search_handle);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::CloseIssuedCredentialsSearch(
+                    search_handle,
+                    Box::new(move |result| {
+                        let err = prepare_result!(result);
+                        trace!("indy_issuer_close_issued_credentials_search:");
+                        cb(command_handle, err)
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_close_issued_credentials_search: <<< res: {:?}", res);
+
+    res
+}
+
+/// Revoke a credential identified by a cred_revoc_id (returned by indy_issuer_create_credential).
+///
+/// The corresponding credential definition and revocation registry must be already
+/// created an stored into the wallet.
+///
+/// This call returns revoc registry delta as json file intended to be shared as REVOC_REG_ENTRY transaction.
+/// Note that it is possible to accumulate deltas to reduce ledger load.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// blob_storage_reader_cfg_handle: configuration of blob storage reader handle that will allow to read revocation tails
+/// rev_reg_id: id of revocation registry stored in wallet
+/// cred_revoc_id: local id for revocation info
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// revoc_reg_delta_json: Revocation registry delta json with a revoked credential
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_revoke_credential(command_handle: IndyHandle,
+                                            wallet_handle: IndyHandle,
+                                            blob_storage_reader_cfg_handle: IndyHandle,
+                                            rev_reg_id: *const c_char,
+                                            cred_revoc_id: *const c_char,
+                                            cb: Option<extern fn(command_handle_: IndyHandle, err: ErrorCode,
+                                                                 revoc_reg_delta_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_revoke_credential: >>> wallet_handle: {:?}, blob_storage_reader_cfg_handle: {:?}, rev_reg_id: {:?}, cred_revoc_id: {:?}",
+           wallet_handle, blob_storage_reader_cfg_handle, rev_reg_id, cred_revoc_id);
+
+    check_useful_c_str!(rev_reg_id, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(cred_revoc_id, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_issuer_revoke_credential: entities >>> wallet_handle: {:?}, blob_storage_reader_cfg_handle: {:?}, rev_reg_id: {:?}, cred_revoc_id: {:?}",
+           wallet_handle, blob_storage_reader_cfg_handle, rev_reg_id, secret!(cred_revoc_id.as_str()));
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::RevokeCredential(
+                    wallet_handle,
+                    blob_storage_reader_cfg_handle,
+                    rev_reg_id,
+                    cred_revoc_id,
+                    Box::new(move |result| {
+                        let (err, revoc_reg_update_json) = prepare_result_1!(result, String::new());
+                        trace!("indy_issuer_revoke_credential: revoc_reg_update_json: {:?}", revoc_reg_update_json);
+                        let revoc_reg_update_json = ctypes::string_to_cstring(revoc_reg_update_json);
+                        cb(command_handle, err, revoc_reg_update_json.as_ptr())
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_revoke_credential: <<< res: {:?}", res);
+
+    res
+}
+
+/// Revoke a batch of credentials of the same revocation registry identified by cred_revoc_ids
+/// (returned by indy_issuer_create_credential).
+///
+/// The corresponding credential definition and revocation registry must be already
+/// created an stored into the wallet.
+///
+/// Tails are accessed through the same blob storage reader for the whole batch. All ids are checked
+/// before revocation: if any of them is invalid, repeated or already revoked nothing is revoked.
+/// The registry is stored only after all credentials are revoked, so a failed call leaves it untouched.
+///
+/// A batch is limited to one revocation registry as deltas of different registries can't be merged and are
+/// published as separate REVOC_REG_ENTRY transactions: credentials of several registries are revoked by
+/// a call per registry.
+///
+/// This call returns a single revoc registry delta for the whole batch as json file intended to be shared
+/// as REVOC_REG_ENTRY transaction (see indy_build_revoc_reg_entry_request).
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// blob_storage_reader_cfg_handle: configuration of blob storage reader handle that will allow to read revocation tails
+/// rev_reg_id: id of revocation registry stored in wallet
+/// cred_revoc_ids_json: json array of local ids for revocation info: ["cred_revoc_id1", "cred_revoc_id2", ...]
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// revoc_reg_delta_json: Revocation registry delta json with all revoked credentials
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_revoke_credentials(command_handle: IndyHandle,
+                                             wallet_handle: IndyHandle,
+                                             blob_storage_reader_cfg_handle: IndyHandle,
+                                             rev_reg_id: *const c_char,
+                                             cred_revoc_ids_json: *const c_char,
+                                             cb: Option<extern fn(command_handle_: IndyHandle, err: ErrorCode,
+                                                                  revoc_reg_delta_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_revoke_credentials: >>> wallet_handle: {:?}, blob_storage_reader_cfg_handle: {:?}, rev_reg_id: {:?}, cred_revoc_ids_json: {:?}",
+           wallet_handle, blob_storage_reader_cfg_handle, rev_reg_id, cred_revoc_ids_json);
+
+    check_useful_c_str!(rev_reg_id, ErrorCode::CommonInvalidParam4);
+    check_useful_json!(cred_revoc_ids_json, ErrorCode::CommonInvalidParam5, Vec<String>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_issuer_revoke_credentials: entities >>> wallet_handle: {:?}, blob_storage_reader_cfg_handle: {:?}, rev_reg_id: {:?}, cred_revoc_ids_json: {:?}",
+           wallet_handle, blob_storage_reader_cfg_handle, rev_reg_id, secret!(&cred_revoc_ids_json));
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::RevokeCredentials(
+                    wallet_handle,
+                    blob_storage_reader_cfg_handle,
+                    rev_reg_id,
+                    cred_revoc_ids_json,
+                    Box::new(move |result| {
+                        let (err, revoc_reg_delta_json) = prepare_result_1!(result, String::new());
+                        trace!("indy_issuer_revoke_credentials: revoc_reg_delta_json: {:?}", revoc_reg_delta_json);
+                        let revoc_reg_delta_json = ctypes::string_to_cstring(revoc_reg_delta_json);
+                        cb(command_handle, err, revoc_reg_delta_json.as_ptr())
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_revoke_credentials: <<< res: {:?}", res);
+
+    res
+}
+
+/*/// Recover a credential identified by a cred_revoc_id (returned by indy_issuer_create_credential).
+///
+/// The corresponding credential definition and revocation registry must be already
+/// created an stored into the wallet.
+///
+/// This call returns revoc registry delta as json file intended to be shared as REVOC_REG_ENTRY transaction.
+/// Note that it is possible to accumulate deltas to reduce ledger load.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// blob_storage_reader_cfg_handle: configuration of blob storage reader handle that will allow to read revocation tails
+/// rev_reg_id: id of revocation registry stored in wallet
+/// cred_revoc_id: local id for revocation info
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// revoc_reg_delta_json: Revocation registry delta json with a recovered credential
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_recover_credential(command_handle: IndyHandle,
+                                             wallet_handle: IndyHandle,
+                                             blob_storage_reader_cfg_handle: IndyHandle,
+                                             rev_reg_id: *const c_char,
+                                             cred_revoc_id: *const c_char,
+                                             cb: Option<extern fn(command_handle_: IndyHandle, err: ErrorCode,
+                                                                  revoc_reg_delta_json: *const c_char,
+                                             )>) -> ErrorCode {
+    check_useful_c_str!(rev_reg_id, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(cred_revoc_id, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::RecoverCredential(
+                    wallet_handle,
+                    blob_storage_reader_cfg_handle,
+                    rev_reg_id,
+                    cred_revoc_id,
+                    Box::new(move |result| {
+                        let (err, revoc_reg_update_json) = prepare_result_1!(result, String::new());
+                        let revoc_reg_update_json = ctypes::string_to_cstring(revoc_reg_update_json);
+                        cb(command_handle, err, revoc_reg_update_json.as_ptr())
+                    })
+                ))));
+
+    prepare_result!(result)
+}*/
+
+/// Merge two revocation registry deltas (returned by indy_issuer_create_credential or indy_issuer_revoke_credential) to accumulate common delta.
+/// Send common delta to ledger to reduce the load.
 ///
 /// #Params
 /// command_handle: command handle to map callback to user context.
@@ -752,6 +1338,10 @@ pub extern fn indy_prover_create_credential_req(command_handle: IndyHandle,
 ///         "attr::<attribute name>::value": <attribute raw value>,
 ///     }
 ///
+/// If "verify_credential_on_store" runtime option is set (see indy_set_runtime_config), the credential is also checked
+/// against credential definition and revocation registry definition as indy_prover_verify_credential does.
+/// As schema isn't known there, encodings of integer and date values are accepted too.
+///
 /// #Params
 /// command_handle: command handle to map callback to user context.
 /// wallet_handle: wallet handler (created by open_wallet).
@@ -782,94 +1372,337 @@ pub extern fn indy_prover_store_credential(command_handle: IndyHandle,
     trace!("indy_prover_store_credential: >>> wallet_handle: {:?}, cred_id: {:?}, cred_req_metadata_json: {:?}, cred_json: {:?}, cred_def_json: {:?}, \
     cred_def_json: {:?}", wallet_handle, cred_id, cred_req_metadata_json, cred_json, cred_def_json, rev_reg_def_json);
 
-    check_useful_opt_c_str!(cred_id, ErrorCode::CommonInvalidParam3);
-    check_useful_json!(cred_req_metadata_json, ErrorCode::CommonInvalidParam4, CredentialRequestMetadata);
-    check_useful_json!(cred_json, ErrorCode::CommonInvalidParam5, Credential);
-    check_useful_json!(cred_def_json, ErrorCode::CommonInvalidParam6, CredentialDefinition);
-    check_useful_opt_json!(rev_reg_def_json, ErrorCode::CommonInvalidParam7, RevocationRegistryDefinition);
-    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+    check_useful_opt_c_str!(cred_id, ErrorCode::CommonInvalidParam3);
+    check_useful_json!(cred_req_metadata_json, ErrorCode::CommonInvalidParam4, CredentialRequestMetadata);
+    check_useful_json!(cred_json, ErrorCode::CommonInvalidParam5, Credential);
+    check_useful_json!(cred_def_json, ErrorCode::CommonInvalidParam6, CredentialDefinition);
+    check_useful_opt_json!(rev_reg_def_json, ErrorCode::CommonInvalidParam7, RevocationRegistryDefinition);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+
+    trace!("indy_prover_store_credential: entities >>> wallet_handle: {:?}, cred_id: {:?}, cred_req_metadata_json: {:?}, cred_json: {:?}, cred_def_json: {:?}, \
+    rev_reg_def_json: {:?}", wallet_handle, cred_id, cred_req_metadata_json, cred_json, cred_def_json, rev_reg_def_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Prover(
+                ProverCommand::StoreCredential(
+                    wallet_handle,
+                    cred_id,
+                    cred_req_metadata_json,
+                    cred_json,
+                    cred_def_json,
+                    rev_reg_def_json,
+                    Box::new(move |result| {
+                        let (err, out_cred_id) = prepare_result_1!(result, String::new());
+                        trace!("indy_prover_store_credential: out_cred_id: {:?}", out_cred_id);
+                        let out_cred_id = ctypes::string_to_cstring(out_cred_id);
+                        cb(command_handle, err, out_cred_id.as_ptr())
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_store_credential: <<< res: {:?}", res);
+
+    res
+}
+
+/// Verifies credential provided by Issuer before storing: checks the credential against the schema attributes,
+/// credential definition, revocation registry definition and processes its signature with the credential
+/// request metadata. The credential is not stored in the wallet.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// cred_req_metadata_json: a credential request metadata created by indy_prover_create_credential_req
+/// cred_json: credential json received from issuer
+/// schema_json: schema json related to <schema_id> in <cred_json>
+/// cred_def_json: credential definition json related to <cred_def_id> in <cred_json>
+/// rev_reg_def_json: revocation registry definition json related to <rev_reg_def_id> in <cred_json>
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// report_json: credential verification report
+///     {
+///         "valid": bool, // true if all checks passed
+///         "schema": <check>, // schema id and attribute names match
+///         "values": <check>, // encoded values match encoding of raw values by attribute types of schema (see indy_encode_credential_attribute)
+///         "cred_def": <check>, // credential definition id and its schema match
+///         "rev_reg": <check>, // revocation registry definition and revocation data match credential definition
+///         "signature": <check> // signature and its correctness proof are valid for credential request metadata
+///     }
+/// where
+/// check: {
+///     "valid": bool,
+///     "errors": Optional<[string]> // description of violations
+/// }
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_prover_verify_credential(command_handle: IndyHandle,
+                                            wallet_handle: IndyHandle,
+                                            cred_req_metadata_json: *const c_char,
+                                            cred_json: *const c_char,
+                                            schema_json: *const c_char,
+                                            cred_def_json: *const c_char,
+                                            rev_reg_def_json: *const c_char,
+                                            cb: Option<extern fn(command_handle_: IndyHandle, err: ErrorCode,
+                                                                 report_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_verify_credential: >>> wallet_handle: {:?}, cred_req_metadata_json: {:?}, cred_json: {:?}, schema_json: {:?}, cred_def_json: {:?}, \
+    rev_reg_def_json: {:?}", wallet_handle, cred_req_metadata_json, cred_json, schema_json, cred_def_json, rev_reg_def_json);
+
+    check_useful_json!(cred_req_metadata_json, ErrorCode::CommonInvalidParam3, CredentialRequestMetadata);
+    check_useful_json!(cred_json, ErrorCode::CommonInvalidParam4, Credential);
+    check_useful_json!(schema_json, ErrorCode::CommonInvalidParam5, Schema);
+    check_useful_json!(cred_def_json, ErrorCode::CommonInvalidParam6, CredentialDefinition);
+    check_useful_opt_json!(rev_reg_def_json, ErrorCode::CommonInvalidParam7, RevocationRegistryDefinition);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+
+    trace!("indy_prover_verify_credential: entities >>> wallet_handle: {:?}, cred_req_metadata_json: {:?}, cred_json: {:?}, schema_json: {:?}, cred_def_json: {:?}, \
+    rev_reg_def_json: {:?}", wallet_handle, cred_req_metadata_json, cred_json, schema_json, cred_def_json, rev_reg_def_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Prover(
+                ProverCommand::VerifyCredential(
+                    wallet_handle,
+                    cred_req_metadata_json,
+                    cred_json,
+                    schema_json,
+                    cred_def_json,
+                    rev_reg_def_json,
+                    Box::new(move |result| {
+                        let (err, report_json) = prepare_result_1!(result, String::new());
+                        trace!("indy_prover_verify_credential: report_json: {:?}", report_json);
+                        let report_json = ctypes::string_to_cstring(report_json);
+                        cb(command_handle, err, report_json.as_ptr())
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_verify_credential: <<< res: {:?}", res);
+
+    res
+}
+
+/// Gets human readable credential by the given id.
+///
+/// #Params
+/// wallet_handle: wallet handler (created by open_wallet).
+/// cred_id: Identifier by which requested credential is stored in the wallet
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// credential json:
+///     {
+///         "referent": string, // cred_id in the wallet
+///         "attrs": {"key1":"raw_value1", "key2":"raw_value2"},
+///         "schema_id": string,
+///         "cred_def_id": string,
+///         "rev_reg_id": Optional<string>,
+///         "cred_rev_id": Optional<string>
+///     }
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_prover_get_credential(command_handle: IndyHandle,
+                                         wallet_handle: IndyHandle,
+                                         cred_id: *const c_char,
+                                         cb: Option<extern fn(
+                                             command_handle_: IndyHandle, err: ErrorCode,
+                                             credential_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_get_credential: >>> wallet_handle: {:?}, cred_id: {:?}", wallet_handle, cred_id);
+
+    check_useful_c_str!(cred_id, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_prover_get_credential: entities >>> wallet_handle: {:?}, cred_id: {:?}", cred_id, cred_id);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Prover(
+                ProverCommand::GetCredential(
+                    wallet_handle,
+                    cred_id,
+                    Box::new(move |result| {
+                        let (err, credential_json) = prepare_result_1!(result, String::new());
+                        trace!("indy_prover_get_credential: credential_json: {:?}", credential_json);
+                        let credential_json = ctypes::string_to_cstring(credential_json);
+                        cb(command_handle, err, credential_json.as_ptr())
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_get_credential: <<< res: {:?}", res);
+
+    res
+}
+
+/// Deletes credential with the given id from the wallet.
+/// Revocation state cached for the credential (see indy_prover_update_credential_revocation_state) is deleted too.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// cred_id: Identifier by which requested credential is stored in the wallet
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// None
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_prover_delete_credential(command_handle: IndyHandle,
+                                            wallet_handle: IndyHandle,
+                                            cred_id: *const c_char,
+                                            cb: Option<extern fn(command_handle_: IndyHandle, err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_prover_delete_credential: >>> wallet_handle: {:?}, cred_id: {:?}", wallet_handle, cred_id);
+
+    check_useful_c_str!(cred_id, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_prover_delete_credential: entities >>> wallet_handle: {:?}, cred_id: {:?}", wallet_handle, cred_id);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Prover(
+                ProverCommand::DeleteCredential(
+                    wallet_handle,
+                    cred_id,
+                    Box::new(move |result| {
+                        let err = prepare_result!(result);
+                        trace!("indy_prover_delete_credential:");
+                        cb(command_handle, err)
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_delete_credential: <<< res: {:?}", res);
+
+    res
+}
+
+/// Adds holder's own tags (labels) to the credential stored in the wallet.
+/// These tags can be used in credential search queries and in `extra_query` of proof request search.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// cred_id: Identifier by which requested credential is stored in the wallet
+/// tags_json: the tags to add as json:
+///   {
+///     "tagName1": <str>, // string tag (will be stored encrypted)
+///     "~tagName2": <str>, // string tag (will be stored un-encrypted)
+///   }
+///   Note if some from provided tags already assigned to the credential than
+///     corresponding tags values will be replaced.
+///   Tags built by libindy on credential storing ("schema_id", "schema_issuer_did", "schema_name",
+///     "schema_version", "issuer_did", "cred_def_id", "rev_reg_id" and "attr::*") are reserved.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// None
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_prover_add_credential_tags(command_handle: IndyHandle,
+                                              wallet_handle: IndyHandle,
+                                              cred_id: *const c_char,
+                                              tags_json: *const c_char,
+                                              cb: Option<extern fn(command_handle_: IndyHandle, err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_prover_add_credential_tags: >>> wallet_handle: {:?}, cred_id: {:?}, tags_json: {:?}", wallet_handle, cred_id, tags_json);
+
+    check_useful_c_str!(cred_id, ErrorCode::CommonInvalidParam3);
+    check_useful_json!(tags_json, ErrorCode::CommonInvalidParam4, Tags);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
 
-    trace!("indy_prover_store_credential: entities >>> wallet_handle: {:?}, cred_id: {:?}, cred_req_metadata_json: {:?}, cred_json: {:?}, cred_def_json: {:?}, \
-    rev_reg_def_json: {:?}", wallet_handle, cred_id, cred_req_metadata_json, cred_json, cred_def_json, rev_reg_def_json);
+    trace!("indy_prover_add_credential_tags: entities >>> wallet_handle: {:?}, cred_id: {:?}, tags_json: {:?}", wallet_handle, cred_id, tags_json);
 
     let result = CommandExecutor::instance()
         .send(Command::Anoncreds(
             AnoncredsCommand::Prover(
-                ProverCommand::StoreCredential(
+                ProverCommand::AddCredentialTags(
                     wallet_handle,
                     cred_id,
-                    cred_req_metadata_json,
-                    cred_json,
-                    cred_def_json,
-                    rev_reg_def_json,
+                    tags_json,
                     Box::new(move |result| {
-                        let (err, out_cred_id) = prepare_result_1!(result, String::new());
-                        trace!("indy_prover_store_credential: out_cred_id: {:?}", out_cred_id);
-                        let out_cred_id = ctypes::string_to_cstring(out_cred_id);
-                        cb(command_handle, err, out_cred_id.as_ptr())
+                        let err = prepare_result!(result);
+                        trace!("indy_prover_add_credential_tags:");
+                        cb(command_handle, err)
                     })
                 ))));
 
     let res = prepare_result!(result);
 
-    trace!("indy_prover_store_credential: <<< res: {:?}", res);
+    trace!("indy_prover_add_credential_tags: <<< res: {:?}", res);
 
     res
 }
 
-/// Gets human readable credential by the given id.
+/// Deletes holder's own tags from the credential stored in the wallet.
 ///
 /// #Params
+/// command_handle: command handle to map callback to caller context.
 /// wallet_handle: wallet handler (created by open_wallet).
 /// cred_id: Identifier by which requested credential is stored in the wallet
+/// tag_names_json: the list of tag names to remove from the credential as json array:
+///   ["tagName1", "tagName2", ...]
+///   Tags built by libindy on credential storing can't be deleted.
 /// cb: Callback that takes command result as parameter.
 ///
 /// #Returns
-/// credential json:
-///     {
-///         "referent": string, // cred_id in the wallet
-///         "attrs": {"key1":"raw_value1", "key2":"raw_value2"},
-///         "schema_id": string,
-///         "cred_def_id": string,
-///         "rev_reg_id": Optional<string>,
-///         "cred_rev_id": Optional<string>
-///     }
+/// None
 ///
 /// #Errors
 /// Annoncreds*
 /// Common*
 /// Wallet*
 #[no_mangle]
-pub extern fn indy_prover_get_credential(command_handle: IndyHandle,
-                                         wallet_handle: IndyHandle,
-                                         cred_id: *const c_char,
-                                         cb: Option<extern fn(
-                                             command_handle_: IndyHandle, err: ErrorCode,
-                                             credential_json: *const c_char)>) -> ErrorCode {
-    trace!("indy_prover_get_credential: >>> wallet_handle: {:?}, cred_id: {:?}", wallet_handle, cred_id);
+pub extern fn indy_prover_delete_credential_tags(command_handle: IndyHandle,
+                                                 wallet_handle: IndyHandle,
+                                                 cred_id: *const c_char,
+                                                 tag_names_json: *const c_char,
+                                                 cb: Option<extern fn(command_handle_: IndyHandle, err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_prover_delete_credential_tags: >>> wallet_handle: {:?}, cred_id: {:?}, tag_names_json: {:?}", wallet_handle, cred_id, tag_names_json);
 
     check_useful_c_str!(cred_id, ErrorCode::CommonInvalidParam3);
-    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+    check_useful_json!(tag_names_json, ErrorCode::CommonInvalidParam4, Vec<String>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
 
-    trace!("indy_prover_get_credential: entities >>> wallet_handle: {:?}, cred_id: {:?}", cred_id, cred_id);
+    trace!("indy_prover_delete_credential_tags: entities >>> wallet_handle: {:?}, cred_id: {:?}, tag_names_json: {:?}", wallet_handle, cred_id, tag_names_json);
 
     let result = CommandExecutor::instance()
         .send(Command::Anoncreds(
             AnoncredsCommand::Prover(
-                ProverCommand::GetCredential(
+                ProverCommand::DeleteCredentialTags(
                     wallet_handle,
                     cred_id,
+                    tag_names_json,
                     Box::new(move |result| {
-                        let (err, credential_json) = prepare_result_1!(result, String::new());
-                        trace!("indy_prover_get_credential: credential_json: {:?}", credential_json);
-                        let credential_json = ctypes::string_to_cstring(credential_json);
-                        cb(command_handle, err, credential_json.as_ptr())
+                        let err = prepare_result!(result);
+                        trace!("indy_prover_delete_credential_tags:");
+                        cb(command_handle, err)
                     })
                 ))));
 
     let res = prepare_result!(result);
 
-    trace!("indy_prover_get_credential: <<< res: {:?}", res);
+    trace!("indy_prover_delete_credential_tags: <<< res: {:?}", res);
 
     res
 }
@@ -1118,6 +1951,9 @@ pub  extern fn indy_prover_close_credentials_search(command_handle: IndyHandle,
 ///                        // If specified prover must proof non-revocation
 ///                        // for date in this interval for each attribute
 ///                        // (can be overridden on attribute level)
+///         "ver": Optional<str>  - proof request version:
+///             - omit or "1.0" to use unqualified identifiers for restrictions
+///             - "2.0" to use fully qualified identifiers for restrictions
 ///     }
 /// cb: Callback that takes command result as parameter.
 ///
@@ -1126,6 +1962,9 @@ pub  extern fn indy_prover_close_credentials_search(command_handle: IndyHandle,
 /// attr_info: Describes requested attribute
 ///     {
 ///         "name": string, // attribute name, (case insensitive and ignore spaces)
+///         "names": [string, string], // attribute names, (case insensitive and ignore spaces)
+///                                    // NOTE: should either be "name" or "names", not both and not none of them.
+///                                    // Use "names" to specify several attributes that have to match a single credential.
 ///         "restrictions": Optional<filter_json>, // see above
 ///         "non_revoked": Optional<<non_revoc_interval>>, // see below,
 ///                        // If specified prover must proof non-revocation
@@ -1136,7 +1975,7 @@ pub  extern fn indy_prover_close_credentials_search(command_handle: IndyHandle,
 /// predicate_info: Describes requested attribute predicate
 ///     {
 ///         "name": attribute name, (case insensitive and ignore spaces)
-///         "p_type": predicate type (Currently ">=" only)
+///         "p_type": predicate type (">=", ">", "<=", "<")
 ///         "p_value": int predicate value
 ///         "restrictions": Optional<filter_json>, // see above
 ///         "non_revoked": Optional<<non_revoc_interval>>, // see below,
@@ -1237,6 +2076,9 @@ pub extern fn indy_prover_get_credentials_for_proof_req(command_handle: IndyHand
 ///                        // If specified prover must proof non-revocation
 ///                        // for date in this interval for each attribute
 ///                        // (can be overridden on attribute level)
+///         "ver": Optional<str>  - proof request version:
+///             - omit or "1.0" to use unqualified identifiers for restrictions
+///             - "2.0" to use fully qualified identifiers for restrictions
 ///     }
 /// extra_query_json:(Optional) List of extra queries that will be applied to correspondent attribute/predicate:
 ///     {
@@ -1430,6 +2272,9 @@ pub  extern fn indy_prover_close_credentials_search_for_proof_req(command_handle
 ///                        // If specified prover must proof non-revocation
 ///                        // for date in this interval for each attribute
 ///                        // (can be overridden on attribute level)
+///         "ver": Optional<str>  - proof request version:
+///             - omit or "1.0" to use unqualified identifiers for restrictions
+///             - "2.0" to use fully qualified identifiers for restrictions
 ///     }
 /// requested_credentials_json: either a credential or self-attested attribute for each requested attribute
 ///     {
@@ -1470,6 +2315,9 @@ pub  extern fn indy_prover_close_credentials_search_for_proof_req(command_handle
 ///             "timestamp4": <rev_state4>
 ///         },
 ///     }
+///     Revocation states cached in the wallet by indy_prover_update_credential_revocation_state can be omitted:
+///     the cached state of requested credential is used if its timestamp isn't later than the requested timestamp.
+///     Such proof is valid only if revocation registry wasn't changed between timestamp of cached state and the requested one.
 /// cb: Callback that takes command result as parameter.
 ///
 /// where
@@ -1478,6 +2326,9 @@ pub  extern fn indy_prover_close_credentials_search_for_proof_req(command_handle
 /// attr_info: Describes requested attribute
 ///     {
 ///         "name": string, // attribute name, (case insensitive and ignore spaces)
+///         "names": [string, string], // attribute names, (case insensitive and ignore spaces)
+///                                    // NOTE: should either be "name" or "names", not both and not none of them.
+///                                    // Use "names" to specify several attributes that have to match a single credential.
 ///         "restrictions": Optional<wql query>,
 ///         "non_revoked": Optional<<non_revoc_interval>>, // see below,
 ///                        // If specified prover must proof non-revocation
@@ -1488,7 +2339,7 @@ pub  extern fn indy_prover_close_credentials_search_for_proof_req(command_handle
 /// predicate_info: Describes requested attribute predicate
 ///     {
 ///         "name": attribute name, (case insensitive and ignore spaces)
-///         "p_type": predicate type (Currently >= only)
+///         "p_type": predicate type (">=", ">", "<=", "<")
 ///         "p_value": predicate value
 ///         "restrictions": Optional<wql query>,
 ///         "non_revoked": Optional<<non_revoc_interval>>, // see below,
@@ -1514,6 +2365,14 @@ pub  extern fn indy_prover_close_credentials_search_for_proof_req(command_handle
 ///                 "requested_attr1_id": {sub_proof_index: number, raw: string, encoded: string},
 ///                 "requested_attr4_id": {sub_proof_index: number: string, encoded: string},
 ///             },
+///             "revealed_attr_groups": {
+///                 "requested_attr5_id": {
+///                     "sub_proof_index": number,
+///                     "values": {
+///                         "attribute_name": {raw: string, encoded: string}
+///                     },
+///                 }
+///             },
 ///             "unrevealed_attrs": {
 ///                 "requested_attr3_id": {sub_proof_index: number}
 ///             },
@@ -1590,6 +2449,10 @@ pub extern fn indy_prover_create_proof(command_handle: IndyHandle,
 /// Verifies a proof (of multiple credential).
 /// All required schemas, public keys and revocation registries must be provided.
 ///
+/// Verifier also checks that identifiers of the proof satisfy restrictions of requested attributes and predicates
+/// (in the same form as Prover uses to search for credentials) and that timestamps fall within non_revoked intervals.
+/// Note that restrictions on values of attributes can be satisfied only by revealed attributes.
+///
 /// #Params
 /// wallet_handle: wallet handler (created by open_wallet).
 /// command_handle: command handle to map callback to user context.
@@ -1610,6 +2473,9 @@ pub extern fn indy_prover_create_proof(command_handle: IndyHandle,
 ///                        // If specified prover must proof non-revocation
 ///                        // for date in this interval for each attribute
 ///                        // (can be overridden on attribute level)
+///         "ver": Optional<str>  - proof request version:
+///             - omit or "1.0" to use unqualified identifiers for restrictions
+///             - "2.0" to use fully qualified identifiers for restrictions
 ///     }
 /// proof_json: created for request proof json
 ///     {
@@ -1618,6 +2484,14 @@ pub extern fn indy_prover_create_proof(command_handle: IndyHandle,
 ///                 "requested_attr1_id": {sub_proof_index: number, raw: string, encoded: string},
 ///                 "requested_attr4_id": {sub_proof_index: number: string, encoded: string},
 ///             },
+///             "revealed_attr_groups": {
+///                 "requested_attr5_id": {
+///                     "sub_proof_index": number,
+///                     "values": {
+///                         "attribute_name": {raw: string, encoded: string}
+///                     },
+///                 }
+///             },
 ///             "unrevealed_attrs": {
 ///                 "requested_attr3_id": {sub_proof_index: number}
 ///             },
@@ -1672,6 +2546,7 @@ pub extern fn indy_prover_create_proof(command_handle: IndyHandle,
 /// valid: true - if signature is valid, false - otherwise
 ///
 /// #Errors
+/// AnoncredsProofRejected - if the proof violates restrictions or non_revoked intervals of the proof request
 /// Annoncreds*
 /// Common*
 /// Wallet*
@@ -1722,6 +2597,99 @@ pub extern fn indy_verifier_verify_proof(command_handle: IndyHandle,
     res
 }
 
+/// Verifies a proof (of multiple credential) as indy_verifier_verify_proof does,
+/// but returns a report describing the result of each check instead of bare boolean.
+///
+/// Violations of restrictions, non_revoked intervals and mismatches of revealed values are reported
+/// in the report instead of returning an error. Malformed input data still causes an error.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// proof_request_json: proof request json (see indy_verifier_verify_proof)
+/// proof_json: created for request proof json (see indy_verifier_verify_proof)
+/// schemas_json: all schema jsons participating in the proof
+/// credential_defs_json: all credential definitions json participating in the proof
+/// rev_reg_defs_json: all revocation registry definitions json participating in the proof
+/// rev_regs_json: all revocation registries json participating in the proof
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// report_json: verification report
+///     {
+///         "valid": bool, // true if all checks passed and referents match requested ones;
+///                        // the same as indy_verifier_verify_proof returns
+///         "proof_verified": bool, // result of cryptographic verification of the proof
+///         "sub_proofs": [{
+///             "sub_proof_index": int,
+///             "identifier": {schema_id, cred_def_id, Optional<rev_reg_id>, Optional<timestamp>},
+///             "referents": [{
+///                 "referent": string, // attribute or predicate referent
+///                 "verified": bool,
+///                 "error": Optional<string> // violated restriction, timestamp or revealed value
+///             }],
+///             "revocation": "passed" | "failed" | "unknown" | "not_applicable"
+///                 // "not_applicable" if identifier has no rev_reg_id,
+///                 // "failed" if timestamp is missing or out of requested non_revoked interval,
+///                 // "unknown" if cryptographic proof isn't verified (see proof_verified): non-revocation proof
+///                 // is verified together with the rest of it, e.g. it fails for revoked credential,
+///                 // "passed" otherwise
+///         }],
+///         "self_attested_attrs": [{"referent": string, "verified": bool, "error": Optional<string>}],
+///         "missing_referents": [string], // requested referents not found in the proof
+///         "unexpected_referents": [string] // referents of the proof which were not requested
+///     }
+///
+/// #Errors
+/// Annoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_verifier_verify_proof_verbose(command_handle: IndyHandle,
+                                                 proof_request_json: *const c_char,
+                                                 proof_json: *const c_char,
+                                                 schemas_json: *const c_char,
+                                                 credential_defs_json: *const c_char,
+                                                 rev_reg_defs_json: *const c_char,
+                                                 rev_regs_json: *const c_char,
+                                                 cb: Option<extern fn(command_handle_: IndyHandle, err: ErrorCode,
+                                                                      report_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_verifier_verify_proof_verbose: >>> proof_request_json: {:?}, proof_json: {:?}, schemas_json: {:?}, credential_defs_json: {:?}, \
+    rev_reg_defs_json: {:?}, rev_regs_json: {:?}", proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json);
+
+    check_useful_json!(proof_request_json, ErrorCode::CommonInvalidParam2, ProofRequest);
+    check_useful_json!(proof_json, ErrorCode::CommonInvalidParam3, Proof);
+    check_useful_json!(schemas_json, ErrorCode::CommonInvalidParam4, HashMap<String, Schema>);
+    check_useful_json!(credential_defs_json, ErrorCode::CommonInvalidParam5, HashMap<String, CredentialDefinition>);
+    check_useful_json!(rev_reg_defs_json, ErrorCode::CommonInvalidParam6, HashMap<String, RevocationRegistryDefinition>);
+    check_useful_json!(rev_regs_json, ErrorCode::CommonInvalidParam7, HashMap<String, HashMap<u64, RevocationRegistry>>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+
+    trace!("indy_verifier_verify_proof_verbose: entities >>> proof_request_json: {:?}, proof_json: {:?}, schemas_json: {:?}, credential_defs_json: {:?}, \
+    rev_reg_defs_json: {:?}, rev_regs_json: {:?}", proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Verifier(VerifierCommand::VerifyProofVerbose(
+            proof_request_json,
+            proof_json,
+            schemas_json,
+            credential_defs_json,
+            rev_reg_defs_json,
+            rev_regs_json,
+            Box::new(move |result| {
+                let (err, report_json) = prepare_result_1!(result, String::new());
+                trace!("indy_verifier_verify_proof_verbose: report_json: {:?}", report_json);
+                let report_json = ctypes::string_to_cstring(report_json);
+                cb(command_handle, err, report_json.as_ptr())
+            })
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_verifier_verify_proof_verbose: <<< res: {:?}", res);
+
+    res
+}
+
 /// Create revocation state for a credential in the particular time moment.
 ///
 /// #Params
@@ -1858,3 +2826,284 @@ pub extern fn indy_update_revocation_state(command_handle: IndyHandle,
 
     res
 }
+
+/// Create or update revocation state of the credential stored in the wallet and cache it in the wallet.
+///
+/// If the credential has no cached state the state is created from the delta (as indy_create_revocation_state does),
+/// otherwise cached state is updated incrementally (as indy_update_revocation_state does), so the delta
+/// must be taken from timestamp of cached state to the new timestamp.
+///
+/// Cached states are used by indy_prover_create_proof for requested credentials missed in rev_states_json.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context
+/// wallet_handle: wallet handler (created by open_wallet).
+/// blob_storage_reader_handle: configuration of blob storage reader handle that will allow to read revocation tails
+/// cred_id: identifier by which credential is stored in the wallet
+/// rev_reg_def_json: revocation registry definition json of the credential
+/// rev_reg_delta_json: revocation registry delta json
+/// timestamp: time represented as a total number of seconds from Unix Epoch (can't be less than timestamp of cached state)
+/// cb: Callback that takes command result as parameter
+///
+/// #Returns
+/// revocation state json:
+///     {
+///         "rev_reg": <revocation registry>,
+///         "witness": <witness>,
+///         "timestamp" : integer
+///     }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Anoncreds*
+#[no_mangle]
+pub extern fn indy_prover_update_credential_revocation_state(command_handle: IndyHandle,
+                                                             wallet_handle: IndyHandle,
+                                                             blob_storage_reader_handle: IndyHandle,
+                                                             cred_id: *const c_char,
+                                                             rev_reg_def_json: *const c_char,
+                                                             rev_reg_delta_json: *const c_char,
+                                                             timestamp: u64,
+                                                             cb: Option<extern fn(
+                                                                 command_handle_: IndyHandle, err: ErrorCode,
+                                                                 rev_state_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_update_credential_revocation_state: >>> wallet_handle: {:?}, blob_storage_reader_handle: {:?}, cred_id: {:?}, rev_reg_def_json: {:?}, \
+    rev_reg_delta_json: {:?}, timestamp: {:?}", wallet_handle, blob_storage_reader_handle, cred_id, rev_reg_def_json, rev_reg_delta_json, timestamp);
+
+    check_useful_c_str!(cred_id, ErrorCode::CommonInvalidParam4);
+    check_useful_json!(rev_reg_def_json, ErrorCode::CommonInvalidParam5, RevocationRegistryDefinition);
+    check_useful_json!(rev_reg_delta_json, ErrorCode::CommonInvalidParam6, RevocationRegistryDelta);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+
+    trace!("indy_prover_update_credential_revocation_state: entities >>> wallet_handle: {:?}, blob_storage_reader_handle: {:?}, cred_id: {:?}, rev_reg_def_json: {:?}, \
+    rev_reg_delta_json: {:?}, timestamp: {:?}", wallet_handle, blob_storage_reader_handle, cred_id, rev_reg_def_json, rev_reg_delta_json, timestamp);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::UpdateCredentialRevocationState(
+            wallet_handle,
+            blob_storage_reader_handle,
+            cred_id,
+            rev_reg_def_json,
+            rev_reg_delta_json,
+            timestamp,
+            Box::new(move |result| {
+                let (err, rev_state_json) = prepare_result_1!(result, String::new());
+                trace!("indy_prover_update_credential_revocation_state: rev_state_json: {:?}", rev_state_json);
+                let rev_state_json = ctypes::string_to_cstring(rev_state_json);
+                cb(command_handle, err, rev_state_json.as_ptr())
+            })
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_update_credential_revocation_state: <<< res: {:?}", res);
+
+    res
+}
+
+/// Validates proof request and reports all found problems instead of failing on the first one.
+///
+/// Checks that the request is well formed: nonce, name and version are set, attributes and predicates
+/// are specified correctly, restrictions use only known tags and operators, non-revocation intervals
+/// are valid and restrictions of predicates don't require non-integer attribute values.
+/// Request without attributes and predicates is reported as well.
+///
+/// indy_prover_get_credentials_for_proof_req and indy_prover_search_credentials_for_proof_req
+/// perform the same validation and reject invalid proof requests, except that they accept
+/// request without attributes and predicates.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context
+/// proof_request_json: proof request json (see indy_prover_search_credentials_for_proof_req)
+/// cb: Callback that takes command result as parameter
+///
+/// #Returns
+/// problems_json: list of found problems (empty if proof request is valid)
+///     [string, ...]
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_validate_proof_request(command_handle: IndyHandle,
+                                          proof_request_json: *const c_char,
+                                          cb: Option<extern fn(
+                                              command_handle_: IndyHandle, err: ErrorCode,
+                                              problems_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_validate_proof_request: >>> proof_request_json: {:?}", proof_request_json);
+
+    check_useful_c_str!(proof_request_json, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_validate_proof_request: entities >>> proof_request_json: {:?}", proof_request_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::ValidateProofRequest(
+            proof_request_json,
+            Box::new(move |result| {
+                let (err, problems_json) = prepare_result_1!(result, String::new());
+                trace!("indy_validate_proof_request: problems_json: {:?}", problems_json);
+                let problems_json = ctypes::string_to_cstring(problems_json);
+                cb(command_handle, err, problems_json.as_ptr())
+            })
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_validate_proof_request: <<< res: {:?}", res);
+
+    res
+}
+
+/// Get unqualified form (short form without method) of a fully qualified entity like DID.
+///
+/// This function should be used to the proper casting of fully qualified entity to unqualified form in the following cases:
+///     Issuer, which works with fully qualified identifiers, creates a Credential Offer for Prover, which doesn't support fully qualified identifiers.
+///     Verifier prepares a Proof Request based on fully qualified identifiers or Prover, which doesn't support fully qualified identifiers.
+///     another case when casting to unqualified form needed
+///
+/// #Params
+/// command_handle: command handle to map callback to user context
+/// entity: target entity to get unqualified form. Can be one of:
+///             DID
+///             SchemaId
+///             CredentialDefinitionId
+///             RevocationRegistryId
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// res: entity either in unqualified form or original if casting isn't possible
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_to_unqualified(command_handle: IndyHandle,
+                                  entity: *const c_char,
+                                  cb: Option<extern fn(command_handle_: IndyHandle, err: ErrorCode,
+                                                       res: *const c_char)>) -> ErrorCode {
+    trace!("indy_to_unqualified: >>> entity: {:?}", entity);
+
+    check_useful_c_str!(entity, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_to_unqualified: entities >>> entity: {:?}", entity);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::ToUnqualified(
+            entity,
+            Box::new(move |result| {
+                let (err, res) = prepare_result_1!(result, String::new());
+                trace!("indy_to_unqualified: res: {:?}", res);
+                let res = ctypes::string_to_cstring(res);
+                cb(command_handle, err, res.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_to_unqualified: <<< res: {:?}", res);
+
+    res
+}
+
+/// Encode raw value of credential attribute the standard way.
+///
+/// Verifier checks that revealed raw values of a proof match encoded values using this encoding,
+/// so Issuer must use it to build `encoded` values of credential attributes:
+///     a value which is the canonical decimal form of 32-bit integer is encoded as itself ("28" -> "28"),
+///     any other value is encoded as decimal representation of big-endian SHA-256 hash of its UTF-8 bytes.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context
+/// raw_value: raw value of credential attribute
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// encoded_value: encoded value of credential attribute
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_encode_credential_attribute(command_handle: IndyHandle,
+                                               raw_value: *const c_char,
+                                               cb: Option<extern fn(command_handle_: IndyHandle, err: ErrorCode,
+                                                                    encoded_value: *const c_char)>) -> ErrorCode {
+    trace!("indy_encode_credential_attribute: >>> raw_value: {:?}", raw_value);
+
+    check_useful_c_str!(raw_value, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_encode_credential_attribute: entities >>> raw_value: {:?}", raw_value);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::EncodeCredentialAttribute(
+            raw_value,
+            Box::new(move |result| {
+                let (err, encoded_value) = prepare_result_1!(result, String::new());
+                trace!("indy_encode_credential_attribute: encoded_value: {:?}", encoded_value);
+                let encoded_value = ctypes::string_to_cstring(encoded_value);
+                cb(command_handle, err, encoded_value.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_encode_credential_attribute: <<< res: {:?}", res);
+
+    res
+}
+
+/// Get fully qualified form of an unqualified entity like DID.
+///
+/// This function should be used to the proper casting of unqualified entity to fully qualified form in the following cases:
+///     Issuer, which works with unqualified identifiers, creates a Credential Offer for Prover, which expects fully qualified identifiers.
+///     Verifier prepares a Proof Request of version "2.0" based on identifiers received in unqualified form.
+///     another case when casting to fully qualified form needed
+///
+/// #Params
+/// command_handle: command handle to map callback to user context
+/// entity: target entity to get fully qualified form. Can be one of:
+///             DID
+///             SchemaId
+///             CredentialDefinitionId
+///             RevocationRegistryId
+/// method: DID method to qualify entity with, e.g. "sov".
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// res: entity in fully qualified form. Already qualified entity is returned as is.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_to_fully_qualified(command_handle: IndyHandle,
+                                      entity: *const c_char,
+                                      method: *const c_char,
+                                      cb: Option<extern fn(command_handle_: IndyHandle, err: ErrorCode,
+                                                           res: *const c_char)>) -> ErrorCode {
+    trace!("indy_to_fully_qualified: >>> entity: {:?}, method: {:?}", entity, method);
+
+    check_useful_c_str!(entity, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(method, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_to_fully_qualified: entities >>> entity: {:?}, method: {:?}", entity, method);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::ToFullyQualified(
+            entity,
+            method,
+            Box::new(move |result| {
+                let (err, res) = prepare_result_1!(result, String::new());
+                trace!("indy_to_fully_qualified: res: {:?}", res);
+                let res = ctypes::string_to_cstring(res);
+                cb(command_handle, err, res.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_to_fully_qualified: <<< res: {:?}", res);
+
+    res
+}
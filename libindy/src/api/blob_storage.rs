@@ -73,3 +73,181 @@ pub extern fn indy_open_blob_storage_writer(command_handle: IndyHandle,
 
     res
 }
+
+/// Register custom blob storage implementation: writer and reader of blobs (tails files)
+/// that can be opened by indy_open_blob_storage_writer and indy_open_blob_storage_reader with the type name.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// type_: Blob storage type name.
+/// open_writer: BlobStorage open writer operation handler
+/// create_blob: BlobStorage create blob operation handler
+/// append: BlobStorage append operation handler
+/// finalize: BlobStorage finalize operation handler
+/// close_writer: BlobStorage close writer operation handler
+/// open_reader: BlobStorage open reader operation handler
+/// open_blob: BlobStorage open blob operation handler
+/// read: BlobStorage read operation handler
+/// close_blob: BlobStorage close blob operation handler
+/// close_reader: BlobStorage close reader operation handler
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// WalletTypeAlreadyRegisteredError if the type name is already used by built-in or registered blob storage
+#[no_mangle]
+pub extern fn indy_register_blob_storage(command_handle: IndyHandle,
+                                         type_: *const c_char,
+                                         open_writer: Option<BlobStorageOpenWriter>,
+                                         create_blob: Option<BlobStorageCreateBlob>,
+                                         append: Option<BlobStorageAppend>,
+                                         finalize: Option<BlobStorageFinalize>,
+                                         close_writer: Option<BlobStorageCloseWriter>,
+                                         open_reader: Option<BlobStorageOpenReader>,
+                                         open_blob: Option<BlobStorageOpenBlob>,
+                                         read: Option<BlobStorageRead>,
+                                         close_blob: Option<BlobStorageCloseBlob>,
+                                         close_reader: Option<BlobStorageCloseReader>,
+                                         cb: Option<extern fn(command_handle_: IndyHandle,
+                                                              err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_register_blob_storage: >>> type_: {:?}", type_);
+
+    check_useful_c_str!(type_, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(open_writer, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(create_blob, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(append, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(finalize, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(close_writer, ErrorCode::CommonInvalidParam7);
+    check_useful_c_callback!(open_reader, ErrorCode::CommonInvalidParam8);
+    check_useful_c_callback!(open_blob, ErrorCode::CommonInvalidParam9);
+    check_useful_c_callback!(read, ErrorCode::CommonInvalidParam10);
+    check_useful_c_callback!(close_blob, ErrorCode::CommonInvalidParam11);
+    check_useful_c_callback!(close_reader, ErrorCode::CommonInvalidParam12);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam13);
+
+    trace!("indy_register_blob_storage: entities >>> type_: {:?}", type_);
+
+    let result = CommandExecutor::instance()
+        .send(Command::BlobStorage(BlobStorageCommand::RegisterBlobStorage(
+            type_,
+            open_writer,
+            create_blob,
+            append,
+            finalize,
+            close_writer,
+            open_reader,
+            open_blob,
+            read,
+            close_blob,
+            close_reader,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_register_blob_storage: err: {:?}", err);
+                cb(command_handle, err)
+            }),
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_register_blob_storage: <<< res: {:?}", res);
+
+    res
+}
+
+/// Open blob storage writer with the given configuration
+///
+/// #Params
+/// config: writer config json passed to indy_open_blob_storage_writer
+/// writer_handle_p: pointer to store opened writer handle
+pub type BlobStorageOpenWriter = extern fn(config: *const c_char,
+                                           writer_handle_p: *mut IndyHandle) -> ErrorCode;
+
+/// Create a new blob to write
+///
+/// #Params
+/// writer_handle: opened writer handle (See open_writer handler)
+/// blob_handle_p: pointer to store created blob handle
+pub type BlobStorageCreateBlob = extern fn(writer_handle: IndyHandle,
+                                           blob_handle_p: *mut IndyHandle) -> ErrorCode;
+
+/// Append bytes to the end of the blob
+///
+/// #Params
+/// blob_handle: created blob handle (See create_blob handler)
+/// bytes: bytes to append
+/// bytes_len: bytes length
+/// written_len_p: pointer to store the number of written bytes
+pub type BlobStorageAppend = extern fn(blob_handle: IndyHandle,
+                                       bytes: *const u8,
+                                       bytes_len: usize,
+                                       written_len_p: *mut usize) -> ErrorCode;
+
+/// Finalize writing of the blob and publish it
+///
+/// #Params
+/// blob_handle: created blob handle (See create_blob handler)
+/// hash: SHA-256 hash of blob content
+/// hash_len: hash length
+/// location_p: pointer to store blob location (URI that will be used as tails location).
+///             String is owned by the plugin and must be valid until close_blob call for the blob.
+pub type BlobStorageFinalize = extern fn(blob_handle: IndyHandle,
+                                         hash: *const u8,
+                                         hash_len: usize,
+                                         location_p: *mut *const c_char) -> ErrorCode;
+
+/// Close blob storage writer and release its resources
+///
+/// #Params
+/// writer_handle: opened writer handle (See open_writer handler)
+pub type BlobStorageCloseWriter = extern fn(writer_handle: IndyHandle) -> ErrorCode;
+
+/// Open blob storage reader with the given configuration
+///
+/// #Params
+/// config: reader config json passed to indy_open_blob_storage_reader
+/// reader_handle_p: pointer to store opened reader handle
+pub type BlobStorageOpenReader = extern fn(config: *const c_char,
+                                           reader_handle_p: *mut IndyHandle) -> ErrorCode;
+
+/// Open blob to read
+///
+/// #Params
+/// reader_handle: opened reader handle (See open_reader handler)
+/// hash: SHA-256 hash of blob content
+/// hash_len: hash length
+/// location: blob location returned by finalize handler of the writer
+/// blob_handle_p: pointer to store opened blob handle
+pub type BlobStorageOpenBlob = extern fn(reader_handle: IndyHandle,
+                                         hash: *const u8,
+                                         hash_len: usize,
+                                         location: *const c_char,
+                                         blob_handle_p: *mut IndyHandle) -> ErrorCode;
+
+/// Read bytes of the blob
+///
+/// #Params
+/// blob_handle: opened blob handle (See open_blob handler)
+/// size: the number of bytes to read
+/// offset: offset of the first byte to read
+/// bytes_p: pointer to store read bytes (less than size at the end of the blob).
+///          Bytes are owned by the plugin and must be valid until the next call for the blob.
+/// bytes_len_p: pointer to store read bytes length
+pub type BlobStorageRead = extern fn(blob_handle: IndyHandle,
+                                     size: usize,
+                                     offset: usize,
+                                     bytes_p: *mut *const u8,
+                                     bytes_len_p: *mut usize) -> ErrorCode;
+
+/// Close blob (both finalized and opened to read) and release its resources
+///
+/// #Params
+/// blob_handle: blob handle (See create_blob and open_blob handlers)
+pub type BlobStorageCloseBlob = extern fn(blob_handle: IndyHandle) -> ErrorCode;
+
+/// Close blob storage reader and release its resources
+///
+/// #Params
+/// reader_handle: opened reader handle (See open_reader handler)
+pub type BlobStorageCloseReader = extern fn(reader_handle: IndyHandle) -> ErrorCode;
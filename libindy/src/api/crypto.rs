@@ -3,7 +3,8 @@ extern crate libc;
 use api::{ErrorCode, IndyHandle};
 use commands::{Command, CommandExecutor};
 use commands::crypto::CryptoCommand;
-use domain::crypto::key::KeyInfo;
+use domain::crypto::hd::HdMasterSeedConfig;
+use domain::crypto::key::{ExportedKey, KeyExportConfig, KeyImportConfig, KeyInfo};
 use errors::prelude::*;
 use utils::ctypes;
 
@@ -21,6 +22,9 @@ use self::libc::c_char;
 ///     "seed": string, (optional) Seed that allows deterministic key creation (if not set random one will be created).
 ///                                Can be UTF-8, base64 or hex string.
 ///     "crypto_type": string, // Optional (if not set then ed25519 curve is used); Currently only 'ed25519' value is supported for this field.
+///     "hd_path": string, (optional) SLIP-0010 derivation path (for example "m/44'/0'/1'") to derive key from
+///                                   the wallet master seed (see indy_create_hd_master_seed). Can't be used together with seed.
+///     "exportable": bool, (optional) Allows export of the key with indy_export_key. false by default.
 /// }
 /// cb: Callback that takes command result as parameter.
 ///
@@ -68,6 +72,69 @@ pub extern fn indy_create_key(command_handle: IndyHandle,
     res
 }
 
+/// Creates master seed for deterministic (hierarchical) key derivation and stores it in the wallet.
+///
+/// Keys and DIDs can be derived from this seed by passing "hd_path" to indy_create_key,
+/// indy_create_and_store_my_did and indy_replace_keys_start. Derived keys can be restored
+/// with indy_recover_hd_keys. Only one master seed can be stored in the wallet.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// config_json: Master seed configuration as json. Example:
+/// {
+///     "mnemonic": string, (optional) BIP-39 english mnemonic to restore master seed from.
+///     "passphrase": string, (optional) BIP-39 passphrase to use together with mnemonic.
+///     "seed": string, (optional) Raw master seed (16-64 bytes) as hex string. Can't be used together with mnemonic.
+/// }
+/// If neither mnemonic nor seed is set then new 24 words mnemonic will be generated.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: command handle to map callback to caller context.
+/// - err: Error code.
+/// - mnemonic: BIP-39 mnemonic of the master seed (empty string if raw seed was used).
+///             Store it in safe place to be able to restore derived keys.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_create_hd_master_seed(command_handle: IndyHandle,
+                                         wallet_handle: IndyHandle,
+                                         config_json: *const c_char,
+                                         cb: Option<extern fn(command_handle_: IndyHandle,
+                                                              err: ErrorCode,
+                                                              mnemonic: *const c_char)>) -> ErrorCode {
+    trace!("indy_create_hd_master_seed: >>> wallet_handle: {:?}, config_json: {:?}", wallet_handle, config_json);
+
+    check_useful_json!(config_json, ErrorCode::CommonInvalidParam3, HdMasterSeedConfig);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_create_hd_master_seed: entities >>> wallet_handle: {:?}, config_json: {:?}", wallet_handle, secret!(&config_json));
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::CreateHdMasterSeed(
+            wallet_handle,
+            config_json,
+            Box::new(move |result| {
+                let (err, mnemonic) = prepare_result_1!(result, String::new());
+                trace!("indy_create_hd_master_seed: mnemonic: {:?}", secret!(&mnemonic));
+                let mnemonic = ctypes::string_to_cstring(mnemonic);
+                cb(command_handle, err, mnemonic.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_create_hd_master_seed: <<< res: {:?}", res);
+
+    res
+}
+
 /// Saves/replaces the meta information for the giving key in the wallet.
 ///
 /// #Params
@@ -173,6 +240,143 @@ pub  extern fn indy_get_key_metadata(command_handle: IndyHandle,
     res
 }
 
+/// Exports the key with its metadata from the wallet as password encrypted JWK or PKCS#8.
+/// Only keys created with "exportable" flag can be exported.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// verkey - The key (verkey, key id) to export.
+/// export_config_json: Export configuration json:
+/// {
+///     "key": string, Passphrase used to derive export encryption key
+///     "key_derivation_method": optional<string> algorithm to use for export key derivation:
+///                              ARGON2I_MOD - derive secured export key (used by default)
+///                              ARGON2I_INT - derive secured export key (less secured but faster)
+///     "format": optional<string> format of exported key:
+///                              jwk - Ed25519 private key as JWK (RFC 8037) (used by default)
+///                              pkcs8 - Ed25519 private key as PEM encoded PKCS#8 (RFC 8410)
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - exported_key_json - Encrypted key json that can be passed to indy_import_key.
+///   It is libindy specific envelope (not a JWE). Version "1.0" is the JSON serialization
+///   {"key": <JWK json or PEM>, "metadata": <key metadata>} encrypted with ChaCha20-Poly1305-IETF
+///   under a key derived from the passphrase and salt by Argon2i with parameters of key_derivation_method:
+/// {
+///     "ver": string, envelope version, currently "1.0"
+///     "format": string, "jwk" or "pkcs8"
+///     "key_derivation_method": string,
+///     "salt": string, base64url encoded salt of key derivation
+///     "nonce": string, base64url encoded nonce
+///     "ciphertext": string, base64url encoded encrypted key and metadata
+/// }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_export_key(command_handle: IndyHandle,
+                               wallet_handle: IndyHandle,
+                               verkey: *const c_char,
+                               export_config_json: *const c_char,
+                               cb: Option<extern fn(command_handle_: IndyHandle,
+                                                    err: ErrorCode,
+                                                    exported_key_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_export_key: >>> wallet_handle: {:?}, verkey: {:?}, export_config_json: {:?}", wallet_handle, verkey, secret!(export_config_json));
+
+    check_useful_c_str!(verkey, ErrorCode::CommonInvalidParam3);
+    check_useful_json!(export_config_json, ErrorCode::CommonInvalidParam4, KeyExportConfig);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_export_key: entities >>> wallet_handle: {:?}, verkey: {:?}, export_config_json: {:?}", wallet_handle, verkey, secret!(&export_config_json));
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::ExportKey(
+            wallet_handle,
+            verkey,
+            export_config_json,
+            Box::new(move |result| {
+                let (err, exported_key_json) = prepare_result_1!(result, String::new());
+                trace!("indy_export_key: exported_key_json: {:?}", exported_key_json);
+                let exported_key_json = ctypes::string_to_cstring(exported_key_json);
+                cb(command_handle, err, exported_key_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_export_key: <<< res: {:?}", res);
+
+    res
+}
+
+/// Imports the key with its metadata exported by indy_export_key into the wallet.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// exported_key_json - Encrypted key json returned by indy_export_key.
+/// import_config_json: Import configuration json:
+/// {
+///     "key": string, Passphrase used on export
+///     "exportable": optional<bool>, Allows further export of imported key. false by default.
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - verkey: Ver key of imported key pair, also used as key identifier
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_import_key(command_handle: IndyHandle,
+                               wallet_handle: IndyHandle,
+                               exported_key_json: *const c_char,
+                               import_config_json: *const c_char,
+                               cb: Option<extern fn(command_handle_: IndyHandle,
+                                                    err: ErrorCode,
+                                                    verkey: *const c_char)>) -> ErrorCode {
+    trace!("indy_import_key: >>> wallet_handle: {:?}, exported_key_json: {:?}, import_config_json: {:?}", wallet_handle, exported_key_json, secret!(import_config_json));
+
+    check_useful_json!(exported_key_json, ErrorCode::CommonInvalidParam3, ExportedKey);
+    check_useful_json!(import_config_json, ErrorCode::CommonInvalidParam4, KeyImportConfig);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_import_key: entities >>> wallet_handle: {:?}, exported_key_json: {:?}, import_config_json: {:?}", wallet_handle, exported_key_json, secret!(&import_config_json));
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::ImportKey(
+            wallet_handle,
+            exported_key_json,
+            import_config_json,
+            Box::new(move |result| {
+                let (err, verkey) = prepare_result_1!(result, String::new());
+                trace!("indy_import_key: verkey: {:?}", verkey);
+                let verkey = ctypes::string_to_cstring(verkey);
+                cb(command_handle, err, verkey.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_import_key: <<< res: {:?}", res);
+
+    res
+}
+
 /// Signs a message with a key.
 ///
 /// Note to use DID keys with this function you can call indy_key_for_did to get key id (verkey)
@@ -752,3 +956,456 @@ pub extern fn indy_unpack_message(
 
     res
 }
+
+/// Packs the content of a file by encrypting it in fixed-size segments (Experimental)
+///
+/// Unlike indy_pack_message the message is never loaded into memory as a whole, so this function
+/// is suitable for large payloads like attachments. Every segment is encrypted with its own
+/// authentication tag, so corrupted, reordered or truncated output is detected on unpacking.
+///
+/// Note to use DID keys with this function you can call indy_key_for_did to get key id (verkey)
+/// for specific DID.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// input_path: path to the file with the message to be packed
+/// output_path: path to the file where packed message will be written. File must not exist.
+/// receivers: a string in the format of a json list which will contain the list of receiver's keys
+///                the message is being encrypted for.
+///                Example:
+///                "[<receiver edge_agent_1 verkey>, <receiver edge_agent_2 verkey>]"
+/// sender: the sender's verkey as a string When null pointer is used in this parameter, anoncrypt is used
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Output file has the following layout:
+///     <header length: u32 little endian>
+///     <header: json {
+///         "protected": <the same as "protected" field of indy_pack_message output>,
+///         "iv": <b64URLencode(initial iv)>,
+///         "chunk_size": <max size of plaintext segment>
+///     }>
+///     <segments: [<final flag: u8> <ciphertext length: u32 little endian> <ciphertext> <tag>]>
+/// iv is incremented for every segment. Segment is encrypted with "protected" value and final flag as aad.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_pack_file(command_handle: IndyHandle,
+                             wallet_handle: IndyHandle,
+                             input_path: *const c_char,
+                             output_path: *const c_char,
+                             receiver_keys: *const c_char,
+                             sender: *const c_char,
+                             cb: Option<extern fn(command_handle_: IndyHandle,
+                                                  err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_pack_file: >>> wallet_handle: {:?}, input_path: {:?}, output_path: {:?}, receiver_keys: {:?}, sender: {:?}",
+           wallet_handle, input_path, output_path, receiver_keys, sender);
+
+    check_useful_c_str!(input_path, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(output_path, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(receiver_keys, ErrorCode::CommonInvalidParam5);
+    check_useful_opt_c_str!(sender, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_pack_file: entities >>> wallet_handle: {:?}, input_path: {:?}, output_path: {:?}, receiver_keys: {:?}, sender: {:?}",
+           wallet_handle, input_path, output_path, receiver_keys, sender);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::PackFile(
+            wallet_handle,
+            input_path,
+            output_path,
+            receiver_keys,
+            sender,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_pack_file:");
+                cb(command_handle, err)
+            }),
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_pack_file: <<< res: {:?}", res);
+
+    res
+}
+
+/// Unpacks a file produced by indy_pack_file (Experimental)
+///
+/// Decrypted message is written to the output file segment by segment. If any segment fails
+/// authentication or input file contains data after the final segment the output file is removed.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// input_path: path to the file produced by indy_pack_file
+/// output_path: path to the file where unpacked message will be written. File must not exist.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// if authcrypt was used to pack the message returns this json structure:
+/// {
+///     sender_verkey: <sender_verkey>,
+///     recipient_verkey: <recipient_verkey>
+/// }
+///
+/// OR
+///
+/// if anoncrypt was used to pack the message returns this json structure:
+/// {
+///     recipient_verkey: <recipient_verkey>
+/// }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_unpack_file(command_handle: IndyHandle,
+                               wallet_handle: IndyHandle,
+                               input_path: *const c_char,
+                               output_path: *const c_char,
+                               cb: Option<extern fn(command_handle_: IndyHandle,
+                                                    err: ErrorCode,
+                                                    res_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_unpack_file: >>> wallet_handle: {:?}, input_path: {:?}, output_path: {:?}", wallet_handle, input_path, output_path);
+
+    check_useful_c_str!(input_path, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(output_path, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_unpack_file: entities >>> wallet_handle: {:?}, input_path: {:?}, output_path: {:?}", wallet_handle, input_path, output_path);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::UnpackFile(
+            wallet_handle,
+            input_path,
+            output_path,
+            Box::new(move |result| {
+                let (err, res_json) = prepare_result_1!(result, String::new());
+                trace!("indy_unpack_file: res_json: {:?}", res_json);
+                let res_json = ctypes::string_to_cstring(res_json);
+                cb(command_handle, err, res_json.as_ptr())
+            }),
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_unpack_file: <<< res: {:?}", res);
+
+    res
+}
+
+/// Starts packing of a message passed by caller-provided buffers (Experimental)
+///
+/// The same as indy_pack_file but message and packed output are passed by parts through
+/// indy_pack_stream_update and indy_pack_stream_finish, so caller controls where data are read from and written to.
+/// Concatenation of returned header and all parts returned by update and finish calls has
+/// the same layout as indy_pack_file output and can be unpacked by indy_unpack_file.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// receivers: a string in the format of a json list which will contain the list of receiver's keys
+///                the message is being encrypted for.
+///                Example:
+///                "[<receiver edge_agent_1 verkey>, <receiver edge_agent_2 verkey>]"
+/// sender: the sender's verkey as a string When null pointer is used in this parameter, anoncrypt is used
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// stream_handle: handle of the stream to pass to indy_pack_stream_update and indy_pack_stream_finish
+/// header_raw: header of packed message to be written before packed parts
+/// header_len: header length
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_pack_stream_start(command_handle: IndyHandle,
+                                     wallet_handle: IndyHandle,
+                                     receiver_keys: *const c_char,
+                                     sender: *const c_char,
+                                     cb: Option<extern fn(command_handle_: IndyHandle,
+                                                          err: ErrorCode,
+                                                          stream_handle: IndyHandle,
+                                                          header_raw: *const u8,
+                                                          header_len: u32)>) -> ErrorCode {
+    trace!("indy_pack_stream_start: >>> wallet_handle: {:?}, receiver_keys: {:?}, sender: {:?}", wallet_handle, receiver_keys, sender);
+
+    check_useful_c_str!(receiver_keys, ErrorCode::CommonInvalidParam3);
+    check_useful_opt_c_str!(sender, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_pack_stream_start: entities >>> wallet_handle: {:?}, receiver_keys: {:?}, sender: {:?}", wallet_handle, receiver_keys, sender);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::PackStreamStart(
+            wallet_handle,
+            receiver_keys,
+            sender,
+            Box::new(move |result| {
+                let (err, stream_handle, header) = prepare_result_2!(result, 0, Vec::new());
+                trace!("indy_pack_stream_start: stream_handle: {:?}, header: {:?}", stream_handle, header);
+                let (header_raw, header_len) = ctypes::vec_to_pointer(&header);
+                cb(command_handle, err, stream_handle, header_raw, header_len)
+            }),
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_pack_stream_start: <<< res: {:?}", res);
+
+    res
+}
+
+/// Packs the next part of a message started by indy_pack_stream_start (Experimental)
+///
+/// Data are encrypted by fixed-size segments, so returned packed part can be empty
+/// if there is not enough data for a segment yet.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// stream_handle: stream handle returned by indy_pack_stream_start.
+/// data_raw: a pointer to the first byte of the message part
+/// data_len: the length of the message part in bytes
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// packed_raw: packed part to be written after previously returned parts
+/// packed_len: packed part length
+///
+/// #Errors
+/// Common*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_pack_stream_update(command_handle: IndyHandle,
+                                      stream_handle: IndyHandle,
+                                      data_raw: *const u8,
+                                      data_len: u32,
+                                      cb: Option<extern fn(command_handle_: IndyHandle,
+                                                           err: ErrorCode,
+                                                           packed_raw: *const u8,
+                                                           packed_len: u32)>) -> ErrorCode {
+    trace!("indy_pack_stream_update: >>> stream_handle: {:?}, data_raw: {:?}, data_len: {:?}", stream_handle, data_raw, data_len);
+
+    check_useful_c_byte_array!(data_raw, data_len, ErrorCode::CommonInvalidParam3, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_pack_stream_update: entities >>> stream_handle: {:?}, data_raw: {:?}, data_len: {:?}", stream_handle, data_raw, data_len);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::PackStreamUpdate(
+            stream_handle,
+            data_raw,
+            Box::new(move |result| {
+                let (err, packed) = prepare_result_1!(result, Vec::new());
+                trace!("indy_pack_stream_update: packed: {:?}", packed);
+                let (packed_raw, packed_len) = ctypes::vec_to_pointer(&packed);
+                cb(command_handle, err, packed_raw, packed_len)
+            }),
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_pack_stream_update: <<< res: {:?}", res);
+
+    res
+}
+
+/// Finishes packing of a message started by indy_pack_stream_start (Experimental)
+///
+/// Stream handle is released after this call.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// stream_handle: stream handle returned by indy_pack_stream_start.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// packed_raw: the last packed part to be written after previously returned parts
+/// packed_len: packed part length
+///
+/// #Errors
+/// Common*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_pack_stream_finish(command_handle: IndyHandle,
+                                      stream_handle: IndyHandle,
+                                      cb: Option<extern fn(command_handle_: IndyHandle,
+                                                           err: ErrorCode,
+                                                           packed_raw: *const u8,
+                                                           packed_len: u32)>) -> ErrorCode {
+    trace!("indy_pack_stream_finish: >>> stream_handle: {:?}", stream_handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_pack_stream_finish: entities >>> stream_handle: {:?}", stream_handle);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::PackStreamFinish(
+            stream_handle,
+            Box::new(move |result| {
+                let (err, packed) = prepare_result_1!(result, Vec::new());
+                trace!("indy_pack_stream_finish: packed: {:?}", packed);
+                let (packed_raw, packed_len) = ctypes::vec_to_pointer(&packed);
+                cb(command_handle, err, packed_raw, packed_len)
+            }),
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_pack_stream_finish: <<< res: {:?}", res);
+
+    res
+}
+
+/// Starts unpacking of a message produced by indy_pack_file or indy_pack_stream_* functions
+/// and passed by caller-provided buffers (Experimental)
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// stream_handle: handle of the stream to pass to indy_unpack_stream_update and indy_unpack_stream_finish
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_unpack_stream_start(command_handle: IndyHandle,
+                                       wallet_handle: IndyHandle,
+                                       cb: Option<extern fn(command_handle_: IndyHandle,
+                                                            err: ErrorCode,
+                                                            stream_handle: IndyHandle)>) -> ErrorCode {
+    trace!("indy_unpack_stream_start: >>> wallet_handle: {:?}", wallet_handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_unpack_stream_start: entities >>> wallet_handle: {:?}", wallet_handle);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::UnpackStreamStart(
+            wallet_handle,
+            Box::new(move |result| {
+                let (err, stream_handle) = prepare_result_1!(result, 0);
+                trace!("indy_unpack_stream_start: stream_handle: {:?}", stream_handle);
+                cb(command_handle, err, stream_handle)
+            }),
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_unpack_stream_start: <<< res: {:?}", res);
+
+    res
+}
+
+/// Unpacks the next part of a packed message (Experimental)
+///
+/// Parts can be of any size. Returned plaintext contains only authenticated segments,
+/// so it can be empty if there is not enough data for a segment yet.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// stream_handle: stream handle returned by indy_unpack_stream_start.
+/// data_raw: a pointer to the first byte of the packed part
+/// data_len: the length of the packed part in bytes
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// message_raw: unpacked message part
+/// message_len: unpacked message part length
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_unpack_stream_update(command_handle: IndyHandle,
+                                        stream_handle: IndyHandle,
+                                        data_raw: *const u8,
+                                        data_len: u32,
+                                        cb: Option<extern fn(command_handle_: IndyHandle,
+                                                             err: ErrorCode,
+                                                             message_raw: *const u8,
+                                                             message_len: u32)>) -> ErrorCode {
+    trace!("indy_unpack_stream_update: >>> stream_handle: {:?}, data_raw: {:?}, data_len: {:?}", stream_handle, data_raw, data_len);
+
+    check_useful_c_byte_array!(data_raw, data_len, ErrorCode::CommonInvalidParam3, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_unpack_stream_update: entities >>> stream_handle: {:?}, data_raw: {:?}, data_len: {:?}", stream_handle, data_raw, data_len);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::UnpackStreamUpdate(
+            stream_handle,
+            data_raw,
+            Box::new(move |result| {
+                let (err, message) = prepare_result_1!(result, Vec::new());
+                trace!("indy_unpack_stream_update: message len: {:?}", message.len());
+                let (message_raw, message_len) = ctypes::vec_to_pointer(&message);
+                cb(command_handle, err, message_raw, message_len)
+            }),
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_unpack_stream_update: <<< res: {:?}", res);
+
+    res
+}
+
+/// Finishes unpacking of a packed message (Experimental)
+///
+/// Fails if the final segment hasn't been received, so truncated message is detected.
+/// Stream handle is released after this call.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// stream_handle: stream handle returned by indy_unpack_stream_start.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// the same json as indy_unpack_file returns
+///
+/// #Errors
+/// Common*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_unpack_stream_finish(command_handle: IndyHandle,
+                                        stream_handle: IndyHandle,
+                                        cb: Option<extern fn(command_handle_: IndyHandle,
+                                                             err: ErrorCode,
+                                                             res_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_unpack_stream_finish: >>> stream_handle: {:?}", stream_handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_unpack_stream_finish: entities >>> stream_handle: {:?}", stream_handle);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::UnpackStreamFinish(
+            stream_handle,
+            Box::new(move |result| {
+                let (err, res_json) = prepare_result_1!(result, String::new());
+                trace!("indy_unpack_stream_finish: res_json: {:?}", res_json);
+                let res_json = ctypes::string_to_cstring(res_json);
+                cb(command_handle, err, res_json.as_ptr())
+            }),
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_unpack_stream_finish: <<< res: {:?}", res);
+
+    res
+}
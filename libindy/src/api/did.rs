@@ -3,7 +3,8 @@ extern crate libc;
 use api::{ErrorCode, IndyHandle};
 use commands::{Command, CommandExecutor};
 use commands::did::DidCommand;
-use domain::crypto::did::{MyDidInfo, TheirDidInfo};
+use domain::crypto::did::{KeyRotationNotice, MyDidInfo, TheirDidInfo};
+use domain::crypto::hd::HdRecoveryInfo;
 use domain::crypto::key::KeyInfo;
 use errors::prelude::*;
 use utils::ctypes;
@@ -36,6 +37,11 @@ use domain::ledger::attrib::Endpoint;
 ///     "crypto_type": string, (optional; if not set then ed25519 curve is used;
 ///               currently only 'ed25519' value is supported for this field)
 ///     "cid": bool, (optional; if not set then false is used;)
+///     "hd_path": string, (optional) SLIP-0010 derivation path (for example "m/44'/0'/1'") to derive keys from
+///                                   the wallet master seed (see indy_create_hd_master_seed). Can't be used together with seed.
+///     "method_name": string, (optional) Method of DID to create: "sov" for fully qualified did:sov, "key" for did:key or "peer" for did:peer.
+///                                       If not set unqualified Indy DID is created. Can't be used together with did and cid.
+///     "numalgo": int, (optional) did:peer numalgo: 0 (inception key) or 2 (default).
 /// }
 /// cb: Callback that takes command result as parameter.
 ///
@@ -99,6 +105,8 @@ pub  extern fn indy_create_and_store_my_did(command_handle: IndyHandle,
 ///                                Can be UTF-8, base64 or hex string.
 ///     "crypto_type": string, (optional; if not set then ed25519 curve is used;
 ///               currently only 'ed25519' value is supported for this field)
+///     "hd_path": string, (optional) SLIP-0010 derivation path (for example "m/44'/0'/1'") to derive keys from
+///                                   the wallet master seed (see indy_create_hd_master_seed). Can't be used together with seed.
 /// }
 /// cb: Callback that takes command result as parameter.
 ///
@@ -199,6 +207,123 @@ pub  extern fn indy_replace_keys_apply(command_handle: IndyHandle,
     res
 }
 
+/// Creates key rotation notice for an existing DID (owned by the caller of the library)
+/// with started key replacement (see indy_replace_keys_start).
+///
+/// Notice is signed by the current key of DID and can be sent to the other side of pairwise
+/// relationships to let them verify and apply the new key (see indy_apply_key_rotation_notice).
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// did: DID stored in the wallet
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - notice_json: Key rotation notice json:
+///     {
+///         "did": string, - DID which key is rotated
+///         "verkey": string, - current verkey of DID
+///         "new_verkey": string, - verkey replacing the current one
+///         "timestamp": int, - time of notice creation (seconds since epoch)
+///         "signature": string, - base58 encoded signature of "did|verkey|new_verkey|timestamp" by current key
+///     }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_create_key_rotation_notice(command_handle: IndyHandle,
+                                              wallet_handle: IndyHandle,
+                                              did: *const c_char,
+                                              cb: Option<extern fn(command_handle_: IndyHandle,
+                                                                   err: ErrorCode,
+                                                                   notice_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_create_key_rotation_notice: >>> wallet_handle: {:?}, did: {:?}", wallet_handle, did);
+
+    check_useful_c_str!(did, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_create_key_rotation_notice: entities >>> wallet_handle: {:?}, did: {:?}", wallet_handle, did);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Did(DidCommand::CreateKeyRotationNotice(
+            wallet_handle,
+            did,
+            Box::new(move |result| {
+                let (err, notice_json) = prepare_result_1!(result, String::new());
+                trace!("indy_create_key_rotation_notice: notice_json: {:?}", notice_json);
+                let notice_json = ctypes::string_to_cstring(notice_json);
+                cb(command_handle, err, notice_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_create_key_rotation_notice: <<< res: {:?}", res);
+
+    res
+}
+
+/// Verifies key rotation notice received from the other side of pairwise relationship
+/// and replaces their verkey stored in the wallet.
+///
+/// Notice must be signed by the currently stored verkey of their DID and be newer than
+/// the last applied notice. Pairwise must exist for their DID. Both their DID record
+/// and pairwise (its "their_key_rotated_at" field) are updated.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// notice_json: Key rotation notice json (see indy_create_key_rotation_notice).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_apply_key_rotation_notice(command_handle: IndyHandle,
+                                             wallet_handle: IndyHandle,
+                                             notice_json: *const c_char,
+                                             cb: Option<extern fn(command_handle_: IndyHandle,
+                                                                  err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_apply_key_rotation_notice: >>> wallet_handle: {:?}, notice_json: {:?}", wallet_handle, notice_json);
+
+    check_useful_json!(notice_json, ErrorCode::CommonInvalidParam3, KeyRotationNotice);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_apply_key_rotation_notice: entities >>> wallet_handle: {:?}, notice_json: {:?}", wallet_handle, notice_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Did(DidCommand::ApplyKeyRotationNotice(
+            wallet_handle,
+            notice_json,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_apply_key_rotation_notice:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_apply_key_rotation_notice: <<< res: {:?}", res);
+
+    res
+}
+
 /// Saves their DID for a pairwise connection in a secured Wallet,
 /// so that it can be used to verify transaction.
 ///
@@ -380,6 +505,124 @@ pub extern fn indy_key_for_local_did(command_handle: IndyHandle,
     res
 }
 
+/// Returns ver key (key id) which was active for the given DID at the given time.
+///
+/// Lookup is performed in the local wallet only using the history of DID keys.
+/// History is tracked for DIDs created by "indy_create_and_store_my_did" and stored by
+/// "indy_store_their_did" and is updated by "indy_replace_keys_apply" and "indy_apply_key_rotation_notice".
+/// For DIDs stored before the history was introduced the current key is treated as active since epoch.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// did - The DID to resolve key.
+/// timestamp - Time (seconds since epoch) to resolve key at.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - key - The DIDs ver key (key id) active at the given time.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_key_for_local_did_at(command_handle: IndyHandle,
+                                        wallet_handle: IndyHandle,
+                                        did: *const c_char,
+                                        timestamp: u64,
+                                        cb: Option<extern fn(command_handle_: IndyHandle,
+                                                             err: ErrorCode,
+                                                             key: *const c_char)>) -> ErrorCode {
+    trace!("indy_key_for_local_did_at: >>> wallet_handle: {:?}, did: {:?}, timestamp: {:?}", wallet_handle, did, timestamp);
+
+    check_useful_c_str!(did, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_key_for_local_did_at: entities >>> wallet_handle: {:?}, did: {:?}, timestamp: {:?}", wallet_handle, did, timestamp);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Did(DidCommand::KeyForLocalDidAt(
+            wallet_handle,
+            did,
+            timestamp,
+            Box::new(move |result| {
+                let (err, key) = prepare_result_1!(result, String::new());
+                trace!("indy_key_for_local_did_at: key: {:?}", key);
+                let key = ctypes::string_to_cstring(key);
+                cb(command_handle, err, key.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_key_for_local_did_at: <<< res: {:?}", res);
+
+    res
+}
+
+/// Returns the history of ver keys for the given DID stored in the local wallet.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// did - The DID to list keys.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - keys_json - The DIDs keys in order of creation:
+///   [{
+///       "verkey": string, - ver key (key id)
+///       "activated_at": Optional<int>, - time (seconds since epoch) key became active.
+///                                        Absent for temporary key created by indy_replace_keys_start.
+///       "deactivated_at": Optional<int>, - time (seconds since epoch) key was replaced. Absent for current key.
+///   }]
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_list_did_keys(command_handle: IndyHandle,
+                                 wallet_handle: IndyHandle,
+                                 did: *const c_char,
+                                 cb: Option<extern fn(command_handle_: IndyHandle,
+                                                      err: ErrorCode,
+                                                      keys_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_list_did_keys: >>> wallet_handle: {:?}, did: {:?}", wallet_handle, did);
+
+    check_useful_c_str!(did, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_list_did_keys: entities >>> wallet_handle: {:?}, did: {:?}", wallet_handle, did);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Did(DidCommand::ListDidKeys(
+            wallet_handle,
+            did,
+            Box::new(move |result| {
+                let (err, keys_json) = prepare_result_1!(result, String::new());
+                trace!("indy_list_did_keys: keys_json: {:?}", keys_json);
+                let keys_json = ctypes::string_to_cstring(keys_json);
+                cb(command_handle, err, keys_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_list_did_keys: <<< res: {:?}", res);
+
+    res
+}
+
 /// Set/replaces endpoint information for the given DID.
 ///
 /// #Params
@@ -762,4 +1005,68 @@ pub  extern fn indy_abbreviate_verkey(command_handle: IndyHandle,
     trace!("indy_abbreviate_verkey: <<< res: {:?}", res);
 
     res
-}
\ No newline at end of file
+}
+
+/// Re-derives keys and DIDs from the wallet master seed (see indy_create_hd_master_seed)
+/// and stores them in the wallet. Already existing keys and DIDs are kept untouched.
+///
+/// Allows to restore keys and pairwise DIDs after a wallet loss by restoring master seed
+/// from the mnemonic and calling this function with the list of used derivation paths.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// recovery_json: derivation paths to recover:
+/// {
+///     "keys": [string], (optional) derivation paths of keys created by indy_create_key
+///     "dids": [string], (optional) derivation paths of DIDs created by indy_create_and_store_my_did
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - recovered_json: recovered keys and DIDs in the same order as paths:
+/// {
+///     "keys": [<verkey>],
+///     "dids": [{"did": <did>, "verkey": <verkey>}],
+/// }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_recover_hd_keys(command_handle: IndyHandle,
+                                   wallet_handle: IndyHandle,
+                                   recovery_json: *const c_char,
+                                   cb: Option<extern fn(command_handle_: IndyHandle,
+                                                        err: ErrorCode,
+                                                        recovered_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_recover_hd_keys: >>> wallet_handle: {:?}, recovery_json: {:?}", wallet_handle, recovery_json);
+
+    check_useful_json!(recovery_json, ErrorCode::CommonInvalidParam3, HdRecoveryInfo);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_recover_hd_keys: entities >>> wallet_handle: {:?}, recovery_json: {:?}", wallet_handle, recovery_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Did(DidCommand::RecoverHdKeys(
+            wallet_handle,
+            recovery_json,
+            Box::new(move |result| {
+                let (err, recovered_json) = prepare_result_1!(result, String::new());
+                trace!("indy_recover_hd_keys: recovered_json: {:?}", recovered_json);
+                let recovered_json = ctypes::string_to_cstring(recovered_json);
+                cb(command_handle, err, recovered_json.as_ptr())
+            }),
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_recover_hd_keys: <<< res: {:?}", res);
+
+    res
+}
@@ -9,10 +9,12 @@ use domain::anoncreds::schema::Schema;
 use domain::anoncreds::revocation_registry_definition::RevocationRegistryDefinition;
 use domain::anoncreds::revocation_registry_delta::RevocationRegistryDelta;
 use domain::ledger::node::NodeOperationData;
+use domain::ledger::signatures::SignatureQuorum;
 use utils::ctypes;
 
 use serde_json;
 use self::libc::c_char;
+use std::collections::HashMap;
 
 /// Signs and submits request message to validator pool.
 ///
@@ -301,6 +303,92 @@ pub extern fn indy_multi_sign_request(command_handle: IndyHandle,
     res
 }
 
+/// Verifies signatures of the request.
+///
+/// Signatures are checked against the request serialized the same way as for signing
+/// (see indy_sign_request and indy_multi_sign_request). Verkey of each signer is taken from
+/// verkeys_json if provided, otherwise from my or their DID stored in the wallet.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// request_json: Signed request data json.
+/// verkeys_json: (Optional) map of signer DIDs to their verkeys:
+///     {
+///         "<did>": "<verkey>", // abbreviated verkeys are supported
+///         ...
+///     }
+/// quorum_json: (Optional) required signatures:
+///     {
+///         "signers": Optional<[string]>, // DIDs whose valid signatures are counted (any signer if not set)
+///         "required": Optional<[string]>, // DIDs whose valid signatures must be present
+///         "threshold": Optional<int>, // minimal number of counted valid signatures (1 by default)
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Signatures report json:
+///     {
+///         "signatures": {
+///             "<did>": {
+///                 "valid": bool,
+///                 "verkey": Optional<string>, // resolved verkey of the signer
+///                 "error": Optional<string>, // reason signature can't be verified
+///             },
+///             ...
+///         },
+///         "valid_signers": [string],
+///         "invalid_signers": [string],
+///         "missing_required": [string],
+///         "threshold": int,
+///         "quorum_met": bool
+///     }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Ledger*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_verify_request_signatures(command_handle: IndyHandle,
+                                             wallet_handle: IndyHandle,
+                                             request_json: *const c_char,
+                                             verkeys_json: *const c_char,
+                                             quorum_json: *const c_char,
+                                             cb: Option<extern fn(command_handle_: IndyHandle, err: ErrorCode,
+                                                                  report_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_verify_request_signatures: >>> wallet_handle: {:?}, request_json: {:?}, verkeys_json: {:?}, quorum_json: {:?}",
+           wallet_handle, request_json, verkeys_json, quorum_json);
+
+    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam3);
+    check_useful_opt_json!(verkeys_json, ErrorCode::CommonInvalidParam4, HashMap<String, String>);
+    check_useful_opt_json!(quorum_json, ErrorCode::CommonInvalidParam5, SignatureQuorum);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_verify_request_signatures: entities >>> wallet_handle: {:?}, request_json: {:?}, verkeys_json: {:?}, quorum_json: {:?}",
+           wallet_handle, request_json, verkeys_json, quorum_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::VerifyRequestSignatures(
+            wallet_handle,
+            request_json,
+            verkeys_json,
+            quorum_json,
+            Box::new(move |result| {
+                let (err, report_json) = prepare_result_1!(result, String::new());
+                trace!("indy_verify_request_signatures: report_json: {:?}", report_json);
+                let report_json = ctypes::string_to_cstring(report_json);
+                cb(command_handle, err, report_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_verify_request_signatures: <<< res: {:?}", res);
+
+    res
+}
+
 
 /// Builds a request to get a DDO.
 ///
@@ -598,6 +686,8 @@ pub extern fn indy_build_get_attrib_request(command_handle: IndyHandle,
 ///     name: Schema's name string
 ///     version: Schema's version string,
 ///     ver: Version of the Schema json
+///     attrTypes: (optional) type hints of attributes (see indy_issuer_create_schema_with_types),
+///                published with the schema so verifiers check revealed values by them
 /// }
 /// cb: Callback that takes command result as parameter.
 ///
@@ -702,6 +792,7 @@ pub extern fn indy_build_get_schema_request(command_handle: IndyHandle,
 ///     name: Schema's name string
 ///     version: Schema's version string
 ///     ver: Version of the Schema json
+///     attrTypes: (optional) type hints of attributes if they were published with the schema
 /// }
 ///
 /// #Errors
@@ -245,6 +245,8 @@ pub enum ErrorCode
 ///     "collect_backtrace": Optional<bool> - whether errors backtrace should be collected.
 ///         Capturing of backtrace can affect library performance.
 ///         NOTE: must be set before invocation of any other API functions.
+///     "verify_credential_on_store": Optional<bool> - whether indy_prover_store_credential should check the credential
+///         against credential definition and revocation registry definition as indy_prover_verify_credential does. (false by default)
 /// }
 ///
 /// #Errors
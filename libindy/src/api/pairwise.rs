@@ -3,6 +3,7 @@ extern crate libc;
 use api::{ErrorCode, IndyHandle};
 use commands::{Command, CommandExecutor};
 use commands::pairwise::PairwiseCommand;
+use domain::wallet::Tags;
 use errors::prelude::*;
 use utils::ctypes;
 
@@ -245,4 +246,205 @@ pub  extern fn indy_set_pairwise_metadata(command_handle: IndyHandle,
     trace!("indy_set_pairwise_metadata: <<< res: {:?}", res);
 
     res
-}
\ No newline at end of file
+}
+
+/// Replaces searchable tags of pairwise associated with Did.
+/// Tags can be used to search pairwise with indy_search_pairwise.
+///
+/// #Params
+/// wallet_handle: wallet handler (created by open_wallet).
+/// command_handle: command handle to map callback to user context.
+/// their_did: encoded Did
+/// tags_json: tags of pairwise as json, for example label, state or last contact time:
+///     {
+///         "tagName1": <str>, // string tag (will be stored encrypted)
+///         "~tagName2": <str>, // string tag (will be stored un-encrypted)
+///     }
+///     Note that only un-encrypted tags can be used in range ($gt, $lt ...) queries.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub  extern fn indy_set_pairwise_tags(command_handle: IndyHandle,
+                                      wallet_handle: IndyHandle,
+                                      their_did: *const c_char,
+                                      tags_json: *const c_char,
+                                      cb: Option<extern fn(command_handle_: IndyHandle,
+                                                           err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_set_pairwise_tags: >>> wallet_handle: {:?}, their_did: {:?}, tags_json: {:?}", wallet_handle, their_did, tags_json);
+
+    check_useful_c_str!(their_did, ErrorCode::CommonInvalidParam3);
+    check_useful_json!(tags_json, ErrorCode::CommonInvalidParam4, Tags);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_set_pairwise_tags: entities >>> wallet_handle: {:?}, their_did: {:?}, tags_json: {:?}", wallet_handle, their_did, tags_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Pairwise(PairwiseCommand::SetPairwiseTags(
+            wallet_handle,
+            their_did,
+            tags_json,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_set_pairwise_tags:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_set_pairwise_tags: <<< res: {:?}", res);
+
+    res
+}
+
+/// Search for pairwise stored in the wallet.
+/// Pairwise can be filtered by tags set with indy_set_pairwise_tags.
+///
+/// Instead of immediately returning of fetched pairwise
+/// this call returns search_handle that can be used later
+/// to fetch records by small batches (with indy_fetch_pairwise_search).
+///
+/// #Params
+/// wallet_handle: wallet handler (created by open_wallet).
+/// command_handle: command handle to map callback to user context.
+/// query_json: Wql query filter for pairwise searching based on tags.
+/// where query: indy-sdk/doc/design/011-wallet-query-language/README.md
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// search_handle: Search handle that can be used later to fetch records by small batches (with indy_fetch_pairwise_search)
+/// total_count: Total count of records
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub  extern fn indy_search_pairwise(command_handle: IndyHandle,
+                                    wallet_handle: IndyHandle,
+                                    query_json: *const c_char,
+                                    cb: Option<extern fn(command_handle_: IndyHandle,
+                                                         err: ErrorCode,
+                                                         search_handle: IndyHandle,
+                                                         total_count: usize)>) -> ErrorCode {
+    trace!("indy_search_pairwise: >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
+
+    check_useful_opt_c_str!(query_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_search_pairwise: entities >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Pairwise(PairwiseCommand::SearchPairwise(
+            wallet_handle,
+            query_json,
+            Box::new(move |result| {
+                let (err, handle, total_count) = prepare_result_2!(result, 0, 0);
+                trace!("indy_search_pairwise: handle: {:?}, total_count: {:?}", handle, total_count);
+                cb(command_handle, err, handle, total_count)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_search_pairwise: <<< res: {:?}", res);
+
+    res
+}
+
+/// Fetch next pairwise for search.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// search_handle: Search handle (created by indy_search_pairwise)
+/// count: Count of pairwise to fetch
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// pairwise_list_json: List of pairwise:
+///     [{
+///         "their_did": string,
+///         "my_did": string,
+///         "metadata": Optional<string>,
+///         "their_key_rotated_at": Optional<int>, - timestamp of the last applied key rotation notice
+///         "tags": {"tagName1": string, ...}
+///     }]
+/// NOTE: The list of length less than the requested count means pairwise search iterator is completed.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub  extern fn indy_fetch_pairwise_search(command_handle: IndyHandle,
+                                          search_handle: IndyHandle,
+                                          count: usize,
+                                          cb: Option<extern fn(command_handle_: IndyHandle,
+                                                               err: ErrorCode,
+                                                               pairwise_list_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_fetch_pairwise_search: >>> search_handle: {:?}, count: {:?}", search_handle, count);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_fetch_pairwise_search: entities >>> search_handle: {:?}, count: {:?}", search_handle, count);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Pairwise(PairwiseCommand::FetchPairwiseSearch(
+            search_handle,
+            count,
+            Box::new(move |result| {
+                let (err, pairwise_list_json) = prepare_result_1!(result, String::new());
+                trace!("indy_fetch_pairwise_search: pairwise_list_json: {:?}", pairwise_list_json);
+                let pairwise_list_json = ctypes::string_to_cstring(pairwise_list_json);
+                cb(command_handle, err, pairwise_list_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_fetch_pairwise_search: <<< res: {:?}", res);
+
+    res
+}
+
+/// Close pairwise search (make search handle invalid)
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// search_handle: Search handle (created by indy_search_pairwise)
+/// cb: Callback that takes command result as parameter.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub  extern fn indy_close_pairwise_search(command_handle: IndyHandle,
+                                          search_handle: IndyHandle,
+                                          cb: Option<extern fn(command_handle_: IndyHandle,
+                                                               err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_close_pairwise_search: >>> search_handle: {:?}", search_handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_close_pairwise_search: entities >>> search_handle: {:?}", search_handle);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Pairwise(PairwiseCommand::ClosePairwiseSearch(
+            search_handle,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_close_pairwise_search:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_close_pairwise_search: <<< res: {:?}", res);
+
+    res
+}
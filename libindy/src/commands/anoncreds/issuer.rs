@@ -11,13 +11,14 @@ use indy_crypto::cl::{CredentialKeyCorrectnessProof, CredentialPrivateKey};
 
 use commands::{Command, CommandExecutor};
 use commands::anoncreds::AnoncredsCommand;
-use domain::anoncreds::credential::{AttributeValues, Credential};
+use domain::anoncreds::credential::{AttributeValues, Credential, IssuanceLogKey, IssuedCredentialInfo};
 use domain::anoncreds::credential_definition::{
     CredentialDefinition,
     CredentialDefinitionConfig,
     CredentialDefinitionCorrectnessProof,
     CredentialDefinitionData,
     CredentialDefinitionPrivateKey,
+    CredentialDefinitionRotationInfo,
     CredentialDefinitionV1,
     SignatureType,
 };
@@ -28,6 +29,7 @@ use domain::anoncreds::revocation_registry::{
     RevocationRegistryV1,
 };
 use domain::anoncreds::revocation_registry_definition::{
+    CredentialDefinitionRevocationInfo,
     IssuanceType,
     RegistryType,
     RevocationRegistryConfig,
@@ -36,20 +38,25 @@ use domain::anoncreds::revocation_registry_definition::{
     RevocationRegistryDefinitionV1,
     RevocationRegistryDefinitionValue,
     RevocationRegistryInfo,
+    RevocationRegistryRotationConfig,
+    RevocationRegistryRotationResult,
 };
 use domain::anoncreds::revocation_registry_delta::{
     RevocationRegistryDelta,
     RevocationRegistryDeltaV1,
 };
-use domain::anoncreds::schema::{AttributeNames, Schema, SchemaV1, MAX_ATTRIBUTES_COUNT};
+use domain::anoncreds::schema::{AttributeNames, AttributeTypes, Schema, SchemaAttributeTypes, SchemaV1, MAX_ATTRIBUTES_COUNT};
 use domain::wallet::Tags;
 use errors::prelude::*;
 use services::anoncreds::AnoncredsService;
-use services::anoncreds::helpers::parse_cred_rev_id;
+use services::anoncreds::helpers::{attr_common_view, encode_typed_credential_attribute, get_attribute_type, hash_credential_values, parse_cred_rev_id};
 use services::blob_storage::BlobStorageService;
 use services::crypto::CryptoService;
+use services::ledger::LedgerService;
 use services::pool::PoolService;
-use services::wallet::{RecordOptions, WalletService};
+use services::wallet::{RecordOptions, SearchOptions, WalletSearch, WalletService};
+use utils::crypto::hmacsha256;
+use utils::sequence;
 
 use super::tails::{SDKTailsAccessor, store_tails_from_generator};
 
@@ -59,6 +66,7 @@ pub enum IssuerCommand {
         String, // name
         String, // version
         AttributeNames, // attribute names
+        Option<AttributeTypes>, // attribute types
         Box<Fn(IndyResult<(String, String)>) + Send>),
     CreateAndStoreCredentialDefinition(
         i32, // wallet handle
@@ -68,6 +76,14 @@ pub enum IssuerCommand {
         Option<String>, // type
         Option<CredentialDefinitionConfig>, // config
         Box<Fn(IndyResult<(String, String)>) + Send>),
+    RotateCredentialDefinition(
+        i32, // wallet handle
+        String, // issuer did
+        String, // credential definition id
+        Schema, // schema
+        String, // tag
+        Option<CredentialDefinitionConfig>, // config
+        Box<Fn(IndyResult<(String, String)>) + Send>),
     CreateCredentialDefinition(AttributeNames,
                                bool,
                                Box<Fn(IndyResult<(CredentialDefinitionData,
@@ -83,6 +99,7 @@ pub enum IssuerCommand {
         IndyResult<(CredentialDefinitionData,
                     CredentialPrivateKey,
                     CredentialKeyCorrectnessProof)>,
+        Option<String>, // predecessor credential definition id
         i32),
     CreateAndStoreRevocationRegistry(
         i32, // wallet handle
@@ -93,6 +110,13 @@ pub enum IssuerCommand {
         RevocationRegistryConfig, // config
         i32, // tails writer handle
         Box<Fn(IndyResult<(String, String, String)>) + Send>),
+    RotateRevocationRegistry(
+        i32, // wallet handle
+        String, // issuer did
+        String, // credential definition id
+        RevocationRegistryRotationConfig, // config
+        i32, // tails writer handle
+        Box<Fn(IndyResult<String>) + Send>),
     CreateCredentialOffer(
         i32, // wallet handle
         String, // credential definition id
@@ -104,6 +128,7 @@ pub enum IssuerCommand {
         HashMap<String, AttributeValues>, // credential values
         Option<String>, // revocation registry id
         Option<i32>, // blob storage reader config handle
+        Option<Tags>, // issuance log tags
         Box<Fn(IndyResult<(String, Option<String>, Option<String>)>) + Send>),
     RevokeCredential(
         i32, // wallet handle
@@ -111,6 +136,23 @@ pub enum IssuerCommand {
         String, //revocation revoc id
         String, //credential revoc id
         Box<Fn(IndyResult<String>) + Send>),
+    RevokeCredentials(
+        i32, // wallet handle
+        i32, // blob storage reader config handle
+        String, //revocation revoc id
+        Vec<String>, //credential revoc ids
+        Box<Fn(IndyResult<String>) + Send>),
+    SearchIssuedCredentials(
+        i32, // wallet handle
+        Option<String>, // query json
+        Box<Fn(IndyResult<(i32, usize)>) + Send>),
+    FetchIssuedCredentials(
+        i32, // search handle
+        usize, // count
+        Box<Fn(IndyResult<String>) + Send>),
+    CloseIssuedCredentialsSearch(
+        i32, // search handle
+        Box<Fn(IndyResult<()>) + Send>),
     /*    RecoverCredential(
             i32, // wallet handle
             i32, // blob storage reader config handle
@@ -129,7 +171,9 @@ pub struct IssuerCommandExecutor {
     pub pool_service: Rc<PoolService>,
     pub wallet_service: Rc<WalletService>,
     pub crypto_service: Rc<CryptoService>,
+    pub ledger_service: Rc<LedgerService>,
     pending_callbacks: RefCell<HashMap<i32, Box<Fn(IndyResult<(String, String)>) + Send>>>,
+    issued_credentials_searches: RefCell<HashMap<i32, Box<WalletSearch>>>,
 }
 
 impl IssuerCommandExecutor {
@@ -137,34 +181,42 @@ impl IssuerCommandExecutor {
                pool_service: Rc<PoolService>,
                blob_storage_service: Rc<BlobStorageService>,
                wallet_service: Rc<WalletService>,
-               crypto_service: Rc<CryptoService>) -> IssuerCommandExecutor {
+               crypto_service: Rc<CryptoService>,
+               ledger_service: Rc<LedgerService>) -> IssuerCommandExecutor {
         IssuerCommandExecutor {
             anoncreds_service,
             pool_service,
             blob_storage_service,
             wallet_service,
             crypto_service,
+            ledger_service,
             pending_callbacks: RefCell::new(HashMap::new()),
+            issued_credentials_searches: RefCell::new(HashMap::new()),
         }
     }
 
     pub fn execute(&self, command: IssuerCommand) {
         match command {
-            IssuerCommand::CreateSchema(issuer_did, name, version, attrs, cb) => {
+            IssuerCommand::CreateSchema(issuer_did, name, version, attrs, attr_types, cb) => {
                 info!(target: "issuer_command_executor", "CreateSchema command received");
-                cb(self.create_schema(&issuer_did, &name, &version, attrs));
+                cb(self.create_schema(&issuer_did, &name, &version, attrs, attr_types));
             }
             IssuerCommand::CreateAndStoreCredentialDefinition(wallet_handle, issuer_did, schema, tag, type_, config, cb) => {
                 info!(target: "issuer_command_executor", "CreateAndStoreCredentialDefinition command received");
                 self.create_and_store_credential_definition(wallet_handle, &issuer_did, &SchemaV1::from(schema), &tag,
-                                                            type_.as_ref().map(String::as_str), config.as_ref(), cb);
+                                                            type_.as_ref().map(String::as_str), config.as_ref(), None, cb);
+            }
+            IssuerCommand::RotateCredentialDefinition(wallet_handle, issuer_did, cred_def_id, schema, tag, config, cb) => {
+                info!(target: "issuer_command_executor", "RotateCredentialDefinition command received");
+                self.rotate_credential_definition(wallet_handle, &issuer_did, &cred_def_id, &SchemaV1::from(schema), &tag, config.as_ref(), cb);
             }
             IssuerCommand::CreateCredentialDefinition(attr_names, support_revocation, cb) => {
                 self._create_credential_definition(&attr_names, support_revocation, cb)
             }
-            IssuerCommand::CreateAndStoreCredentialDefinitionContinue(wallet_handle, schema, schema_id, cred_def_id, tag, signature_type, result, cb_id) => {
+            IssuerCommand::CreateAndStoreCredentialDefinitionContinue(wallet_handle, schema, schema_id, cred_def_id, tag, signature_type, result, predecessor_cred_def_id, cb_id) => {
                 debug!(target: "wallet_command_executor", "CreateAndStoreCredentialDefinitionContinue command received");
-                self._create_and_store_credential_definition_continue(cb_id, wallet_handle, &schema, &schema_id, &cred_def_id, &tag, &signature_type, result)
+                self._create_and_store_credential_definition_continue(cb_id, wallet_handle, &schema, &schema_id, &cred_def_id, &tag, &signature_type, result,
+                                                                      predecessor_cred_def_id.as_ref().map(String::as_str))
             }
             IssuerCommand::CreateAndStoreRevocationRegistry(wallet_handle, issuer_did, type_, tag, cred_def_id, config,
                                                             tails_writer_handle, cb) => {
@@ -177,18 +229,39 @@ impl IssuerCommandExecutor {
                                                              &config,
                                                              tails_writer_handle));
             }
+            IssuerCommand::RotateRevocationRegistry(wallet_handle, issuer_did, cred_def_id, config, tails_writer_handle, cb) => {
+                info!(target: "issuer_command_executor", "RotateRevocationRegistry command received");
+                cb(self.rotate_revocation_registry(wallet_handle, &issuer_did, &cred_def_id, &config, tails_writer_handle));
+            }
             IssuerCommand::CreateCredentialOffer(wallet_handle, cred_def_id, cb) => {
                 info!(target: "issuer_command_executor", "CreateCredentialOffer command received");
                 cb(self.create_credential_offer(wallet_handle, &cred_def_id));
             }
-            IssuerCommand::CreateCredential(wallet_handle, cred_offer, cred_req, cred_values, rev_reg_id, blob_storage_reader_handle, cb) => {
+            IssuerCommand::CreateCredential(wallet_handle, cred_offer, cred_req, cred_values, rev_reg_id, blob_storage_reader_handle, log_tags, cb) => {
                 info!(target: "issuer_command_executor", "CreateCredential command received");
-                cb(self.new_credential(wallet_handle, &cred_offer, &cred_req, &cred_values, rev_reg_id.as_ref().map(String::as_str), blob_storage_reader_handle));
+                cb(self.new_credential(wallet_handle, &cred_offer, &cred_req, &cred_values, rev_reg_id.as_ref().map(String::as_str), blob_storage_reader_handle,
+                                       log_tags.as_ref()));
             }
             IssuerCommand::RevokeCredential(wallet_handle, blob_storage_reader_handle, rev_reg_id, cred_revoc_id, cb) => {
                 info!(target: "issuer_command_executor", "RevokeCredential command received");
                 cb(self.revoke_credential(wallet_handle, blob_storage_reader_handle, &rev_reg_id, &cred_revoc_id));
             }
+            IssuerCommand::RevokeCredentials(wallet_handle, blob_storage_reader_handle, rev_reg_id, cred_revoc_ids, cb) => {
+                info!(target: "issuer_command_executor", "RevokeCredentials command received");
+                cb(self.revoke_credentials(wallet_handle, blob_storage_reader_handle, &rev_reg_id, &cred_revoc_ids));
+            }
+            IssuerCommand::SearchIssuedCredentials(wallet_handle, query_json, cb) => {
+                info!(target: "issuer_command_executor", "SearchIssuedCredentials command received");
+                cb(self.search_issued_credentials(wallet_handle, query_json.as_ref().map(String::as_str)));
+            }
+            IssuerCommand::FetchIssuedCredentials(search_handle, count, cb) => {
+                info!(target: "issuer_command_executor", "FetchIssuedCredentials command received");
+                cb(self.fetch_issued_credentials(search_handle, count));
+            }
+            IssuerCommand::CloseIssuedCredentialsSearch(search_handle, cb) => {
+                info!(target: "issuer_command_executor", "CloseIssuedCredentialsSearch command received");
+                cb(self.close_issued_credentials_search(search_handle));
+            }
             /*            IssuerCommand::RecoverCredential(wallet_handle, blob_storage_reader_handle, rev_reg_id, cred_revoc_id, cb) => {
                             info!(target: "issuer_command_executor", "RecoverCredential command received");
                             cb(self.recovery_credential(wallet_handle, blob_storage_reader_handle, &rev_reg_id, &cred_revoc_id));
@@ -205,8 +278,9 @@ impl IssuerCommandExecutor {
                      issuer_did: &str,
                      name: &str,
                      version: &str,
-                     attrs: AttributeNames) -> IndyResult<(String, String)> {
-        debug!("create_schema >>> issuer_did: {:?}, name: {:?}, version: {:?}, attrs: {:?}", issuer_did, name, version, attrs);
+                     attrs: AttributeNames,
+                     attr_types: Option<AttributeTypes>) -> IndyResult<(String, String)> {
+        debug!("create_schema >>> issuer_did: {:?}, name: {:?}, version: {:?}, attrs: {:?}, attr_types: {:?}", issuer_did, name, version, attrs, attr_types);
 
         self.crypto_service.validate_did(issuer_did)?;
 
@@ -215,6 +289,14 @@ impl IssuerCommandExecutor {
                                format!("The number of Schema attributes {} cannot be greater than {}", attrs.len(), MAX_ATTRIBUTES_COUNT)));
         }
 
+        if let Some(ref attr_types) = attr_types {
+            let attr_names: HashSet<String> = attrs.iter().map(|attr| attr_common_view(attr)).collect();
+
+            if let Some(attr) = attr_types.keys().find(|attr| !attr_names.contains(&attr_common_view(attr))) {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Type is specified for unknown Schema attribute {:?}", attr)));
+            }
+        }
+
         let schema_id = Schema::schema_id(issuer_did, name, version);
 
         let schema = Schema::SchemaV1(SchemaV1 {
@@ -223,6 +305,7 @@ impl IssuerCommandExecutor {
             version: version.to_string(),
             attr_names: attrs,
             seq_no: None,
+            attr_types,
         });
 
         let schema_json = serde_json::to_string(&schema)
@@ -240,9 +323,10 @@ impl IssuerCommandExecutor {
                                               tag: &str,
                                               type_: Option<&str>,
                                               config: Option<&CredentialDefinitionConfig>,
+                                              predecessor_cred_def_id: Option<&str>,
                                               cb: Box<Fn(IndyResult<(String, String)>) + Send>) {
         debug!("create_and_store_credential_definition >>> wallet_handle: {:?}, issuer_did: {:?}, schema: {:?}, tag: {:?}, \
-              type_: {:?}, config: {:?}", wallet_handle, issuer_did, schema, tag, type_, config);
+              type_: {:?}, config: {:?}, predecessor_cred_def_id: {:?}", wallet_handle, issuer_did, schema, tag, type_, config, predecessor_cred_def_id);
 
         let (cred_def_config, schema_id, cred_def_id, signature_type) =
             try_cb!(self._prepare_create_and_store_credential_definition(wallet_handle, issuer_did, schema, tag, type_, config), cb);
@@ -252,6 +336,7 @@ impl IssuerCommandExecutor {
 
         let tag = tag.to_string();
         let schema = schema.clone();
+        let predecessor_cred_def_id = predecessor_cred_def_id.map(String::from);
 
         CommandExecutor::instance().send(Command::Anoncreds(
             AnoncredsCommand::Issuer(
@@ -270,6 +355,7 @@ impl IssuerCommandExecutor {
                                         tag.clone(),
                                         signature_type.clone(),
                                         res,
+                                        predecessor_cred_def_id.clone(),
                                         cb_id,
                                     ))
                             )).unwrap();
@@ -278,6 +364,78 @@ impl IssuerCommandExecutor {
         )).unwrap();
     }
 
+    fn rotate_credential_definition(&self,
+                                    wallet_handle: i32,
+                                    issuer_did: &str,
+                                    cred_def_id: &str,
+                                    schema: &SchemaV1,
+                                    tag: &str,
+                                    config: Option<&CredentialDefinitionConfig>,
+                                    cb: Box<Fn(IndyResult<(String, String)>) + Send>) {
+        debug!("rotate_credential_definition >>> wallet_handle: {:?}, issuer_did: {:?}, cred_def_id: {:?}, schema: {:?}, tag: {:?}, config: {:?}",
+               wallet_handle, issuer_did, cred_def_id, schema, tag, config);
+
+        let (signature_type, cred_def_config) =
+            try_cb!(self._prepare_rotate_credential_definition(wallet_handle, issuer_did, cred_def_id, schema, config), cb);
+
+        self.create_and_store_credential_definition(wallet_handle, issuer_did, schema, tag, Some(signature_type.to_str()),
+                                                    Some(&cred_def_config), Some(cred_def_id), cb);
+    }
+
+    fn _prepare_rotate_credential_definition(&self,
+                                             wallet_handle: i32,
+                                             issuer_did: &str,
+                                             cred_def_id: &str,
+                                             schema: &SchemaV1,
+                                             config: Option<&CredentialDefinitionConfig>) -> IndyResult<(SignatureType, CredentialDefinitionConfig)> {
+        let cred_def: CredentialDefinitionV1 =
+            CredentialDefinitionV1::from(
+                self.wallet_service.get_indy_object::<CredentialDefinition>(wallet_handle, cred_def_id, &RecordOptions::id_value())?);
+
+        let schema_id = schema.seq_no.map(|n| n.to_string()).unwrap_or(schema.id.clone());
+
+        if CredentialDefinition::cred_def_id(issuer_did, &schema_id, cred_def.signature_type.to_str(), &cred_def.tag) != cred_def.id {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("CredentialDefinition {} isn't created by issuer {} for Schema {}", cred_def.id, issuer_did, schema.id)));
+        }
+
+        if let Some(successor_id) = self._wallet_get_cred_def_rotation_info(wallet_handle, cred_def_id)?.successor_id {
+            return Err(err_msg(IndyErrorKind::InvalidState,
+                               format!("CredentialDefinition {} is already rotated to {}", cred_def_id, successor_id)));
+        }
+
+        let cred_def_config = config.cloned().unwrap_or(CredentialDefinitionConfig {
+            support_revocation: cred_def.value.revocation.is_some()
+        });
+
+        Ok((cred_def.signature_type, cred_def_config))
+    }
+
+    fn _link_credential_definitions(&self, wallet_handle: i32, predecessor_cred_def_id: &str, successor_cred_def_id: &str) -> IndyResult<()> {
+        let mut predecessor_info = self._wallet_get_cred_def_rotation_info(wallet_handle, predecessor_cred_def_id)?;
+        predecessor_info.successor_id = Some(successor_cred_def_id.to_string());
+        self.wallet_service.upsert_indy_object(wallet_handle, predecessor_cred_def_id, &predecessor_info)?;
+
+        let successor_info = CredentialDefinitionRotationInfo {
+            predecessor_id: Some(predecessor_cred_def_id.to_string()),
+            successor_id: None,
+        };
+        self.wallet_service.upsert_indy_object(wallet_handle, successor_cred_def_id, &successor_info)?;
+
+        Ok(())
+    }
+
+    // Credential definitions replaced by keys rotation are resolved to the latest successor
+    fn _resolve_active_cred_def_id(&self, wallet_handle: i32, cred_def_id: &str) -> IndyResult<String> {
+        let mut active_cred_def_id = cred_def_id.to_string();
+
+        while let Some(successor_id) = self._wallet_get_cred_def_rotation_info(wallet_handle, &active_cred_def_id)?.successor_id {
+            active_cred_def_id = successor_id;
+        }
+
+        Ok(active_cred_def_id)
+    }
+
     fn _create_credential_definition(&self,
                                      attr_names: &AttributeNames,
                                      support_revocation: bool,
@@ -298,11 +456,13 @@ impl IssuerCommandExecutor {
                                                         signature_type: &SignatureType,
                                                         result: IndyResult<(CredentialDefinitionData,
                                                                             CredentialPrivateKey,
-                                                                            CredentialKeyCorrectnessProof)>) {
+                                                                            CredentialKeyCorrectnessProof)>,
+                                                        predecessor_cred_def_id: Option<&str>) {
         let cb = self.pending_callbacks.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
         cb(result
             .and_then(|result| {
-                self._complete_create_and_store_credential_definition(wallet_handle, schema, schema_id, cred_def_id, tag, signature_type.clone(), result)
+                self._complete_create_and_store_credential_definition(wallet_handle, schema, schema_id, cred_def_id, tag, signature_type.clone(), result,
+                                                                      predecessor_cred_def_id)
             }))
     }
 
@@ -345,7 +505,8 @@ impl IssuerCommandExecutor {
                                                         signature_type: SignatureType,
                                                         res: (::domain::anoncreds::credential_definition::CredentialDefinitionData,
                                                               indy_crypto::cl::CredentialPrivateKey,
-                                                              indy_crypto::cl::CredentialKeyCorrectnessProof)) -> IndyResult<(String, String)> {
+                                                              indy_crypto::cl::CredentialKeyCorrectnessProof),
+                                                        predecessor_cred_def_id: Option<&str>) -> IndyResult<(String, String)> {
         let (credential_definition_value, cred_priv_key, cred_key_correctness_proof) = res;
 
         let cred_def =
@@ -372,6 +533,19 @@ impl IssuerCommandExecutor {
 
         self._wallet_set_schema_id(wallet_handle, &cred_def_id, &schema.id)?; // TODO: FIXME delete temporary storing of schema id
 
+        if let Some(ref attr_types) = schema.attr_types {
+            let schema_attr_types = SchemaAttributeTypes {
+                schema_id: schema.id.clone(),
+                attr_types: attr_types.clone(),
+            };
+
+            self.wallet_service.upsert_indy_object(wallet_handle, &schema.id, &schema_attr_types)?;
+        }
+
+        if let Some(predecessor_cred_def_id) = predecessor_cred_def_id {
+            self._link_credential_definitions(wallet_handle, predecessor_cred_def_id, cred_def_id)?;
+        }
+
         debug!("create_and_store_credential_definition <<< cred_def_id: {:?}, cred_def_json: {:?}", cred_def_id, cred_def_json);
         Ok((cred_def_id.to_string(), cred_def_json))
     }
@@ -387,6 +561,8 @@ impl IssuerCommandExecutor {
         debug!("create_and_store_revocation_registry >>> wallet_handle: {:?}, issuer_did: {:?}, type_: {:?}, tag: {:?}, cred_def_id: {:?}, config: {:?}, \
                tails_handle: {:?}", wallet_handle, issuer_did, type_, tag, cred_def_id, config, tails_writer_handle);
 
+        let cred_def_id = &self._resolve_active_cred_def_id(wallet_handle, cred_def_id)?;
+
         let rev_reg_type = if let Some(type_) = type_ {
             serde_json::from_str::<RegistryType>(&format!("\"{}\"", type_))
                 .to_indy(IndyErrorKind::InvalidStructure, "Invalid Registry Type format")?
@@ -395,14 +571,34 @@ impl IssuerCommandExecutor {
         };
 
         let issuance_type = if let Some(ref type_) = config.issuance_type {
-            serde_json::from_str::<IssuanceType>(&format!("\"{}\"", type_))
-                .to_indy(IndyErrorKind::InvalidStructure, "Invalid Issuance Type format")?
+            _parse_issuance_type(type_)?
         } else {
             IssuanceType::ISSUANCE_ON_DEMAND
         };
 
         let max_cred_num = config.max_cred_num.unwrap_or(100000);
 
+        let (rev_reg_id, revoc_reg_def_json, revoc_reg_json) =
+            self._create_and_store_revocation_registry(wallet_handle, issuer_did, rev_reg_type, tag, cred_def_id,
+                                                       issuance_type, max_cred_num, tails_writer_handle)?;
+
+        self._register_revocation_registry(wallet_handle, cred_def_id, &rev_reg_id)?;
+
+        debug!("create_and_store_revocation_registry <<< rev_reg_id: {:?}, revoc_reg_def_json: {:?}, revoc_reg_json: {:?}",
+               rev_reg_id, revoc_reg_def_json, revoc_reg_json);
+
+        Ok((rev_reg_id, revoc_reg_def_json, revoc_reg_json))
+    }
+
+    fn _create_and_store_revocation_registry(&self,
+                                             wallet_handle: i32,
+                                             issuer_did: &str,
+                                             rev_reg_type: RegistryType,
+                                             tag: &str,
+                                             cred_def_id: &str,
+                                             issuance_type: IssuanceType,
+                                             max_cred_num: u32,
+                                             tails_writer_handle: i32) -> IndyResult<(String, String, String)> {
         let rev_reg_id = RevocationRegistryDefinition::rev_reg_id(issuer_did, cred_def_id, &rev_reg_type, tag);
 
         let cred_def: CredentialDefinition = self.wallet_service.get_indy_object(wallet_handle, &cred_def_id, &RecordOptions::id_value())?;
@@ -459,17 +655,172 @@ impl IssuerCommandExecutor {
 
         self.wallet_service.add_indy_object(wallet_handle, &rev_reg_id, &rev_reg_info, &HashMap::new())?;
 
-        debug!("create_and_store_revocation_registry <<< rev_reg_id: {:?}, revoc_reg_def_json: {:?}, revoc_reg_json: {:?}",
-               rev_reg_id, revoc_reg_def_json, revoc_reg_json);
-
         Ok((rev_reg_id, revoc_reg_def_json, revoc_reg_json))
     }
 
+    fn _register_revocation_registry(&self, wallet_handle: i32, cred_def_id: &str, rev_reg_id: &str) -> IndyResult<()> {
+        let mut rev_info = self.wallet_service.get_indy_opt_object::<CredentialDefinitionRevocationInfo>(wallet_handle, cred_def_id, &RecordOptions::id_value())?
+            .unwrap_or(CredentialDefinitionRevocationInfo {
+                cred_def_id: cred_def_id.to_string(),
+                rev_reg_ids: Vec::new(),
+                active_rev_reg_id: None,
+                next_rev_reg_id: None,
+                issued: 0,
+            });
+
+        rev_info.rev_reg_ids.push(rev_reg_id.to_string());
+
+        if rev_info.active_rev_reg_id.is_none() {
+            rev_info.active_rev_reg_id = Some(rev_reg_id.to_string());
+        }
+
+        self.wallet_service.upsert_indy_object(wallet_handle, cred_def_id, &rev_info)?;
+
+        Ok(())
+    }
+
+    fn rotate_revocation_registry(&self,
+                                  wallet_handle: i32,
+                                  issuer_did: &str,
+                                  cred_def_id: &str,
+                                  config: &RevocationRegistryRotationConfig,
+                                  tails_writer_handle: i32) -> IndyResult<String> {
+        debug!("rotate_revocation_registry >>> wallet_handle: {:?}, issuer_did: {:?}, cred_def_id: {:?}, config: {:?}, tails_writer_handle: {:?}",
+               wallet_handle, issuer_did, cred_def_id, config, tails_writer_handle);
+
+        self.crypto_service.validate_did(issuer_did)?;
+
+        let cred_def_id = &self._resolve_active_cred_def_id(wallet_handle, cred_def_id)?;
+
+        let mut rev_info: CredentialDefinitionRevocationInfo =
+            self.wallet_service.get_indy_opt_object(wallet_handle, cred_def_id, &RecordOptions::id_value())?
+                .ok_or(err_msg(IndyErrorKind::InvalidState, format!("No RevocationRegistry created for CredentialDefinition: {}", cred_def_id)))?;
+
+        let active_rev_reg_id = rev_info.active_rev_reg_id.clone()
+            .ok_or(err_msg(IndyErrorKind::InvalidState, format!("No active RevocationRegistry for CredentialDefinition: {}", cred_def_id)))?;
+
+        let active_rev_reg_def = RevocationRegistryDefinitionV1::from(self._wallet_get_rev_reg_def(wallet_handle, &active_rev_reg_id)?);
+        let active_rev_reg_info = self._wallet_get_rev_reg_info(wallet_handle, &active_rev_reg_id)?;
+
+        let threshold = config.threshold.unwrap_or(active_rev_reg_def.value.max_cred_num);
+
+        let mut rev_reg_def = None;
+        let mut ledger_requests = Vec::new();
+
+        if rev_info.next_rev_reg_id.is_none() && active_rev_reg_info.curr_id >= threshold {
+            let issuance_type = match config.issuance_type {
+                Some(ref type_) => _parse_issuance_type(type_)?,
+                None => active_rev_reg_def.value.issuance_type.clone()
+            };
+
+            let max_cred_num = config.max_cred_num.unwrap_or(active_rev_reg_def.value.max_cred_num);
+
+            let tag = self._next_revocation_registry_tag(wallet_handle, issuer_did, &active_rev_reg_def.revoc_def_type, cred_def_id,
+                                                         &rev_info, config.tag.as_ref().map(String::as_str))?;
+
+            // Registry records are stored before the bookkeeping record refers them. If rotation fails in between
+            // only unreferenced records are left and the next call creates the registry with another default tag.
+            let (next_rev_reg_id, next_rev_reg_def_json, _) =
+                self._create_and_store_revocation_registry(wallet_handle, issuer_did, active_rev_reg_def.revoc_def_type, &tag, cred_def_id,
+                                                           issuance_type, max_cred_num, tails_writer_handle)?;
+
+            let next_rev_reg_def = RevocationRegistryDefinitionV1::from(self._wallet_get_rev_reg_def(wallet_handle, &next_rev_reg_id)?);
+            let next_rev_reg = RevocationRegistryV1::from(self._wallet_get_rev_reg(wallet_handle, &next_rev_reg_id)?);
+            let next_rev_reg_delta = CryptoRevocationRegistryDelta::from_parts(None, &next_rev_reg.value, &HashSet::new(), &HashSet::new());
+            let rev_reg_type = next_rev_reg_def.revoc_def_type.to_str();
+
+            ledger_requests.push(self.ledger_service.build_revoc_reg_def_request(issuer_did, next_rev_reg_def)?);
+            ledger_requests.push(self.ledger_service.build_revoc_reg_entry_request(issuer_did, &next_rev_reg_id, rev_reg_type,
+                                                                                   RevocationRegistryDeltaV1 { value: next_rev_reg_delta })?);
+
+            rev_info.rev_reg_ids.push(next_rev_reg_id.clone());
+            rev_info.next_rev_reg_id = Some(next_rev_reg_id);
+            rev_reg_def = Some(serde_json::from_str::<RevocationRegistryDefinition>(&next_rev_reg_def_json)
+                .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize RevocationRegistryDefinition")?);
+        }
+
+        self._switch_to_next_revocation_registry_if_full(wallet_handle, &mut rev_info)?;
+
+        // The only write of bookkeeping: registering of the next registry and switching to it are applied together
+        self.wallet_service.update_indy_object(wallet_handle, cred_def_id, &rev_info)?;
+
+        let active_rev_reg_id = rev_info.active_rev_reg_id.clone().unwrap_or(active_rev_reg_id);
+        let active_rev_reg_def = RevocationRegistryDefinitionV1::from(self._wallet_get_rev_reg_def(wallet_handle, &active_rev_reg_id)?);
+        let active_rev_reg_info = self._wallet_get_rev_reg_info(wallet_handle, &active_rev_reg_id)?;
+
+        let res = RevocationRegistryRotationResult {
+            active_rev_reg_id,
+            active_issued: active_rev_reg_info.curr_id,
+            active_max_cred_num: active_rev_reg_def.value.max_cred_num,
+            next_rev_reg_id: rev_info.next_rev_reg_id,
+            rev_reg_def,
+            ledger_requests,
+        };
+
+        let res = serde_json::to_string(&res)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationRegistryRotationResult")?;
+
+        debug!("rotate_revocation_registry <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn _next_revocation_registry_tag(&self,
+                                     wallet_handle: i32,
+                                     issuer_did: &str,
+                                     rev_reg_type: &RegistryType,
+                                     cred_def_id: &str,
+                                     rev_info: &CredentialDefinitionRevocationInfo,
+                                     tag: Option<&str>) -> IndyResult<String> {
+        if let Some(tag) = tag {
+            let rev_reg_id = RevocationRegistryDefinition::rev_reg_id(issuer_did, cred_def_id, rev_reg_type, tag);
+
+            if self.wallet_service.record_exists::<RevocationRegistryDefinition>(wallet_handle, &rev_reg_id)? {
+                return Err(err_msg(IndyErrorKind::WalletItemAlreadyExists, format!("RevocationRegistryDefinition for rev_reg_id: {:?} already exists", rev_reg_id)));
+            }
+
+            return Ok(tag.to_string());
+        }
+
+        // Registries created with explicit tags or left by failed rotation may already take the default tag
+        let mut tag_num = rev_info.rev_reg_ids.len();
+
+        loop {
+            let tag = tag_num.to_string();
+            let rev_reg_id = RevocationRegistryDefinition::rev_reg_id(issuer_did, cred_def_id, rev_reg_type, &tag);
+
+            if !self.wallet_service.record_exists::<RevocationRegistryDefinition>(wallet_handle, &rev_reg_id)? {
+                return Ok(tag);
+            }
+
+            tag_num += 1;
+        }
+    }
+
+    fn _switch_to_next_revocation_registry_if_full(&self, wallet_handle: i32, rev_info: &mut CredentialDefinitionRevocationInfo) -> IndyResult<()> {
+        let is_active_full = match (&rev_info.active_rev_reg_id, &rev_info.next_rev_reg_id) {
+            (&Some(ref active_rev_reg_id), &Some(_)) => {
+                let rev_reg_def = RevocationRegistryDefinitionV1::from(self._wallet_get_rev_reg_def(wallet_handle, active_rev_reg_id)?);
+                let rev_reg_info = self._wallet_get_rev_reg_info(wallet_handle, active_rev_reg_id)?;
+                rev_reg_info.curr_id >= rev_reg_def.value.max_cred_num
+            }
+            _ => false
+        };
+
+        if is_active_full {
+            rev_info.active_rev_reg_id = rev_info.next_rev_reg_id.take();
+        }
+
+        Ok(())
+    }
+
     fn create_credential_offer(&self,
                                wallet_handle: i32,
                                cred_def_id: &str) -> IndyResult<String> {
         debug!("create_credential_offer >>> wallet_handle: {:?}, cred_def_id: {:?}", wallet_handle, cred_def_id);
 
+        let cred_def_id = self._resolve_active_cred_def_id(wallet_handle, cred_def_id)?;
+
         let cred_def_correctness_proof: CredentialDefinitionCorrectnessProof =
             self.wallet_service.get_indy_object(wallet_handle, &cred_def_id, &RecordOptions::id_value())?;
 
@@ -498,9 +849,10 @@ impl IssuerCommandExecutor {
                       cred_request: &CredentialRequest,
                       cred_values: &HashMap<String, AttributeValues>,
                       rev_reg_id: Option<&str>,
-                      blob_storage_reader_handle: Option<i32>) -> IndyResult<(String, Option<String>, Option<String>)> {
-        debug!("new_credential >>> wallet_handle: {:?}, cred_offer: {:?}, cred_req: {:?}, cred_values_json: {:?}, rev_reg_id: {:?}, blob_storage_reader_handle: {:?}",
-               wallet_handle, secret!(&cred_offer), secret!(&cred_request), secret!(&cred_values), rev_reg_id, blob_storage_reader_handle);
+                      blob_storage_reader_handle: Option<i32>,
+                      log_tags: Option<&Tags>) -> IndyResult<(String, Option<String>, Option<String>)> {
+        debug!("new_credential >>> wallet_handle: {:?}, cred_offer: {:?}, cred_req: {:?}, cred_values_json: {:?}, rev_reg_id: {:?}, blob_storage_reader_handle: {:?}, \
+               log_tags: {:?}", wallet_handle, secret!(&cred_offer), secret!(&cred_request), secret!(&cred_values), rev_reg_id, blob_storage_reader_handle, log_tags);
 
         let cred_def: CredentialDefinitionV1 =
             CredentialDefinitionV1::from(
@@ -511,6 +863,8 @@ impl IssuerCommandExecutor {
 
         let schema_id = self._wallet_get_schema_id(wallet_handle, &cred_offer.cred_def_id)?;  // TODO: FIXME get CredDef from wallet and use CredDef.schema_id
 
+        let cred_values = self._encode_credential_values(wallet_handle, &schema_id, cred_values)?;
+
         let (rev_reg_def, mut rev_reg,
             rev_reg_def_priv, sdk_tails_accessor, rev_reg_info) = match rev_reg_id {
             Some(ref r_reg_id) => {
@@ -599,20 +953,62 @@ impl IssuerCommandExecutor {
             .map_or(Ok(None), |v| v.map(Some))
             .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationRegistryDelta")?;
 
-        if let (Some(r_reg), Some(r_reg_id), Some(r_reg_info)) = (credential.rev_reg, rev_reg_id, rev_reg_info.clone()) {
+        let cred_rev_id = rev_reg_info.as_ref().map(|r_reg_info| r_reg_info.curr_id.to_string());
+
+        // Issuance is logged before revocation registry is stored, so a failed logging doesn't consume cred_rev_id
+        if let Some(log_tags) = log_tags {
+            self._log_issued_credential(wallet_handle, &credential.schema_id, &credential.cred_def_id, rev_reg_id, cred_rev_id.as_ref().map(String::as_str),
+                                        &cred_values, log_tags)?;
+        }
+
+        if let (Some(r_reg), Some(r_reg_id), Some(r_reg_info)) = (credential.rev_reg, rev_reg_id, rev_reg_info) {
             let revoc_reg = RevocationRegistry::RevocationRegistryV1(RevocationRegistryV1 { value: r_reg });
 
             self.wallet_service.update_indy_object(wallet_handle, &r_reg_id, &revoc_reg)?;
             self.wallet_service.update_indy_object(wallet_handle, &r_reg_id, &r_reg_info)?;
-        };
 
-        let cred_rev_id = rev_reg_info.map(|r_reg_info| r_reg_info.curr_id.to_string());
+            self._update_revocation_info_on_issuance(wallet_handle, &cred_request.cred_def_id)?;
+        };
 
         debug!("new_credential <<< cred_json: {:?}, cred_rev_id: {:?}, rev_reg_delta_json: {:?}", secret!(&cred_json), secret!(&cred_rev_id), rev_reg_delta_json);
 
         Ok((cred_json, cred_rev_id, rev_reg_delta_json))
     }
 
+    // Fills missed encoded values and checks given ones for attributes typed in schema
+    fn _encode_credential_values(&self,
+                                 wallet_handle: i32,
+                                 schema_id: &str,
+                                 cred_values: &HashMap<String, AttributeValues>) -> IndyResult<HashMap<String, AttributeValues>> {
+        let schema_attr_types: Option<SchemaAttributeTypes> =
+            self.wallet_service.get_indy_opt_object(wallet_handle, schema_id, &RecordOptions::id_value())?;
+
+        let attr_types = schema_attr_types.as_ref().map(|schema_attr_types| &schema_attr_types.attr_types);
+
+        let mut encoded_values: HashMap<String, AttributeValues> = HashMap::new();
+
+        for (attr, values) in cred_values {
+            let attr_type = get_attribute_type(attr_types, attr);
+
+            let encoded = if values.encoded.is_empty() || attr_type.is_some() {
+                let encoded = encode_typed_credential_attribute(&values.raw, attr_type)?;
+
+                if !values.encoded.is_empty() && values.encoded != encoded {
+                    return Err(err_msg(IndyErrorKind::InvalidStructure,
+                                       format!("Encoded value of attribute {:?} doesn't match encoding of its type {:?}", attr, attr_type)));
+                }
+
+                encoded
+            } else {
+                values.encoded.clone()
+            };
+
+            encoded_values.insert(attr.clone(), AttributeValues { raw: values.raw.clone(), encoded });
+        }
+
+        Ok(encoded_values)
+    }
+
     fn revoke_credential(&self,
                          wallet_handle: i32,
                          blob_storage_reader_handle: i32,
@@ -667,11 +1063,97 @@ impl IssuerCommandExecutor {
         self.wallet_service.update_indy_object(wallet_handle, &rev_reg_id, &rev_reg)?;
         self.wallet_service.update_indy_object(wallet_handle, &rev_reg_id, &rev_reg_info)?;
 
+        self._set_issued_credential_revoked(wallet_handle, &rev_reg_id, cred_revoc_id, true)?;
+
         debug!("revoke_credential <<< rev_reg_delta_json: {:?}", rev_reg_delta_json);
 
         Ok(rev_reg_delta_json)
     }
 
+    fn revoke_credentials(&self,
+                          wallet_handle: i32,
+                          blob_storage_reader_handle: i32,
+                          rev_reg_id: &str,
+                          cred_revoc_ids: &[String]) -> IndyResult<String> {
+        debug!("revoke_credentials >>> wallet_handle: {:?}, blob_storage_reader_handle:  {:?}, rev_reg_id: {:?}, cred_revoc_ids: {:?}",
+               wallet_handle, blob_storage_reader_handle, rev_reg_id, secret!(cred_revoc_ids));
+
+        if cred_revoc_ids.is_empty() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Empty list of credential revocation ids"));
+        }
+
+        let cred_revoc_ids = cred_revoc_ids
+            .iter()
+            .map(|cred_revoc_id| parse_cred_rev_id(cred_revoc_id))
+            .collect::<IndyResult<Vec<u32>>>()?;
+
+        let revocation_registry_definition: RevocationRegistryDefinitionV1 =
+            RevocationRegistryDefinitionV1::from(
+                self._wallet_get_rev_reg_def(wallet_handle, &rev_reg_id)?);
+
+        let mut rev_reg: RevocationRegistryV1 =
+            RevocationRegistryV1::from(
+                self._wallet_get_rev_reg(wallet_handle, &rev_reg_id)?);
+
+        let mut rev_reg_info = self._wallet_get_rev_reg_info(wallet_handle, &rev_reg_id)?;
+
+        // All ids are checked before any revocation so that an invalid id leaves the registry untouched
+        for cred_revoc_id in cred_revoc_ids.iter() {
+            if *cred_revoc_id > revocation_registry_definition.value.max_cred_num + 1 {
+                return Err(err_msg(IndyErrorKind::InvalidUserRevocId, format!("Revocation id: {:?} not found in RevocationRegistry", cred_revoc_id)));
+            }
+
+            let is_valid = match revocation_registry_definition.value.issuance_type {
+                IssuanceType::ISSUANCE_ON_DEMAND => rev_reg_info.used_ids.remove(cred_revoc_id),
+                IssuanceType::ISSUANCE_BY_DEFAULT => rev_reg_info.used_ids.insert(*cred_revoc_id)
+            };
+
+            if !is_valid {
+                return Err(err_msg(IndyErrorKind::InvalidUserRevocId, format!("Revocation id: {:?} not found in RevocationRegistry", cred_revoc_id)));
+            }
+        }
+
+        let sdk_tails_accessor = SDKTailsAccessor::new(self.blob_storage_service.clone(),
+                                                       blob_storage_reader_handle,
+                                                       &revocation_registry_definition)?;
+
+        let mut merged_rev_reg_delta: Option<CryptoRevocationRegistryDelta> = None;
+
+        for cred_revoc_id in cred_revoc_ids.iter().cloned() {
+            let rev_reg_delta =
+                self.anoncreds_service.issuer.revoke(&mut rev_reg.value, revocation_registry_definition.value.max_cred_num, cred_revoc_id, &sdk_tails_accessor)?;
+
+            merged_rev_reg_delta = match merged_rev_reg_delta {
+                Some(mut merged_rev_reg_delta) => {
+                    merged_rev_reg_delta.merge(&rev_reg_delta)?;
+                    Some(merged_rev_reg_delta)
+                }
+                None => Some(rev_reg_delta)
+            };
+        }
+
+        let rev_reg_delta = merged_rev_reg_delta
+            .ok_or(err_msg(IndyErrorKind::InvalidState, "RevocationRegistryDelta not built"))?;
+
+        let rev_reg_delta = RevocationRegistryDelta::RevocationRegistryDeltaV1(RevocationRegistryDeltaV1 { value: rev_reg_delta });
+
+        let rev_reg_delta_json = serde_json::to_string(&rev_reg_delta)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationRegistryDelta")?;
+
+        let rev_reg = RevocationRegistry::RevocationRegistryV1(rev_reg);
+
+        self.wallet_service.update_indy_object(wallet_handle, &rev_reg_id, &rev_reg)?;
+        self.wallet_service.update_indy_object(wallet_handle, &rev_reg_id, &rev_reg_info)?;
+
+        for cred_revoc_id in cred_revoc_ids {
+            self._set_issued_credential_revoked(wallet_handle, &rev_reg_id, cred_revoc_id, true)?;
+        }
+
+        debug!("revoke_credentials <<< rev_reg_delta_json: {:?}", rev_reg_delta_json);
+
+        Ok(rev_reg_delta_json)
+    }
+
     fn _recovery_credential(&self,
                             wallet_handle: i32,
                             blob_storage_reader_handle: i32,
@@ -726,11 +1208,81 @@ impl IssuerCommandExecutor {
         self.wallet_service.update_indy_object(wallet_handle, &rev_reg_id, &rev_reg)?;
         self.wallet_service.update_indy_object(wallet_handle, &rev_reg_id, &rev_reg_info)?;
 
+        self._set_issued_credential_revoked(wallet_handle, &rev_reg_id, cred_revoc_id, false)?;
+
         debug!("recovery_credential <<< rev_reg_delta_json: {:?}", rev_reg_delta_json);
 
         Ok(rev_reg_delta_json)
     }
 
+    fn search_issued_credentials(&self,
+                                 wallet_handle: i32,
+                                 query_json: Option<&str>) -> IndyResult<(i32, usize)> {
+        debug!("search_issued_credentials >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
+
+        let issued_credentials_search =
+            self.wallet_service.search_indy_records::<IssuedCredentialInfo>(wallet_handle, query_json.unwrap_or("{}"), &SearchOptions::id_value())?;
+
+        let total_count = issued_credentials_search.get_total_count()?.unwrap_or(0);
+
+        let handle = sequence::get_next_id();
+
+        self.issued_credentials_searches.borrow_mut().insert(handle, Box::new(issued_credentials_search));
+
+        let res = (handle, total_count);
+
+        debug!("search_issued_credentials <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn fetch_issued_credentials(&self,
+                                search_handle: i32,
+                                count: usize) -> IndyResult<String> {
+        trace!("fetch_issued_credentials >>> search_handle: {:?}, count: {:?}", search_handle, count);
+
+        let mut searches = self.issued_credentials_searches.borrow_mut();
+        let search = searches.get_mut(&search_handle)
+            .ok_or(err_msg(IndyErrorKind::InvalidWalletHandle, format!("Unknown IssuedCredentialsSearch handle: {}", search_handle)))?;
+
+        let mut issued_credentials: Vec<IssuedCredentialInfo> = Vec::new();
+
+        for _ in 0..count {
+            match search.fetch_next_record()? {
+                Some(issued_credential_record) => {
+                    let issued_credential_json = issued_credential_record.get_value()
+                        .ok_or(err_msg(IndyErrorKind::InvalidState, format!("IssuedCredentialInfo not found for id: {}", issued_credential_record.get_id())))?;
+
+                    let issued_credential: IssuedCredentialInfo = serde_json::from_str(issued_credential_json)
+                        .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize IssuedCredentialInfo")?;
+
+                    issued_credentials.push(issued_credential);
+                }
+                None => break
+            }
+        }
+
+        let res = serde_json::to_string(&issued_credentials)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize list of IssuedCredentialInfo")?;
+
+        trace!("fetch_issued_credentials <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn close_issued_credentials_search(&self, search_handle: i32) -> IndyResult<()> {
+        trace!("close_issued_credentials_search >>> search_handle: {:?}", search_handle);
+
+        let res = match self.issued_credentials_searches.borrow_mut().remove(&search_handle) {
+            Some(_) => Ok(()),
+            None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, format!("Unknown IssuedCredentialsSearch handle: {}", search_handle)))
+        }?;
+
+        trace!("close_issued_credentials_search <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn merge_revocation_registry_deltas(&self,
                                         rev_reg_delta: &mut RevocationRegistryDeltaV1,
                                         other_rev_reg_delta: &RevocationRegistryDeltaV1) -> IndyResult<String> {
@@ -748,6 +1300,80 @@ impl IssuerCommandExecutor {
         Ok(merged_rev_reg_delta_json)
     }
 
+    fn _update_revocation_info_on_issuance(&self, wallet_handle: i32, cred_def_id: &str) -> IndyResult<()> {
+        // Registries created before bookkeeping was introduced have no record to update
+        if let Some(mut rev_info) = self.wallet_service.get_indy_opt_object::<CredentialDefinitionRevocationInfo>(wallet_handle, cred_def_id, &RecordOptions::id_value())? {
+            rev_info.issued += 1;
+            self._switch_to_next_revocation_registry_if_full(wallet_handle, &mut rev_info)?;
+            self.wallet_service.update_indy_object(wallet_handle, cred_def_id, &rev_info)?;
+        }
+
+        Ok(())
+    }
+
+    fn _log_issued_credential(&self,
+                              wallet_handle: i32,
+                              schema_id: &str,
+                              cred_def_id: &str,
+                              rev_reg_id: Option<&str>,
+                              cred_rev_id: Option<&str>,
+                              cred_values: &HashMap<String, AttributeValues>,
+                              log_tags: &Tags) -> IndyResult<()> {
+        let referent = match (rev_reg_id, cred_rev_id) {
+            (Some(rev_reg_id), Some(cred_rev_id)) => _issued_credential_referent(rev_reg_id, cred_rev_id),
+            _ => uuid::Uuid::new_v4().to_string()
+        };
+
+        let issued_credential = IssuedCredentialInfo {
+            referent: referent.clone(),
+            schema_id: schema_id.to_string(),
+            cred_def_id: cred_def_id.to_string(),
+            rev_reg_id: rev_reg_id.map(String::from),
+            cred_rev_id: cred_rev_id.map(String::from),
+            attrs_hash: hash_credential_values(cred_values, &self._get_issuance_log_key(wallet_handle)?)?,
+            revoked: false,
+            tags: log_tags.clone(),
+        };
+
+        let tags = _build_issued_credential_tags(&issued_credential);
+
+        // Entry left for cred_rev_id which wasn't consumed because of failed issuance is overwritten
+        if self.wallet_service.record_exists::<IssuedCredentialInfo>(wallet_handle, &referent)? {
+            self.wallet_service.update_indy_object(wallet_handle, &referent, &issued_credential)?;
+            self.wallet_service.update_indy_record_tags::<IssuedCredentialInfo>(wallet_handle, &referent, &tags)?;
+        } else {
+            self.wallet_service.add_indy_object(wallet_handle, &referent, &issued_credential, &tags)?;
+        }
+
+        Ok(())
+    }
+
+    fn _get_issuance_log_key(&self, wallet_handle: i32) -> IndyResult<hmacsha256::Key> {
+        if let Some(key) = self.wallet_service.get_indy_opt_object::<IssuanceLogKey>(wallet_handle, ISSUANCE_LOG_KEY_ID, &RecordOptions::id_value())? {
+            return hmacsha256::Key::from_slice(&key.value);
+        }
+
+        let key = hmacsha256::gen_key();
+
+        self.wallet_service.add_indy_object(wallet_handle, ISSUANCE_LOG_KEY_ID, &IssuanceLogKey { value: key[..].to_vec() }, &HashMap::new())?;
+
+        Ok(key)
+    }
+
+    fn _set_issued_credential_revoked(&self, wallet_handle: i32, rev_reg_id: &str, cred_rev_id: u32, revoked: bool) -> IndyResult<()> {
+        let referent = _issued_credential_referent(rev_reg_id, &cred_rev_id.to_string());
+
+        // Credentials issued without issuance log have no entry to update
+        if let Some(mut issued_credential) = self.wallet_service.get_indy_opt_object::<IssuedCredentialInfo>(wallet_handle, &referent, &RecordOptions::id_value())? {
+            issued_credential.revoked = revoked;
+
+            self.wallet_service.update_indy_object(wallet_handle, &referent, &issued_credential)?;
+            self.wallet_service.update_indy_record_tags::<IssuedCredentialInfo>(wallet_handle, &referent, &_build_issued_credential_tags(&issued_credential))?;
+        }
+
+        Ok(())
+    }
+
     // TODO: DELETE IT
     fn _wallet_set_schema_id(&self, wallet_handle: i32, id: &str, schema_id: &str) -> IndyResult<()> {
         self.wallet_service.add_record(wallet_handle, &self.wallet_service.add_prefix("SchemaId"), id, schema_id, &Tags::new())
@@ -760,6 +1386,11 @@ impl IssuerCommandExecutor {
             .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("SchemaId not found for id: {}", key)))?.to_string())
     }
 
+    fn _wallet_get_cred_def_rotation_info(&self, wallet_handle: i32, key: &str) -> IndyResult<CredentialDefinitionRotationInfo> {
+        Ok(self.wallet_service.get_indy_opt_object(wallet_handle, &key, &RecordOptions::id_value())?
+            .unwrap_or_default())
+    }
+
     fn _wallet_get_rev_reg_def(&self, wallet_handle: i32, key: &str) -> IndyResult<RevocationRegistryDefinition> {
         self.wallet_service.get_indy_object(wallet_handle, &key, &RecordOptions::id_value())
     }
@@ -772,3 +1403,28 @@ impl IssuerCommandExecutor {
         self.wallet_service.get_indy_object(wallet_handle, &key, &RecordOptions::id_value())
     }
 }
+
+const ISSUANCE_LOG_KEY_ID: &'static str = "issuance_log_key";
+
+fn _parse_issuance_type(type_: &str) -> IndyResult<IssuanceType> {
+    serde_json::from_str::<IssuanceType>(&format!("\"{}\"", type_))
+        .to_indy(IndyErrorKind::InvalidStructure, "Invalid Issuance Type format")
+}
+
+fn _issued_credential_referent(rev_reg_id: &str, cred_rev_id: &str) -> String {
+    format!("{}::{}", rev_reg_id, cred_rev_id)
+}
+
+// Caller's correlation tags can't override tags describing the credential
+fn _build_issued_credential_tags(issued_credential: &IssuedCredentialInfo) -> Tags {
+    let mut tags = issued_credential.tags.clone();
+
+    tags.insert("schema_id".to_string(), issued_credential.schema_id.clone());
+    tags.insert("cred_def_id".to_string(), issued_credential.cred_def_id.clone());
+    tags.insert("rev_reg_id".to_string(), issued_credential.rev_reg_id.clone().unwrap_or("None".to_string()));
+    tags.insert("cred_rev_id".to_string(), issued_credential.cred_rev_id.clone().unwrap_or("None".to_string()));
+    tags.insert("attrs_hash".to_string(), issued_credential.attrs_hash.clone());
+    tags.insert("revoked".to_string(), issued_credential.revoked.to_string());
+
+    tags
+}
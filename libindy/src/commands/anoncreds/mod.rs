@@ -8,10 +8,14 @@ use commands::anoncreds::prover::{ProverCommand, ProverCommandExecutor};
 use commands::anoncreds::verifier::{VerifierCommand, VerifierCommandExecutor};
 
 use services::anoncreds::AnoncredsService;
+use services::anoncreds::helpers::encode_credential_attribute;
 use services::blob_storage::BlobStorageService;
 use services::pool::PoolService;
 use services::wallet::WalletService;
 use services::crypto::CryptoService;
+use services::ledger::LedgerService;
+use errors::prelude::*;
+use utils::qualifier;
 
 use std::rc::Rc;
 
@@ -19,6 +23,16 @@ pub enum AnoncredsCommand {
     Issuer(IssuerCommand),
     Prover(ProverCommand),
     Verifier(VerifierCommand),
+    ToUnqualified(
+        String, // entity
+        Box<Fn(IndyResult<String>) + Send>),
+    ToFullyQualified(
+        String, // entity
+        String, // method
+        Box<Fn(IndyResult<String>) + Send>),
+    EncodeCredentialAttribute(
+        String, // raw value
+        Box<Fn(IndyResult<String>) + Send>),
 }
 
 pub struct AnoncredsCommandExecutor {
@@ -32,11 +46,12 @@ impl AnoncredsCommandExecutor {
                blob_storage_service: Rc<BlobStorageService>,
                pool_service: Rc<PoolService>,
                wallet_service: Rc<WalletService>,
-               crypto_service: Rc<CryptoService>) -> AnoncredsCommandExecutor {
+               crypto_service: Rc<CryptoService>,
+               ledger_service: Rc<LedgerService>) -> AnoncredsCommandExecutor {
         AnoncredsCommandExecutor {
             issuer_command_cxecutor: IssuerCommandExecutor::new(
                 anoncreds_service.clone(), pool_service.clone(),
-                blob_storage_service.clone(), wallet_service.clone(), crypto_service.clone()),
+                blob_storage_service.clone(), wallet_service.clone(), crypto_service.clone(), ledger_service.clone()),
             prover_command_cxecutor: ProverCommandExecutor::new(
                 anoncreds_service.clone(), wallet_service.clone(), crypto_service.clone(), blob_storage_service.clone()),
             verifier_command_cxecutor: VerifierCommandExecutor::new(
@@ -58,6 +73,48 @@ impl AnoncredsCommandExecutor {
                 info!(target: "anoncreds_command_executor", "Verifier command received");
                 self.verifier_command_cxecutor.execute(cmd);
             }
+            AnoncredsCommand::ToUnqualified(entity, cb) => {
+                info!(target: "anoncreds_command_executor", "ToUnqualified command received");
+                cb(self.to_unqualified(&entity));
+            }
+            AnoncredsCommand::ToFullyQualified(entity, method, cb) => {
+                info!(target: "anoncreds_command_executor", "ToFullyQualified command received");
+                cb(self.to_fully_qualified(&entity, &method));
+            }
+            AnoncredsCommand::EncodeCredentialAttribute(raw_value, cb) => {
+                info!(target: "anoncreds_command_executor", "EncodeCredentialAttribute command received");
+                cb(self.encode_credential_attribute(&raw_value));
+            }
         };
     }
+
+    fn to_unqualified(&self, entity: &str) -> IndyResult<String> {
+        debug!("to_unqualified >>> entity: {:?}", entity);
+
+        let res = qualifier::unqualify(entity);
+
+        debug!("to_unqualified <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn to_fully_qualified(&self, entity: &str, method: &str) -> IndyResult<String> {
+        debug!("to_fully_qualified >>> entity: {:?}, method: {:?}", entity, method);
+
+        let res = qualifier::qualify(entity, method)?;
+
+        debug!("to_fully_qualified <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn encode_credential_attribute(&self, raw_value: &str) -> IndyResult<String> {
+        debug!("encode_credential_attribute >>> raw_value: {:?}", raw_value);
+
+        let res = encode_credential_attribute(raw_value)?;
+
+        debug!("encode_credential_attribute <<< res: {:?}", res);
+
+        Ok(res)
+    }
 }
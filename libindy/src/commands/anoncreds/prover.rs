@@ -4,7 +4,7 @@ use std::rc::Rc;
 
 use indy_crypto::cl::{new_nonce, RevocationRegistry, Witness};
 
-use domain::anoncreds::credential::{Credential, CredentialInfo};
+use domain::anoncreds::credential::{Credential, CredentialCheck, CredentialInfo};
 use domain::anoncreds::credential_definition::{cred_defs_map_to_cred_defs_v1_map, CredentialDefinition, CredentialDefinitionV1};
 use domain::anoncreds::credential_for_proof_request::{CredentialsForProofRequest, RequestedCredential};
 use domain::anoncreds::credential_offer::CredentialOffer;
@@ -16,12 +16,15 @@ use domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefini
 use domain::anoncreds::revocation_registry_delta::{RevocationRegistryDelta, RevocationRegistryDeltaV1};
 use domain::anoncreds::revocation_state::RevocationState;
 use domain::anoncreds::schema::{Schema, schemas_map_to_schemas_v1_map, SchemaV1};
+use domain::wallet::Tags;
 use errors::prelude::*;
 use services::anoncreds::AnoncredsService;
 use services::anoncreds::helpers::parse_cred_rev_id;
+use services::anoncreds::prover::verify_credential_on_store;
 use services::blob_storage::BlobStorageService;
 use services::crypto::CryptoService;
 use services::wallet::{RecordOptions, SearchOptions, WalletRecord, WalletSearch, WalletService};
+use utils::qualifier;
 use utils::sequence;
 
 use super::tails::SDKTailsAccessor;
@@ -46,6 +49,14 @@ pub enum ProverCommand {
         CredentialDefinition, // credential definition
         Option<RevocationRegistryDefinition>, // revocation registry definition
         Box<Fn(IndyResult<String>) + Send>),
+    VerifyCredential(
+        i32, // wallet handle
+        CredentialRequestMetadata, // credential request metadata
+        Credential, // credential
+        Schema, // schema
+        CredentialDefinition, // credential definition
+        Option<RevocationRegistryDefinition>, // revocation registry definition
+        Box<Fn(IndyResult<String>) + Send>),
     GetCredentials(
         i32, // wallet handle
         Option<String>, // filter json
@@ -54,6 +65,20 @@ pub enum ProverCommand {
         i32, // wallet handle
         String, // credential id
         Box<Fn(IndyResult<String>) + Send>),
+    DeleteCredential(
+        i32, // wallet handle
+        String, // credential id
+        Box<Fn(IndyResult<()>) + Send>),
+    AddCredentialTags(
+        i32, // wallet handle
+        String, // credential id
+        Tags, // tags
+        Box<Fn(IndyResult<()>) + Send>),
+    DeleteCredentialTags(
+        i32, // wallet handle
+        String, // credential id
+        Vec<String>, // tag names
+        Box<Fn(IndyResult<()>) + Send>),
     SearchCredentials(
         i32, // wallet handle
         Option<String>, // query json
@@ -105,6 +130,17 @@ pub enum ProverCommand {
         RevocationRegistryDelta, // revocation registry delta
         u64, //timestamp
         String, //credential revocation id
+        Box<Fn(IndyResult<String>) + Send>),
+    UpdateCredentialRevocationState(
+        i32, // wallet handle
+        i32, // tails reader _handle
+        String, // credential id
+        RevocationRegistryDefinition, // revocation registry definition
+        RevocationRegistryDelta, // revocation registry delta
+        u64, //timestamp
+        Box<Fn(IndyResult<String>) + Send>),
+    ValidateProofRequest(
+        String, // proof request json
         Box<Fn(IndyResult<String>) + Send>)
 }
 
@@ -169,6 +205,13 @@ impl ProverCommandExecutor {
                                          &CredentialDefinitionV1::from(cred_def),
                                          rev_reg_def.map(RevocationRegistryDefinitionV1::from).as_ref()));
             }
+            ProverCommand::VerifyCredential(wallet_handle, cred_req_metadata, mut cred, schema, cred_def, rev_reg_def, cb) => {
+                info!(target: "prover_command_executor", "VerifyCredential command received");
+                cb(self.verify_credential(wallet_handle, &cred_req_metadata, &mut cred,
+                                          &SchemaV1::from(schema),
+                                          &CredentialDefinitionV1::from(cred_def),
+                                          rev_reg_def.map(RevocationRegistryDefinitionV1::from).as_ref()));
+            }
             ProverCommand::GetCredentials(wallet_handle, filter_json, cb) => {
                 info!(target: "prover_command_executor", "GetCredentials command received");
                 cb(self.get_credentials(wallet_handle, filter_json.as_ref().map(String::as_str)));
@@ -177,6 +220,18 @@ impl ProverCommandExecutor {
                 info!(target: "prover_command_executor", "GetCredential command received");
                 cb(self.get_credential(wallet_handle, &cred_id));
             }
+            ProverCommand::DeleteCredential(wallet_handle, cred_id, cb) => {
+                info!(target: "prover_command_executor", "DeleteCredential command received");
+                cb(self.delete_credential(wallet_handle, &cred_id));
+            }
+            ProverCommand::AddCredentialTags(wallet_handle, cred_id, tags, cb) => {
+                info!(target: "prover_command_executor", "AddCredentialTags command received");
+                cb(self.add_credential_tags(wallet_handle, &cred_id, &tags));
+            }
+            ProverCommand::DeleteCredentialTags(wallet_handle, cred_id, tag_names, cb) => {
+                info!(target: "prover_command_executor", "DeleteCredentialTags command received");
+                cb(self.delete_credential_tags(wallet_handle, &cred_id, &tag_names));
+            }
             ProverCommand::SearchCredentials(wallet_handle, query_json, cb) => {
                 info!(target: "prover_command_executor", "SearchCredentials command received");
                 cb(self.search_credentials(wallet_handle, query_json.as_ref().map(String::as_str)));
@@ -221,6 +276,14 @@ impl ProverCommandExecutor {
                 info!(target: "prover_command_executor", "UpdateRevocationState command received");
                 cb(self.update_revocation_state(blob_storage_reader_handle, rev_state, rev_reg_def, rev_reg_delta, timestamp, &cred_rev_id));
             }
+            ProverCommand::UpdateCredentialRevocationState(wallet_handle, blob_storage_reader_handle, cred_id, rev_reg_def, rev_reg_delta, timestamp, cb) => {
+                info!(target: "prover_command_executor", "UpdateCredentialRevocationState command received");
+                cb(self.update_credential_revocation_state(wallet_handle, blob_storage_reader_handle, &cred_id, rev_reg_def, rev_reg_delta, timestamp));
+            }
+            ProverCommand::ValidateProofRequest(proof_req_json, cb) => {
+                info!(target: "prover_command_executor", "ValidateProofRequest command received");
+                cb(self.validate_proof_request(&proof_req_json));
+            }
         };
     }
 
@@ -305,6 +368,14 @@ impl ProverCommandExecutor {
 
         let master_secret: MasterSecret = self._wallet_get_master_secret(wallet_handle, &cred_req_metadata.master_secret_name)?;
 
+        if verify_credential_on_store() {
+            let report = self.anoncreds_service.prover.check_credential(credential, None, cred_def, rev_reg_def)?;
+
+            if !report.valid {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Credential verification failed: {:?}", report)));
+            }
+        }
+
         self.anoncreds_service.prover.process_credential(credential,
                                                          &cred_req_metadata,
                                                          &master_secret.value,
@@ -324,6 +395,41 @@ impl ProverCommandExecutor {
         Ok(out_cred_id)
     }
 
+    fn verify_credential(&self,
+                         wallet_handle: i32,
+                         cred_req_metadata: &CredentialRequestMetadata,
+                         credential: &mut Credential,
+                         schema: &SchemaV1,
+                         cred_def: &CredentialDefinitionV1,
+                         rev_reg_def: Option<&RevocationRegistryDefinitionV1>) -> IndyResult<String> {
+        debug!("verify_credential >>> wallet_handle: {:?}, cred_req_metadata: {:?}, credential: {:?}, schema: {:?}, cred_def: {:?}, \
+        rev_reg_def: {:?}", wallet_handle, cred_req_metadata, credential, schema, cred_def, rev_reg_def);
+
+        let mut report = self.anoncreds_service.prover.check_credential(credential, Some(schema), cred_def, rev_reg_def)?;
+
+        let master_secret: MasterSecret = self._wallet_get_master_secret(wallet_handle, &cred_req_metadata.master_secret_name)?;
+
+        let signature_errors = match self.anoncreds_service.prover.process_credential(credential,
+                                                                                      &cred_req_metadata,
+                                                                                      &master_secret.value,
+                                                                                      cred_def,
+                                                                                      rev_reg_def) {
+            Ok(()) => Vec::new(),
+            Err(ref err) if err.kind() == IndyErrorKind::InvalidStructure || err.kind() == IndyErrorKind::ProofRejected =>
+                vec!["Credential signature can't be processed with the credential request metadata".to_string()],
+            Err(err) => return Err(err)
+        };
+
+        report.set_signature_check(CredentialCheck::new(signature_errors));
+
+        let res = serde_json::to_string(&report)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize CredentialVerificationReport")?;
+
+        debug!("verify_credential <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn get_credentials(&self,
                        wallet_handle: i32,
                        filter_json: Option<&str>) -> IndyResult<String> {
@@ -365,6 +471,55 @@ impl ProverCommandExecutor {
         Ok(credential_info_json)
     }
 
+    fn delete_credential(&self,
+                         wallet_handle: i32,
+                         cred_id: &str) -> IndyResult<()> {
+        debug!("delete_credential >>> wallet_handle: {:?}, cred_id: {:?}", wallet_handle, cred_id);
+
+        self.wallet_service.delete_indy_record::<Credential>(wallet_handle, cred_id)?;
+
+        // RevocationState cached for the credential is useless without it
+        if self.wallet_service.record_exists::<RevocationState>(wallet_handle, cred_id)? {
+            self.wallet_service.delete_indy_record::<RevocationState>(wallet_handle, cred_id)?;
+        }
+
+        debug!("delete_credential <<<");
+
+        Ok(())
+    }
+
+    fn add_credential_tags(&self,
+                           wallet_handle: i32,
+                           cred_id: &str,
+                           tags: &Tags) -> IndyResult<()> {
+        debug!("add_credential_tags >>> wallet_handle: {:?}, cred_id: {:?}, tags: {:?}", wallet_handle, cred_id, tags);
+
+        self.anoncreds_service.prover.check_user_credential_tags(tags.keys())?;
+
+        self.wallet_service.add_indy_record_tags::<Credential>(wallet_handle, cred_id, tags)?;
+
+        debug!("add_credential_tags <<<");
+
+        Ok(())
+    }
+
+    fn delete_credential_tags(&self,
+                              wallet_handle: i32,
+                              cred_id: &str,
+                              tag_names: &[String]) -> IndyResult<()> {
+        debug!("delete_credential_tags >>> wallet_handle: {:?}, cred_id: {:?}, tag_names: {:?}", wallet_handle, cred_id, tag_names);
+
+        self.anoncreds_service.prover.check_user_credential_tags(tag_names)?;
+
+        let tag_names: Vec<&str> = tag_names.iter().map(String::as_str).collect();
+
+        self.wallet_service.delete_indy_record_tags::<Credential>(wallet_handle, cred_id, &tag_names)?;
+
+        debug!("delete_credential_tags <<<");
+
+        Ok(())
+    }
+
     fn search_credentials(&self,
                           wallet_handle: i32,
                           query_json: Option<&str>) -> IndyResult<(i32, usize)> {
@@ -428,15 +583,41 @@ impl ProverCommandExecutor {
         Ok(res)
     }
 
+    fn validate_proof_request(&self,
+                              proof_req_json: &str) -> IndyResult<String> {
+        debug!("validate_proof_request >>> proof_req_json: {:?}", proof_req_json);
+
+        let problems = self.anoncreds_service.prover.validate_proof_request_json(proof_req_json);
+
+        let res = serde_json::to_string(&problems)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize proof request problems")?;
+
+        debug!("validate_proof_request <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn _check_proof_request(&self, proof_request: &ProofRequest) -> IndyResult<()> {
+        let problems = self.anoncreds_service.prover.check_proof_request_structure(proof_request);
+
+        if !problems.is_empty() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid proof request: {}", problems.join("; "))));
+        }
+
+        Ok(())
+    }
+
     fn get_credentials_for_proof_req(&self,
                                      wallet_handle: i32,
                                      proof_request: &ProofRequest) -> IndyResult<String> {
         debug!("get_credentials_for_proof_req >>> wallet_handle: {:?}, proof_request: {:?}", wallet_handle, proof_request);
 
+        self._check_proof_request(proof_request)?;
+
         let mut credentials_for_proof_request = CredentialsForProofRequest::default();
 
         for (attr_id, requested_attr) in &proof_request.requested_attributes {
-            let query_json = self.anoncreds_service.prover.build_query(&requested_attr.name,
+            let query_json = self.anoncreds_service.prover.build_query(&requested_attr.names()?,
                                                                        &attr_id,
                                                                        &requested_attr.restrictions,
                                                                        &None)?;
@@ -449,7 +630,7 @@ impl ProverCommandExecutor {
         }
 
         for (predicate_id, requested_predicate) in &proof_request.requested_predicates {
-            let query_json = self.anoncreds_service.prover.build_query(&requested_predicate.name,
+            let query_json = self.anoncreds_service.prover.build_query(&[requested_predicate.name.clone()],
                                                                        &predicate_id,
                                                                        &requested_predicate.restrictions,
                                                                        &None)?;
@@ -476,10 +657,12 @@ impl ProverCommandExecutor {
                                         extra_query: Option<&ProofRequestExtraQuery>) -> IndyResult<i32> {
         debug!("search_credentials_for_proof_req >>> wallet_handle: {:?}, proof_request: {:?}, extra_query: {:?}", wallet_handle, proof_request, extra_query);
 
+        self._check_proof_request(proof_request)?;
+
         let mut credentials_for_proof_request_search = HashMap::<String, SearchForProofRequest>::new();
 
         for (attr_id, requested_attr) in &proof_request.requested_attributes {
-            let query_json = self.anoncreds_service.prover.build_query(&requested_attr.name,
+            let query_json = self.anoncreds_service.prover.build_query(&requested_attr.names()?,
                                                                        &attr_id,
                                                                        &requested_attr.restrictions,
                                                                        &extra_query)?;
@@ -494,7 +677,7 @@ impl ProverCommandExecutor {
         }
 
         for (predicate_id, requested_predicate) in &proof_request.requested_predicates {
-            let query_json = self.anoncreds_service.prover.build_query(&requested_predicate.name,
+            let query_json = self.anoncreds_service.prover.build_query(&[requested_predicate.name.clone()],
                                                                        &predicate_id,
                                                                        &requested_predicate.restrictions,
                                                                        &extra_query)?;
@@ -584,6 +767,8 @@ impl ProverCommandExecutor {
             credentials.insert(cred_referent, credential);
         }
 
+        let rev_states = self._add_cached_revocation_states(wallet_handle, requested_credentials, &credentials, rev_states)?;
+
         let proof = self.anoncreds_service.prover.create_proof(&credentials,
                                                                &proof_req,
                                                                &requested_credentials,
@@ -609,23 +794,13 @@ impl ProverCommandExecutor {
         debug!("create_revocation_state >>> , blob_storage_reader_handle: {:?}, revoc_reg_def: {:?}, rev_reg_delta: {:?}, timestamp: {:?}, cred_rev_id: {:?}",
                blob_storage_reader_handle, revoc_reg_def, rev_reg_delta, timestamp, cred_rev_id);
 
-        let revoc_reg_def = RevocationRegistryDefinitionV1::from(revoc_reg_def);
-
         let rev_idx = parse_cred_rev_id(cred_rev_id)?;
 
-        let sdk_tails_accessor = SDKTailsAccessor::new(self.blob_storage_service.clone(),
-                                                       blob_storage_reader_handle,
-                                                       &revoc_reg_def)?;
-
-        let rev_reg_delta = RevocationRegistryDeltaV1::from(rev_reg_delta);
-
-        let witness = Witness::new(rev_idx, revoc_reg_def.value.max_cred_num, revoc_reg_def.value.issuance_type.to_bool(), &rev_reg_delta.value, &sdk_tails_accessor)?;
-
-        let revocation_state = RevocationState {
-            witness,
-            rev_reg: RevocationRegistry::from(rev_reg_delta.value),
-            timestamp,
-        };
+        let revocation_state = self._create_revocation_state(blob_storage_reader_handle,
+                                                             &RevocationRegistryDefinitionV1::from(revoc_reg_def),
+                                                             RevocationRegistryDeltaV1::from(rev_reg_delta),
+                                                             timestamp,
+                                                             rev_idx)?;
 
         let revocation_state_json = serde_json::to_string(&revocation_state)
             .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationState")?;
@@ -645,27 +820,138 @@ impl ProverCommandExecutor {
         debug!("update_revocation_state >>> blob_storage_reader_handle: {:?}, rev_state: {:?}, rev_reg_def: {:?}, rev_reg_delta: {:?}, timestamp: {:?}, cred_rev_id: {:?}",
                blob_storage_reader_handle, rev_state, rev_reg_def, rev_reg_delta, timestamp, cred_rev_id);
 
-        let revocation_registry_definition = RevocationRegistryDefinitionV1::from(rev_reg_def);
+        let rev_idx = parse_cred_rev_id(cred_rev_id)?;
+
+        self._update_revocation_state(blob_storage_reader_handle,
+                                      &mut rev_state,
+                                      &RevocationRegistryDefinitionV1::from(rev_reg_def),
+                                      RevocationRegistryDeltaV1::from(rev_reg_delta),
+                                      timestamp,
+                                      rev_idx)?;
+
+        let rev_state_json = serde_json::to_string(&rev_state)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationState")?;
+
+        debug!("update_revocation_state <<< rev_state: {:?}", rev_state_json);
+
+        Ok(rev_state_json)
+    }
+
+    fn update_credential_revocation_state(&self,
+                                          wallet_handle: i32,
+                                          blob_storage_reader_handle: i32,
+                                          cred_id: &str,
+                                          rev_reg_def: RevocationRegistryDefinition,
+                                          rev_reg_delta: RevocationRegistryDelta,
+                                          timestamp: u64) -> IndyResult<String> {
+        debug!("update_credential_revocation_state >>> wallet_handle: {:?}, blob_storage_reader_handle: {:?}, cred_id: {:?}, rev_reg_def: {:?}, rev_reg_delta: {:?}, \
+               timestamp: {:?}", wallet_handle, blob_storage_reader_handle, cred_id, rev_reg_def, rev_reg_delta, timestamp);
+
+        let credential: Credential = self.wallet_service.get_indy_object(wallet_handle, cred_id, &RecordOptions::id_value())?;
+
+        let rev_reg_def = RevocationRegistryDefinitionV1::from(rev_reg_def);
+
+        if credential.rev_reg_id() != Some(qualifier::unqualify(&rev_reg_def.id)) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Credential {} doesn't belong to RevocationRegistry {}", cred_id, rev_reg_def.id)));
+        }
+
+        let rev_idx = credential.signature.extract_index()
+            .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("Credential {} doesn't contain revocation id", cred_id)))?;
 
         let rev_reg_delta = RevocationRegistryDeltaV1::from(rev_reg_delta);
 
-        let rev_idx = parse_cred_rev_id(cred_rev_id)?;
+        let rev_state = match self.wallet_service.get_indy_opt_object::<RevocationState>(wallet_handle, cred_id, &RecordOptions::id_value())? {
+            Some(ref rev_state) if rev_state.timestamp > timestamp => {
+                return Err(err_msg(IndyErrorKind::InvalidStructure,
+                                   format!("Cached RevocationState of credential {} is newer than timestamp {}", cred_id, timestamp)));
+            }
+            Some(mut rev_state) => {
+                self._update_revocation_state(blob_storage_reader_handle, &mut rev_state, &rev_reg_def, rev_reg_delta, timestamp, rev_idx)?;
+                rev_state
+            }
+            None => self._create_revocation_state(blob_storage_reader_handle, &rev_reg_def, rev_reg_delta, timestamp, rev_idx)?
+        };
+
+        let rev_state_json = self.wallet_service.upsert_indy_object(wallet_handle, cred_id, &rev_state)?;
+
+        debug!("update_credential_revocation_state <<< rev_state: {:?}", rev_state_json);
+
+        Ok(rev_state_json)
+    }
+
+    fn _create_revocation_state(&self,
+                                blob_storage_reader_handle: i32,
+                                rev_reg_def: &RevocationRegistryDefinitionV1,
+                                rev_reg_delta: RevocationRegistryDeltaV1,
+                                timestamp: u64,
+                                rev_idx: u32) -> IndyResult<RevocationState> {
+        let sdk_tails_accessor = SDKTailsAccessor::new(self.blob_storage_service.clone(),
+                                                       blob_storage_reader_handle,
+                                                       rev_reg_def)?;
+
+        let witness = Witness::new(rev_idx, rev_reg_def.value.max_cred_num, rev_reg_def.value.issuance_type.to_bool(), &rev_reg_delta.value, &sdk_tails_accessor)?;
+
+        Ok(RevocationState {
+            witness,
+            rev_reg: RevocationRegistry::from(rev_reg_delta.value),
+            timestamp,
+        })
+    }
 
+    fn _update_revocation_state(&self,
+                                blob_storage_reader_handle: i32,
+                                rev_state: &mut RevocationState,
+                                rev_reg_def: &RevocationRegistryDefinitionV1,
+                                rev_reg_delta: RevocationRegistryDeltaV1,
+                                timestamp: u64,
+                                rev_idx: u32) -> IndyResult<()> {
         let sdk_tails_accessor = SDKTailsAccessor::new(self.blob_storage_service.clone(),
                                                        blob_storage_reader_handle,
-                                                       &revocation_registry_definition)?;
+                                                       rev_reg_def)?;
 
-        rev_state.witness.update(rev_idx, revocation_registry_definition.value.max_cred_num, &rev_reg_delta.value, &sdk_tails_accessor)?;
+        rev_state.witness.update(rev_idx, rev_reg_def.value.max_cred_num, &rev_reg_delta.value, &sdk_tails_accessor)?;
 
         rev_state.rev_reg = RevocationRegistry::from(rev_reg_delta.value);
         rev_state.timestamp = timestamp;
 
-        let rev_state_json = serde_json::to_string(&rev_state)
-            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationState")?;
+        Ok(())
+    }
 
-        debug!("update_revocation_state <<< rev_state: {:?}", rev_state_json);
+    // Revocation states not passed by caller are taken from states cached by update_credential_revocation_state.
+    // Cached state is the latest known one for the requested timestamp if it isn't newer than requested timestamp.
+    fn _add_cached_revocation_states(&self,
+                                     wallet_handle: i32,
+                                     requested_credentials: &RequestedCredentials,
+                                     credentials: &HashMap<String, Credential>,
+                                     rev_states: &HashMap<String, HashMap<u64, RevocationState>>) -> IndyResult<HashMap<String, HashMap<u64, RevocationState>>> {
+        let mut res = rev_states.clone();
+
+        let requested_keys = requested_credentials.requested_attributes
+            .values()
+            .map(|requested_attr| (&requested_attr.cred_id, requested_attr.timestamp))
+            .chain(requested_credentials.requested_predicates
+                .values()
+                .map(|requested_predicate| (&requested_predicate.cred_id, requested_predicate.timestamp)));
 
-        Ok(rev_state_json)
+        for (cred_id, timestamp) in requested_keys {
+            let (rev_reg_id, timestamp) = match (credentials.get(cred_id).and_then(|credential| credential.rev_reg_id.as_ref()), timestamp) {
+                (Some(rev_reg_id), Some(timestamp)) => (rev_reg_id, timestamp),
+                _ => continue
+            };
+
+            if res.get(rev_reg_id).map(|rev_states| rev_states.contains_key(&timestamp)).unwrap_or(false) {
+                continue;
+            }
+
+            if let Some(rev_state) = self.wallet_service.get_indy_opt_object::<RevocationState>(wallet_handle, cred_id, &RecordOptions::id_value())? {
+                if rev_state.timestamp <= timestamp {
+                    res.entry(rev_reg_id.to_string()).or_insert_with(HashMap::new).insert(timestamp, rev_state);
+                }
+            }
+        }
+
+        Ok(res)
     }
 
     fn _get_credential_info(&self,
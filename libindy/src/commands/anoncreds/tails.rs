@@ -31,7 +31,8 @@ impl SDKTailsAccessor {
 
         let tails_reader_handle = tails_service.open_blob(tails_reader_handle,
                                                           &rev_reg_def.value.tails_location,
-                                                          tails_hash.as_slice())?;
+                                                          tails_hash.as_slice(),
+                                                          tails_size(rev_reg_def))?;
 
         Ok(SDKTailsAccessor {
             tails_service,
@@ -70,6 +71,11 @@ impl RevocationTailsAccessor for SDKTailsAccessor {
     }
 }
 
+// Tails file consists of version tag followed by 2 * max_cred_num + 1 tails
+fn tails_size(rev_reg_def: &RevocationRegistryDefinitionV1) -> usize {
+    TAILS_BLOB_TAG_SZ as usize + TAIL_SIZE * (2 * rev_reg_def.value.max_cred_num as usize + 1)
+}
+
 pub fn store_tails_from_generator(service: Rc<BlobStorageService>,
                                   writer_handle: i32,
                                   rtg: &mut RevocationTailsGenerator) -> IndyResult<(String, String)> {
@@ -8,6 +8,7 @@ use domain::anoncreds::revocation_registry::{rev_regs_map_to_rev_regs_local_map,
 use domain::anoncreds::revocation_registry_definition::{rev_reg_defs_map_to_rev_reg_defs_v1_map, RevocationRegistryDefinition, RevocationRegistryDefinitionV1};
 use domain::anoncreds::schema::{Schema, schemas_map_to_schemas_v1_map, SchemaV1};
 use errors::prelude::*;
+use serde_json;
 use services::anoncreds::AnoncredsService;
 
 pub enum VerifierCommand {
@@ -18,7 +19,15 @@ pub enum VerifierCommand {
         HashMap<String, CredentialDefinition>, // credential defs
         HashMap<String, RevocationRegistryDefinition>, // rev reg defs
         HashMap<String, HashMap<u64, RevocationRegistry>>, // rev reg entries
-        Box<Fn(IndyResult<bool>) + Send>)
+        Box<Fn(IndyResult<bool>) + Send>),
+    VerifyProofVerbose(
+        ProofRequest, // proof request
+        Proof, // proof
+        HashMap<String, Schema>, // credential schemas
+        HashMap<String, CredentialDefinition>, // credential defs
+        HashMap<String, RevocationRegistryDefinition>, // rev reg defs
+        HashMap<String, HashMap<u64, RevocationRegistry>>, // rev reg entries
+        Box<Fn(IndyResult<String>) + Send>)
 }
 
 pub struct VerifierCommandExecutor {
@@ -42,6 +51,14 @@ impl VerifierCommandExecutor {
                                      &rev_reg_defs_map_to_rev_reg_defs_v1_map(rev_reg_defs),
                                      &rev_regs_map_to_rev_regs_local_map(rev_regs)));
             }
+            VerifierCommand::VerifyProofVerbose(proof_request, proof, schemas, credential_defs, rev_reg_defs, rev_regs, cb) => {
+                info!(target: "verifier_command_executor", "VerifyProofVerbose command received");
+                cb(self.verify_proof_verbose(proof_request, proof,
+                                             &schemas_map_to_schemas_v1_map(schemas),
+                                             &cred_defs_map_to_cred_defs_v1_map(credential_defs),
+                                             &rev_reg_defs_map_to_rev_reg_defs_v1_map(rev_reg_defs),
+                                             &rev_regs_map_to_rev_regs_local_map(rev_regs)));
+            }
         };
     }
 
@@ -66,6 +83,7 @@ impl VerifierCommandExecutor {
         let received_revealed_attrs: HashSet<String> =
             proof.requested_proof.revealed_attrs
                 .keys()
+                .chain(proof.requested_proof.revealed_attr_groups.keys())
                 .cloned()
                 .into_iter()
                 .collect::<HashSet<String>>();
@@ -127,4 +145,30 @@ impl VerifierCommandExecutor {
 
         Ok(result)
     }
-}
\ No newline at end of file
+
+    fn verify_proof_verbose(&self,
+                            proof_req: ProofRequest,
+                            proof: Proof,
+                            schemas: &HashMap<String, SchemaV1>,
+                            cred_defs: &HashMap<String, CredentialDefinitionV1>,
+                            rev_reg_defs: &HashMap<String, RevocationRegistryDefinitionV1>,
+                            rev_regs: &HashMap<String, HashMap<u64, RevocationRegistryV1>>) -> IndyResult<String> {
+        debug!("verify_proof_verbose >>> proof_req: {:?}, proof: {:?}, schemas: {:?}, cred_defs: {:?},  \
+               rev_reg_defs: {:?}, rev_regs: {:?}",
+               proof_req, proof, schemas, cred_defs, rev_reg_defs, rev_regs);
+
+        let report = self.anoncreds_service.verifier.verify_verbose(&proof,
+                                                                    &proof_req,
+                                                                    schemas,
+                                                                    cred_defs,
+                                                                    rev_reg_defs,
+                                                                    rev_regs)?;
+
+        let res = serde_json::to_string(&report)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize ProofVerificationReport")?;
+
+        debug!("verify_proof_verbose <<< res: {:?}", res);
+
+        Ok(res)
+    }
+}
@@ -1,3 +1,4 @@
+use api::blob_storage::*;
 use services::blob_storage::BlobStorageService;
 use std::rc::Rc;
 
@@ -12,6 +13,19 @@ pub enum BlobStorageCommand {
         String, // writer type
         String, // writer config JSON
         Box<Fn(IndyResult<i32 /* handle */>) + Send>),
+    RegisterBlobStorage(
+        String, // type
+        BlobStorageOpenWriter, // open writer
+        BlobStorageCreateBlob, // create blob
+        BlobStorageAppend, // append
+        BlobStorageFinalize, // finalize
+        BlobStorageCloseWriter, // close writer
+        BlobStorageOpenReader, // open reader
+        BlobStorageOpenBlob, // open blob
+        BlobStorageRead, // read
+        BlobStorageCloseBlob, // close blob
+        BlobStorageCloseReader, // close reader
+        Box<Fn(IndyResult<()>) + Send>),
 }
 
 pub struct BlobStorageCommandExecutor {
@@ -35,6 +49,12 @@ impl BlobStorageCommandExecutor {
                 info!("OpenWriter command received");
                 cb(self.open_writer(&writer_type, &writer_config));
             }
+            BlobStorageCommand::RegisterBlobStorage(type_, open_writer, create_blob, append, finalize, close_writer,
+                                                    open_reader, open_blob, read, close_blob, close_reader, cb) => {
+                info!("RegisterBlobStorage command received");
+                cb(self.register_blob_storage(&type_, open_writer, create_blob, append, finalize, close_writer,
+                                              open_reader, open_blob, read, close_blob, close_reader));
+            }
         }
     }
 
@@ -57,4 +77,26 @@ impl BlobStorageCommandExecutor {
 
         res
     }
+
+    fn register_blob_storage(&self,
+                             type_: &str,
+                             open_writer: BlobStorageOpenWriter,
+                             create_blob: BlobStorageCreateBlob,
+                             append: BlobStorageAppend,
+                             finalize: BlobStorageFinalize,
+                             close_writer: BlobStorageCloseWriter,
+                             open_reader: BlobStorageOpenReader,
+                             open_blob: BlobStorageOpenBlob,
+                             read: BlobStorageRead,
+                             close_blob: BlobStorageCloseBlob,
+                             close_reader: BlobStorageCloseReader) -> IndyResult<()> {
+        debug!("register_blob_storage >>> type_: {:?}", type_);
+
+        let res = self.blob_storage_service.register_blob_storage(type_, open_writer, create_blob, append, finalize, close_writer,
+                                                                  open_reader, open_blob, read, close_blob, close_reader);
+
+        debug!("register_blob_storage << res: {:?}", res);
+
+        res
+    }
 }
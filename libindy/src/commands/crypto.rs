@@ -2,9 +2,16 @@ extern crate indy_crypto;
 extern crate serde_json;
 extern crate zeroize;
 
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::fs;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
 
-use domain::crypto::key::{Key, KeyInfo, KeyMetadata};
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+
+use domain::crypto::hd::{HD_MASTER_SEED_ID, HdMasterSeed, HdMasterSeedConfig};
+use domain::crypto::key::{ExportedKey, Key, KeyExportConfig, KeyImportConfig, KeyInfo, KeyMetadata};
 use domain::crypto::pack::*;
 use errors::prelude::*;
 use services::crypto::CryptoService;
@@ -14,14 +21,23 @@ use std::rc::Rc;
 use std::str;
 use utils::crypto::base64;
 use utils::crypto::chacha20poly1305_ietf;
+use utils::sequence;
 use domain::crypto::combo_box::ComboBox;
 
+const PACK_STREAM_CHUNK_SIZE: usize = 64 * 1024;
+const MAX_STREAM_HEADER_SIZE: usize = 1024 * 1024;
+
 pub enum CryptoCommand {
     CreateKey(
         i32,     // wallet handle
         KeyInfo, // key info
         Box<Fn(IndyResult<String /*verkey*/>) + Send>,
     ),
+    CreateHdMasterSeed(
+        i32,     // wallet handle
+        HdMasterSeedConfig, // master seed config
+        Box<Fn(IndyResult<String /*mnemonic*/>) + Send>,
+    ),
     SetKeyMetadata(
         i32,    // wallet handle
         String, // verkey
@@ -33,6 +49,18 @@ pub enum CryptoCommand {
         String, // verkey
         Box<Fn(IndyResult<String>) + Send>,
     ),
+    ExportKey(
+        i32,    // wallet handle
+        String, // verkey
+        KeyExportConfig, // export config
+        Box<Fn(IndyResult<String /*exported key*/>) + Send>,
+    ),
+    ImportKey(
+        i32,    // wallet handle
+        ExportedKey, // exported key
+        KeyImportConfig, // import config
+        Box<Fn(IndyResult<String /*verkey*/>) + Send>,
+    ),
     CryptoSign(
         i32,     // wallet handle
         String,  // my vk
@@ -81,11 +109,76 @@ pub enum CryptoCommand {
         i32,     // wallet handle
         Box<Fn(IndyResult<Vec<u8>>) + Send>,
     ),
+    PackFile(
+        i32,     // wallet handle
+        String,  // input path
+        String,  // output path
+        String,  // list of receiver's keys
+        Option<String>,  // senders verkey
+        Box<Fn(IndyResult<()>) + Send>,
+    ),
+    UnpackFile(
+        i32,     // wallet handle
+        String,  // input path
+        String,  // output path
+        Box<Fn(IndyResult<String>) + Send>,
+    ),
+    PackStreamStart(
+        i32,     // wallet handle
+        String,  // list of receiver's keys
+        Option<String>,  // senders verkey
+        Box<Fn(IndyResult<(i32, Vec<u8>)>) + Send>,
+    ),
+    PackStreamUpdate(
+        i32,     // stream handle
+        Vec<u8>, // plaintext part
+        Box<Fn(IndyResult<Vec<u8>>) + Send>,
+    ),
+    PackStreamFinish(
+        i32,     // stream handle
+        Box<Fn(IndyResult<Vec<u8>>) + Send>,
+    ),
+    UnpackStreamStart(
+        i32,     // wallet handle
+        Box<Fn(IndyResult<i32>) + Send>,
+    ),
+    UnpackStreamUpdate(
+        i32,     // stream handle
+        Vec<u8>, // packed part
+        Box<Fn(IndyResult<Vec<u8>>) + Send>,
+    ),
+    UnpackStreamFinish(
+        i32,     // stream handle
+        Box<Fn(IndyResult<String>) + Send>,
+    ),
+}
+
+struct PackStreamState {
+    protected: String,
+    cek: chacha20poly1305_ietf::Key,
+    nonce: chacha20poly1305_ietf::Nonce,
+    buffer: Vec<u8>,
+}
+
+struct UnpackStreamState {
+    wallet_handle: i32,
+    buffer: Vec<u8>,
+    opened: Option<OpenedUnpackStream>,
+    finished: bool,
+}
+
+struct OpenedUnpackStream {
+    header: StreamHeader,
+    cek: chacha20poly1305_ietf::Key,
+    nonce: chacha20poly1305_ietf::Nonce,
+    result: UnpackStream,
 }
 
 pub struct CryptoCommandExecutor {
     wallet_service: Rc<WalletService>,
     crypto_service: Rc<CryptoService>,
+    pack_streams: RefCell<HashMap<i32, PackStreamState>>,
+    unpack_streams: RefCell<HashMap<i32, UnpackStreamState>>,
 }
 
 impl CryptoCommandExecutor {
@@ -96,6 +189,8 @@ impl CryptoCommandExecutor {
         CryptoCommandExecutor {
             wallet_service,
             crypto_service,
+            pack_streams: RefCell::new(HashMap::new()),
+            unpack_streams: RefCell::new(HashMap::new()),
         }
     }
 
@@ -105,6 +200,10 @@ impl CryptoCommandExecutor {
                 info!("CreateKey command received");
                 cb(self.create_key(wallet_handle, &key_info));
             }
+            CryptoCommand::CreateHdMasterSeed(wallet_handle, config, cb) => {
+                info!("CreateHdMasterSeed command received");
+                cb(self.create_hd_master_seed(wallet_handle, &config));
+            }
             CryptoCommand::SetKeyMetadata(wallet_handle, verkey, metadata, cb) => {
                 info!("SetKeyMetadata command received");
                 cb(self.set_key_metadata(wallet_handle, &verkey, &metadata));
@@ -113,6 +212,14 @@ impl CryptoCommandExecutor {
                 info!("GetKeyMetadata command received");
                 cb(self.get_key_metadata(wallet_handle, &verkey));
             }
+            CryptoCommand::ExportKey(wallet_handle, verkey, config, cb) => {
+                info!("ExportKey command received");
+                cb(self.export_key(wallet_handle, &verkey, &config));
+            }
+            CryptoCommand::ImportKey(wallet_handle, exported_key, config, cb) => {
+                info!("ImportKey command received");
+                cb(self.import_key(wallet_handle, &exported_key, &config));
+            }
             CryptoCommand::CryptoSign(wallet_handle, my_vk, msg, cb) => {
                 info!("CryptoSign command received");
                 cb(self.crypto_sign(wallet_handle, &my_vk, &msg));
@@ -145,6 +252,38 @@ impl CryptoCommandExecutor {
                 info!("UnpackMessage command received");
                 cb(self.unpack_msg(jwe_json, wallet_handle));
             }
+            CryptoCommand::PackFile(wallet_handle, input_path, output_path, receivers, sender_vk, cb) => {
+                info!("PackFile command received");
+                cb(self.pack_file(wallet_handle, &input_path, &output_path, &receivers, sender_vk));
+            }
+            CryptoCommand::UnpackFile(wallet_handle, input_path, output_path, cb) => {
+                info!("UnpackFile command received");
+                cb(self.unpack_file(wallet_handle, &input_path, &output_path));
+            }
+            CryptoCommand::PackStreamStart(wallet_handle, receivers, sender_vk, cb) => {
+                info!("PackStreamStart command received");
+                cb(self.pack_stream_start(wallet_handle, &receivers, sender_vk));
+            }
+            CryptoCommand::PackStreamUpdate(stream_handle, data, cb) => {
+                info!("PackStreamUpdate command received");
+                cb(self.pack_stream_update(stream_handle, &data));
+            }
+            CryptoCommand::PackStreamFinish(stream_handle, cb) => {
+                info!("PackStreamFinish command received");
+                cb(self.pack_stream_finish(stream_handle));
+            }
+            CryptoCommand::UnpackStreamStart(wallet_handle, cb) => {
+                info!("UnpackStreamStart command received");
+                cb(self.unpack_stream_start(wallet_handle));
+            }
+            CryptoCommand::UnpackStreamUpdate(stream_handle, data, cb) => {
+                info!("UnpackStreamUpdate command received");
+                cb(self.unpack_stream_update(stream_handle, &data));
+            }
+            CryptoCommand::UnpackStreamFinish(stream_handle, cb) => {
+                info!("UnpackStreamFinish command received");
+                cb(self.unpack_stream_finish(stream_handle));
+            }
         };
     }
 
@@ -155,7 +294,13 @@ impl CryptoCommandExecutor {
             secret!(key_info)
         );
 
-        let key = self.crypto_service.create_key(key_info)?;
+        let key = if key_info.hd_path.is_some() {
+            let master_seed = get_hd_master_seed(&self.wallet_service, wallet_handle)?;
+            self.crypto_service.create_hd_key(&master_seed, key_info)?
+        } else {
+            self.crypto_service.create_key(key_info)?
+        };
+
         self.wallet_service
             .add_indy_object(wallet_handle, &key.verkey, &key, &HashMap::new())?;
 
@@ -164,6 +309,21 @@ impl CryptoCommandExecutor {
         Ok(res)
     }
 
+    fn create_hd_master_seed(&self, wallet_handle: i32, config: &HdMasterSeedConfig) -> IndyResult<String> {
+        debug!("create_hd_master_seed >>> wallet_handle: {:?}, config: {:?}", wallet_handle, secret!(config));
+
+        let (master_seed, mnemonic) = self.crypto_service.generate_hd_master_seed(config)?;
+
+        self.wallet_service
+            .add_indy_object(wallet_handle, HD_MASTER_SEED_ID, &master_seed, &HashMap::new())?;
+
+        let res = mnemonic.unwrap_or_default();
+
+        debug!("create_hd_master_seed <<<");
+
+        Ok(res)
+    }
+
     fn crypto_sign(&self, wallet_handle: i32, my_vk: &str, msg: &[u8]) -> IndyResult<Vec<u8>> {
         trace!(
             "crypto_sign >>> wallet_handle: {:?}, sender_vk: {:?}, msg: {:?}",
@@ -355,6 +515,43 @@ impl CryptoCommandExecutor {
         Ok(res)
     }
 
+    fn export_key(&self, wallet_handle: i32, verkey: &str, config: &KeyExportConfig) -> IndyResult<String> {
+        debug!("export_key >>> wallet_handle: {:?}, verkey: {:?}, config: {:?}", wallet_handle, verkey, secret!(config));
+
+        self.crypto_service.validate_key(verkey)?;
+
+        let key: Key = self.wallet_service.get_indy_object(wallet_handle, verkey, &RecordOptions::id_value())?;
+
+        let metadata = self.wallet_service.get_indy_opt_object::<KeyMetadata>(wallet_handle, verkey, &RecordOptions::id_value())?
+            .map(|metadata| metadata.value);
+
+        let res = self.crypto_service.export_key(&key, metadata, config)?;
+
+        debug!("export_key <<<");
+
+        Ok(res)
+    }
+
+    fn import_key(&self, wallet_handle: i32, exported_key: &ExportedKey, config: &KeyImportConfig) -> IndyResult<String> {
+        debug!("import_key >>> wallet_handle: {:?}, exported_key: {:?}, config: {:?}", wallet_handle, exported_key, secret!(config));
+
+        let (key, metadata) = self.crypto_service.import_key(exported_key, config)?;
+
+        self.wallet_service
+            .add_indy_object(wallet_handle, &key.verkey, &key, &HashMap::new())?;
+
+        if let Some(metadata) = metadata {
+            self.wallet_service
+                .upsert_indy_object(wallet_handle, &key.verkey, &KeyMetadata { value: metadata })?;
+        }
+
+        let res = key.verkey.to_string();
+
+        debug!("import_key <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     //TODO: Refactor pack to be more modular to version changes or crypto_scheme changes
     //this match statement is super messy, but the easiest way to comply with current architecture
     pub fn pack_msg(
@@ -365,6 +562,21 @@ impl CryptoCommandExecutor {
         wallet_handle: i32,
     ) -> IndyResult<Vec<u8>> {
 
+        let (base64_protected, cek) = self._prepare_protected(receivers, sender_vk, wallet_handle)?;
+
+        // encrypt ciphertext and integrity protect "protected" field
+        let (ciphertext, iv, tag) =
+            self.crypto_service
+                .encrypt_plaintext(message, &base64_protected, &cek);
+
+        self._format_pack_message(&base64_protected, &ciphertext, &iv, &tag)
+    }
+
+    fn _prepare_protected(&self,
+                          receivers: &str,
+                          sender_vk: Option<String>,
+                          wallet_handle: i32,
+    ) -> IndyResult<(String, chacha20poly1305_ietf::Key)> {
         //parse receivers to structs
         let receiver_list: Vec<String> = serde_json::from_str(receivers).map_err(|err| {
             err_msg(IndyErrorKind::InvalidStructure, format!(
@@ -380,22 +592,15 @@ impl CryptoCommandExecutor {
             )));
         }
 
-        let (base64_protected, cek) = if let Some(sender_vk) = sender_vk {
+        if let Some(sender_vk) = sender_vk {
             self.crypto_service.validate_key(&sender_vk)?;
 
             //returns authcrypted pack_message format. See Wire message format HIPE for details
-            self._prepare_protected_authcrypt(receiver_list, &sender_vk, wallet_handle)?
+            self._prepare_protected_authcrypt(receiver_list, &sender_vk, wallet_handle)
         } else {
             //returns anoncrypted pack_message format. See Wire message format HIPE for details
-            self._prepare_protected_anoncrypt(receiver_list)?
-        };
-
-        // encrypt ciphertext and integrity protect "protected" field
-        let (ciphertext, iv, tag) =
-            self.crypto_service
-                .encrypt_plaintext(message, &base64_protected, &cek);
-
-        self._format_pack_message(&base64_protected, &ciphertext, &iv, &tag)
+            self._prepare_protected_anoncrypt(receiver_list)
+        }
     }
 
     fn _prepare_protected_anoncrypt(&self,
@@ -510,8 +715,336 @@ impl CryptoCommandExecutor {
                 err
             ))
         })?;
+
+        let (recipient, sender_verkey_option, cek) = self._unpack_protected(&jwe_struct.protected, wallet_handle)?;
+
+        //decrypt message
+        let message = self.crypto_service.decrypt_ciphertext(
+            &jwe_struct.ciphertext,
+            &jwe_struct.protected,
+            &jwe_struct.iv,
+            &jwe_struct.tag,
+            &cek,
+        )?;
+
+        //serialize and return decrypted message
+        let res = UnpackMessage {
+            message,
+            sender_verkey: sender_verkey_option,
+            recipient_verkey: recipient.header.kid
+        };
+
+        return serde_json::to_vec(&res).map_err(|err| {
+            err_msg(IndyErrorKind::InvalidStructure, format!(
+                "Failed to serialize message {}",
+                err
+            ))
+        });
+    }
+
+    fn pack_file(&self,
+                 wallet_handle: i32,
+                 input_path: &str,
+                 output_path: &str,
+                 receivers: &str,
+                 sender_vk: Option<String>) -> IndyResult<()> {
+        debug!("pack_file >>> wallet_handle: {:?}, input_path: {:?}, output_path: {:?}, receivers: {:?}, sender_vk: {:?}",
+               wallet_handle, input_path, output_path, receivers, sender_vk);
+
+        let mut reader = BufReader::new(_open_input_file(input_path)?);
+        let mut writer = BufWriter::new(_create_output_file(output_path)?);
+
+        let res = self.pack_stream(&mut reader, &mut writer, receivers, sender_vk, wallet_handle);
+
+        if res.is_err() {
+            drop(writer);
+            fs::remove_file(output_path).ok();
+        }
+
+        debug!("pack_file <<< res: {:?}", res);
+
+        res
+    }
+
+    fn unpack_file(&self,
+                   wallet_handle: i32,
+                   input_path: &str,
+                   output_path: &str) -> IndyResult<String> {
+        debug!("unpack_file >>> wallet_handle: {:?}, input_path: {:?}, output_path: {:?}", wallet_handle, input_path, output_path);
+
+        let mut reader = BufReader::new(_open_input_file(input_path)?);
+        let mut writer = BufWriter::new(_create_output_file(output_path)?);
+
+        let res = self.unpack_stream(&mut reader, &mut writer, wallet_handle);
+
+        if res.is_err() {
+            drop(writer);
+            fs::remove_file(output_path).ok();
+        }
+
+        let res = serde_json::to_string(&res?)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize unpack result")?;
+
+        debug!("unpack_file <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    // Streamed message layout:
+    // header len (u32 LE) | StreamHeader json | segments produced by CryptoService::encrypt_plaintext_stream
+    pub fn pack_stream(&self,
+                       reader: &mut Read,
+                       writer: &mut Write,
+                       receivers: &str,
+                       sender_vk: Option<String>,
+                       wallet_handle: i32) -> IndyResult<()> {
+        let (header_bytes, header, cek, nonce) = self._prepare_stream_header(receivers, sender_vk, wallet_handle)?;
+
+        writer.write_all(&header_bytes)?;
+
+        self.crypto_service.encrypt_plaintext_stream(reader, writer, &header.protected, &cek, &nonce, header.chunk_size)
+    }
+
+    pub fn unpack_stream(&self,
+                         reader: &mut Read,
+                         writer: &mut Write,
+                         wallet_handle: i32) -> IndyResult<UnpackStream> {
+        let header_len = reader.read_u32::<LittleEndian>()
+            .to_indy(IndyErrorKind::InvalidStructure, "Failed to read stream header length")? as usize;
+
+        _check_stream_header_len(header_len)?;
+
+        let mut header_json = vec![0u8; header_len];
+        reader.read_exact(&mut header_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Failed to read stream header")?;
+
+        let opened = self._open_stream_header(&header_json, wallet_handle)?;
+
+        self.crypto_service.decrypt_ciphertext_stream(reader, writer, &opened.header.protected, &opened.cek, &opened.nonce, opened.header.chunk_size)?;
+
+        Ok(opened.result)
+    }
+
+    fn pack_stream_start(&self,
+                         wallet_handle: i32,
+                         receivers: &str,
+                         sender_vk: Option<String>) -> IndyResult<(i32, Vec<u8>)> {
+        debug!("pack_stream_start >>> wallet_handle: {:?}, receivers: {:?}, sender_vk: {:?}", wallet_handle, receivers, sender_vk);
+
+        let (header_bytes, header, cek, nonce) = self._prepare_stream_header(receivers, sender_vk, wallet_handle)?;
+
+        let stream_handle = sequence::get_next_id();
+
+        self.pack_streams.borrow_mut().insert(stream_handle, PackStreamState {
+            protected: header.protected,
+            cek,
+            nonce,
+            buffer: Vec::new(),
+        });
+
+        debug!("pack_stream_start <<< stream_handle: {:?}", stream_handle);
+
+        Ok((stream_handle, header_bytes))
+    }
+
+    fn pack_stream_update(&self,
+                          stream_handle: i32,
+                          data: &[u8]) -> IndyResult<Vec<u8>> {
+        debug!("pack_stream_update >>> stream_handle: {:?}, data len: {:?}", stream_handle, data.len());
+
+        let mut streams = self.pack_streams.borrow_mut();
+        let stream = streams.get_mut(&stream_handle)
+            .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("Unknown pack stream handle: {}", stream_handle)))?;
+
+        stream.buffer.extend_from_slice(data);
+
+        // only full segments are emitted, the rest waits for the next part or finish
+        let mut res = Vec::new();
+        let mut consumed = 0;
+
+        while stream.buffer.len() - consumed >= PACK_STREAM_CHUNK_SIZE {
+            res.extend(self.crypto_service.encrypt_stream_segment(&stream.buffer[consumed..consumed + PACK_STREAM_CHUNK_SIZE],
+                                                                  &stream.protected, &stream.cek, &mut stream.nonce, false));
+            consumed += PACK_STREAM_CHUNK_SIZE;
+        }
+
+        stream.buffer.drain(..consumed);
+
+        debug!("pack_stream_update <<< res len: {:?}", res.len());
+
+        Ok(res)
+    }
+
+    fn pack_stream_finish(&self,
+                          stream_handle: i32) -> IndyResult<Vec<u8>> {
+        debug!("pack_stream_finish >>> stream_handle: {:?}", stream_handle);
+
+        let mut stream = self.pack_streams.borrow_mut().remove(&stream_handle)
+            .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("Unknown pack stream handle: {}", stream_handle)))?;
+
+        let res = self.crypto_service.encrypt_stream_segment(&stream.buffer, &stream.protected, &stream.cek, &mut stream.nonce, true);
+
+        debug!("pack_stream_finish <<< res len: {:?}", res.len());
+
+        Ok(res)
+    }
+
+    fn unpack_stream_start(&self,
+                           wallet_handle: i32) -> IndyResult<i32> {
+        debug!("unpack_stream_start >>> wallet_handle: {:?}", wallet_handle);
+
+        let stream_handle = sequence::get_next_id();
+
+        self.unpack_streams.borrow_mut().insert(stream_handle, UnpackStreamState {
+            wallet_handle,
+            buffer: Vec::new(),
+            opened: None,
+            finished: false,
+        });
+
+        debug!("unpack_stream_start <<< stream_handle: {:?}", stream_handle);
+
+        Ok(stream_handle)
+    }
+
+    fn unpack_stream_update(&self,
+                            stream_handle: i32,
+                            data: &[u8]) -> IndyResult<Vec<u8>> {
+        debug!("unpack_stream_update >>> stream_handle: {:?}, data len: {:?}", stream_handle, data.len());
+
+        let mut streams = self.unpack_streams.borrow_mut();
+        let stream = streams.get_mut(&stream_handle)
+            .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("Unknown unpack stream handle: {}", stream_handle)))?;
+
+        if stream.finished {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Packed stream contains data after the final segment"));
+        }
+
+        stream.buffer.extend_from_slice(data);
+
+        let mut consumed = 0;
+
+        if stream.opened.is_none() {
+            if stream.buffer.len() < 4 {
+                return Ok(Vec::new());
+            }
+
+            let header_len = (&stream.buffer[..4]).read_u32::<LittleEndian>()? as usize;
+
+            _check_stream_header_len(header_len)?;
+
+            if stream.buffer.len() < 4 + header_len {
+                return Ok(Vec::new());
+            }
+
+            stream.opened = Some(self._open_stream_header(&stream.buffer[4..4 + header_len], stream.wallet_handle)?);
+            consumed = 4 + header_len;
+        }
+
+        let mut res = Vec::new();
+
+        {
+            let opened = stream.opened.as_mut().unwrap(); // opened above
+
+            while let Some((plaintext, is_final, len)) =
+                self.crypto_service.decrypt_stream_segment(&stream.buffer[consumed..], &opened.header.protected, &opened.cek,
+                                                           &mut opened.nonce, opened.header.chunk_size)? {
+                res.extend(plaintext);
+                consumed += len;
+
+                if is_final {
+                    stream.finished = true;
+                    break;
+                }
+            }
+        }
+
+        if stream.finished && consumed < stream.buffer.len() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Packed stream contains data after the final segment"));
+        }
+
+        stream.buffer.drain(..consumed);
+
+        debug!("unpack_stream_update <<< res len: {:?}", res.len());
+
+        Ok(res)
+    }
+
+    fn unpack_stream_finish(&self,
+                            stream_handle: i32) -> IndyResult<String> {
+        debug!("unpack_stream_finish >>> stream_handle: {:?}", stream_handle);
+
+        let stream = self.unpack_streams.borrow_mut().remove(&stream_handle)
+            .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("Unknown unpack stream handle: {}", stream_handle)))?;
+
+        if !stream.finished {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Packed stream is truncated"));
+        }
+
+        let opened = stream.opened.ok_or(err_msg(IndyErrorKind::InvalidState, "Finished unpack stream isn't opened"))?;
+
+        let res = serde_json::to_string(&opened.result)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize unpack result")?;
+
+        debug!("unpack_stream_finish <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    // Returns header bytes (header len and StreamHeader json) to be written before segments
+    fn _prepare_stream_header(&self,
+                              receivers: &str,
+                              sender_vk: Option<String>,
+                              wallet_handle: i32) -> IndyResult<(Vec<u8>, StreamHeader, chacha20poly1305_ietf::Key, chacha20poly1305_ietf::Nonce)> {
+        let (base64_protected, cek) = self._prepare_protected(receivers, sender_vk, wallet_handle)?;
+
+        let nonce = chacha20poly1305_ietf::gen_nonce();
+
+        let header = StreamHeader {
+            protected: base64_protected,
+            iv: base64::encode_urlsafe(&nonce[..]),
+            chunk_size: PACK_STREAM_CHUNK_SIZE,
+        };
+
+        let header_json = serde_json::to_vec(&header)
+            .to_indy(IndyErrorKind::InvalidState, "Failed to serialize stream header")?;
+
+        let mut header_bytes = Vec::with_capacity(4 + header_json.len());
+        header_bytes.write_u32::<LittleEndian>(header_json.len() as u32)?;
+        header_bytes.extend_from_slice(&header_json);
+
+        Ok((header_bytes, header, cek, nonce))
+    }
+
+    fn _open_stream_header(&self, header_json: &[u8], wallet_handle: i32) -> IndyResult<OpenedUnpackStream> {
+        let header: StreamHeader = serde_json::from_slice(header_json).map_err(|err| {
+            err_msg(IndyErrorKind::InvalidStructure, format!(
+                "Failed to deserialize stream header {}",
+                err
+            ))
+        })?;
+
+        let nonce = base64::decode_urlsafe(&header.iv)?;
+        let nonce = chacha20poly1305_ietf::Nonce::from_slice(&nonce).map_err(|err| {
+            err_msg(IndyErrorKind::InvalidStructure, format!("Failed to convert IV to Nonce type {}", err))
+        })?;
+
+        let (recipient, sender_verkey, cek) = self._unpack_protected(&header.protected, wallet_handle)?;
+
+        Ok(OpenedUnpackStream {
+            header,
+            cek,
+            nonce,
+            result: UnpackStream {
+                recipient_verkey: recipient.header.kid,
+                sender_verkey,
+            },
+        })
+    }
+
+    fn _unpack_protected(&self, base64_protected: &str, wallet_handle: i32) -> IndyResult<(Recipient, Option<String>, chacha20poly1305_ietf::Key)> {
         //decode protected data
-        let protected_decoded_vec = base64::decode_urlsafe(&jwe_struct.protected)?;
+        let protected_decoded_vec = base64::decode_urlsafe(base64_protected)?;
         let protected_decoded_str = String::from_utf8(protected_decoded_vec).map_err(|err| {
             err_msg(IndyErrorKind::InvalidStructure, format!(
                 "Failed to utf8 encode data {}",
@@ -536,28 +1069,7 @@ impl CryptoCommandExecutor {
             self._unpack_cek_anoncrypt(recipient.clone(), wallet_handle)
         }?; //close cek and sender_data match statement
 
-        //decrypt message
-        let message = self.crypto_service.decrypt_ciphertext(
-            &jwe_struct.ciphertext,
-            &jwe_struct.protected,
-            &jwe_struct.iv,
-            &jwe_struct.tag,
-            &cek,
-        )?;
-
-        //serialize and return decrypted message
-        let res = UnpackMessage {
-            message,
-            sender_verkey: sender_verkey_option,
-            recipient_verkey: recipient.header.kid
-        };
-
-        return serde_json::to_vec(&res).map_err(|err| {
-            err_msg(IndyErrorKind::InvalidStructure, format!(
-                "Failed to serialize message {}",
-                err
-            ))
-        });
+        Ok((recipient, sender_verkey_option, cek))
     }
 
     fn _find_correct_recipient(&self, protected_struct: Protected, wallet_handle: i32) -> IndyResult<(Recipient, bool)>{
@@ -637,3 +1149,36 @@ impl CryptoCommandExecutor {
     }
 
 }
+
+// Shared with DID commands that derive DID keys from the master seed
+pub fn get_hd_master_seed(wallet_service: &WalletService, wallet_handle: i32) -> IndyResult<HdMasterSeed> {
+    wallet_service.get_indy_object(wallet_handle, HD_MASTER_SEED_ID, &RecordOptions::id_value())
+        .map_err(|err| err.extend("HD master seed isn't created in the wallet"))
+}
+
+fn _check_stream_header_len(header_len: usize) -> IndyResult<()> {
+    if header_len == 0 || header_len > MAX_STREAM_HEADER_SIZE {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid stream header length: {}", header_len)));
+    }
+
+    Ok(())
+}
+
+fn _open_input_file(path: &str) -> IndyResult<fs::File> {
+    fs::File::open(path)
+        .to_indy(IndyErrorKind::IOError, format!("Can't open input file: {}", path))
+}
+
+fn _create_output_file(path: &str) -> IndyResult<fs::File> {
+    if let Some(parent_path) = Path::new(path).parent() {
+        fs::DirBuilder::new()
+            .recursive(true)
+            .create(parent_path)?;
+    }
+
+    fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .to_indy(IndyErrorKind::IOError, format!("Can't create output file: {}", path))
+}
@@ -4,19 +4,24 @@ use std::rc::Rc;
 use std::str;
 
 use serde_json;
+use time;
 
 use commands::{Command, CommandExecutor};
+use commands::crypto::get_hd_master_seed;
 use commands::ledger::LedgerCommand;
-use domain::crypto::did::{Did, DidMetadata, DidWithMeta, MyDidInfo, TemporaryDid, TheirDid, TheirDidInfo};
-use domain::crypto::key::KeyInfo;
+use domain::crypto::did::{Did, DidKeyHistory, DidMetadata, DidWithMeta, KeyRotationNotice, MyDidInfo, TemporaryDid, TheirDid, TheirDidInfo};
+use domain::crypto::hd::{HdRecoveryInfo, HdRecoveryResult};
+use domain::crypto::key::{Key, KeyInfo};
 use domain::ledger::attrib::{AttribData, Endpoint, GetAttrReplyResult};
 use domain::ledger::nym::{GetNymReplyResult, GetNymResultDataV0};
 use domain::ledger::response::Reply;
+use domain::pairwise::Pairwise;
 use errors::prelude::*;
 use services::crypto::CryptoService;
 use services::ledger::LedgerService;
 use services::wallet::{RecordOptions, SearchOptions, WalletService};
 use utils::crypto::base58;
+use utils::crypto::did_methods;
 use utils::sequence;
 
 pub enum DidCommand {
@@ -53,6 +58,15 @@ pub enum DidCommand {
         i32, // wallet handle
         String, // did (my or their)
         Box<Fn(IndyResult<String/*key*/>) + Send>),
+    KeyForLocalDidAt(
+        i32, // wallet handle
+        String, // did (my or their)
+        u64, // timestamp
+        Box<Fn(IndyResult<String/*key*/>) + Send>),
+    ListDidKeys(
+        i32, // wallet handle
+        String, // did (my or their)
+        Box<Fn(IndyResult<String>) + Send>),
     SetEndpointForDid(
         i32, // wallet handle
         String, // did
@@ -76,6 +90,18 @@ pub enum DidCommand {
         String, // did
         String, // verkey
         Box<Fn(IndyResult<String>) + Send>),
+    RecoverHdKeys(
+        i32, // wallet handle
+        HdRecoveryInfo, // derivation paths to recover
+        Box<Fn(IndyResult<String>) + Send>),
+    CreateKeyRotationNotice(
+        i32, // wallet handle
+        String, // my did
+        Box<Fn(IndyResult<String>) + Send>),
+    ApplyKeyRotationNotice(
+        i32, // wallet handle
+        KeyRotationNotice, // notice received from their side
+        Box<Fn(IndyResult<()>) + Send>),
     // Internal commands
     GetNymAck(
         i32, // wallet_handle
@@ -152,6 +178,14 @@ impl DidCommandExecutor {
                 info!("KeyForLocalDid command received");
                 cb(self.key_for_local_did(wallet_handle, &did));
             }
+            DidCommand::KeyForLocalDidAt(wallet_handle, did, timestamp, cb) => {
+                info!("KeyForLocalDidAt command received");
+                cb(self.key_for_local_did_at(wallet_handle, &did, timestamp));
+            }
+            DidCommand::ListDidKeys(wallet_handle, did, cb) => {
+                info!("ListDidKeys command received");
+                cb(self.list_did_keys(wallet_handle, &did));
+            }
             DidCommand::SetEndpointForDid(wallet_handle, did, endpoint, cb) => {
                 info!("SetEndpointForDid command received");
                 cb(self.set_endpoint_for_did(wallet_handle, &did, &endpoint));
@@ -172,6 +206,18 @@ impl DidCommandExecutor {
                 info!("AbbreviateVerkey command received");
                 cb(self.abbreviate_verkey(&did, verkey));
             }
+            DidCommand::RecoverHdKeys(wallet_handle, recovery_info, cb) => {
+                info!("RecoverHdKeys command received");
+                cb(self.recover_hd_keys(wallet_handle, &recovery_info));
+            }
+            DidCommand::CreateKeyRotationNotice(wallet_handle, my_did, cb) => {
+                info!("CreateKeyRotationNotice command received");
+                cb(self.create_key_rotation_notice(wallet_handle, &my_did));
+            }
+            DidCommand::ApplyKeyRotationNotice(wallet_handle, notice, cb) => {
+                info!("ApplyKeyRotationNotice command received");
+                cb(self.apply_key_rotation_notice(wallet_handle, &notice));
+            }
             DidCommand::GetNymAck(wallet_handle, result, deferred_cmd_id) => {
                 info!("GetNymAck command received");
                 self.get_nym_ack(wallet_handle, result, deferred_cmd_id);
@@ -188,7 +234,12 @@ impl DidCommandExecutor {
                                my_did_info: &MyDidInfo) -> IndyResult<(String, String)> {
         debug!("create_and_store_my_did >>> wallet_handle: {:?}, my_did_info_json: {:?}", wallet_handle, secret!(my_did_info));
 
-        let (did, key) = self.crypto_service.create_my_did(&my_did_info)?;
+        let (did, key) = if my_did_info.hd_path.is_some() {
+            let master_seed = get_hd_master_seed(&self.wallet_service, wallet_handle)?;
+            self.crypto_service.create_hd_my_did(&master_seed, &my_did_info)?
+        } else {
+            self.crypto_service.create_my_did(&my_did_info)?
+        };
 
         if self.wallet_service.record_exists::<Did>(wallet_handle, &did.did)? {
             return Err(err_msg(IndyErrorKind::DIDAlreadyExists, did.did));
@@ -196,6 +247,7 @@ impl DidCommandExecutor {
 
         self.wallet_service.add_indy_object(wallet_handle, &did.did, &did, &HashMap::new())?;
         self.wallet_service.add_indy_object(wallet_handle, &key.verkey, &key, &HashMap::new())?;
+        self._store_did_key(wallet_handle, &did.did, &did.verkey)?;
 
         let res = (did.did, did.verkey);
 
@@ -212,14 +264,27 @@ impl DidCommandExecutor {
 
         self.crypto_service.validate_did(my_did)?;
 
+        if did_methods::is_key_based_did(my_did) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Key of key based DID can't be replaced: {}", my_did)));
+        }
+
         let my_did = self._wallet_get_my_did(wallet_handle, my_did)?;
 
-        let temporary_key = self.crypto_service.create_key(&key_info)?;
+        let temporary_key = if key_info.hd_path.is_some() {
+            let master_seed = get_hd_master_seed(&self.wallet_service, wallet_handle)?;
+            self.crypto_service.create_hd_key(&master_seed, &key_info)?
+        } else {
+            self.crypto_service.create_key(&key_info)?
+        };
         let my_temporary_did = TemporaryDid { did: my_did.did, verkey: temporary_key.verkey.clone() };
 
         self.wallet_service.add_indy_object(wallet_handle, &temporary_key.verkey, &temporary_key, &HashMap::new())?;
         self.wallet_service.add_indy_object(wallet_handle, &my_temporary_did.did, &my_temporary_did, &HashMap::new())?;
 
+        let mut key_history = self._get_did_key_history(wallet_handle, &my_temporary_did.did)?;
+        key_history.add_temporary_key(&my_temporary_did.verkey);
+        self.wallet_service.upsert_indy_object(wallet_handle, &key_history.did, &key_history)?;
+
         let res = my_temporary_did.verkey;
 
         debug!("replace_keys_start <<< res: {:?}", res);
@@ -238,16 +303,114 @@ impl DidCommandExecutor {
         let my_temporary_did: TemporaryDid =
             self.wallet_service.get_indy_object(wallet_handle, &my_did.did, &RecordOptions::id_value())?;
 
+        let mut key_history = self._get_did_key_history(wallet_handle, &my_did.did)?;
+
         let my_did = Did::from(my_temporary_did);
 
         self.wallet_service.update_indy_object(wallet_handle, &my_did.did, &my_did)?;
         self.wallet_service.delete_indy_record::<TemporaryDid>(wallet_handle, &my_did.did)?;
 
+        key_history.activate_key(&my_did.verkey, time::get_time().sec as u64)?;
+        self.wallet_service.upsert_indy_object(wallet_handle, &key_history.did, &key_history)?;
+
         debug!("replace_keys_apply <<<");
 
         Ok(())
     }
 
+    fn create_key_rotation_notice(&self,
+                                  wallet_handle: i32,
+                                  my_did: &str) -> IndyResult<String> {
+        debug!("create_key_rotation_notice >>> wallet_handle: {:?}, my_did: {:?}", wallet_handle, my_did);
+
+        self.crypto_service.validate_did(my_did)?;
+
+        let my_did = self._wallet_get_my_did(wallet_handle, my_did)?;
+        let my_temporary_did: TemporaryDid =
+            self.wallet_service.get_indy_object(wallet_handle, &my_did.did, &RecordOptions::id_value())
+                .map_err(|err| err.extend("Key replacement isn't started for DID"))?;
+
+        let my_key: Key = self.wallet_service.get_indy_object(wallet_handle, &my_did.verkey, &RecordOptions::id_value())?;
+
+        let mut notice = KeyRotationNotice {
+            did: my_did.did,
+            verkey: my_did.verkey,
+            new_verkey: my_temporary_did.verkey,
+            timestamp: time::get_time().sec as u64,
+            signature: String::new(),
+        };
+
+        notice.signature = base58::encode(&self.crypto_service.sign(&my_key, &notice.signature_data())?);
+
+        let res = serde_json::to_string(&notice)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize KeyRotationNotice")?;
+
+        debug!("create_key_rotation_notice <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn apply_key_rotation_notice(&self,
+                                 wallet_handle: i32,
+                                 notice: &KeyRotationNotice) -> IndyResult<()> {
+        debug!("apply_key_rotation_notice >>> wallet_handle: {:?}, notice: {:?}", wallet_handle, notice);
+
+        self.crypto_service.validate_did(&notice.did)?;
+        self.crypto_service.validate_key(&notice.new_verkey)?;
+
+        if did_methods::is_key_based_did(&notice.did) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Key of key based DID can't be rotated: {}", notice.did)));
+        }
+
+        let their_did = self._wallet_get_their_did(wallet_handle, &notice.did)?;
+        let mut pairwise: Pairwise =
+            self.wallet_service.get_indy_object(wallet_handle, &notice.did, &RecordOptions::id_value())?;
+
+        if their_did.verkey != notice.verkey {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Key rotation notice doesn't match stored verkey for DID: {}", notice.did)));
+        }
+
+        if their_did.key_rotated_at.map(|rotated_at| notice.timestamp <= rotated_at).unwrap_or(false) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Key rotation notice is older than the last applied one for DID: {}", notice.did)));
+        }
+
+        let signature = base58::decode(&notice.signature)?;
+
+        if !self.crypto_service.verify(&their_did.verkey, &notice.signature_data(), &signature)? {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Key rotation notice signature is invalid"));
+        }
+
+        // Wallet has no transactions, so key history is written first and is safe to re-apply;
+        // their DID record commits the rotation and is restored if pairwise can't be updated
+        let mut key_history = self._get_did_key_history(wallet_handle, &their_did.did)?;
+
+        if key_history.key_at(notice.timestamp).map(|key| key.verkey != notice.new_verkey).unwrap_or(true) {
+            key_history.activate_key(&notice.new_verkey, notice.timestamp)?;
+            self.wallet_service.upsert_indy_object(wallet_handle, &key_history.did, &key_history)?;
+        }
+
+        let rotated_their_did = TheirDid {
+            did: their_did.did.clone(),
+            verkey: notice.new_verkey.clone(),
+            key_rotated_at: Some(notice.timestamp),
+        };
+
+        pairwise.their_key_rotated_at = Some(notice.timestamp);
+
+        self.wallet_service.update_indy_object(wallet_handle, &rotated_their_did.did, &rotated_their_did)?;
+
+        if let Err(err) = self.wallet_service.update_indy_object(wallet_handle, &pairwise.their_did, &pairwise) {
+            self.wallet_service.update_indy_object(wallet_handle, &their_did.did, &their_did)?;
+            return Err(err);
+        }
+
+        debug!("apply_key_rotation_notice <<<");
+
+        Ok(())
+    }
+
     fn store_their_did(&self,
                        wallet_handle: i32,
                        their_did_info: &TheirDidInfo) -> IndyResult<()> {
@@ -256,6 +419,7 @@ impl DidCommandExecutor {
         let their_did = self.crypto_service.create_their_did(their_did_info)?;
 
         self.wallet_service.add_indy_object(wallet_handle, &their_did.did, &their_did, &HashMap::new())?;
+        self._store_did_key(wallet_handle, &their_did.did, &their_did.verkey)?;
 
         debug!("store_their_did <<<");
 
@@ -339,6 +503,11 @@ impl DidCommandExecutor {
             Err(err) => return cb(Err(err))
         };
 
+        // Key based DIDs aren't written to the ledger, key is resolved from the identifier itself
+        if did_methods::is_key_based_did(&did) {
+            return cb(self.key_for_local_did(wallet_handle, &did));
+        }
+
         // look to their did
         let their_did = ensure_their_did!(self,
                                           wallet_handle,
@@ -375,15 +544,54 @@ impl DidCommandExecutor {
         };
 
         // look to their did
-        let their_did = self._wallet_get_their_did(wallet_handle, did)?;
-
-        let res = their_did.verkey;
+        let res = match self._wallet_get_their_did(wallet_handle, did) {
+            Ok(their_did) => their_did.verkey,
+            Err(ref err) if err.kind() == IndyErrorKind::WalletItemNotFound && did_methods::is_key_based_did(did) =>
+                did_methods::verkey_for_key_based_did(did)?,
+            Err(err) => return Err(err)
+        };
 
         info!("key_for_local_did <<< res: {:?}", res);
 
         Ok(res)
     }
 
+    fn key_for_local_did_at(&self,
+                            wallet_handle: i32,
+                            did: &str,
+                            timestamp: u64) -> IndyResult<String> {
+        debug!("key_for_local_did_at >>> wallet_handle: {:?}, did: {:?}, timestamp: {:?}", wallet_handle, did, timestamp);
+
+        self.crypto_service.validate_did(&did)?;
+
+        let key_history = self._get_did_key_history(wallet_handle, did)?;
+
+        let res = key_history.key_at(timestamp)
+            .map(|key| key.verkey.clone())
+            .ok_or(err_msg(IndyErrorKind::WalletItemNotFound, format!("No key was active for DID {} at {}", did, timestamp)))?;
+
+        debug!("key_for_local_did_at <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn list_did_keys(&self,
+                     wallet_handle: i32,
+                     did: &str) -> IndyResult<String> {
+        debug!("list_did_keys >>> wallet_handle: {:?}, did: {:?}", wallet_handle, did);
+
+        self.crypto_service.validate_did(&did)?;
+
+        let key_history = self._get_did_key_history(wallet_handle, did)?;
+
+        let res = serde_json::to_string(&key_history.keys)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize DID keys")?;
+
+        debug!("list_did_keys <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn set_endpoint_for_did(&self,
                             wallet_handle: i32,
                             did: &str,
@@ -485,6 +693,52 @@ impl DidCommandExecutor {
         Ok(res)
     }
 
+    fn recover_hd_keys(&self,
+                       wallet_handle: i32,
+                       recovery_info: &HdRecoveryInfo) -> IndyResult<String> {
+        debug!("recover_hd_keys >>> wallet_handle: {:?}, recovery_info: {:?}", wallet_handle, recovery_info);
+
+        let master_seed = get_hd_master_seed(&self.wallet_service, wallet_handle)?;
+
+        let mut recovered = HdRecoveryResult { keys: Vec::new(), dids: Vec::new() };
+
+        for path in recovery_info.keys.iter().flat_map(|paths| paths.iter()) {
+            let key_info = KeyInfo { seed: None, crypto_type: None, hd_path: Some(path.clone()), exportable: None };
+
+            let key = self.crypto_service.create_hd_key(&master_seed, &key_info)?;
+
+            if !self.wallet_service.record_exists::<Key>(wallet_handle, &key.verkey)? {
+                self.wallet_service.add_indy_object(wallet_handle, &key.verkey, &key, &HashMap::new())?;
+            }
+
+            recovered.keys.push(key.verkey.clone());
+        }
+
+        for path in recovery_info.dids.iter().flat_map(|paths| paths.iter()) {
+            let my_did_info = MyDidInfo { did: None, seed: None, crypto_type: None, cid: None, hd_path: Some(path.clone()), method_name: None, numalgo: None };
+
+            let (did, key) = self.crypto_service.create_hd_my_did(&master_seed, &my_did_info)?;
+
+            if !self.wallet_service.record_exists::<Did>(wallet_handle, &did.did)? {
+                self.wallet_service.add_indy_object(wallet_handle, &did.did, &did, &HashMap::new())?;
+                self._store_did_key(wallet_handle, &did.did, &did.verkey)?;
+            }
+
+            if !self.wallet_service.record_exists::<Key>(wallet_handle, &key.verkey)? {
+                self.wallet_service.add_indy_object(wallet_handle, &key.verkey, &key, &HashMap::new())?;
+            }
+
+            recovered.dids.push(did);
+        }
+
+        let res = serde_json::to_string(&recovered)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize recovered keys")?;
+
+        debug!("recover_hd_keys <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn get_nym_ack(&self,
                    wallet_handle: i32,
                    get_nym_reply_result: IndyResult<String>,
@@ -518,6 +772,7 @@ impl DidCommandExecutor {
         let their_did = self.crypto_service.create_their_did(&their_did_info)?;
 
         self.wallet_service.add_indy_object(wallet_handle, &their_did.did, &their_did, &HashMap::new())?;
+        self._store_did_key(wallet_handle, &their_did.did, &their_did.verkey)?;
 
         trace!("_get_nym_ack <<<");
 
@@ -649,6 +904,40 @@ impl DidCommandExecutor {
             ))).unwrap();
     }
 
+    fn _store_did_key(&self, wallet_handle: i32, did: &str, verkey: &str) -> IndyResult<()> {
+        let timestamp = time::get_time().sec as u64;
+
+        let key_history = match self.wallet_service.get_indy_opt_object::<DidKeyHistory>(wallet_handle, did, &RecordOptions::id_value())? {
+            Some(mut key_history) => {
+                if key_history.key_at(timestamp).map(|key| key.verkey != verkey).unwrap_or(true) {
+                    key_history.activate_key(verkey, timestamp)?;
+                }
+                key_history
+            }
+            None => DidKeyHistory::new(did, verkey, timestamp)
+        };
+
+        self.wallet_service.upsert_indy_object(wallet_handle, did, &key_history)?;
+
+        Ok(())
+    }
+
+    fn _get_did_key_history(&self, wallet_handle: i32, did: &str) -> IndyResult<DidKeyHistory> {
+        if let Some(key_history) = self.wallet_service.get_indy_opt_object::<DidKeyHistory>(wallet_handle, did, &RecordOptions::id_value())? {
+            return Ok(key_history);
+        }
+
+        // DID was stored without key history, so activation time of its key is unknown
+        let verkey = self.key_for_local_did(wallet_handle, did)?;
+        let mut key_history = DidKeyHistory::new(did, &verkey, 0);
+
+        if let Some(temporary_did) = self.wallet_service.get_indy_opt_object::<TemporaryDid>(wallet_handle, did, &RecordOptions::id_value())? {
+            key_history.add_temporary_key(&temporary_did.verkey);
+        }
+
+        Ok(key_history)
+    }
+
     fn _wallet_get_my_did(&self, wallet_handle: i32, my_did: &str) -> IndyResult<Did> {
         self.wallet_service.get_indy_object(wallet_handle, &my_did, &RecordOptions::id_value())
     }
@@ -1,5 +1,5 @@
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::rc::Rc;
 
 use serde_json;
@@ -10,9 +10,10 @@ use domain::anoncreds::credential_definition::{CredentialDefinition, CredentialD
 use domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefinition, RevocationRegistryDefinitionV1};
 use domain::anoncreds::revocation_registry_delta::{RevocationRegistryDelta, RevocationRegistryDeltaV1};
 use domain::anoncreds::schema::{Schema, SchemaV1};
-use domain::crypto::did::Did;
+use domain::crypto::did::{Did, TheirDid};
 use domain::crypto::key::Key;
 use domain::ledger::node::NodeOperationData;
+use domain::ledger::signatures::{SignatureQuorum, SignatureVerification};
 use errors::prelude::*;
 use services::crypto::CryptoService;
 use services::ledger::LedgerService;
@@ -23,6 +24,8 @@ use services::pool::{
 use services::wallet::{RecordOptions, WalletService};
 use utils::crypto::base58;
 use utils::crypto::signature_serializer::serialize_signature;
+use utils::crypto::verkey_builder::build_full_verkey;
+use utils::qualifier;
 
 pub enum LedgerCommand {
     SignAndSubmitRequest(
@@ -55,6 +58,12 @@ pub enum LedgerCommand {
         String, // submitter did
         String, // request json
         Box<Fn(IndyResult<String>) + Send>),
+    VerifyRequestSignatures(
+        i32, // wallet handle
+        String, // request json
+        Option<HashMap<String, String>>, // signer verkeys
+        Option<SignatureQuorum>, // quorum
+        Box<Fn(IndyResult<String>) + Send>),
     BuildGetDdoRequest(
         Option<String>, // submitter did
         String, // target did
@@ -245,6 +254,10 @@ impl LedgerCommandExecutor {
                 info!(target: "ledger_command_executor", "MultiSignRequest command received");
                 cb(self.multi_sign_request(wallet_handle, &submitter_did, &request_json));
             }
+            LedgerCommand::VerifyRequestSignatures(wallet_handle, request_json, verkeys, quorum, cb) => {
+                info!(target: "ledger_command_executor", "VerifyRequestSignatures command received");
+                cb(self.verify_request_signatures(wallet_handle, &request_json, verkeys.as_ref(), quorum.as_ref()));
+            }
             LedgerCommand::BuildGetDdoRequest(submitter_did, target_did, cb) => {
                 info!(target: "ledger_command_executor", "BuildGetDdoRequest command received");
                 cb(self.build_get_ddo_request(submitter_did.as_ref().map(String::as_str), &target_did));
@@ -417,7 +430,7 @@ impl LedgerCommandExecutor {
                         if !request.contains_key("signatures") {
                             request.insert("signatures".to_string(), Value::Object(serde_json::Map::new()));
                         }
-                        request["signatures"].as_object_mut().unwrap().insert(submitter_did.to_string(), Value::String(base58::encode(&signature)));
+                        request["signatures"].as_object_mut().unwrap().insert(qualifier::unqualify_did(submitter_did), Value::String(base58::encode(&signature)));
                     });
             }
         }
@@ -488,6 +501,104 @@ impl LedgerCommandExecutor {
         Ok(res)
     }
 
+    fn verify_request_signatures(&self,
+                                 wallet_handle: i32,
+                                 request_json: &str,
+                                 verkeys: Option<&HashMap<String, String>>,
+                                 quorum: Option<&SignatureQuorum>) -> IndyResult<String> {
+        debug!("verify_request_signatures >>> wallet_handle: {:?}, request_json: {:?}, verkeys: {:?}, quorum: {:?}", wallet_handle, request_json, verkeys, quorum);
+
+        let request: Value = serde_json::from_str(request_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Message is invalid json")?;
+
+        if !request.is_object() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Message isn't json object"));
+        }
+
+        let mut signatures: Vec<(String, String)> = Vec::new();
+
+        if let Some(signature) = request["signature"].as_str() {
+            let identifier = request["identifier"].as_str()
+                .ok_or(err_msg(IndyErrorKind::InvalidStructure, "Signed request doesn't contain identifier"))?;
+            signatures.push((identifier.to_string(), signature.to_string()));
+        }
+
+        if let Some(multi_signatures) = request["signatures"].as_object() {
+            for (did, signature) in multi_signatures {
+                let signature = signature.as_str()
+                    .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid signature of {}", did)))?;
+                signatures.push((did.to_string(), signature.to_string()));
+            }
+        }
+
+        if signatures.is_empty() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Request isn't signed"));
+        }
+
+        let serialized_request = serialize_signature(request.clone())?;
+
+        let mut verifications: BTreeMap<String, SignatureVerification> = BTreeMap::new();
+
+        for (did, signature) in signatures {
+            let verification = self._verify_request_signature(wallet_handle, &did, &signature, serialized_request.as_bytes(), verkeys)?;
+            verifications.insert(did, verification);
+        }
+
+        let report = match quorum {
+            Some(quorum) => quorum.evaluate(verifications),
+            None => SignatureQuorum::default().evaluate(verifications)
+        };
+
+        let res = serde_json::to_string(&report)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize signatures report")?;
+
+        debug!("verify_request_signatures <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn _verify_request_signature(&self,
+                                 wallet_handle: i32,
+                                 did: &str,
+                                 signature: &str,
+                                 message: &[u8],
+                                 verkeys: Option<&HashMap<String, String>>) -> IndyResult<SignatureVerification> {
+        let verkey = match self._resolve_signer_verkey(wallet_handle, did, verkeys)? {
+            Some(verkey) => verkey,
+            None => return Ok(SignatureVerification::failed(None, format!("Verkey not found for {}", did)))
+        };
+
+        let signature = match base58::decode(signature) {
+            Ok(signature) => signature,
+            Err(_) => return Ok(SignatureVerification::failed(Some(verkey), "Signature isn't valid base58".to_string()))
+        };
+
+        let res = match self.crypto_service.verify(&verkey, message, &signature) {
+            Ok(valid) => SignatureVerification::valid(verkey, valid),
+            Err(err) => SignatureVerification::failed(Some(verkey), err.to_string())
+        };
+
+        Ok(res)
+    }
+
+    // Provided verkeys take precedence over DIDs stored in the wallet
+    fn _resolve_signer_verkey(&self,
+                              wallet_handle: i32,
+                              did: &str,
+                              verkeys: Option<&HashMap<String, String>>) -> IndyResult<Option<String>> {
+        if let Some(verkey) = verkeys.and_then(|verkeys| verkeys.get(did)) {
+            return build_full_verkey(did, Some(verkey)).map(Some);
+        }
+
+        if let Some(my_did) = self.wallet_service.get_indy_opt_object::<Did>(wallet_handle, did, &RecordOptions::id_value())? {
+            return Ok(Some(my_did.verkey));
+        }
+
+        let their_did = self.wallet_service.get_indy_opt_object::<TheirDid>(wallet_handle, did, &RecordOptions::id_value())?;
+
+        Ok(their_did.map(|their_did| their_did.verkey))
+    }
+
     fn build_get_ddo_request(&self,
                              submitter_did: Option<&str>,
                              target_did: &str) -> IndyResult<String> {
@@ -1,10 +1,13 @@
 use domain::crypto::did::{Did, TheirDid};
-use domain::pairwise::{Pairwise, PairwiseInfo};
+use domain::pairwise::{Pairwise, PairwiseInfo, PairwiseSearchInfo};
+use domain::wallet::Tags;
 use errors::prelude::*;
-use services::wallet::{RecordOptions, WalletService};
+use services::wallet::{RecordOptions, SearchOptions, WalletSearch, WalletService};
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
 use std::str;
+use utils::sequence;
 
 
 pub enum PairwiseCommand {
@@ -29,17 +32,35 @@ pub enum PairwiseCommand {
         i32, // wallet handle
         String, // their_did
         Option<String>, // metadata
+        Box<Fn(IndyResult<()>) + Send>),
+    SetPairwiseTags(
+        i32, // wallet handle
+        String, // their_did
+        Tags, // tags
+        Box<Fn(IndyResult<()>) + Send>),
+    SearchPairwise(
+        i32, // wallet handle
+        Option<String>, // query json
+        Box<Fn(IndyResult<(i32, usize)>) + Send>),
+    FetchPairwiseSearch(
+        i32, // search handle
+        usize, // count
+        Box<Fn(IndyResult<String>) + Send>),
+    ClosePairwiseSearch(
+        i32, // search handle
         Box<Fn(IndyResult<()>) + Send>)
 }
 
 pub struct PairwiseCommandExecutor {
-    wallet_service: Rc<WalletService>
+    wallet_service: Rc<WalletService>,
+    searches: RefCell<HashMap<i32, Box<WalletSearch>>>
 }
 
 impl PairwiseCommandExecutor {
     pub fn new(wallet_service: Rc<WalletService>) -> PairwiseCommandExecutor {
         PairwiseCommandExecutor {
-            wallet_service
+            wallet_service,
+            searches: RefCell::new(HashMap::new())
         }
     }
 
@@ -65,6 +86,22 @@ impl PairwiseCommandExecutor {
                 info!(target: "pairwise_command_executor", "SetPairwiseMetadata command received");
                 cb(self.set_pairwise_metadata(wallet_handle, &their_did, metadata.as_ref().map(String::as_str)));
             }
+            PairwiseCommand::SetPairwiseTags(wallet_handle, their_did, tags, cb) => {
+                info!(target: "pairwise_command_executor", "SetPairwiseTags command received");
+                cb(self.set_pairwise_tags(wallet_handle, &their_did, &tags));
+            }
+            PairwiseCommand::SearchPairwise(wallet_handle, query_json, cb) => {
+                info!(target: "pairwise_command_executor", "SearchPairwise command received");
+                cb(self.search_pairwise(wallet_handle, query_json.as_ref().map(String::as_str)));
+            }
+            PairwiseCommand::FetchPairwiseSearch(search_handle, count, cb) => {
+                info!(target: "pairwise_command_executor", "FetchPairwiseSearch command received");
+                cb(self.fetch_pairwise_search(search_handle, count));
+            }
+            PairwiseCommand::ClosePairwiseSearch(search_handle, cb) => {
+                info!(target: "pairwise_command_executor", "ClosePairwiseSearch command received");
+                cb(self.close_pairwise_search(search_handle));
+            }
         };
     }
 
@@ -93,7 +130,8 @@ impl PairwiseCommandExecutor {
         let pairwise = Pairwise {
             my_did: my_did.to_string(),
             their_did: their_did.to_string(),
-            metadata: metadata.map(str::to_string)
+            metadata: metadata.map(str::to_string),
+            their_key_rotated_at: None
         };
 
         self.wallet_service.add_indy_object(wallet_handle, &their_did, &pairwise, &HashMap::new())?;
@@ -164,4 +202,85 @@ impl PairwiseCommandExecutor {
 
         Ok(())
     }
+
+    fn set_pairwise_tags(&self,
+                         wallet_handle: i32,
+                         their_did: &str,
+                         tags: &Tags) -> IndyResult<()> {
+        debug!("set_pairwise_tags >>> wallet_handle: {:?}, their_did: {:?}, tags: {:?}", wallet_handle, their_did, tags);
+
+        self.wallet_service.update_indy_record_tags::<Pairwise>(wallet_handle, &their_did, tags)?;
+
+        debug!("set_pairwise_tags <<<");
+
+        Ok(())
+    }
+
+    fn search_pairwise(&self,
+                       wallet_handle: i32,
+                       query_json: Option<&str>) -> IndyResult<(i32, usize)> {
+        debug!("search_pairwise >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
+
+        let pairwise_search =
+            self.wallet_service.search_indy_records::<Pairwise>(wallet_handle, query_json.unwrap_or("{}"), &SearchOptions::id_value_tags())?;
+
+        let total_count = pairwise_search.get_total_count()?.unwrap_or(0);
+
+        let handle = sequence::get_next_id();
+
+        self.searches.borrow_mut().insert(handle, Box::new(pairwise_search));
+
+        let res = (handle, total_count);
+
+        debug!("search_pairwise <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn fetch_pairwise_search(&self,
+                             search_handle: i32,
+                             count: usize) -> IndyResult<String> {
+        debug!("fetch_pairwise_search >>> search_handle: {:?}, count: {:?}", search_handle, count);
+
+        let mut searches = self.searches.borrow_mut();
+        let search = searches.get_mut(&search_handle)
+            .ok_or(err_msg(IndyErrorKind::InvalidWalletHandle, format!("Unknown PairwiseSearch handle: {}", search_handle)))?;
+
+        let mut pairwise_list: Vec<PairwiseSearchInfo> = Vec::new();
+
+        for _ in 0..count {
+            match search.fetch_next_record()? {
+                Some(pairwise_record) => {
+                    let pairwise_value = pairwise_record.get_value()
+                        .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("Pairwise not found for id: {}", pairwise_record.get_id())))?;
+
+                    let pairwise: Pairwise = serde_json::from_str(pairwise_value)
+                        .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize Pairwise")?;
+
+                    pairwise_list.push(PairwiseSearchInfo::new(pairwise, pairwise_record.get_tags().cloned()));
+                }
+                None => break
+            }
+        }
+
+        let res = serde_json::to_string(&pairwise_list)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize pairwise list")?;
+
+        debug!("fetch_pairwise_search <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn close_pairwise_search(&self, search_handle: i32) -> IndyResult<()> {
+        debug!("close_pairwise_search >>> search_handle: {:?}", search_handle);
+
+        let res = match self.searches.borrow_mut().remove(&search_handle) {
+            Some(_) => Ok(()),
+            None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, format!("Unknown PairwiseSearch handle: {}", search_handle)))
+        }?;
+
+        debug!("close_pairwise_search <<< res: {:?}", res);
+
+        Ok(res)
+    }
 }
@@ -7,6 +7,8 @@ use indy_crypto::cl::{
 
 use super::DELIMITER;
 
+use utils::qualifier;
+
 use std::collections::HashMap;
 use named_type::NamedType;
 
@@ -22,30 +24,33 @@ pub struct Credential {
     pub witness: Option<Witness>
 }
 
+// Identifiers are exposed in unqualified form so that tags match restrictions in either form
 impl Credential {
-    fn schema_parts(&self) -> Vec<&str> {
-        self.schema_id.split_terminator(DELIMITER).collect::<Vec<&str>>()
+    fn schema_parts(&self) -> Vec<String> {
+        self.schema_id().split_terminator(DELIMITER).map(String::from).collect::<Vec<String>>()
     }
 
-    pub fn schema_id(&self) -> String { self.schema_id.to_string() }
+    pub fn schema_id(&self) -> String { qualifier::unqualify(&self.schema_id) }
 
     pub fn schema_issuer_did(&self) -> String {
-        self.schema_parts().get(0).map(|s| s.to_string()).unwrap_or(String::new())
+        self.schema_parts().get(0).cloned().unwrap_or(String::new())
     }
 
     pub fn schema_name(&self) -> String {
-        self.schema_parts().get(2).map(|s| s.to_string()).unwrap_or(String::new())
+        self.schema_parts().get(2).cloned().unwrap_or(String::new())
     }
 
     pub fn schema_version(&self) -> String {
-        self.schema_parts().get(3).map(|s| s.to_string()).unwrap_or(String::new())
+        self.schema_parts().get(3).cloned().unwrap_or(String::new())
     }
 
     pub fn issuer_did(&self) -> String {
-        self.cred_def_id.split_terminator(DELIMITER).collect::<Vec<&str>>()[0].to_string()
+        self.cred_def_id().split_terminator(DELIMITER).next().unwrap_or("").to_string()
     }
 
-    pub fn cred_def_id(&self) -> String { self.cred_def_id.to_string() }
+    pub fn cred_def_id(&self) -> String { qualifier::unqualify(&self.cred_def_id) }
+
+    pub fn rev_reg_id(&self) -> Option<String> { self.rev_reg_id.as_ref().map(|rev_reg_id| qualifier::unqualify(rev_reg_id)) }
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
@@ -61,5 +66,59 @@ pub struct CredentialInfo {
 #[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq)]
 pub struct AttributeValues {
     pub raw: String,
+    #[serde(default)]
     pub encoded: String
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CredentialVerificationReport {
+    pub valid: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<CredentialCheck>,
+    pub values: CredentialCheck,
+    pub cred_def: CredentialCheck,
+    pub rev_reg: CredentialCheck,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<CredentialCheck>
+}
+
+impl CredentialVerificationReport {
+    pub fn set_signature_check(&mut self, signature: CredentialCheck) {
+        self.valid = self.valid && signature.valid;
+        self.signature = Some(signature);
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CredentialCheck {
+    pub valid: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub errors: Vec<String>
+}
+
+impl CredentialCheck {
+    pub fn new(errors: Vec<String>) -> CredentialCheck {
+        CredentialCheck { valid: errors.is_empty(), errors }
+    }
+}
+
+/// Issuer's issuance log entry for a credential issued by indy_issuer_create_credential_with_log.
+#[derive(Debug, Clone, Deserialize, Serialize, NamedType)]
+pub struct IssuedCredentialInfo {
+    pub referent: String,
+    pub schema_id: String,
+    pub cred_def_id: String,
+    pub rev_reg_id: Option<String>,
+    pub cred_rev_id: Option<String>,
+    pub attrs_hash: String,
+    pub revoked: bool,
+    #[serde(default)]
+    pub tags: HashMap<String, String>
+}
+
+/// HMAC key of issuance log hashes of attribute values, generated once per wallet.
+#[derive(Debug, Deserialize, Serialize, NamedType)]
+pub struct IssuanceLogKey {
+    pub value: Vec<u8>
+}
@@ -1,6 +1,8 @@
 use super::DELIMITER;
 use super::super::ledger::request::ProtocolVersion;
 
+use utils::qualifier;
+
 use indy_crypto::cl::{
     CredentialPrimaryPublicKey,
     CredentialRevocationPublicKey,
@@ -70,7 +72,9 @@ pub enum CredentialDefinition {
 
 impl CredentialDefinition {
     pub fn cred_def_id(did: &str, schema_id: &str, signature_type: &str, tag: &str) -> String {
-        if ProtocolVersion::is_node_1_3(){
+        if let Some(method) = qualifier::method(did) {
+            format!("{}{}{}{}{}{}{}{}{}{}{}{}{}", qualifier::CRED_DEF_PREFIX, DELIMITER, method, DELIMITER, did, DELIMITER, CRED_DEF_MARKER, DELIMITER, signature_type, DELIMITER, schema_id, DELIMITER, tag)
+        } else if ProtocolVersion::is_node_1_3(){
             format!("{}{}{}{}{}{}{}", did, DELIMITER, CRED_DEF_MARKER, DELIMITER, signature_type, DELIMITER, schema_id)
         } else {
             format!("{}{}{}{}{}{}{}{}{}", did, DELIMITER, CRED_DEF_MARKER, DELIMITER, signature_type, DELIMITER, schema_id, DELIMITER, tag)
@@ -96,6 +100,14 @@ pub fn cred_defs_map_to_cred_defs_v1_map(cred_defs: HashMap<String, CredentialDe
     cred_defs_v1
 }
 
+/// Issuer's links between credential definitions created by keys rotation.
+/// Credential definition is active until it gets successor.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, NamedType)]
+pub struct CredentialDefinitionRotationInfo {
+    pub predecessor_id: Option<String>,
+    pub successor_id: Option<String>
+}
+
 #[derive(Debug, Serialize, Deserialize, NamedType)]
 pub struct CredentialDefinitionPrivateKey {
     pub value: CredentialPrivateKey
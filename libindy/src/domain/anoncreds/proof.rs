@@ -12,6 +12,9 @@ pub struct Proof {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct RequestedProof {
     pub revealed_attrs: HashMap<String, RevealedAttributeInfo>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub revealed_attr_groups: HashMap<String, RevealedAttributeGroupInfo>,
     pub self_attested_attrs: HashMap<String, String>,
     pub unrevealed_attrs: HashMap<String, SubProofReferent>,
     pub predicates: HashMap<String, SubProofReferent>
@@ -21,6 +24,7 @@ impl Default for RequestedProof {
     fn default() -> Self {
         RequestedProof {
             revealed_attrs: HashMap::new(),
+            revealed_attr_groups: HashMap::new(),
             self_attested_attrs: HashMap::new(),
             unrevealed_attrs: HashMap::new(),
             predicates: HashMap::new(),
@@ -40,6 +44,17 @@ pub struct RevealedAttributeInfo {
     pub encoded: String
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RevealedAttributeGroupInfo {
+    pub sub_proof_index: i32,
+    pub values: HashMap<String, AttributeValue>
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AttributeValue {
+    pub raw: String,
+    pub encoded: String
+}
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
 pub struct Identifier {
@@ -47,4 +62,42 @@ pub struct Identifier {
     pub cred_def_id: String,
     pub rev_reg_id: Option<String>,
     pub timestamp: Option<u64>
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProofVerificationReport {
+    pub valid: bool,
+    pub proof_verified: bool,
+    pub sub_proofs: Vec<SubProofReport>,
+    pub self_attested_attrs: Vec<ReferentReport>,
+    pub missing_referents: Vec<String>,
+    pub unexpected_referents: Vec<String>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SubProofReport {
+    pub sub_proof_index: usize,
+    pub identifier: Identifier,
+    pub referents: Vec<ReferentReport>,
+    pub revocation: RevocationCheckStatus
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReferentReport {
+    pub referent: String,
+    pub verified: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum RevocationCheckStatus {
+    #[serde(rename = "passed")]
+    Passed,
+    #[serde(rename = "failed")]
+    Failed,
+    #[serde(rename = "unknown")]
+    Unknown,
+    #[serde(rename = "not_applicable")]
+    NotApplicable
+}
@@ -1,8 +1,12 @@
+use serde::{de, Deserialize, Deserializer};
 use serde_json;
 use std::collections::HashMap;
 
 use indy_crypto::cl::Nonce;
 
+use errors::prelude::*;
+use super::schema::date_to_epoch_days;
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ProofRequest {
     pub nonce: Nonce,
@@ -10,7 +14,25 @@ pub struct ProofRequest {
     pub version: String,
     pub requested_attributes: HashMap<String, AttributeInfo>,
     pub requested_predicates: HashMap<String, PredicateInfo>,
-    pub non_revoked: Option<NonRevocedInterval>
+    pub non_revoked: Option<NonRevocedInterval>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ver: Option<ProofRequestsVersion>
+}
+
+/// Version of proof request defines the form of identifiers verifier expects in the proof:
+/// 1.0 (default) - unqualified, 2.0 - as they are stored by prover (fully qualified or not).
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq)]
+pub enum ProofRequestsVersion {
+    #[serde(rename = "1.0")]
+    V1,
+    #[serde(rename = "2.0")]
+    V2
+}
+
+impl ProofRequest {
+    pub fn expects_qualified_identifiers(&self) -> bool {
+        self.ver == Some(ProofRequestsVersion::V2)
+    }
 }
 
 pub type ProofRequestExtraQuery = HashMap<String, serde_json::Map<String, serde_json::Value>>;
@@ -23,24 +45,67 @@ pub struct NonRevocedInterval {
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AttributeInfo {
-    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub names: Option<Vec<String>>,
     pub restrictions: Option<serde_json::Value>,
     pub non_revoked: Option<NonRevocedInterval>
 }
 
+impl AttributeInfo {
+    /// Names of requested attributes: either single `name` or group of `names` which must be
+    /// proven by one credential.
+    pub fn names(&self) -> IndyResult<Vec<String>> {
+        match (&self.name, &self.names) {
+            (&Some(ref name), &None) => Ok(vec![name.clone()]),
+            (&None, &Some(ref names)) if !names.is_empty() => Ok(names.clone()),
+            _ => Err(err_msg(IndyErrorKind::InvalidStructure, format!("Either \"name\" or non-empty \"names\" must be specified for requested attribute: {:?}", self)))
+        }
+    }
+
+    pub fn is_group(&self) -> bool {
+        self.names.is_some()
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PredicateInfo {
     pub name: String,
     pub p_type: PredicateTypes,
+    #[serde(deserialize_with = "deserialize_predicate_value")]
     pub p_value: i32,
     pub restrictions: Option<serde_json::Value>,
     pub non_revoked: Option<NonRevocedInterval>
 }
 
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PredicateValue {
+    Int(i32),
+    Date(String)
+}
+
+/// Predicate value is either integer or date in "YYYY-MM-DD" format that is converted
+/// to number of days since Unix Epoch (the encoding of date attributes).
+fn deserialize_predicate_value<'de, D>(deserializer: D) -> Result<i32, D::Error> where D: Deserializer<'de> {
+    match PredicateValue::deserialize(deserializer)? {
+        PredicateValue::Int(value) => Ok(value),
+        PredicateValue::Date(date) => date_to_epoch_days(&date)
+            .ok_or(de::Error::custom(format!("Predicate value {:?} is neither integer nor date", date)))
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
-pub enum PredicateTypes{
+pub enum PredicateTypes {
     #[serde(rename = ">=")]
-    GE
+    GE,
+    #[serde(rename = "<=")]
+    LE,
+    #[serde(rename = ">")]
+    GT,
+    #[serde(rename = "<")]
+    LT
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -54,4 +119,46 @@ pub struct RequestedAttributeInfo {
 pub struct RequestedPredicateInfo {
     pub predicate_referent: String,
     pub predicate_info: PredicateInfo
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attribute_info_names_works_for_name() {
+        let attr_info: AttributeInfo = serde_json::from_str(r#"{"name":"age"}"#).unwrap();
+        assert_eq!(vec!["age".to_string()], attr_info.names().unwrap());
+        assert!(!attr_info.is_group());
+    }
+
+    #[test]
+    fn attribute_info_names_works_for_names() {
+        let attr_info: AttributeInfo = serde_json::from_str(r#"{"names":["first_name","last_name"]}"#).unwrap();
+        assert_eq!(vec!["first_name".to_string(), "last_name".to_string()], attr_info.names().unwrap());
+        assert!(attr_info.is_group());
+    }
+
+    #[test]
+    fn attribute_info_names_works_for_invalid() {
+        let attr_info: AttributeInfo = serde_json::from_str(r#"{"name":"age","names":["first_name"]}"#).unwrap();
+        assert_kind!(IndyErrorKind::InvalidStructure, attr_info.names());
+
+        let attr_info: AttributeInfo = serde_json::from_str(r#"{"names":[]}"#).unwrap();
+        assert_kind!(IndyErrorKind::InvalidStructure, attr_info.names());
+
+        let attr_info: AttributeInfo = serde_json::from_str(r#"{}"#).unwrap();
+        assert_kind!(IndyErrorKind::InvalidStructure, attr_info.names());
+    }
+
+    #[test]
+    fn predicate_info_deserialize_works_for_date_value() {
+        let predicate_info: PredicateInfo = serde_json::from_str(r#"{"name":"dob","p_type":"<=","p_value":"2000-02-29"}"#).unwrap();
+        assert_eq!(11016, predicate_info.p_value);
+
+        let predicate_info: PredicateInfo = serde_json::from_str(r#"{"name":"age","p_type":">=","p_value":18}"#).unwrap();
+        assert_eq!(18, predicate_info.p_value);
+
+        assert!(serde_json::from_str::<PredicateInfo>(r#"{"name":"dob","p_type":"<=","p_value":"29.02.2000"}"#).is_err());
+    }
+}
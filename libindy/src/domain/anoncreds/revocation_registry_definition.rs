@@ -2,6 +2,8 @@ use indy_crypto::cl::{RevocationKeyPublic, RevocationKeyPrivate};
 
 use super::DELIMITER;
 
+use utils::qualifier;
+
 use std::collections::{HashMap, HashSet};
 use named_type::NamedType;
 
@@ -14,6 +16,14 @@ pub struct RevocationRegistryConfig {
     pub max_cred_num: Option<u32>
 }
 
+#[derive(Deserialize, Debug, Serialize)]
+pub struct RevocationRegistryRotationConfig {
+    pub threshold: Option<u32>,
+    pub tag: Option<String>,
+    pub issuance_type: Option<String>,
+    pub max_cred_num: Option<u32>
+}
+
 #[allow(non_camel_case_types)]
 #[derive(Deserialize, Debug, Serialize, PartialEq, Clone)]
 pub enum IssuanceType {
@@ -76,7 +86,10 @@ pub enum RevocationRegistryDefinition {
 
 impl RevocationRegistryDefinition {
     pub fn rev_reg_id(did: &str, cred_def_id: &str, rev_reg_type: &RegistryType, tag: &str) -> String {
-        format!("{}{}{}{}{}{}{}{}{}", did, DELIMITER, REV_REG_DEG_MARKER, DELIMITER, cred_def_id, DELIMITER, rev_reg_type.to_str(), DELIMITER, tag)
+        match qualifier::method(did) {
+            Some(method) => format!("{}{}{}{}{}{}{}{}{}{}{}{}{}", qualifier::REV_REG_PREFIX, DELIMITER, method, DELIMITER, did, DELIMITER, REV_REG_DEG_MARKER, DELIMITER, cred_def_id, DELIMITER, rev_reg_type.to_str(), DELIMITER, tag),
+            None => format!("{}{}{}{}{}{}{}{}{}", did, DELIMITER, REV_REG_DEG_MARKER, DELIMITER, cred_def_id, DELIMITER, rev_reg_type.to_str(), DELIMITER, tag)
+        }
     }
 }
 
@@ -109,3 +122,23 @@ pub struct RevocationRegistryInfo {
     pub curr_id: u32,
     pub used_ids: HashSet<u32>
 }
+
+/// Issuer's bookkeeping of revocation registries created for a credential definition.
+#[derive(Debug, Deserialize, Serialize, Clone, NamedType)]
+pub struct CredentialDefinitionRevocationInfo {
+    pub cred_def_id: String,
+    pub rev_reg_ids: Vec<String>,
+    pub active_rev_reg_id: Option<String>,
+    pub next_rev_reg_id: Option<String>,
+    pub issued: u64
+}
+
+#[derive(Debug, Serialize)]
+pub struct RevocationRegistryRotationResult {
+    pub active_rev_reg_id: String,
+    pub active_issued: u32,
+    pub active_max_cred_num: u32,
+    pub next_rev_reg_id: Option<String>,
+    pub rev_reg_def: Option<RevocationRegistryDefinition>,
+    pub ledger_requests: Vec<String>
+}
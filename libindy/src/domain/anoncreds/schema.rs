@@ -1,6 +1,9 @@
 use super::DELIMITER;
 
+use utils::qualifier;
+
 use std::collections::{HashMap, HashSet};
+use named_type::NamedType;
 
 pub const SCHEMA_MARKER: &'static str = "2";
 pub const MAX_ATTRIBUTES_COUNT: usize = 125;
@@ -14,6 +17,8 @@ pub struct SchemaV1 {
     #[serde(rename = "attrNames")]
     pub attr_names: AttributeNames,
     pub seq_no: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attr_types: Option<AttributeTypes>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -25,7 +30,10 @@ pub enum Schema {
 
 impl Schema {
     pub fn schema_id(did: &str, name: &str, version: &str) -> String {
-        format!("{}{}{}{}{}{}{}", did, DELIMITER, SCHEMA_MARKER, DELIMITER, name, DELIMITER, version)
+        match qualifier::method(did) {
+            Some(method) => format!("{}{}{}{}{}{}{}{}{}{}{}", qualifier::SCHEMA_PREFIX, DELIMITER, method, DELIMITER, did, DELIMITER, SCHEMA_MARKER, DELIMITER, name, DELIMITER, version),
+            None => format!("{}{}{}{}{}{}{}", did, DELIMITER, SCHEMA_MARKER, DELIMITER, name, DELIMITER, version)
+        }
     }
 }
 
@@ -47,4 +55,105 @@ pub fn schemas_map_to_schemas_v1_map(schemas: HashMap<String, Schema>) -> HashMa
     schemas_v1
 }
 
-pub type AttributeNames = HashSet<String>;
\ No newline at end of file
+pub type AttributeNames = HashSet<String>;
+
+/// Type hint of schema attribute that defines how its raw values are encoded.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum AttributeType {
+    Int,
+    Date,
+    String,
+    Enum { values: Vec<String> }
+}
+
+pub type AttributeTypes = HashMap<String, AttributeType>;
+
+/// Issuer's copy of schema attribute types used to encode values of credentials,
+/// as schema given for credential definition may be taken without them.
+#[derive(Debug, Serialize, Deserialize, NamedType)]
+pub struct SchemaAttributeTypes {
+    pub schema_id: String,
+    pub attr_types: AttributeTypes
+}
+
+/// Number of days since Unix Epoch for date in "YYYY-MM-DD" format.
+pub fn date_to_epoch_days(date: &str) -> Option<i32> {
+    let parts: Vec<&str> = date.split('-').collect();
+
+    if parts.len() != 3 || parts[0].len() != 4 || parts[1].len() != 2 || parts[2].len() != 2 ||
+        !parts.iter().all(|part| part.chars().all(|c| c.is_ascii_digit())) {
+        return None;
+    }
+
+    let year = parts[0].parse::<i64>().ok()?;
+    let month = parts[1].parse::<i64>().ok()?;
+    let day = parts[2].parse::<i64>().ok()?;
+
+    let is_leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year => 29,
+        2 => 28,
+        _ => return None
+    };
+
+    if day < 1 || day > days_in_month {
+        return None;
+    }
+
+    // Days from civil date (proleptic Gregorian calendar) with years starting from March
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    Some((era * 146097 + day_of_era - 719468) as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn date_to_epoch_days_works() {
+        assert_eq!(Some(0), date_to_epoch_days("1970-01-01"));
+        assert_eq!(Some(-1), date_to_epoch_days("1969-12-31"));
+        assert_eq!(Some(11016), date_to_epoch_days("2000-02-29"));
+        assert_eq!(Some(17897), date_to_epoch_days("2019-01-01"));
+    }
+
+    #[test]
+    fn date_to_epoch_days_works_for_invalid_date() {
+        assert_eq!(None, date_to_epoch_days("2001-02-29"));
+        assert_eq!(None, date_to_epoch_days("2001-13-01"));
+        assert_eq!(None, date_to_epoch_days("2001-1-1"));
+        assert_eq!(None, date_to_epoch_days("+001-01-01"));
+        assert_eq!(None, date_to_epoch_days("01.01.2001"));
+    }
+
+    #[test]
+    fn schema_v1_deserialize_works_for_attr_types() {
+        let schema: SchemaV1 = serde_json::from_value(json!({
+            "id": "NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0",
+            "name": "gvt",
+            "version": "1.0",
+            "attrNames": ["age", "dob", "status"],
+            "attrTypes": {
+                "age": {"type": "int"},
+                "dob": {"type": "date"},
+                "status": {"type": "enum", "values": ["active", "inactive"]}
+            },
+            "seqNo": null
+        })).unwrap();
+
+        let attr_types = schema.attr_types.unwrap();
+        assert_eq!(AttributeType::Int, attr_types["age"]);
+        assert_eq!(AttributeType::Date, attr_types["dob"]);
+        assert_eq!(AttributeType::Enum { values: vec!["active".to_string(), "inactive".to_string()] }, attr_types["status"]);
+    }
+}
@@ -2,12 +2,17 @@ extern crate indy_crypto;
 
 use named_type::NamedType;
 
+use errors::prelude::*;
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct MyDidInfo {
     pub did: Option<String>,
     pub seed: Option<String>,
     pub crypto_type: Option<String>,
-    pub cid: Option<bool>
+    pub cid: Option<bool>,
+    pub hd_path: Option<String>,
+    pub method_name: Option<String>,
+    pub numalgo: Option<u8>
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -57,7 +62,10 @@ pub struct DidWithMeta {
 #[derive(Serialize, Deserialize, Debug, NamedType)]
 pub struct TheirDid {
     pub did: String,
-    pub verkey: String
+    pub verkey: String,
+    // timestamp of the last applied key rotation notice, newer notices only are accepted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_rotated_at: Option<u64>
 }
 
 #[derive(Serialize, Deserialize, Debug, NamedType)]
@@ -75,3 +83,130 @@ impl From<TemporaryDid> for Did {
     }
 }
 
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct KeyRotationNotice {
+    pub did: String,
+    pub verkey: String,
+    pub new_verkey: String,
+    pub timestamp: u64,
+    pub signature: String
+}
+
+impl KeyRotationNotice {
+    /// Bytes covered by the signature of the previous key.
+    pub fn signature_data(&self) -> Vec<u8> {
+        format!("{}|{}|{}|{}", self.did, self.verkey, self.new_verkey, self.timestamp).into_bytes()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct DidKey {
+    pub verkey: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activated_at: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deactivated_at: Option<u64>
+}
+
+/// History of verkeys used by a DID. Key without activation time is a temporary one
+/// created by replace_keys_start and not applied yet.
+#[derive(Serialize, Deserialize, Debug, NamedType)]
+pub struct DidKeyHistory {
+    pub did: String,
+    pub keys: Vec<DidKey>
+}
+
+impl DidKeyHistory {
+    pub fn new(did: &str, verkey: &str, activated_at: u64) -> DidKeyHistory {
+        DidKeyHistory {
+            did: did.to_string(),
+            keys: vec![DidKey { verkey: verkey.to_string(), activated_at: Some(activated_at), deactivated_at: None }]
+        }
+    }
+
+    pub fn add_temporary_key(&mut self, verkey: &str) {
+        self.keys.retain(|key| key.activated_at.is_some());
+        self.keys.push(DidKey { verkey: verkey.to_string(), activated_at: None, deactivated_at: None });
+    }
+
+    pub fn activate_key(&mut self, verkey: &str, timestamp: u64) -> IndyResult<()> {
+        let active_since = self.keys.iter()
+            .filter(|key| key.deactivated_at.is_none())
+            .filter_map(|key| key.activated_at)
+            .max();
+
+        if active_since.map(|activated_at| timestamp < activated_at).unwrap_or(false) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Key of DID {} can't be deactivated at {} before it was activated", self.did, timestamp)));
+        }
+
+        for key in self.keys.iter_mut().filter(|key| key.activated_at.is_some() && key.deactivated_at.is_none()) {
+            key.deactivated_at = Some(timestamp);
+        }
+
+        self.keys.retain(|key| key.activated_at.is_some());
+        self.keys.push(DidKey { verkey: verkey.to_string(), activated_at: Some(timestamp), deactivated_at: None });
+
+        Ok(())
+    }
+
+    pub fn key_at(&self, timestamp: u64) -> Option<&DidKey> {
+        self.keys
+            .iter()
+            .filter(|key| key.activated_at.map(|activated_at| activated_at <= timestamp).unwrap_or(false))
+            .filter(|key| key.deactivated_at.map(|deactivated_at| timestamp < deactivated_at).unwrap_or(true))
+            .last()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DID: &'static str = "8wZcEriaNLNKtteJvx7f8i";
+    const VERKEY_1: &'static str = "5L2HBnzbu6Auh2pkDRbFt5f4prvgE2LzknkuYLsKkacp";
+    const VERKEY_2: &'static str = "CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW";
+
+    #[test]
+    fn did_key_history_key_at_works() {
+        let mut history = DidKeyHistory::new(DID, VERKEY_1, 100);
+        history.add_temporary_key(VERKEY_2);
+        history.activate_key(VERKEY_2, 200).unwrap();
+
+        assert!(history.key_at(99).is_none());
+        assert_eq!(VERKEY_1, history.key_at(100).unwrap().verkey);
+        assert_eq!(VERKEY_1, history.key_at(199).unwrap().verkey);
+        assert_eq!(VERKEY_2, history.key_at(200).unwrap().verkey);
+        assert_eq!(VERKEY_2, history.key_at(1000).unwrap().verkey);
+    }
+
+    #[test]
+    fn did_key_history_key_at_ignores_temporary_key() {
+        let mut history = DidKeyHistory::new(DID, VERKEY_1, 100);
+        history.add_temporary_key(VERKEY_2);
+
+        assert_eq!(2, history.keys.len());
+        assert_eq!(VERKEY_1, history.key_at(1000).unwrap().verkey);
+    }
+
+    #[test]
+    fn did_key_history_add_temporary_key_replaces_previous() {
+        let mut history = DidKeyHistory::new(DID, VERKEY_1, 100);
+        history.add_temporary_key(VERKEY_1);
+        history.add_temporary_key(VERKEY_2);
+
+        assert_eq!(2, history.keys.len());
+        assert_eq!(None, history.keys[1].activated_at);
+        assert_eq!(VERKEY_2, history.keys[1].verkey);
+    }
+
+    #[test]
+    fn did_key_history_activate_key_works_for_time_before_current_key() {
+        let mut history = DidKeyHistory::new(DID, VERKEY_1, 100);
+
+        assert_kind!(IndyErrorKind::InvalidStructure, history.activate_key(VERKEY_2, 99));
+        assert_eq!(1, history.keys.len());
+        assert_eq!(None, history.keys[0].deactivated_at);
+    }
+}
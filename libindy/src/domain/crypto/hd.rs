@@ -0,0 +1,38 @@
+extern crate zeroize;
+
+use self::zeroize::Zeroize;
+
+use named_type::NamedType;
+
+use domain::crypto::did::Did;
+
+pub const HD_MASTER_SEED_ID: &'static str = "default";
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct HdMasterSeedConfig {
+    pub seed: Option<String>,
+    pub mnemonic: Option<String>,
+    pub passphrase: Option<String>
+}
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+#[derive(Serialize, Deserialize, NamedType)]
+pub struct HdMasterSeed {
+    #[derivative(Debug="ignore")]
+    pub seed: String
+}
+
+memzeroize!(HdMasterSeed, seed);
+
+#[derive(Deserialize, Debug)]
+pub struct HdRecoveryInfo {
+    pub keys: Option<Vec<String>>,
+    pub dids: Option<Vec<String>>
+}
+
+#[derive(Serialize, Debug)]
+pub struct HdRecoveryResult {
+    pub keys: Vec<String>,
+    pub dids: Vec<Did>
+}
@@ -4,6 +4,7 @@ extern crate zeroize;
 use self::zeroize::Zeroize;
 
 use named_type::NamedType;
+use domain::wallet::{KeyDerivationMethod, default_key_derivation_method};
 
 
 #[derive(Derivative)]
@@ -15,14 +16,17 @@ pub struct Key {
     #[derivative(Debug="ignore")]
     pub signkey: String,
     #[cfg(test)]
-    pub signkey: String
+    pub signkey: String,
+    #[serde(default)]
+    pub exportable: bool
 }
 
 impl Key {
     pub fn new(verkey: String, signkey: String) -> Key {
         Key {
             verkey,
-            signkey
+            signkey,
+            exportable: false
         }
     }
 }
@@ -32,10 +36,77 @@ memzeroize!(Key, signkey);
 #[derive(Serialize, Deserialize, Debug)]
 pub struct KeyInfo {
     pub seed: Option<String>,
-    pub crypto_type: Option<String>
+    pub crypto_type: Option<String>,
+    pub hd_path: Option<String>,
+    pub exportable: Option<bool>
 }
 
 #[derive(Serialize, Deserialize, Debug, NamedType)]
 pub struct KeyMetadata {
     pub value: String
 }
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum KeyExportFormat {
+    #[serde(rename = "jwk")]
+    Jwk,
+    #[serde(rename = "pkcs8")]
+    Pkcs8
+}
+
+impl Default for KeyExportFormat {
+    fn default() -> Self {
+        KeyExportFormat::Jwk
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct KeyExportConfig {
+    pub key: String,
+    #[serde(default = "default_key_derivation_method")]
+    pub key_derivation_method: KeyDerivationMethod,
+    #[serde(default)]
+    pub format: KeyExportFormat
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct KeyImportConfig {
+    pub key: String,
+    pub exportable: Option<bool>
+}
+
+/// Password encrypted key as it is returned by export. This is libindy specific envelope,
+/// not a JWE: version "1.0" encrypts `ExportedKeyPayload` json with ChaCha20-Poly1305-IETF
+/// under a key derived from the passphrase by Argon2i (`key_derivation_method` parameters).
+/// Salt, nonce and ciphertext are base64url encoded.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ExportedKey {
+    pub ver: String,
+    pub format: KeyExportFormat,
+    pub key_derivation_method: KeyDerivationMethod,
+    pub salt: String,
+    pub nonce: String,
+    pub ciphertext: String
+}
+
+/// Plaintext of exported key: JWK json or PEM encoded PKCS#8 and key metadata.
+#[derive(Serialize, Deserialize)]
+pub struct ExportedKeyPayload {
+    pub key: String,
+    pub metadata: Option<String>
+}
+
+memzeroize!(ExportedKeyPayload, key);
+
+/// Ed25519 private key in JWK form (RFC 8037).
+#[derive(Serialize, Deserialize)]
+pub struct Jwk {
+    pub kty: String,
+    pub crv: String,
+    pub x: String,
+    pub d: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kid: Option<String>
+}
+
+memzeroize!(Jwk, d);
@@ -2,3 +2,4 @@ pub mod key;
 pub mod did;
 pub mod combo_box;
 pub mod pack;
+pub mod hd;
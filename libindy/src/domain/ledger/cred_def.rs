@@ -3,6 +3,8 @@ use super::response::{GetReplyResultV1, ReplyType};
 use super::super::anoncreds::credential_definition::{CredentialDefinitionData, CredentialDefinitionV1, SignatureType};
 use super::super::ledger::request::ProtocolVersion;
 
+use utils::qualifier;
+
 #[derive(Serialize, Debug)]
 pub struct CredDefOperation {
     #[serde(rename = "ref")]
@@ -18,7 +20,7 @@ pub struct CredDefOperation {
 impl CredDefOperation {
     pub fn new(data: CredentialDefinitionV1) -> CredDefOperation {
         CredDefOperation {
-            _ref: data.schema_id.parse::<i32>().unwrap_or(0),
+            _ref: qualifier::unqualify(&data.schema_id).parse::<i32>().unwrap_or(0),
             signature_type: data.signature_type.to_str().to_string(),
             data: data.value,
             tag: if ProtocolVersion::is_node_1_3() { None } else { Some(data.tag.clone()) },
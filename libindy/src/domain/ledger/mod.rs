@@ -12,3 +12,4 @@ pub mod rev_reg;
 pub mod response;
 pub mod validator_info;
 pub mod constants;
+pub mod signatures;
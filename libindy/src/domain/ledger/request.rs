@@ -2,6 +2,8 @@ use serde;
 use serde_json;
 use time;
 
+use utils::qualifier;
+
 use std::sync::atomic::{AtomicUsize, Ordering};
 
 pub const DEFAULT_LIBIDY_DID: &'static str = "LibindyDid111111111111";
@@ -51,7 +53,8 @@ impl<T: serde::Serialize> Request<T> {
 
     pub fn build_request(identifier: Option<&str>, operation: T) -> Result<String, serde_json::Error> {
         let req_id = time::get_time().sec as u64 * (1e9 as u64) + time::get_time().nsec as u64;
-        let identifier = identifier.unwrap_or(DEFAULT_LIBIDY_DID);
-        serde_json::to_string(&Request::new(req_id, identifier, operation, ProtocolVersion::get()))
+        // Ledger accepts only unqualified DIDs
+        let identifier = qualifier::unqualify_did(identifier.unwrap_or(DEFAULT_LIBIDY_DID));
+        serde_json::to_string(&Request::new(req_id, &identifier, operation, ProtocolVersion::get()))
     }
 }
\ No newline at end of file
@@ -6,6 +6,8 @@ use super::response::{GetReplyResultV1, ReplyType};
 use super::super::anoncreds::revocation_registry::RevocationRegistryV1;
 use super::super::anoncreds::revocation_registry_delta::RevocationRegistryDeltaV1;
 
+use utils::qualifier;
+
 use std::collections::HashSet;
 
 #[derive(Serialize, Debug)]
@@ -23,7 +25,7 @@ impl RevRegEntryOperation {
         RevRegEntryOperation {
             _type: REVOC_REG_ENTRY.to_string(),
             revoc_def_type: rev_def_type.to_string(),
-            revoc_reg_def_id: revoc_reg_def_id.to_string(),
+            revoc_reg_def_id: qualifier::unqualify(revoc_reg_def_id),
             value: value.value
         }
     }
@@ -42,7 +44,7 @@ impl GetRevRegOperation {
     pub fn new(revoc_reg_def_id: &str, timestamp: i64) -> GetRevRegOperation {
         GetRevRegOperation {
             _type: GET_REVOC_REG.to_string(),
-            revoc_reg_def_id: revoc_reg_def_id.to_string(),
+            revoc_reg_def_id: qualifier::unqualify(revoc_reg_def_id),
             timestamp
         }
     }
@@ -63,7 +65,7 @@ impl GetRevRegDeltaOperation {
     pub fn new(revoc_reg_def_id: &str, from: Option<i64>, to: i64) -> GetRevRegDeltaOperation {
         GetRevRegDeltaOperation {
             _type: GET_REVOC_REG_DELTA.to_string(),
-            revoc_reg_def_id: revoc_reg_def_id.to_string(),
+            revoc_reg_def_id: qualifier::unqualify(revoc_reg_def_id),
             from,
             to
         }
@@ -2,6 +2,8 @@ use super::constants::{REVOC_REG_DEF, GET_REVOC_REG_DEF};
 use super::response::{GetReplyResultV1, ReplyType};
 use super::super::anoncreds::revocation_registry_definition::{RevocationRegistryDefinitionV1, RevocationRegistryDefinitionValue};
 
+use utils::qualifier;
+
 #[derive(Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct RevRegDefOperation {
@@ -19,10 +21,10 @@ impl RevRegDefOperation {
     pub fn new(rev_reg_def: RevocationRegistryDefinitionV1) -> RevRegDefOperation {
         RevRegDefOperation {
             _type: REVOC_REG_DEF.to_string(),
-            id: rev_reg_def.id.to_string(),
+            id: qualifier::unqualify(&rev_reg_def.id),
             type_: rev_reg_def.revoc_def_type.to_str().to_string(),
             tag: rev_reg_def.tag.to_string(),
-            cred_def_id: rev_reg_def.cred_def_id.to_string(),
+            cred_def_id: qualifier::unqualify(&rev_reg_def.cred_def_id),
             value: rev_reg_def.value
         }
     }
@@ -40,7 +42,7 @@ impl GetRevRegDefOperation {
     pub fn new(id: &str) -> GetRevRegDefOperation {
         GetRevRegDefOperation {
             _type: GET_REVOC_REG_DEF.to_string(),
-            id: id.to_string()
+            id: qualifier::unqualify(id)
         }
     }
 }
@@ -1,4 +1,5 @@
 use super::constants::{SCHEMA, GET_SCHEMA};
+use domain::anoncreds::schema::AttributeTypes;
 use super::response::{GetReplyResultV1, ReplyType};
 
 use std::collections::HashSet;
@@ -23,15 +24,19 @@ impl SchemaOperation {
 pub struct SchemaOperationData {
     pub name: String,
     pub version: String,
-    pub attr_names: HashSet<String>
+    pub attr_names: HashSet<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub attr_types: Option<AttributeTypes>
 }
 
 impl SchemaOperationData {
-    pub fn new(name: String, version: String, attr_names: HashSet<String>) -> SchemaOperationData {
+    pub fn new(name: String, version: String, attr_names: HashSet<String>, attr_types: Option<AttributeTypes>) -> SchemaOperationData {
         SchemaOperationData {
             name,
             version,
-            attr_names
+            attr_names,
+            attr_types
         }
     }
 }
@@ -103,5 +108,7 @@ pub struct GetSchemaResultDataV1 {
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct GetSchemaResultDataValueV1 {
-    pub attr_names: HashSet<String>
+    pub attr_names: HashSet<String>,
+    #[serde(default)]
+    pub attr_types: Option<AttributeTypes>
 }
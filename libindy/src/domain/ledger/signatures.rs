@@ -0,0 +1,125 @@
+use std::collections::{BTreeMap, HashSet};
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct SignatureQuorum {
+    pub signers: Option<Vec<String>>,
+    pub required: Option<Vec<String>>,
+    pub threshold: Option<usize>
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SignatureVerification {
+    pub valid: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verkey: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>
+}
+
+impl SignatureVerification {
+    pub fn valid(verkey: String, valid: bool) -> SignatureVerification {
+        SignatureVerification { valid, verkey: Some(verkey), error: None }
+    }
+
+    pub fn failed(verkey: Option<String>, error: String) -> SignatureVerification {
+        SignatureVerification { valid: false, verkey, error: Some(error) }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RequestSignaturesReport {
+    pub signatures: BTreeMap<String, SignatureVerification>,
+    pub valid_signers: Vec<String>,
+    pub invalid_signers: Vec<String>,
+    pub missing_required: Vec<String>,
+    pub threshold: usize,
+    pub quorum_met: bool
+}
+
+impl SignatureQuorum {
+    const DEFAULT_THRESHOLD: usize = 1;
+
+    /// Builds the report for verified signatures. Only valid signatures of allowed signers
+    /// are counted to reach the threshold, and all required signers must be among them.
+    pub fn evaluate(&self, signatures: BTreeMap<String, SignatureVerification>) -> RequestSignaturesReport {
+        let allowed: Option<HashSet<&String>> = self.signers.as_ref().map(|signers| signers.iter().collect());
+
+        let (valid_signers, invalid_signers): (Vec<String>, Vec<String>) = signatures
+            .iter()
+            .map(|(did, verification)| (did.clone(), verification.valid))
+            .partition(|&(_, valid)| valid);
+
+        let valid_signers: Vec<String> = valid_signers.into_iter().map(|(did, _)| did).collect();
+        let invalid_signers: Vec<String> = invalid_signers.into_iter().map(|(did, _)| did).collect();
+
+        let counted = valid_signers
+            .iter()
+            .filter(|did| allowed.as_ref().map(|allowed| allowed.contains(did)).unwrap_or(true))
+            .count();
+
+        let missing_required: Vec<String> = self.required
+            .as_ref()
+            .map(|required| required.iter().filter(|did| !valid_signers.contains(did)).cloned().collect())
+            .unwrap_or_default();
+
+        let threshold = self.threshold.unwrap_or(SignatureQuorum::DEFAULT_THRESHOLD);
+        let quorum_met = counted >= threshold && missing_required.is_empty();
+
+        RequestSignaturesReport {
+            signatures,
+            valid_signers,
+            invalid_signers,
+            missing_required,
+            threshold,
+            quorum_met
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _signatures() -> BTreeMap<String, SignatureVerification> {
+        let mut signatures = BTreeMap::new();
+        signatures.insert("did1".to_string(), SignatureVerification::valid("vk1".to_string(), true));
+        signatures.insert("did2".to_string(), SignatureVerification::valid("vk2".to_string(), true));
+        signatures.insert("did3".to_string(), SignatureVerification::valid("vk3".to_string(), false));
+        signatures.insert("did4".to_string(), SignatureVerification::failed(None, "Verkey not found".to_string()));
+        signatures
+    }
+
+    #[test]
+    fn evaluate_works_for_default_quorum() {
+        let report = SignatureQuorum::default().evaluate(_signatures());
+
+        assert_eq!(vec!["did1", "did2"], report.valid_signers);
+        assert_eq!(vec!["did3", "did4"], report.invalid_signers);
+        assert_eq!(1, report.threshold);
+        assert!(report.quorum_met);
+    }
+
+    #[test]
+    fn evaluate_works_for_threshold() {
+        let quorum = SignatureQuorum { signers: None, required: None, threshold: Some(3) };
+        assert!(!quorum.evaluate(_signatures()).quorum_met);
+
+        let quorum = SignatureQuorum { signers: None, required: None, threshold: Some(2) };
+        assert!(quorum.evaluate(_signatures()).quorum_met);
+    }
+
+    #[test]
+    fn evaluate_counts_only_allowed_signers() {
+        let quorum = SignatureQuorum { signers: Some(vec!["did1".to_string(), "did3".to_string()]), required: None, threshold: Some(2) };
+        assert!(!quorum.evaluate(_signatures()).quorum_met);
+    }
+
+    #[test]
+    fn evaluate_works_for_missing_required_signer() {
+        let quorum = SignatureQuorum { signers: None, required: Some(vec!["did1".to_string(), "did3".to_string()]), threshold: None };
+
+        let report = quorum.evaluate(_signatures());
+        assert_eq!(vec!["did3"], report.missing_required);
+        assert!(!report.quorum_met);
+    }
+}
@@ -9,5 +9,6 @@ pub mod wallet;
 pub struct IndyConfig {
     pub crypto_thread_pool_size : Option<usize>,
     pub collect_backtrace: Option<bool>,
-    pub freshness_threshold: Option<u64>
+    pub freshness_threshold: Option<u64>,
+    pub verify_credential_on_store: Option<bool>
 }
\ No newline at end of file
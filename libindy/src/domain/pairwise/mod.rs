@@ -1,11 +1,16 @@
 use named_type::NamedType;
 
+use domain::wallet::Tags;
+
 #[derive(Serialize, Deserialize, NamedType)]
 pub struct Pairwise {
     pub my_did: String,
     pub their_did: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<String>,
+    // timestamp of the last applied key rotation notice of their DID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub their_key_rotated_at: Option<u64>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -13,13 +18,39 @@ pub struct PairwiseInfo {
     pub my_did: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub their_key_rotated_at: Option<u64>,
 }
 
 impl From<Pairwise> for PairwiseInfo {
     fn from(pairwise: Pairwise) -> Self {
         PairwiseInfo {
             my_did: pairwise.my_did,
-            metadata: pairwise.metadata
+            metadata: pairwise.metadata,
+            their_key_rotated_at: pairwise.their_key_rotated_at
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PairwiseSearchInfo {
+    pub their_did: String,
+    pub my_did: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub their_key_rotated_at: Option<u64>,
+    pub tags: Tags,
+}
+
+impl PairwiseSearchInfo {
+    pub fn new(pairwise: Pairwise, tags: Option<Tags>) -> Self {
+        PairwiseSearchInfo {
+            their_did: pairwise.their_did,
+            my_did: pairwise.my_did,
+            metadata: pairwise.metadata,
+            their_key_rotated_at: pairwise.their_key_rotated_at,
+            tags: tags.unwrap_or_default()
         }
     }
-}
\ No newline at end of file
+}
@@ -29,7 +29,7 @@ pub enum KeyDerivationMethod {
     ARGON2I_INT
 }
 
-fn default_key_derivation_method() -> KeyDerivationMethod {
+pub fn default_key_derivation_method() -> KeyDerivationMethod {
     KeyDerivationMethod::ARGON2I_MOD
 }
 
@@ -1,19 +1,107 @@
+extern crate hex;
 extern crate indy_crypto;
 
 use errors::prelude::*;
 
 use domain::anoncreds::credential::AttributeValues;
-use domain::anoncreds::proof_request::{AttributeInfo, PredicateInfo};
+use domain::anoncreds::schema::{date_to_epoch_days, AttributeType, AttributeTypes};
+use utils::crypto::hash::hash;
+use utils::crypto::hmacsha256;
+use domain::anoncreds::proof_request::{AttributeInfo, PredicateInfo, PredicateTypes};
 
+use self::hex::ToHex;
 use self::indy_crypto::cl::{issuer, verifier, CredentialSchema, NonCredentialSchema, MasterSecret, CredentialValues, SubProofRequest};
 
 
-use std::collections::{HashSet, HashMap};
+use std::collections::{BTreeMap, HashSet, HashMap};
+
+pub const ATTRIBUTE_EXISTENCE_MARKER: &'static str = "1";
+pub const IDENTIFIER_TAGS: [&'static str; 5] = ["schema_id", "schema_issuer_did", "issuer_did", "cred_def_id", "rev_reg_id"];
+/// Tags set by libindy for every stored credential besides attribute tags: identifier tags, schema name and version.
+pub const CREDENTIAL_TAGS: [&'static str; 7] = ["schema_id", "schema_issuer_did", "schema_name", "schema_version", "issuer_did", "cred_def_id", "rev_reg_id"];
 
 pub fn attr_common_view(attr: &str) -> String {
     attr.replace(" ", "").to_lowercase()
 }
 
+/// Standard encoding of credential attribute raw value: a value that is the canonical decimal
+/// form of 32-bit integer is encoded as itself, any other value as decimal representation
+/// of SHA-256 hash (big-endian) of its UTF-8 bytes.
+pub fn encode_credential_attribute(raw_value: &str) -> IndyResult<String> {
+    match raw_value.parse::<i32>() {
+        Ok(value) if value.to_string() == raw_value => Ok(raw_value.to_string()),
+        _ => Ok(_bytes_to_decimal(&hash(raw_value.as_bytes())?))
+    }
+}
+
+/// Encoding of credential attribute raw value according to its type in schema: integers are encoded
+/// as themselves, dates ("YYYY-MM-DD") as number of days since Unix Epoch, so they can be used in predicates,
+/// enum values must be one of allowed values. Strings, enums and untyped attributes use standard encoding.
+pub fn encode_typed_credential_attribute(raw_value: &str, attr_type: Option<&AttributeType>) -> IndyResult<String> {
+    match attr_type {
+        Some(&AttributeType::Int) => raw_value.parse::<i32>()
+            .map(|value| value.to_string())
+            .to_indy(IndyErrorKind::InvalidStructure, format!("Value {:?} of integer attribute isn't 32-bit integer", raw_value)),
+        Some(&AttributeType::Date) => date_to_epoch_days(raw_value)
+            .map(|days| days.to_string())
+            .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("Value {:?} of date attribute isn't date in YYYY-MM-DD format", raw_value))),
+        Some(&AttributeType::Enum { ref values }) if !values.iter().any(|value| value == raw_value) =>
+            Err(err_msg(IndyErrorKind::InvalidStructure, format!("Value {:?} of enum attribute isn't one of {:?}", raw_value, values))),
+        _ => encode_credential_attribute(raw_value)
+    }
+}
+
+/// Checks that encoded value is encoding of raw value as one of types usable in predicates (integer or date).
+/// Used when schema and so attribute types are unknown, e.g. on store of credential.
+pub fn is_typed_encoding(raw_value: &str, encoded_value: &str) -> bool {
+    [AttributeType::Int, AttributeType::Date].iter()
+        .any(|attr_type| encode_typed_credential_attribute(raw_value, Some(attr_type)).map(|encoded| encoded == encoded_value).unwrap_or(false))
+}
+
+pub fn get_attribute_type<'a>(attr_types: Option<&'a AttributeTypes>, attr: &str) -> Option<&'a AttributeType> {
+    attr_types.and_then(|attr_types|
+        attr_types.iter()
+            .find(|&(name, _)| attr_common_view(name) == attr_common_view(attr))
+            .map(|(_, attr_type)| attr_type))
+}
+
+fn _bytes_to_decimal(bytes: &[u8]) -> String {
+    let mut number = bytes.to_vec();
+    let mut digits: Vec<u8> = Vec::new();
+
+    while number.iter().any(|byte| *byte != 0) {
+        let mut remainder = 0u32;
+
+        for byte in number.iter_mut() {
+            let acc = (remainder << 8) | *byte as u32;
+            *byte = (acc / 10) as u8;
+            remainder = acc % 10;
+        }
+
+        digits.push(remainder as u8);
+    }
+
+    if digits.is_empty() {
+        return "0".to_string();
+    }
+
+    digits.iter().rev().map(|digit| (b'0' + digit) as char).collect()
+}
+
+/// Hash of credential attribute raw values that doesn't depend on attributes order and names form.
+/// Keyed hash of attribute raw values: without the key raw values can't be guessed from the hash.
+pub fn hash_credential_values(credential_values: &HashMap<String, AttributeValues>, key: &hmacsha256::Key) -> IndyResult<String> {
+    let raw_values: BTreeMap<String, &str> = credential_values
+        .iter()
+        .map(|(attr, values)| (attr_common_view(attr), values.raw.as_str()))
+        .collect();
+
+    let raw_values_json = serde_json::to_string(&raw_values)
+        .to_indy(IndyErrorKind::InvalidState, "Cannot serialize credential values")?;
+
+    Ok(hmacsha256::authenticate(raw_values_json.as_bytes(), key)[..].to_hex())
+}
+
 pub fn build_credential_schema(attrs: &HashSet<String>) -> IndyResult<CredentialSchema> {
     trace!("build_credential_schema >>> attrs: {:?}", attrs);
 
@@ -58,6 +146,16 @@ pub fn build_credential_values(credential_values: &HashMap<String, AttributeValu
     Ok(res)
 }
 
+// Several predicates on the same attribute (e.g. ">=" and "<") form a range proof
+pub fn predicate_type_to_crypto(p_type: &PredicateTypes) -> &'static str {
+    match p_type {
+        PredicateTypes::GE => "GE",
+        PredicateTypes::LE => "LE",
+        PredicateTypes::GT => "GT",
+        PredicateTypes::LT => "LT"
+    }
+}
+
 pub fn build_sub_proof_request(attrs_for_credential: &Vec<AttributeInfo>,
                                predicates_for_credential: &Vec<PredicateInfo>) -> IndyResult<SubProofRequest> {
     trace!("build_sub_proof_request >>> attrs_for_credential: {:?}, predicates_for_credential: {:?}", attrs_for_credential, predicates_for_credential);
@@ -65,11 +163,13 @@ pub fn build_sub_proof_request(attrs_for_credential: &Vec<AttributeInfo>,
     let mut sub_proof_request_builder = verifier::Verifier::new_sub_proof_request_builder()?;
 
     for attr in attrs_for_credential {
-        sub_proof_request_builder.add_revealed_attr(&attr_common_view(&attr.name))?
+        for name in attr.names()? {
+            sub_proof_request_builder.add_revealed_attr(&attr_common_view(&name))?
+        }
     }
 
     for predicate in predicates_for_credential {
-        sub_proof_request_builder.add_predicate(&attr_common_view(&predicate.name), "GE", predicate.p_value)?;
+        sub_proof_request_builder.add_predicate(&attr_common_view(&predicate.name), predicate_type_to_crypto(&predicate.p_type), predicate.p_value)?;
     }
 
     let res = sub_proof_request_builder.finalize()?;
@@ -89,3 +189,83 @@ pub fn parse_cred_rev_id(cred_rev_id: &str) -> IndyResult<u32> {
 
     Ok(res)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_credential_attribute_works_for_integer() {
+        assert_eq!("28", encode_credential_attribute("28").unwrap());
+        assert_eq!("-1", encode_credential_attribute("-1").unwrap());
+        assert_eq!("2147483647", encode_credential_attribute("2147483647").unwrap());
+    }
+
+    #[test]
+    fn encode_credential_attribute_works_for_string() {
+        assert_eq!("101327353979588246869873249766058188995681113722618593621043638294296500696424", encode_credential_attribute("SLC").unwrap());
+        assert_eq!("102987336249554097029535212322581322789799900648198034993379397001115665086549", encode_credential_attribute("").unwrap());
+    }
+
+    fn _credential_values(values: &[(&str, &str)]) -> HashMap<String, AttributeValues> {
+        values
+            .iter()
+            .map(|&(attr, raw)| (attr.to_string(), AttributeValues { raw: raw.to_string(), encoded: encode_credential_attribute(raw).unwrap() }))
+            .collect()
+    }
+
+    #[test]
+    fn hash_credential_values_works_for_attribute_names_form() {
+        let key = hmacsha256::gen_key();
+        assert_eq!(hash_credential_values(&_credential_values(&[("name", "Alex"), ("age", "28")]), &key).unwrap(),
+                   hash_credential_values(&_credential_values(&[("Name", "Alex"), ("a ge", "28")]), &key).unwrap());
+    }
+
+    #[test]
+    fn hash_credential_values_works_for_different_values() {
+        let key = hmacsha256::gen_key();
+        assert_ne!(hash_credential_values(&_credential_values(&[("name", "Alex"), ("age", "28")]), &key).unwrap(),
+                   hash_credential_values(&_credential_values(&[("name", "Alex"), ("age", "29")]), &key).unwrap());
+    }
+
+    #[test]
+    fn hash_credential_values_works_for_different_keys() {
+        let values = _credential_values(&[("name", "Alex"), ("age", "28")]);
+        assert_ne!(hash_credential_values(&values, &hmacsha256::gen_key()).unwrap(),
+                   hash_credential_values(&values, &hmacsha256::gen_key()).unwrap());
+    }
+
+    #[test]
+    fn encode_credential_attribute_hashes_non_canonical_integer() {
+        assert_ne!("007", encode_credential_attribute("007").unwrap());
+        assert_ne!("2147483648", encode_credential_attribute("2147483648").unwrap());
+    }
+
+    #[test]
+    fn encode_typed_credential_attribute_works() {
+        assert_eq!("7", encode_typed_credential_attribute("007", Some(&AttributeType::Int)).unwrap());
+        assert_eq!("11016", encode_typed_credential_attribute("2000-02-29", Some(&AttributeType::Date)).unwrap());
+        assert_eq!(encode_credential_attribute("28").unwrap(), encode_typed_credential_attribute("28", Some(&AttributeType::String)).unwrap());
+        assert_eq!(encode_credential_attribute("SLC").unwrap(), encode_typed_credential_attribute("SLC", None).unwrap());
+
+        let attr_type = AttributeType::Enum { values: vec!["active".to_string(), "inactive".to_string()] };
+        assert_eq!(encode_credential_attribute("active").unwrap(), encode_typed_credential_attribute("active", Some(&attr_type)).unwrap());
+    }
+
+    #[test]
+    fn is_typed_encoding_works() {
+        assert!(is_typed_encoding("007", "7"));
+        assert!(is_typed_encoding("2000-02-29", "11016"));
+        assert!(!is_typed_encoding("2000-02-29", &encode_credential_attribute("2000-02-29").unwrap()));
+        assert!(!is_typed_encoding("SLC", "7"));
+    }
+
+    #[test]
+    fn encode_typed_credential_attribute_works_for_invalid_value() {
+        assert_kind!(IndyErrorKind::InvalidStructure, encode_typed_credential_attribute("28.5", Some(&AttributeType::Int)));
+        assert_kind!(IndyErrorKind::InvalidStructure, encode_typed_credential_attribute("2000-02-30", Some(&AttributeType::Date)));
+
+        let attr_type = AttributeType::Enum { values: vec!["active".to_string()] };
+        assert_kind!(IndyErrorKind::InvalidStructure, encode_typed_credential_attribute("deleted", Some(&attr_type)));
+    }
+}
@@ -1,5 +1,6 @@
 use std::collections::hash_map::Entry;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
 
 use indy_crypto::cl::{
     BlindedCredentialSecrets,
@@ -13,11 +14,11 @@ use indy_crypto::cl::issuer::Issuer as CryptoIssuer;
 use indy_crypto::cl::prover::Prover as CryptoProver;
 use indy_crypto::cl::verifier::Verifier as CryptoVerifier;
 
-use domain::anoncreds::credential::{AttributeValues, Credential};
+use domain::anoncreds::credential::{AttributeValues, Credential, CredentialCheck, CredentialVerificationReport};
 use domain::anoncreds::credential_definition::CredentialDefinitionV1 as CredentialDefinition;
 use domain::anoncreds::credential_offer::CredentialOffer;
 use domain::anoncreds::credential_request::CredentialRequestMetadata;
-use domain::anoncreds::proof::{Identifier, Proof, RequestedProof, RevealedAttributeInfo, SubProofReferent};
+use domain::anoncreds::proof::{AttributeValue, Identifier, Proof, RequestedProof, RevealedAttributeGroupInfo, RevealedAttributeInfo, SubProofReferent};
 use domain::anoncreds::proof_request::{NonRevocedInterval, PredicateInfo, PredicateTypes, ProofRequest, ProofRequestExtraQuery, RequestedAttributeInfo, RequestedPredicateInfo};
 use domain::anoncreds::requested_credential::ProvingCredentialKey;
 use domain::anoncreds::requested_credential::RequestedCredentials;
@@ -26,11 +27,22 @@ use domain::anoncreds::revocation_state::RevocationState;
 use domain::anoncreds::schema::SchemaV1;
 use errors::prelude::*;
 use services::anoncreds::helpers::*;
-
-const ATTRIBUTE_EXISTENCE_MARKER: &'static str = "1";
+use utils::qualifier;
 
 pub struct Prover {}
 
+lazy_static! {
+    static ref VERIFY_CREDENTIAL_ON_STORE: Mutex<bool> = Mutex::new(false);
+}
+
+pub fn set_verify_credential_on_store(verify: bool) {
+    *VERIFY_CREDENTIAL_ON_STORE.lock().unwrap() = verify;
+}
+
+pub fn verify_credential_on_store() -> bool {
+    *VERIFY_CREDENTIAL_ON_STORE.lock().unwrap()
+}
+
 macro_rules! serde_map {
     ($( $key: expr => $val: expr ),*) => {
         {
@@ -111,6 +123,106 @@ impl Prover {
         Ok(())
     }
 
+    /// Checks the credential against its schema, credential definition and revocation registry
+    /// definition without processing of the signature.
+    pub fn check_credential(&self,
+                            credential: &Credential,
+                            schema: Option<&SchemaV1>,
+                            cred_def: &CredentialDefinition,
+                            rev_reg_def: Option<&RevocationRegistryDefinitionV1>) -> IndyResult<CredentialVerificationReport> {
+        trace!("check_credential >>> credential: {:?}, schema: {:?}, cred_def: {:?}, rev_reg_def: {:?}", credential, schema, cred_def, rev_reg_def);
+
+        let schema_check = schema.map(|schema| {
+            let mut errors: Vec<String> = Vec::new();
+
+            if qualifier::unqualify(&schema.id) != credential.schema_id() {
+                errors.push(format!("Credential schema id {:?} doesn't match schema {:?}", credential.schema_id, schema.id));
+            }
+
+            let schema_attrs: HashSet<String> = schema.attr_names.iter().map(|attr| attr_common_view(attr)).collect();
+            let credential_attrs: HashSet<String> = credential.values.keys().map(|attr| attr_common_view(attr)).collect();
+
+            if schema_attrs != credential_attrs {
+                errors.push(format!("Credential attributes {:?} don't match schema attributes {:?}", credential_attrs, schema_attrs));
+            }
+
+            CredentialCheck::new(errors)
+        });
+
+        let mut values_errors: Vec<String> = Vec::new();
+
+        let attr_types = schema.and_then(|schema| schema.attr_types.as_ref());
+
+        for (attr, values) in credential.values.iter() {
+            let attr_type = get_attribute_type(attr_types, attr);
+
+            match encode_typed_credential_attribute(&values.raw, attr_type) {
+                Ok(ref encoded) if encoded == &values.encoded => {}
+                // Without schema (on store) attribute types are unknown, so encodings of typed values are accepted as well
+                _ if schema.is_none() && is_typed_encoding(&values.raw, &values.encoded) => {}
+                Ok(_) => values_errors.push(format!("Encoded value of attribute {:?} doesn't match standard encoding of raw value", attr)),
+                Err(err) => values_errors.push(format!("Raw value of attribute {:?} doesn't match its type: {}", attr, err))
+            }
+        }
+
+        let mut cred_def_errors: Vec<String> = Vec::new();
+
+        if qualifier::unqualify(&cred_def.id) != credential.cred_def_id() {
+            cred_def_errors.push(format!("Credential definition id {:?} doesn't match credential definition {:?}", credential.cred_def_id, cred_def.id));
+        }
+
+        if let Some(schema) = schema {
+            if qualifier::unqualify(&cred_def.schema_id) != qualifier::unqualify(&schema.id) &&
+                schema.seq_no.map(|seq_no| seq_no.to_string()) != Some(cred_def.schema_id.clone()) {
+                cred_def_errors.push(format!("Credential definition schema {:?} doesn't match schema {:?}", cred_def.schema_id, schema.id));
+            }
+        }
+
+        let mut rev_reg_errors: Vec<String> = Vec::new();
+
+        match (cred_def.value.revocation.is_some(), rev_reg_def) {
+            (true, Some(rev_reg_def)) => {
+                if credential.rev_reg_id().map(|id| id != qualifier::unqualify(&rev_reg_def.id)).unwrap_or(true) {
+                    rev_reg_errors.push(format!("Credential revocation registry id {:?} doesn't match revocation registry definition {:?}", credential.rev_reg_id, rev_reg_def.id));
+                }
+
+                if qualifier::unqualify(&rev_reg_def.cred_def_id) != qualifier::unqualify(&cred_def.id) {
+                    rev_reg_errors.push(format!("Revocation registry definition belongs to other credential definition {:?}", rev_reg_def.cred_def_id));
+                }
+
+                if credential.rev_reg.is_none() || credential.witness.is_none() {
+                    rev_reg_errors.push("Revocation registry or witness not found in revocable credential".to_string());
+                }
+            }
+            (true, None) => rev_reg_errors.push("Revocation registry definition is required for revocable credential".to_string()),
+            (false, Some(rev_reg_def)) => rev_reg_errors.push(format!("Revocation registry definition {:?} is provided for non-revocable credential", rev_reg_def.id)),
+            (false, None) => {
+                if credential.rev_reg_id.is_some() {
+                    rev_reg_errors.push(format!("Non-revocable credential refers revocation registry {:?}", credential.rev_reg_id));
+                }
+            }
+        }
+
+        let values = CredentialCheck::new(values_errors);
+        let cred_def = CredentialCheck::new(cred_def_errors);
+        let rev_reg = CredentialCheck::new(rev_reg_errors);
+
+        let valid = schema_check.as_ref().map(|check| check.valid).unwrap_or(true) && values.valid && cred_def.valid && rev_reg.valid;
+
+        let res = CredentialVerificationReport {
+            valid,
+            schema: schema_check,
+            values,
+            cred_def,
+            rev_reg,
+            signature: None
+        };
+
+        trace!("check_credential <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     pub fn create_proof(&self,
                         credentials: &HashMap<String, Credential>,
                         proof_req: &ProofRequest,
@@ -175,12 +287,23 @@ impl Prover {
                                                 rev_state.as_ref().map(|r_info| &r_info.rev_reg),
                                                 rev_state.as_ref().map(|r_info| &r_info.witness))?;
 
-            identifiers.push(Identifier {
-                schema_id: credential.schema_id.clone(),
-                cred_def_id: credential.cred_def_id.clone(),
-                rev_reg_id: credential.rev_reg_id.clone(),
-                timestamp: cred_key.timestamp.clone(),
-            });
+            let identifier = if proof_req.expects_qualified_identifiers() {
+                Identifier {
+                    schema_id: credential.schema_id.clone(),
+                    cred_def_id: credential.cred_def_id.clone(),
+                    rev_reg_id: credential.rev_reg_id.clone(),
+                    timestamp: cred_key.timestamp.clone(),
+                }
+            } else {
+                Identifier {
+                    schema_id: credential.schema_id(),
+                    cred_def_id: credential.cred_def_id(),
+                    rev_reg_id: credential.rev_reg_id(),
+                    timestamp: cred_key.timestamp.clone(),
+                }
+            };
+
+            identifiers.push(identifier);
 
             self._update_requested_proof(req_attrs_for_cred,
                                          req_predicates_for_cred,
@@ -284,14 +407,14 @@ impl Prover {
     pub fn build_credential_tags(&self, credential: &Credential) -> HashMap<String, String> {
         trace!("build_credential_tags >>> credential: {:?}", credential);
 
-        let mut res: HashMap<String, String> = HashMap::new();
-        res.insert("schema_id".to_string(), credential.schema_id());
-        res.insert("schema_issuer_did".to_string(), credential.schema_issuer_did());
-        res.insert("schema_name".to_string(), credential.schema_name());
-        res.insert("schema_version".to_string(), credential.schema_version());
-        res.insert("issuer_did".to_string(), credential.issuer_did());
-        res.insert("cred_def_id".to_string(), credential.cred_def_id());
-        res.insert("rev_reg_id".to_string(), credential.rev_reg_id.clone().unwrap_or("None".to_string()));
+        // values go in order of CREDENTIAL_TAGS
+        let values = [credential.schema_id(), credential.schema_issuer_did(), credential.schema_name(), credential.schema_version(),
+            credential.issuer_did(), credential.cred_def_id(), credential.rev_reg_id().unwrap_or("None".to_string())];
+
+        let mut res: HashMap<String, String> = CREDENTIAL_TAGS.iter()
+            .map(|tag| tag.to_string())
+            .zip(values.iter().cloned())
+            .collect();
 
         credential.values
             .iter()
@@ -305,18 +428,40 @@ impl Prover {
         res
     }
 
+    /// Checks that tags set by holder don't clash with tags built by `build_credential_tags`,
+    /// so user labels can't break searching of credentials for proof requests.
+    pub fn check_user_credential_tags<'a, I>(&self, tag_names: I) -> IndyResult<()> where I: IntoIterator<Item=&'a String> {
+        trace!("check_user_credential_tags >>>");
+
+        for tag_name in tag_names {
+            let name = tag_name.trim_left_matches('~');
+
+            if name.is_empty() || name.starts_with("attr::") || CREDENTIAL_TAGS.contains(&name) {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Credential tag {:?} is reserved", tag_name)));
+            }
+        }
+
+        trace!("check_user_credential_tags <<<");
+
+        Ok(())
+    }
+
+    /// Builds WQL query for credentials containing all of `names` attributes (one for single attribute
+    /// or predicate and several for attribute group) and satisfying restrictions.
     pub fn build_query(&self,
-                       name: &str,
+                       names: &[String],
                        referent: &str,
                        restrictions: &Option<serde_json::Value>,
                        extra_query: &Option<&ProofRequestExtraQuery>) -> IndyResult<String> {
-        trace!("build_query >>> name: {:?}, referent: {:?}, restrictions: {:?}, extra_query: {:?}", name, referent, restrictions, extra_query);
+        trace!("build_query >>> names: {:?}, referent: {:?}, restrictions: {:?}, extra_query: {:?}", names, referent, restrictions, extra_query);
 
         let mut sub_queries: Vec<serde_json::Value> = vec![];
 
-        sub_queries.push(serde_json::Value::Object(serde_map!(
-            format!("attr::{}::marker", &attr_common_view(name)) => serde_json::Value::String(ATTRIBUTE_EXISTENCE_MARKER.to_string())
-        )));
+        for name in names {
+            sub_queries.push(serde_json::Value::Object(serde_map!(
+                format!("attr::{}::marker", &attr_common_view(name)) => serde_json::Value::String(ATTRIBUTE_EXISTENCE_MARKER.to_string())
+            )));
+        }
 
         match restrictions.as_ref() {
             // Convert old restrictions format to valid wql
@@ -350,6 +495,9 @@ impl Prover {
             sub_queries.push(serde_json::Value::Object(q.clone()));
         }
 
+        // Credential tags contain unqualified identifiers
+        let sub_queries = sub_queries.into_iter().map(Prover::_unqualify_query).collect();
+
         let mut query: HashMap<String, Vec<serde_json::Value>> = HashMap::new();
         query.insert("$and".to_string(), sub_queries);
 
@@ -361,17 +509,255 @@ impl Prover {
         Ok(res)
     }
 
+    fn _unqualify_query(query: serde_json::Value) -> serde_json::Value {
+        match query {
+            serde_json::Value::Object(map) => serde_json::Value::Object(
+                map.into_iter()
+                    .map(|(key, value)| {
+                        let value = if IDENTIFIER_TAGS.contains(&key.as_str()) {
+                            Prover::_unqualify_tag_value(value)
+                        } else {
+                            Prover::_unqualify_query(value)
+                        };
+                        (key, value)
+                    })
+                    .collect()),
+            serde_json::Value::Array(array) => serde_json::Value::Array(array.into_iter().map(Prover::_unqualify_query).collect()),
+            value => value
+        }
+    }
+
+    fn _unqualify_tag_value(value: serde_json::Value) -> serde_json::Value {
+        match value {
+            serde_json::Value::String(value) => serde_json::Value::String(qualifier::unqualify(&value)),
+            serde_json::Value::Object(map) => serde_json::Value::Object(
+                map.into_iter()
+                    .map(|(operator, value)| (operator, Prover::_unqualify_tag_value(value)))
+                    .collect()),
+            serde_json::Value::Array(array) => serde_json::Value::Array(array.into_iter().map(Prover::_unqualify_tag_value).collect()),
+            value => value
+        }
+    }
+
+    /// Checks proof request given as json, so that missing or invalid nonce is reported
+    /// along with other problems instead of failing deserialization of the whole request.
+    pub fn validate_proof_request_json(&self, proof_req_json: &str) -> Vec<String> {
+        trace!("validate_proof_request_json >>> proof_req_json: {:?}", proof_req_json);
+
+        let mut proof_req = match serde_json::from_str::<serde_json::Value>(proof_req_json) {
+            Ok(proof_req @ serde_json::Value::Object(_)) => proof_req,
+            Ok(_) => return vec!["Proof request has invalid structure: json object expected".to_string()],
+            Err(err) => return vec![format!("Proof request has invalid structure: {}", err)]
+        };
+
+        let mut problems: Vec<String> = Vec::new();
+
+        if let Some(problem) = Prover::_check_nonce(&proof_req["nonce"]) {
+            problems.push(problem);
+            // valid nonce in place of the wrong one allows to check the rest of request
+            proof_req["nonce"] = json!("1");
+        }
+
+        match serde_json::from_value::<ProofRequest>(proof_req) {
+            Ok(proof_req) => problems.extend(self.validate_proof_request(&proof_req)),
+            Err(err) => problems.push(format!("Proof request has invalid structure: {}", err))
+        }
+
+        trace!("validate_proof_request_json <<< problems: {:?}", problems);
+
+        problems
+    }
+
+    fn _check_nonce(nonce: &serde_json::Value) -> Option<String> {
+        match nonce {
+            &serde_json::Value::Null => Some("Proof request \"nonce\" is missing".to_string()),
+            &serde_json::Value::String(ref nonce) if nonce.trim().is_empty() => Some("Proof request \"nonce\" is empty".to_string()),
+            &serde_json::Value::String(ref nonce) if nonce.chars().all(|c| c.is_digit(10)) => None,
+            _ => Some("Proof request \"nonce\" must be a string of decimal digits".to_string())
+        }
+    }
+
+    /// Checks that proof request is well formed and returns the list of found problems.
+    /// Request without attributes and predicates is reported too, though credentials can be searched for it.
+    pub fn validate_proof_request(&self, proof_req: &ProofRequest) -> Vec<String> {
+        trace!("validate_proof_request >>> proof_req: {:?}", proof_req);
+
+        let mut problems = self.check_proof_request_structure(proof_req);
+
+        if proof_req.requested_attributes.is_empty() && proof_req.requested_predicates.is_empty() {
+            problems.push("Proof request doesn't contain neither requested attributes nor requested predicates".to_string());
+        }
+
+        trace!("validate_proof_request <<< problems: {:?}", problems);
+
+        problems
+    }
+
+    /// Returns problems of proof request which don't allow to search credentials for it.
+    pub fn check_proof_request_structure(&self, proof_req: &ProofRequest) -> Vec<String> {
+        let mut problems: Vec<String> = Vec::new();
+
+        if proof_req.name.trim().is_empty() {
+            problems.push("Proof request \"name\" is empty".to_string());
+        }
+
+        if proof_req.version.trim().is_empty() {
+            problems.push("Proof request \"version\" is empty".to_string());
+        }
+
+        Prover::_validate_non_revoked_interval("Proof request", &proof_req.non_revoked, &mut problems);
+
+        for (referent, attr_info) in proof_req.requested_attributes.iter() {
+            let context = format!("Requested attribute \"{}\"", referent);
+
+            match attr_info.names() {
+                Ok(names) => {
+                    if names.iter().any(|name| name.trim().is_empty()) {
+                        problems.push(format!("{} contains empty attribute name", context));
+                    }
+
+                    if names.iter().map(|name| attr_common_view(name)).collect::<HashSet<String>>().len() != names.len() {
+                        problems.push(format!("{} contains duplicated attribute names", context));
+                    }
+                }
+                Err(_) => problems.push(format!("{} must contain either \"name\" or non-empty \"names\"", context))
+            }
+
+            Prover::_validate_restrictions(&context, &attr_info.restrictions, &mut problems);
+            Prover::_validate_non_revoked_interval(&context, &attr_info.non_revoked, &mut problems);
+        }
+
+        for (referent, predicate_info) in proof_req.requested_predicates.iter() {
+            let context = format!("Requested predicate \"{}\"", referent);
+
+            if predicate_info.name.trim().is_empty() {
+                problems.push(format!("{} contains empty attribute name", context));
+            }
+
+            Prover::_validate_restrictions(&context, &predicate_info.restrictions, &mut problems);
+            Prover::_validate_non_revoked_interval(&context, &predicate_info.non_revoked, &mut problems);
+
+            if let Some(ref restrictions) = predicate_info.restrictions {
+                let value_tag = format!("attr::{}::value", attr_common_view(&predicate_info.name));
+
+                if Prover::_restricted_tag_values(restrictions, &value_tag).iter().any(|value| value.parse::<i32>().is_err()) {
+                    problems.push(format!("{} can't be satisfied as its restrictions require non-integer value of attribute \"{}\"", context, predicate_info.name));
+                }
+            }
+        }
+
+        problems
+    }
+
+    fn _validate_non_revoked_interval(context: &str, interval: &Option<NonRevocedInterval>, problems: &mut Vec<String>) {
+        match interval {
+            &Some(NonRevocedInterval { from: None, to: None }) =>
+                problems.push(format!("{} contains \"non_revoked\" interval without \"from\" and \"to\"", context)),
+            &Some(NonRevocedInterval { from: Some(from), to: Some(to) }) if from > to =>
+                problems.push(format!("{} contains \"non_revoked\" interval with \"from\" greater than \"to\"", context)),
+            _ => {}
+        }
+    }
+
+    fn _validate_restrictions(context: &str, restrictions: &Option<serde_json::Value>, problems: &mut Vec<String>) {
+        match restrictions {
+            &Some(serde_json::Value::Array(ref restrictions)) => {
+                for restriction in restrictions {
+                    Prover::_validate_restriction(context, restriction, problems);
+                }
+            }
+            &Some(ref restriction) => Prover::_validate_restriction(context, restriction, problems),
+            &None => {}
+        }
+    }
+
+    fn _validate_restriction(context: &str, restriction: &serde_json::Value, problems: &mut Vec<String>) {
+        let restriction = match restriction {
+            &serde_json::Value::Object(ref restriction) => restriction,
+            _ => return problems.push(format!("{} contains restriction that isn't an object: {}", context, restriction))
+        };
+
+        for (key, value) in restriction.iter() {
+            match (key.as_str(), value) {
+                ("$and", &serde_json::Value::Array(ref operands)) | ("$or", &serde_json::Value::Array(ref operands)) => {
+                    for operand in operands {
+                        Prover::_validate_restriction(context, operand, problems);
+                    }
+                }
+                ("$not", operand @ &serde_json::Value::Object(_)) => Prover::_validate_restriction(context, operand, problems),
+                ("$and", _) | ("$or", _) | ("$not", _) =>
+                    problems.push(format!("{} contains invalid operand of \"{}\" restriction operator", context, key)),
+                (tag, value) if Prover::_is_restriction_tag(tag) => Prover::_validate_restriction_tag_value(context, tag, value, problems),
+                (tag, _) => problems.push(format!("{} contains unknown restriction \"{}\"", context, tag))
+            }
+        }
+    }
+
+    fn _is_restriction_tag(tag: &str) -> bool {
+        if CREDENTIAL_TAGS.contains(&tag) {
+            return true;
+        }
+
+        let parts: Vec<&str> = tag.split("::").collect();
+
+        parts.len() == 3 && parts[0] == "attr" && !parts[1].is_empty() && (parts[2] == "marker" || parts[2] == "value")
+    }
+
+    fn _validate_restriction_tag_value(context: &str, tag: &str, value: &serde_json::Value, problems: &mut Vec<String>) {
+        let is_valid = match value {
+            &serde_json::Value::String(_) | &serde_json::Value::Null => true,
+            &serde_json::Value::Object(ref operators) => operators.iter().all(|(operator, target)| {
+                match (operator.as_str(), target) {
+                    ("$in", &serde_json::Value::Array(ref targets)) => targets.iter().all(serde_json::Value::is_string),
+                    ("$eq", &serde_json::Value::String(_)) | ("$neq", &serde_json::Value::String(_)) |
+                    ("$gt", &serde_json::Value::String(_)) | ("$gte", &serde_json::Value::String(_)) |
+                    ("$lt", &serde_json::Value::String(_)) | ("$lte", &serde_json::Value::String(_)) |
+                    ("$like", &serde_json::Value::String(_)) => true,
+                    _ => false
+                }
+            }),
+            _ => false
+        };
+
+        if !is_valid {
+            problems.push(format!("{} contains invalid value of \"{}\" restriction: {}", context, tag, value));
+        }
+    }
+
+    // Values the restrictions require for the tag
+    fn _restricted_tag_values(restrictions: &serde_json::Value, tag: &str) -> Vec<String> {
+        match restrictions {
+            &serde_json::Value::Array(ref restrictions) =>
+                restrictions.iter().flat_map(|restriction| Prover::_restricted_tag_values(restriction, tag)).collect(),
+            &serde_json::Value::Object(ref restriction) =>
+                restriction.iter()
+                    .flat_map(|(key, value)| {
+                        match (key.as_str(), value) {
+                            ("$and", _) | ("$or", _) => Prover::_restricted_tag_values(value, tag),
+                            (key, &serde_json::Value::String(ref value)) if key == tag => vec![value.clone()],
+                            (key, &serde_json::Value::Object(ref operators)) if key == tag =>
+                                operators.get("$eq").and_then(serde_json::Value::as_str).map(|value| vec![value.to_string()]).unwrap_or(vec![]),
+                            _ => vec![]
+                        }
+                    })
+                    .collect(),
+            _ => vec![]
+        }
+    }
+
     pub fn attribute_satisfy_predicate(&self,
                                        predicate: &PredicateInfo,
                                        attribute_value: &str) -> IndyResult<bool> {
         trace!("attribute_satisfy_predicate >>> predicate: {:?}, attribute_value: {:?}", predicate, attribute_value);
 
+        let attribute_value = attribute_value.parse::<i32>()
+            .to_indy(IndyErrorKind::InvalidStructure, format!("Credential attribute value \"{:?}\" is invalid", attribute_value))?;
+
         let res = match predicate.p_type {
-            PredicateTypes::GE => {
-                let attribute_value = attribute_value.parse::<i32>()
-                    .to_indy(IndyErrorKind::InvalidStructure, format!("Credential attribute value \"{:?}\" is invalid", attribute_value))?;
-                Ok(attribute_value >= predicate.p_value)
-            }
+            PredicateTypes::GE => Ok(attribute_value >= predicate.p_value),
+            PredicateTypes::LE => Ok(attribute_value <= predicate.p_value),
+            PredicateTypes::GT => Ok(attribute_value > predicate.p_value),
+            PredicateTypes::LT => Ok(attribute_value < predicate.p_value)
         };
 
         trace!("attribute_satisfy_predicate <<< res: {:?}", res);
@@ -391,16 +777,36 @@ impl Prover {
         for attr_info in req_attrs_for_credential {
             if attr_info.revealed {
                 let attribute = &proof_req.requested_attributes[&attr_info.attr_referent];
-                let attribute_values =
-                    self.get_credential_values_for_attribute(&credential.values, &attribute.name)
-                        .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("Credential value not found for attribute {:?}", attribute.name)))?;
-
-                requested_proof.revealed_attrs.insert(attr_info.attr_referent,
-                                                      RevealedAttributeInfo {
-                                                          sub_proof_index,
-                                                          raw: attribute_values.raw,
-                                                          encoded: attribute_values.encoded,
-                                                      });
+
+                if attribute.is_group() {
+                    let mut values: HashMap<String, AttributeValue> = HashMap::new();
+
+                    for name in attribute.names()? {
+                        let attribute_values =
+                            self.get_credential_values_for_attribute(&credential.values, &name)
+                                .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("Credential value not found for attribute {:?}", name)))?;
+
+                        values.insert(name, AttributeValue { raw: attribute_values.raw, encoded: attribute_values.encoded });
+                    }
+
+                    requested_proof.revealed_attr_groups.insert(attr_info.attr_referent,
+                                                                RevealedAttributeGroupInfo {
+                                                                    sub_proof_index,
+                                                                    values,
+                                                                });
+                } else {
+                    let name = attribute.names()?.remove(0);
+                    let attribute_values =
+                        self.get_credential_values_for_attribute(&credential.values, &name)
+                            .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("Credential value not found for attribute {:?}", name)))?;
+
+                    requested_proof.revealed_attrs.insert(attr_info.attr_referent,
+                                                          RevealedAttributeInfo {
+                                                              sub_proof_index,
+                                                              raw: attribute_values.raw,
+                                                              encoded: attribute_values.encoded,
+                                                          });
+                }
             } else {
                 requested_proof.unrevealed_attrs.insert(attr_info.attr_referent, SubProofReferent { sub_proof_index });
             }
@@ -424,12 +830,14 @@ impl Prover {
 
         for attr in req_attrs_for_credential {
             if attr.revealed {
-                sub_proof_request_builder.add_revealed_attr(&attr_common_view(&attr.attr_info.name))?
+                for name in attr.attr_info.names()? {
+                    sub_proof_request_builder.add_revealed_attr(&attr_common_view(&name))?
+                }
             }
         }
 
         for predicate in req_predicates_for_credential {
-            sub_proof_request_builder.add_predicate(&attr_common_view(&predicate.predicate_info.name), "GE", predicate.predicate_info.p_value)?;
+            sub_proof_request_builder.add_predicate(&attr_common_view(&predicate.predicate_info.name), predicate_type_to_crypto(&predicate.predicate_info.p_type), predicate.predicate_info.p_value)?;
         }
 
         let sub_proof_request = sub_proof_request_builder.finalize()?;
@@ -536,6 +944,26 @@ mod tests {
         }
     }
 
+    mod check_user_credential_tags {
+        use super::*;
+
+        #[test]
+        fn check_user_credential_tags_works() {
+            let ps = Prover::new();
+            ps.check_user_credential_tags(&vec!["favorite".to_string(), "~work".to_string(), "attr".to_string()]).unwrap();
+        }
+
+        #[test]
+        fn check_user_credential_tags_works_for_system_tags() {
+            let ps = Prover::new();
+
+            for tag_name in &["schema_id", "~rev_reg_id", "attr::name::value", "~attr::age::marker", "~"] {
+                let res = ps.check_user_credential_tags(&vec![tag_name.to_string()]);
+                assert_kind!(IndyErrorKind::InvalidStructure, res);
+            }
+        }
+    }
+
     mod build_query {
         use super::*;
 
@@ -549,7 +977,7 @@ mod tests {
         #[test]
         fn build_query_works() {
             let ps = Prover::new();
-            let query = ps.build_query(ATTR_NAME, ATTR_REFERENT, &None, &None).unwrap();
+            let query = ps.build_query(&[ATTR_NAME.to_string()], ATTR_REFERENT, &None, &None).unwrap();
             let expected_query = json!({
                 "$and": vec![
                     json!({
@@ -560,12 +988,29 @@ mod tests {
             assert_eq!(expected_query, _value(&query));
         }
 
+        #[test]
+        fn build_query_works_for_attribute_group() {
+            let ps = Prover::new();
+            let query = ps.build_query(&[ATTR_NAME.to_string(), "age".to_string()], ATTR_REFERENT, &None, &None).unwrap();
+            let expected_query = json!({
+                "$and": vec![
+                    json!({
+                        "attr::name::marker": ATTRIBUTE_EXISTENCE_MARKER
+                    }),
+                    json!({
+                        "attr::age::marker": ATTRIBUTE_EXISTENCE_MARKER
+                    })
+                ]
+            });
+            assert_eq!(expected_query, _value(&query));
+        }
+
         #[test]
         fn build_query_works_for_restriction() {
             let ps = Prover::new();
 
             let restriction = json!({"schema_id": SCHEMA_ID, "cred_def_id": CRED_DEF_ID});
-            let query = ps.build_query(ATTR_NAME, ATTR_REFERENT, &Some(restriction), &None).unwrap();
+            let query = ps.build_query(&[ATTR_NAME.to_string()], ATTR_REFERENT, &Some(restriction), &None).unwrap();
 
             let expected_query = json!({
                 "$and": vec![
@@ -593,7 +1038,7 @@ mod tests {
                     )
             );
 
-            let query = ps.build_query(ATTR_NAME, ATTR_REFERENT, &None, &Some(&extra_query)).unwrap();
+            let query = ps.build_query(&[ATTR_NAME.to_string()], ATTR_REFERENT, &None, &Some(&extra_query)).unwrap();
 
             let expected_query = json!({
                 "$and": vec![
@@ -622,7 +1067,7 @@ mod tests {
                     )
             );
 
-            let query = ps.build_query(ATTR_NAME, ATTR_REFERENT, &Some(restriction), &Some(&extra_query)).unwrap();
+            let query = ps.build_query(&[ATTR_NAME.to_string()], ATTR_REFERENT, &Some(restriction), &Some(&extra_query)).unwrap();
 
             let expected_query = json!({
                 "$and": vec![
@@ -650,7 +1095,7 @@ mod tests {
             let restriction_2 = json!({"cred_def_id": CRED_DEF_ID});
             let restirctions = serde_json::Value::Array(vec![restriction_1, restriction_2]);
 
-            let query = ps.build_query(ATTR_NAME, ATTR_REFERENT, &Some(restirctions), &None).unwrap();
+            let query = ps.build_query(&[ATTR_NAME.to_string()], ATTR_REFERENT, &Some(restirctions), &None).unwrap();
 
             let expected_query = json!({
                 "$and": vec![
@@ -682,7 +1127,7 @@ mod tests {
             let restriction_2 = json!({"schema_id":  serde_json::Value::Null, "cred_def_id": CRED_DEF_ID});
             let restirctions = serde_json::Value::Array(vec![restriction_1, restriction_2]);
 
-            let query = ps.build_query(ATTR_NAME, ATTR_REFERENT, &Some(restirctions), &None).unwrap();
+            let query = ps.build_query(&[ATTR_NAME.to_string()], ATTR_REFERENT, &Some(restirctions), &None).unwrap();
 
             let expected_query = json!({
                 "$and": vec![
@@ -716,7 +1161,7 @@ mod tests {
                     )
             );
 
-            let query = ps.build_query(ATTR_NAME, ATTR_REFERENT, &None, &Some(&extra_query)).unwrap();
+            let query = ps.build_query(&[ATTR_NAME.to_string()], ATTR_REFERENT, &None, &Some(&extra_query)).unwrap();
 
             let expected_query = json!({
                 "$and": vec![
@@ -751,7 +1196,7 @@ mod tests {
                     )
             );
 
-            let query = ps.build_query(ATTR_NAME, ATTR_REFERENT, &Some(restriction), &Some(&extra_query)).unwrap();
+            let query = ps.build_query(&[ATTR_NAME.to_string()], ATTR_REFERENT, &Some(restriction), &Some(&extra_query)).unwrap();
 
             let expected_query = json!({
                 "$and": [
@@ -781,9 +1226,13 @@ mod tests {
         use super::*;
 
         fn predicate_info() -> PredicateInfo {
+            predicate_info_for_type(PredicateTypes::GE)
+        }
+
+        fn predicate_info_for_type(p_type: PredicateTypes) -> PredicateInfo {
             PredicateInfo {
                 name: "age".to_string(),
-                p_type: PredicateTypes::GE,
+                p_type,
                 p_value: 8,
                 restrictions: None,
                 non_revoked: None,
@@ -810,6 +1259,27 @@ mod tests {
             let res = ps.attribute_satisfy_predicate(&predicate_info(), "string");
             assert_kind!(IndyErrorKind::InvalidStructure, res);
         }
+
+        #[test]
+        fn attribute_satisfy_predicate_works_for_le() {
+            let ps = Prover::new();
+            assert!(ps.attribute_satisfy_predicate(&predicate_info_for_type(PredicateTypes::LE), "8").unwrap());
+            assert!(!ps.attribute_satisfy_predicate(&predicate_info_for_type(PredicateTypes::LE), "9").unwrap());
+        }
+
+        #[test]
+        fn attribute_satisfy_predicate_works_for_gt() {
+            let ps = Prover::new();
+            assert!(ps.attribute_satisfy_predicate(&predicate_info_for_type(PredicateTypes::GT), "9").unwrap());
+            assert!(!ps.attribute_satisfy_predicate(&predicate_info_for_type(PredicateTypes::GT), "8").unwrap());
+        }
+
+        #[test]
+        fn attribute_satisfy_predicate_works_for_lt() {
+            let ps = Prover::new();
+            assert!(ps.attribute_satisfy_predicate(&predicate_info_for_type(PredicateTypes::LT), "7").unwrap());
+            assert!(!ps.attribute_satisfy_predicate(&predicate_info_for_type(PredicateTypes::LT), "8").unwrap());
+        }
     }
 
     mod prepare_credentials_for_proving {
@@ -824,7 +1294,8 @@ mod tests {
 
         fn _attr_info() -> AttributeInfo {
             AttributeInfo {
-                name: "name".to_string(),
+                name: Some("name".to_string()),
+                names: None,
                 restrictions: None,
                 non_revoked: None,
             }
@@ -852,6 +1323,7 @@ mod tests {
                     PREDICATE_REFERENT.to_string() => _predicate_info()
                 ),
                 non_revoked: None,
+                ver: None,
             }
         }
 
@@ -899,7 +1371,8 @@ mod tests {
             });
 
             proof_req.requested_attributes.insert("attribute_referent_2".to_string(), AttributeInfo {
-                name: "last_name".to_string(),
+                name: Some("last_name".to_string()),
+                names: None,
                 restrictions: None,
                 non_revoked: None,
             });
@@ -1002,4 +1475,137 @@ mod tests {
             assert_eq!(_attr_values(), res);
         }
     }
+
+    mod validate_proof_request {
+        use super::*;
+
+        fn _proof_req(json: serde_json::Value) -> ProofRequest {
+            serde_json::from_value(json).unwrap()
+        }
+
+        #[test]
+        fn validate_proof_request_works_for_valid() {
+            let ps = Prover::new();
+            let proof_req = _proof_req(json!({
+                "nonce": "123432421212",
+                "name": "proof_req_1",
+                "version": "0.1",
+                "requested_attributes": {
+                    "attr1_referent": {"name": "name", "restrictions": [{"schema_name": "gvt"}, {"issuer_did": {"$in": ["did1", "did2"]}}]},
+                    "attr2_referent": {"names": ["name", "sex"], "non_revoked": {"to": 100}}
+                },
+                "requested_predicates": {
+                    "predicate1_referent": {"name": "age", "p_type": ">=", "p_value": 18, "restrictions": {"attr::age::value": "28"}}
+                }
+            }));
+
+            assert!(ps.validate_proof_request(&proof_req).is_empty());
+        }
+
+        #[test]
+        fn validate_proof_request_works_for_empty_request() {
+            let ps = Prover::new();
+            let proof_req = _proof_req(json!({
+                "nonce": "123432421212",
+                "name": "proof_req_1",
+                "version": "0.1",
+                "requested_attributes": {},
+                "requested_predicates": {}
+            }));
+
+            assert_eq!(1, ps.validate_proof_request(&proof_req).len());
+            assert!(ps.check_proof_request_structure(&proof_req).is_empty());
+        }
+
+        #[test]
+        fn validate_proof_request_works_for_invalid_attributes_and_predicates() {
+            let ps = Prover::new();
+            let proof_req = _proof_req(json!({
+                "nonce": "123432421212",
+                "name": "proof_req_1",
+                "version": "0.1",
+                "requested_attributes": {
+                    "attr1_referent": {"name": "name", "names": ["sex"]},
+                    "attr2_referent": {"names": ["name", "Name"]},
+                    "attr3_referent": {"name": "name", "restrictions": {"schema_nam": "gvt"}}
+                },
+                "requested_predicates": {
+                    "predicate1_referent": {"name": "age", "p_type": ">=", "p_value": 18, "restrictions": {"attr::age::value": "old"}}
+                },
+                "non_revoked": {"from": 200, "to": 100}
+            }));
+
+            assert_eq!(5, ps.validate_proof_request(&proof_req).len());
+        }
+
+        #[test]
+        fn validate_proof_request_works_for_invalid_restriction_operators() {
+            let ps = Prover::new();
+            let proof_req = _proof_req(json!({
+                "nonce": "123432421212",
+                "name": "proof_req_1",
+                "version": "0.1",
+                "requested_attributes": {
+                    "attr1_referent": {"name": "name", "restrictions": {"$or": {"schema_name": "gvt"}}},
+                    "attr2_referent": {"name": "name", "restrictions": {"issuer_did": {"$in": "did1"}}},
+                    "attr3_referent": {"name": "name", "restrictions": ["schema_name"]}
+                },
+                "requested_predicates": {}
+            }));
+
+            assert_eq!(3, ps.validate_proof_request(&proof_req).len());
+        }
+
+        #[test]
+        fn validate_proof_request_json_works_for_valid() {
+            let ps = Prover::new();
+            let proof_req = json!({
+                "nonce": "123432421212",
+                "name": "proof_req_1",
+                "version": "0.1",
+                "requested_attributes": {"attr1_referent": {"name": "name"}},
+                "requested_predicates": {}
+            });
+
+            assert!(ps.validate_proof_request_json(&proof_req.to_string()).is_empty());
+        }
+
+        #[test]
+        fn validate_proof_request_json_works_for_missing_nonce() {
+            let ps = Prover::new();
+            let proof_req = json!({
+                "name": "proof_req_1",
+                "version": "",
+                "requested_attributes": {"attr1_referent": {"name": "name"}},
+                "requested_predicates": {}
+            });
+
+            let problems = ps.validate_proof_request_json(&proof_req.to_string());
+            assert_eq!(vec!["Proof request \"nonce\" is missing".to_string(), "Proof request \"version\" is empty".to_string()], problems);
+        }
+
+        #[test]
+        fn validate_proof_request_json_works_for_invalid_nonce() {
+            let ps = Prover::new();
+
+            for nonce in vec![json!(""), json!("12a"), json!(123)] {
+                let proof_req = json!({
+                    "nonce": nonce,
+                    "name": "proof_req_1",
+                    "version": "0.1",
+                    "requested_attributes": {"attr1_referent": {"name": "name"}},
+                    "requested_predicates": {}
+                });
+
+                assert_eq!(1, ps.validate_proof_request_json(&proof_req.to_string()).len());
+            }
+        }
+
+        #[test]
+        fn validate_proof_request_json_works_for_invalid_json() {
+            let ps = Prover::new();
+            assert_eq!(1, ps.validate_proof_request_json("[]").len());
+            assert_eq!(1, ps.validate_proof_request_json("{").len());
+        }
+    }
 }
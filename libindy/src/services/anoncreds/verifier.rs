@@ -1,21 +1,34 @@
 extern crate indy_crypto;
 
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 
+use domain::anoncreds::DELIMITER;
 use domain::anoncreds::credential_definition::CredentialDefinitionV1 as CredentialDefinition;
-use domain::anoncreds::proof::{Proof, RequestedProof};
-use domain::anoncreds::proof_request::{AttributeInfo, PredicateInfo, ProofRequest};
+use domain::anoncreds::proof::{Identifier, Proof, ProofVerificationReport, ReferentReport, RequestedProof, RevealedAttributeGroupInfo, RevocationCheckStatus, SubProofReport};
+use domain::anoncreds::proof_request::{AttributeInfo, NonRevocedInterval, PredicateInfo, ProofRequest};
 use domain::anoncreds::revocation_registry::RevocationRegistryV1;
 use domain::anoncreds::revocation_registry_definition::RevocationRegistryDefinitionV1;
-use domain::anoncreds::schema::SchemaV1;
+use domain::anoncreds::schema::{AttributeTypes, SchemaV1};
 use errors::prelude::*;
 use services::anoncreds::helpers::*;
+use utils::qualifier;
+
+use serde_json;
 
 use self::indy_crypto::cl::CredentialPublicKey;
 use self::indy_crypto::cl::verifier::Verifier as CryptoVerifier;
 
 pub struct Verifier {}
 
+#[derive(Debug)]
+struct ReferentCheck {
+    referent: String,
+    sub_proof_index: Option<i32>,
+    restrictions_error: Option<String>,
+    non_revoked_error: Option<String>,
+    value_error: Option<String>
+}
+
 impl Verifier {
     pub fn new() -> Verifier {
         Verifier {}
@@ -31,12 +44,153 @@ impl Verifier {
         trace!("verify >>> full_proof: {:?}, proof_req: {:?}, schemas: {:?}, cred_defs: {:?}, rev_reg_defs: {:?} rev_regs: {:?}",
                full_proof, proof_req, schemas, cred_defs, rev_reg_defs, rev_regs);
 
+        let referent_checks = Verifier::_check_referents(full_proof, proof_req, schemas)?;
+
+        if let Some(error) = referent_checks.iter().filter_map(|check| check.restrictions_error.clone().or(check.non_revoked_error.clone())).next() {
+            return Err(err_msg(IndyErrorKind::ProofRejected, error));
+        }
+
+        if referent_checks.iter().any(|check| check.value_error.is_some()) {
+            trace!("verify <<< valid: false, revealed raw values do not match encoded values");
+            return Ok(false);
+        }
+
+        let valid = Verifier::_verify_crypto_proof(full_proof, proof_req, schemas, cred_defs, rev_reg_defs, rev_regs)?;
+
+        trace!("verify <<< valid: {:?}", valid);
+
+        Ok(valid)
+    }
+
+    /// Verifies the proof as `verify` does, but instead of failing on the first violation
+    /// collects results of all checks by sub proofs and referents.
+    /// `valid` of the report is true exactly when `verify_proof` command returns `Ok(true)`,
+    /// so it also requires referents of the proof to match requested ones.
+    pub fn verify_verbose(&self,
+                          full_proof: &Proof,
+                          proof_req: &ProofRequest,
+                          schemas: &HashMap<String, SchemaV1>,
+                          cred_defs: &HashMap<String, CredentialDefinition>,
+                          rev_reg_defs: &HashMap<String, RevocationRegistryDefinitionV1>,
+                          rev_regs: &HashMap<String, HashMap<u64, RevocationRegistryV1>>) -> IndyResult<ProofVerificationReport> {
+        trace!("verify_verbose >>> full_proof: {:?}, proof_req: {:?}, schemas: {:?}, cred_defs: {:?}, rev_reg_defs: {:?} rev_regs: {:?}",
+               full_proof, proof_req, schemas, cred_defs, rev_reg_defs, rev_regs);
+
+        let referent_checks = Verifier::_check_referents(full_proof, proof_req, schemas)?;
+        let proof_verified = Verifier::_verify_crypto_proof(full_proof, proof_req, schemas, cred_defs, rev_reg_defs, rev_regs)?;
+
+        let mut sub_proofs: Vec<SubProofReport> = Vec::new();
+
+        for (sub_proof_index, identifier) in full_proof.identifiers.iter().enumerate() {
+            sub_proofs.push(SubProofReport {
+                sub_proof_index,
+                identifier: identifier.clone(),
+                referents: Vec::new(),
+                revocation: Verifier::_revocation_status(identifier, sub_proof_index, &referent_checks, proof_verified)
+            });
+        }
+
+        let mut self_attested_attrs: Vec<ReferentReport> = Vec::new();
+
+        for check in referent_checks {
+            let error = check.restrictions_error.or(check.non_revoked_error).or(check.value_error);
+
+            match check.sub_proof_index {
+                Some(sub_proof_index) => {
+                    let sub_proof = sub_proofs.get_mut(sub_proof_index as usize)
+                        .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("Identifier not found for index: {:?}", sub_proof_index)))?;
+
+                    sub_proof.referents.push(ReferentReport { referent: check.referent, verified: proof_verified && error.is_none(), error });
+                }
+                None => self_attested_attrs.push(ReferentReport { referent: check.referent, verified: error.is_none(), error })
+            }
+        }
+
+        sub_proofs.iter_mut().for_each(|sub_proof| sub_proof.referents.sort_by(|a, b| a.referent.cmp(&b.referent)));
+        self_attested_attrs.sort_by(|a, b| a.referent.cmp(&b.referent));
+
+        let (missing_referents, unexpected_referents) = Verifier::_find_unmatched_referents(full_proof, proof_req);
+
+        let valid = proof_verified &&
+            missing_referents.is_empty() &&
+            unexpected_referents.is_empty() &&
+            sub_proofs.iter().all(|sub_proof| sub_proof.referents.iter().all(|referent| referent.verified)) &&
+            self_attested_attrs.iter().all(|referent| referent.verified);
+
+        let report = ProofVerificationReport {
+            valid,
+            proof_verified,
+            sub_proofs,
+            self_attested_attrs,
+            missing_referents,
+            unexpected_referents
+        };
+
+        trace!("verify_verbose <<< report: {:?}", report);
+
+        Ok(report)
+    }
+
+    /// Revocation status is derived from the identifier of the sub proof, non_revoked intervals requested
+    /// for its referents and the result of CL proof verification. Non-revocation proof can't be verified
+    /// apart from the rest of CL proof, so status is unknown when CL proof isn't verified.
+    fn _revocation_status(identifier: &Identifier, sub_proof_index: usize, referent_checks: &[ReferentCheck], proof_verified: bool) -> RevocationCheckStatus {
+        if identifier.rev_reg_id.is_none() {
+            return RevocationCheckStatus::NotApplicable;
+        }
+
+        let interval_violated = referent_checks.iter()
+            .any(|check| check.sub_proof_index == Some(sub_proof_index as i32) && check.non_revoked_error.is_some());
+
+        if identifier.timestamp.is_none() || interval_violated {
+            RevocationCheckStatus::Failed
+        } else if !proof_verified {
+            RevocationCheckStatus::Unknown
+        } else {
+            RevocationCheckStatus::Passed
+        }
+    }
+
+    fn _find_unmatched_referents(full_proof: &Proof, proof_req: &ProofRequest) -> (Vec<String>, Vec<String>) {
+        let requested_proof = &full_proof.requested_proof;
+
+        let requested: BTreeSet<&String> = proof_req.requested_attributes.keys()
+            .chain(proof_req.requested_predicates.keys())
+            .collect();
+
+        let received: BTreeSet<&String> = requested_proof.revealed_attrs.keys()
+            .chain(requested_proof.revealed_attr_groups.keys())
+            .chain(requested_proof.unrevealed_attrs.keys())
+            .chain(requested_proof.self_attested_attrs.keys())
+            .chain(requested_proof.predicates.keys())
+            .collect();
+
+        let missing = requested.difference(&received).map(|referent| referent.to_string()).collect();
+        let unexpected = received.difference(&requested).map(|referent| referent.to_string()).collect();
+
+        (missing, unexpected)
+    }
+
+    fn _verify_crypto_proof(full_proof: &Proof,
+                            proof_req: &ProofRequest,
+                            schemas: &HashMap<String, SchemaV1>,
+                            cred_defs: &HashMap<String, CredentialDefinition>,
+                            rev_reg_defs: &HashMap<String, RevocationRegistryDefinitionV1>,
+                            rev_regs: &HashMap<String, HashMap<u64, RevocationRegistryV1>>) -> IndyResult<bool> {
         let mut proof_verifier = CryptoVerifier::new_proof_verifier()?;
         let non_credential_schema = build_non_credential_schema()?;
 
         for sub_proof_index in 0..full_proof.identifiers.len() {
             let identifier = full_proof.identifiers[sub_proof_index].clone();
 
+            if !proof_req.expects_qualified_identifiers() &&
+                [Some(&identifier.schema_id), Some(&identifier.cred_def_id), identifier.rev_reg_id.as_ref()]
+                    .iter()
+                    .any(|id| id.map(|id| qualifier::is_fully_qualified(id)).unwrap_or(false)) {
+                return Err(err_msg(IndyErrorKind::InvalidStructure,
+                                   format!("Proof contains fully qualified identifiers but proof request of version 1.0 expects unqualified: {:?}", identifier)));
+            }
+
             let schema: &SchemaV1 = schemas.get(&identifier.schema_id)
                 .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("Schema not found for id: {:?}", identifier.schema_id)))?;
 
@@ -85,11 +239,352 @@ impl Verifier {
 
         let valid = proof_verifier.verify(&full_proof.proof, &proof_req.nonce)?;
 
-        trace!("verify <<< valid: {:?}", valid);
-
         Ok(valid)
     }
 
+    /// Checks restrictions, non_revoked intervals and revealed values for each requested referent the proof contains.
+    fn _check_referents(full_proof: &Proof,
+                        proof_req: &ProofRequest,
+                        schemas: &HashMap<String, SchemaV1>) -> IndyResult<Vec<ReferentCheck>> {
+        trace!("_check_referents >>> full_proof: {:?}, proof_req: {:?}, schemas: {:?}", full_proof, proof_req, schemas);
+
+        let requested_proof = &full_proof.requested_proof;
+
+        let crypto_proof = serde_json::to_value(&full_proof.proof)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize Proof")?;
+
+        let mut checks: Vec<ReferentCheck> = Vec::new();
+
+        for (attr_referent, attr_info) in proof_req.requested_attributes.iter() {
+            let non_revoked = attr_info.non_revoked.as_ref().or(proof_req.non_revoked.as_ref());
+
+            if let Some(revealed_attr) = requested_proof.revealed_attrs.get(attr_referent) {
+                checks.push(ReferentCheck {
+                    referent: attr_referent.clone(),
+                    sub_proof_index: Some(revealed_attr.sub_proof_index),
+                    restrictions_error: Verifier::_check_referent_restrictions(attr_referent, &attr_info.restrictions,
+                                                                               revealed_attr.sub_proof_index, proof_req, full_proof, schemas)?,
+                    non_revoked_error: Verifier::_check_non_revoked_interval(attr_referent, non_revoked,
+                                                                             Verifier::_get_identifier(full_proof, revealed_attr.sub_proof_index)?),
+                    value_error: if attr_info.is_group() {
+                        Some(format!("Attribute group is revealed as single attribute for referent: {:?}", attr_referent))
+                    } else {
+                        Verifier::_check_revealed_attribute_value(attr_referent, &attr_info.names()?.remove(0), revealed_attr.sub_proof_index,
+                                                                  &revealed_attr.raw, &revealed_attr.encoded, full_proof, schemas, &crypto_proof)?
+                    }
+                });
+            } else if let Some(revealed_attr_group) = requested_proof.revealed_attr_groups.get(attr_referent) {
+                checks.push(ReferentCheck {
+                    referent: attr_referent.clone(),
+                    sub_proof_index: Some(revealed_attr_group.sub_proof_index),
+                    restrictions_error: Verifier::_check_referent_restrictions(attr_referent, &attr_info.restrictions,
+                                                                               revealed_attr_group.sub_proof_index, proof_req, full_proof, schemas)?,
+                    non_revoked_error: Verifier::_check_non_revoked_interval(attr_referent, non_revoked,
+                                                                             Verifier::_get_identifier(full_proof, revealed_attr_group.sub_proof_index)?),
+                    value_error: Verifier::_check_revealed_attribute_group(attr_referent, attr_info, revealed_attr_group, full_proof, schemas, &crypto_proof)?
+                });
+            } else if let Some(unrevealed_attr) = requested_proof.unrevealed_attrs.get(attr_referent) {
+                checks.push(ReferentCheck {
+                    referent: attr_referent.clone(),
+                    sub_proof_index: Some(unrevealed_attr.sub_proof_index),
+                    restrictions_error: Verifier::_check_referent_restrictions(attr_referent, &attr_info.restrictions,
+                                                                               unrevealed_attr.sub_proof_index, proof_req, full_proof, schemas)?,
+                    non_revoked_error: Verifier::_check_non_revoked_interval(attr_referent, non_revoked,
+                                                                             Verifier::_get_identifier(full_proof, unrevealed_attr.sub_proof_index)?),
+                    value_error: None
+                });
+            } else if requested_proof.self_attested_attrs.contains_key(attr_referent) {
+                checks.push(ReferentCheck {
+                    referent: attr_referent.clone(),
+                    sub_proof_index: None,
+                    restrictions_error: attr_info.restrictions.as_ref()
+                        .map(|_| format!("Attribute with restrictions is self-attested for referent: {:?}", attr_referent)),
+                    non_revoked_error: None,
+                    value_error: None
+                });
+            }
+        }
+
+        for (predicate_referent, predicate_info) in proof_req.requested_predicates.iter() {
+            if let Some(predicate) = requested_proof.predicates.get(predicate_referent) {
+                checks.push(ReferentCheck {
+                    referent: predicate_referent.clone(),
+                    sub_proof_index: Some(predicate.sub_proof_index),
+                    restrictions_error: Verifier::_check_referent_restrictions(predicate_referent,
+                                                                               &predicate_info.restrictions,
+                                                                               predicate.sub_proof_index,
+                                                                               proof_req,
+                                                                               full_proof,
+                                                                               schemas)?,
+                    non_revoked_error: Verifier::_check_non_revoked_interval(predicate_referent,
+                                                                             predicate_info.non_revoked.as_ref().or(proof_req.non_revoked.as_ref()),
+                                                                             Verifier::_get_identifier(full_proof, predicate.sub_proof_index)?),
+                    value_error: None
+                });
+            }
+        }
+
+        trace!("_check_referents <<< checks: {:?}", checks);
+
+        Ok(checks)
+    }
+
+    fn _get_identifier(full_proof: &Proof, sub_proof_index: i32) -> IndyResult<&Identifier> {
+        full_proof.identifiers.get(sub_proof_index as usize)
+            .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("Identifier not found for index: {:?}", sub_proof_index)))
+    }
+
+    fn _check_referent_restrictions(referent: &str,
+                                    restrictions: &Option<serde_json::Value>,
+                                    sub_proof_index: i32,
+                                    proof_req: &ProofRequest,
+                                    full_proof: &Proof,
+                                    schemas: &HashMap<String, SchemaV1>) -> IndyResult<Option<String>> {
+        let identifier = Verifier::_get_identifier(full_proof, sub_proof_index)?;
+
+        if let Some(ref restrictions) = *restrictions {
+            let tags = Verifier::_build_identifier_tags(identifier, sub_proof_index, proof_req, &full_proof.requested_proof, schemas)?;
+
+            if !Verifier::_process_restrictions(restrictions, &tags)? {
+                return Ok(Some(format!("Identifier {:?} doesn't satisfy restrictions {} for referent: {:?}", identifier, restrictions, referent)));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn _check_non_revoked_interval(referent: &str,
+                                   non_revoked: Option<&NonRevocedInterval>,
+                                   identifier: &Identifier) -> Option<String> {
+        let interval = non_revoked?;
+
+        match identifier.timestamp {
+            Some(timestamp) if interval.from.map(|from| timestamp < from).unwrap_or(false) ||
+                interval.to.map(|to| timestamp > to).unwrap_or(false) =>
+                Some(format!("Timestamp {:?} is out of non_revoked interval {:?} for referent: {:?}", timestamp, interval, referent)),
+            None if identifier.rev_reg_id.is_some() =>
+                Some(format!("Timestamp not found but non_revoked interval {:?} is requested for referent: {:?}", interval, referent)),
+            _ => None
+        }
+    }
+
+    /// Checks that all attributes of the group are revealed together by one sub proof.
+    fn _check_revealed_attribute_group(referent: &str,
+                                       attr_info: &AttributeInfo,
+                                       revealed_attr_group: &RevealedAttributeGroupInfo,
+                                       full_proof: &Proof,
+                                       schemas: &HashMap<String, SchemaV1>,
+                                       crypto_proof: &serde_json::Value) -> IndyResult<Option<String>> {
+        if !attr_info.is_group() {
+            return Ok(Some(format!("Single attribute is revealed as attribute group for referent: {:?}", referent)));
+        }
+
+        let names = attr_info.names()?;
+
+        if names.len() != revealed_attr_group.values.len() || names.iter().any(|name| !revealed_attr_group.values.contains_key(name)) {
+            return Ok(Some(format!("Revealed attributes {:?} don't correspond to requested group {:?} for referent: {:?}",
+                                   revealed_attr_group.values.keys().collect::<Vec<&String>>(), names, referent)));
+        }
+
+        for (name, value) in revealed_attr_group.values.iter() {
+            let error = Verifier::_check_revealed_attribute_value(referent, name, revealed_attr_group.sub_proof_index,
+                                                                  &value.raw, &value.encoded, full_proof, schemas, crypto_proof)?;
+            if error.is_some() {
+                return Ok(error);
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Checks that revealed raw value is encoded according to attribute type in schema
+    /// and the encoded value is the one proven by CL proof.
+    fn _check_revealed_attribute_value(referent: &str,
+                                       attr_name: &str,
+                                       sub_proof_index: i32,
+                                       raw: &str,
+                                       encoded: &str,
+                                       full_proof: &Proof,
+                                       schemas: &HashMap<String, SchemaV1>,
+                                       crypto_proof: &serde_json::Value) -> IndyResult<Option<String>> {
+        let attr_types = Verifier::_get_attribute_types(sub_proof_index, full_proof, schemas)?;
+
+        match encode_typed_credential_attribute(raw, get_attribute_type(attr_types, attr_name)) {
+            Ok(ref expected) if expected == encoded => {}
+            _ => return Ok(Some(format!("Encoded value doesn't match raw value of attribute {:?} for referent: {:?}", attr_name, referent)))
+        }
+
+        let proven_value = crypto_proof["proofs"][sub_proof_index as usize]["primary_proof"]["eq_proof"]["revealed_attrs"][&attr_common_view(attr_name)]
+            .as_str();
+
+        if proven_value != Some(encoded) {
+            return Ok(Some(format!("Encoded value doesn't match proven value of attribute {:?} for referent: {:?}", attr_name, referent)));
+        }
+
+        Ok(None)
+    }
+
+    fn _get_attribute_types<'a>(sub_proof_index: i32,
+                                full_proof: &Proof,
+                                schemas: &'a HashMap<String, SchemaV1>) -> IndyResult<Option<&'a AttributeTypes>> {
+        let identifier = Verifier::_get_identifier(full_proof, sub_proof_index)?;
+
+        let schema: &SchemaV1 = schemas.get(&identifier.schema_id)
+            .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("Schema not found for id: {:?}", identifier.schema_id)))?;
+
+        Ok(schema.attr_types.as_ref())
+    }
+
+    /// Builds the same tags as Prover stores for the credential, so restrictions are evaluated
+    /// the way Prover searched for it. Values are known only for revealed attributes.
+    fn _build_identifier_tags(identifier: &Identifier,
+                              sub_proof_index: i32,
+                              proof_req: &ProofRequest,
+                              requested_proof: &RequestedProof,
+                              schemas: &HashMap<String, SchemaV1>) -> IndyResult<HashMap<String, String>> {
+        let schema_id = qualifier::unqualify(&identifier.schema_id);
+        let cred_def_id = qualifier::unqualify(&identifier.cred_def_id);
+        let schema_parts: Vec<&str> = schema_id.split_terminator(DELIMITER).collect();
+
+        let mut tags: HashMap<String, String> = HashMap::new();
+        tags.insert("schema_id".to_string(), schema_id.clone());
+        tags.insert("schema_issuer_did".to_string(), schema_parts.get(0).cloned().unwrap_or("").to_string());
+        tags.insert("schema_name".to_string(), schema_parts.get(2).cloned().unwrap_or("").to_string());
+        tags.insert("schema_version".to_string(), schema_parts.get(3).cloned().unwrap_or("").to_string());
+        tags.insert("issuer_did".to_string(), cred_def_id.split_terminator(DELIMITER).next().unwrap_or("").to_string());
+        tags.insert("cred_def_id".to_string(), cred_def_id.clone());
+        tags.insert("rev_reg_id".to_string(), identifier.rev_reg_id.as_ref().map(|id| qualifier::unqualify(id)).unwrap_or("None".to_string()));
+
+        let schema: &SchemaV1 = schemas.get(&identifier.schema_id)
+            .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("Schema not found for id: {:?}", identifier.schema_id)))?;
+
+        for attr in schema.attr_names.iter() {
+            tags.insert(format!("attr::{}::marker", attr_common_view(attr)), ATTRIBUTE_EXISTENCE_MARKER.to_string());
+        }
+
+        for (attr_referent, attr_info) in requested_proof.revealed_attrs.iter() {
+            if attr_info.sub_proof_index != sub_proof_index {
+                continue;
+            }
+
+            if let Some(name) = proof_req.requested_attributes.get(attr_referent).and_then(|attr| attr.name.as_ref()) {
+                tags.insert(format!("attr::{}::value", attr_common_view(name)), attr_info.raw.clone());
+            }
+        }
+
+        requested_proof.revealed_attr_groups
+            .values()
+            .filter(|attr_group| attr_group.sub_proof_index == sub_proof_index)
+            .flat_map(|attr_group| attr_group.values.iter())
+            .for_each(|(name, value)| {
+                tags.insert(format!("attr::{}::value", attr_common_view(name)), value.raw.clone());
+            });
+
+        Ok(tags)
+    }
+
+    fn _process_restrictions(restrictions: &serde_json::Value, tags: &HashMap<String, String>) -> IndyResult<bool> {
+        match *restrictions {
+            // Old restrictions format: list of alternatives
+            serde_json::Value::Array(ref array) => {
+                for restriction in array {
+                    if Verifier::_process_operator(restriction, tags)? {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            }
+            serde_json::Value::Object(_) => Verifier::_process_operator(restrictions, tags),
+            _ => Err(err_msg(IndyErrorKind::InvalidStructure, "Restriction is invalid"))
+        }
+    }
+
+    fn _process_operator(restriction: &serde_json::Value, tags: &HashMap<String, String>) -> IndyResult<bool> {
+        let restriction = restriction.as_object()
+            .ok_or(err_msg(IndyErrorKind::InvalidStructure, "Restriction is invalid"))?;
+
+        for (key, value) in restriction.iter() {
+            let res = match key.as_str() {
+                "$and" | "$or" => {
+                    let operators = value.as_array()
+                        .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("Restriction operator {} expects array", key)))?;
+
+                    let mut results = Vec::new();
+                    for operator in operators {
+                        results.push(Verifier::_process_operator(operator, tags)?);
+                    }
+
+                    if key == "$and" { results.iter().all(|res| *res) } else { results.iter().any(|res| *res) }
+                }
+                "$not" => !Verifier::_process_operator(value, tags)?,
+                tag_name => Verifier::_process_tag_operator(tag_name, value, tags)?
+            };
+
+            if !res {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn _process_tag_operator(tag_name: &str, value: &serde_json::Value, tags: &HashMap<String, String>) -> IndyResult<bool> {
+        let tag_value = tags.get(tag_name).map(String::as_str);
+
+        match *value {
+            // Old restrictions format allows nulls which are skipped
+            serde_json::Value::Null => Ok(true),
+            serde_json::Value::String(ref target) => Ok(tag_value == Some(Verifier::_unqualify_tag_value(tag_name, target).as_str())),
+            serde_json::Value::Object(ref operators) => {
+                for (operator, target) in operators.iter() {
+                    let res = match (operator.as_str(), target) {
+                        ("$in", &serde_json::Value::Array(ref targets)) =>
+                            targets.iter()
+                                .filter_map(|target| target.as_str())
+                                .any(|target| tag_value == Some(Verifier::_unqualify_tag_value(tag_name, target).as_str())),
+                        (operator, &serde_json::Value::String(ref target)) => {
+                            let target = Verifier::_unqualify_tag_value(tag_name, target);
+                            let target = target.as_str();
+
+                            match operator {
+                                "$eq" => tag_value.map(|value| value == target),
+                                "$neq" => tag_value.map(|value| value != target),
+                                "$gt" => tag_value.map(|value| value > target),
+                                "$gte" => tag_value.map(|value| value >= target),
+                                "$lt" => tag_value.map(|value| value < target),
+                                "$lte" => tag_value.map(|value| value <= target),
+                                "$like" => tag_value.map(|value| Verifier::_like(value.as_bytes(), target.as_bytes())),
+                                operator => return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Unsupported restriction operator: {}", operator)))
+                            }.unwrap_or(false)
+                        }
+                        (operator, _) => return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid value for restriction operator: {}", operator)))
+                    };
+
+                    if !res {
+                        return Ok(false);
+                    }
+                }
+
+                Ok(true)
+            }
+            _ => Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid restriction for tag: {}", tag_name)))
+        }
+    }
+
+    fn _unqualify_tag_value(tag_name: &str, value: &str) -> String {
+        if IDENTIFIER_TAGS.contains(&tag_name) { qualifier::unqualify(value) } else { value.to_string() }
+    }
+
+    /// Matches value against pattern of `$like` operator, where `%` matches any sequence and `_` any single character.
+    fn _like(value: &[u8], pattern: &[u8]) -> bool {
+        match pattern.split_first() {
+            None => value.is_empty(),
+            Some((&b'%', rest)) => (0..value.len() + 1).any(|skip| Verifier::_like(&value[skip..], rest)),
+            Some((&b'_', rest)) => !value.is_empty() && Verifier::_like(&value[1..], rest),
+            Some((byte, rest)) => value.first() == Some(byte) && Verifier::_like(&value[1..], rest)
+        }
+    }
+
     fn _get_revealed_attributes_for_credential(sub_proof_index: usize,
                                                requested_proof: &RequestedProof,
                                                proof_req: &ProofRequest) -> IndyResult<Vec<AttributeInfo>> {
@@ -102,6 +597,12 @@ impl Verifier {
                 sub_proof_index == revealed_attr_info.sub_proof_index as usize && proof_req.requested_attributes.contains_key(attr_referent))
             .map(|(attr_referent, _)|
                 proof_req.requested_attributes[attr_referent].clone())
+            .chain(requested_proof.revealed_attr_groups
+                .iter()
+                .filter(|&(attr_referent, ref revealed_attr_group)|
+                    sub_proof_index == revealed_attr_group.sub_proof_index as usize && proof_req.requested_attributes.contains_key(attr_referent))
+                .map(|(attr_referent, _)|
+                    proof_req.requested_attributes[attr_referent].clone()))
             .collect::<Vec<AttributeInfo>>();
 
         trace!("_get_revealed_attributes_for_credential <<< revealed_attrs_for_credential: {:?}", revealed_attrs_for_credential);
@@ -128,3 +629,131 @@ impl Verifier {
         Ok(predicates_for_credential)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA_ID: &'static str = "123:2:gvt:1.0";
+    const CRED_DEF_ID: &'static str = "123:3:CL:123:2:gvt:1.0:tag";
+    const REV_REG_ID: &'static str = "123:4:123:3:CL:123:2:gvt:1.0:tag:CL_ACCUM:TAG_1";
+
+    fn _tags() -> HashMap<String, String> {
+        let mut tags = HashMap::new();
+        tags.insert("schema_id".to_string(), SCHEMA_ID.to_string());
+        tags.insert("schema_name".to_string(), "gvt".to_string());
+        tags.insert("issuer_did".to_string(), "123".to_string());
+        tags.insert("cred_def_id".to_string(), CRED_DEF_ID.to_string());
+        tags.insert("attr::name::marker".to_string(), ATTRIBUTE_EXISTENCE_MARKER.to_string());
+        tags.insert("attr::name::value".to_string(), "Alex".to_string());
+        tags
+    }
+
+    #[test]
+    fn process_restrictions_works_for_plain_values() {
+        assert!(Verifier::_process_restrictions(&json!({"schema_id": SCHEMA_ID, "cred_def_id": CRED_DEF_ID}), &_tags()).unwrap());
+        assert!(!Verifier::_process_restrictions(&json!({"schema_id": SCHEMA_ID, "issuer_did": "456"}), &_tags()).unwrap());
+        assert!(!Verifier::_process_restrictions(&json!({"rev_reg_id": "456"}), &_tags()).unwrap());
+    }
+
+    #[test]
+    fn process_restrictions_works_for_old_format() {
+        assert!(Verifier::_process_restrictions(&json!([{"issuer_did": "456"}, {"schema_id": SCHEMA_ID, "issuer_did": null}]), &_tags()).unwrap());
+        assert!(!Verifier::_process_restrictions(&json!([{"issuer_did": "456"}, {"schema_name": "xyz"}]), &_tags()).unwrap());
+    }
+
+    #[test]
+    fn process_restrictions_works_for_operators() {
+        assert!(Verifier::_process_restrictions(&json!({"$or": [{"issuer_did": "456"}, {"attr::name::value": "Alex"}]}), &_tags()).unwrap());
+        assert!(!Verifier::_process_restrictions(&json!({"$and": [{"issuer_did": "456"}, {"attr::name::value": "Alex"}]}), &_tags()).unwrap());
+        assert!(Verifier::_process_restrictions(&json!({"$not": {"issuer_did": "456"}}), &_tags()).unwrap());
+        assert!(Verifier::_process_restrictions(&json!({"issuer_did": {"$in": ["456", "123"]}}), &_tags()).unwrap());
+        assert!(Verifier::_process_restrictions(&json!({"issuer_did": {"$neq": "456"}}), &_tags()).unwrap());
+        assert!(Verifier::_process_restrictions(&json!({"cred_def_id": {"$like": "123:3:CL:%"}}), &_tags()).unwrap());
+        assert!(!Verifier::_process_restrictions(&json!({"schema_name": {"$like": "gv"}}), &_tags()).unwrap());
+    }
+
+    #[test]
+    fn process_restrictions_works_for_qualified_identifiers() {
+        assert!(Verifier::_process_restrictions(&json!({"issuer_did": "did:sov:123", "schema_id": format!("schema:sov:did:sov:{}", SCHEMA_ID)}), &_tags()).unwrap());
+    }
+
+    fn _identifier(rev_reg_id: Option<&str>, timestamp: Option<u64>) -> Identifier {
+        Identifier {
+            schema_id: SCHEMA_ID.to_string(),
+            cred_def_id: CRED_DEF_ID.to_string(),
+            rev_reg_id: rev_reg_id.map(String::from),
+            timestamp
+        }
+    }
+
+    fn _interval(from: Option<u64>, to: Option<u64>) -> NonRevocedInterval {
+        NonRevocedInterval { from, to }
+    }
+
+    fn _referent_check(sub_proof_index: i32, non_revoked_error: Option<String>) -> ReferentCheck {
+        ReferentCheck {
+            referent: "attr1_referent".to_string(),
+            sub_proof_index: Some(sub_proof_index),
+            restrictions_error: None,
+            non_revoked_error,
+            value_error: None
+        }
+    }
+
+    #[test]
+    fn check_non_revoked_interval_works() {
+        let identifier = _identifier(Some(REV_REG_ID), Some(100));
+
+        assert!(Verifier::_check_non_revoked_interval("attr1_referent", None, &identifier).is_none());
+        assert!(Verifier::_check_non_revoked_interval("attr1_referent", Some(&_interval(Some(90), Some(110))), &identifier).is_none());
+        assert!(Verifier::_check_non_revoked_interval("attr1_referent", Some(&_interval(None, Some(100))), &identifier).is_none());
+        assert!(Verifier::_check_non_revoked_interval("attr1_referent", Some(&_interval(Some(101), None)), &identifier).is_some());
+        assert!(Verifier::_check_non_revoked_interval("attr1_referent", Some(&_interval(None, Some(99))), &identifier).is_some());
+    }
+
+    #[test]
+    fn check_non_revoked_interval_works_for_missing_timestamp() {
+        let interval = _interval(None, Some(100));
+
+        assert!(Verifier::_check_non_revoked_interval("attr1_referent", Some(&interval), &_identifier(Some(REV_REG_ID), None)).is_some());
+        assert!(Verifier::_check_non_revoked_interval("attr1_referent", Some(&interval), &_identifier(None, None)).is_none());
+    }
+
+    #[test]
+    fn revocation_status_works_for_not_revocable_identifier() {
+        assert_eq!(RevocationCheckStatus::NotApplicable, Verifier::_revocation_status(&_identifier(None, None), 0, &[_referent_check(0, None)], true));
+    }
+
+    #[test]
+    fn revocation_status_works_for_revocable_identifier() {
+        assert_eq!(RevocationCheckStatus::Passed, Verifier::_revocation_status(&_identifier(Some(REV_REG_ID), Some(100)), 0, &[_referent_check(0, None)], true));
+    }
+
+    #[test]
+    fn revocation_status_works_for_missing_timestamp() {
+        assert_eq!(RevocationCheckStatus::Failed, Verifier::_revocation_status(&_identifier(Some(REV_REG_ID), None), 0, &[], true));
+    }
+
+    #[test]
+    fn revocation_status_works_for_interval_violated_by_own_sub_proof_only() {
+        let checks = [_referent_check(0, None), _referent_check(1, Some("out of interval".to_string()))];
+
+        assert_eq!(RevocationCheckStatus::Passed, Verifier::_revocation_status(&_identifier(Some(REV_REG_ID), Some(100)), 0, &checks, true));
+        assert_eq!(RevocationCheckStatus::Failed, Verifier::_revocation_status(&_identifier(Some(REV_REG_ID), Some(100)), 1, &checks, true));
+    }
+
+    #[test]
+    fn revocation_status_works_for_not_verified_proof() {
+        assert_eq!(RevocationCheckStatus::Unknown, Verifier::_revocation_status(&_identifier(Some(REV_REG_ID), Some(100)), 0, &[_referent_check(0, None)], false));
+        assert_eq!(RevocationCheckStatus::Failed, Verifier::_revocation_status(&_identifier(Some(REV_REG_ID), None), 0, &[], false));
+        assert_eq!(RevocationCheckStatus::NotApplicable, Verifier::_revocation_status(&_identifier(None, None), 0, &[_referent_check(0, None)], false));
+    }
+
+    #[test]
+    fn process_restrictions_works_for_invalid_restrictions() {
+        assert_kind!(IndyErrorKind::InvalidStructure, Verifier::_process_restrictions(&json!("restriction"), &_tags()));
+        assert_kind!(IndyErrorKind::InvalidStructure, Verifier::_process_restrictions(&json!({"$or": {"issuer_did": "123"}}), &_tags()));
+        assert_kind!(IndyErrorKind::InvalidStructure, Verifier::_process_restrictions(&json!({"issuer_did": {"$unknown": "123"}}), &_tags()));
+    }
+}
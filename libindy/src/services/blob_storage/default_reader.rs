@@ -34,14 +34,20 @@ impl ReaderType for DefaultReaderType {
 }
 
 impl Reader for DefaultReaderConfig {
-    fn open(&self, hash: &[u8], _location: &str) -> IndyResult<Box<ReadableBlob>> {
+    fn open(&self, hash: &[u8], _location: &str, _size: usize) -> IndyResult<Box<ReadableBlob>> {
         let mut path = PathBuf::from(&self.base_dir);
         path.push(hash.to_base58());
         let file = File::open(path)?;
-        Ok(Box::new(DefaultReader {
+        Ok(Box::new(DefaultReader::new(file, hash)))
+    }
+}
+
+impl DefaultReader {
+    pub fn new(file: File, hash: &[u8]) -> DefaultReader {
+        DefaultReader {
             file,
             hash: hash.to_owned()
-        }))
+        }
     }
 }
 
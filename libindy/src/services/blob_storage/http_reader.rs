@@ -0,0 +1,322 @@
+extern crate digest;
+extern crate sha2;
+extern crate rust_base58;
+
+use self::digest::{FixedOutput, Input};
+use self::sha2::Sha256;
+use self::rust_base58::ToBase58;
+
+use super::{ReadableBlob, Reader, ReaderType};
+use super::default_reader::DefaultReader;
+use errors::prelude::*;
+
+use serde_json;
+use std::fs;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::path::PathBuf;
+use std::time::Duration;
+
+const CONNECT_TIMEOUT_SEC: u64 = 10;
+const DOWNLOAD_TIMEOUT_SEC: u64 = 60;
+const MAX_HEADERS_SIZE: usize = 8 * 1024;
+
+/// Reader of tails files published by issuer: tails file is downloaded by `tails_location` URL
+/// (only plain http is supported, redirects aren't followed), checked against `tails_hash`
+/// and cached in `base_dir` the same way as `default` reader stores them.
+#[derive(Serialize, Deserialize)]
+struct HttpReaderConfig {
+    base_dir: String,
+}
+
+impl ReaderType for HttpReaderType {
+    fn open(&self, config: &str) -> IndyResult<Box<Reader>> {
+        let config: HttpReaderConfig = serde_json::from_str(config)
+            .to_indy(IndyErrorKind::InvalidStructure, "Can't deserialize HttpReaderConfig")?;
+
+        Ok(Box::new(config))
+    }
+}
+
+impl Reader for HttpReaderConfig {
+    fn open(&self, hash: &[u8], location: &str, size: usize) -> IndyResult<Box<ReadableBlob>> {
+        trace!("open >>> hash: {:?}, location: {:?}, size: {:?}", hash, location, size);
+
+        let mut path = PathBuf::from(&self.base_dir);
+        path.push(hash.to_base58());
+
+        if path.exists() {
+            let mut reader = DefaultReader::new(File::open(&path)?, hash);
+
+            if reader.verify()? {
+                trace!("open <<< cached: {:?}", path);
+                return Ok(Box::new(reader));
+            }
+
+            warn!("Cached tails file {:?} doesn't match tails hash, downloading it again", path);
+        }
+
+        let tails = _download(location, size)?;
+
+        let mut hasher = Sha256::default();
+        hasher.process(&tails);
+
+        if hasher.fixed_result().as_slice() != hash {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Tails file downloaded from {} doesn't match tails hash", location)));
+        }
+
+        fs::DirBuilder::new()
+            .recursive(true)
+            .create(&self.base_dir)
+            .map_err(map_err_trace!(format!("path: {:?}", self.base_dir)))?;
+
+        let tmp_path = path.with_extension("tmp");
+
+        File::create(&tmp_path)
+            .and_then(|mut file| file.write_all(&tails).and_then(|_| file.sync_all()))
+            .map_err(map_err_trace!(format!("path: {:?}", tmp_path)))?;
+
+        fs::rename(&tmp_path, &path)
+            .map_err(map_err_trace!(format!("path: {:?}", path)))?;
+
+        trace!("open <<< downloaded: {:?}", path);
+
+        Ok(Box::new(DefaultReader::new(File::open(&path)?, hash)))
+    }
+}
+
+fn _download(url: &str, size: usize) -> IndyResult<Vec<u8>> {
+    let (host, port, path) = _parse_url(url)?;
+
+    let mut stream = _connect(url, &host, port)?;
+
+    stream.set_read_timeout(Some(Duration::from_secs(DOWNLOAD_TIMEOUT_SEC)))?;
+    stream.set_write_timeout(Some(Duration::from_secs(DOWNLOAD_TIMEOUT_SEC)))?;
+
+    write!(stream, "GET {} HTTP/1.0\r\nHost: {}\r\nConnection: close\r\n\r\n", path, host)
+        .to_indy(IndyErrorKind::IOError, format!("Can't send request to {}", url))?;
+
+    // Response is never read beyond expected tails size, so oversized one is rejected without buffering it all
+    let limit = size + MAX_HEADERS_SIZE;
+
+    let mut response = Vec::new();
+    stream.take(limit as u64 + 1).read_to_end(&mut response)
+        .to_indy(IndyErrorKind::IOError, format!("Can't read response from {}", url))?;
+
+    if response.len() > limit {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Tails file downloaded from {} exceeds expected size {}", url, size)));
+    }
+
+    _parse_response(url, response, size)
+}
+
+fn _connect(url: &str, host: &str, port: u16) -> IndyResult<TcpStream> {
+    let addrs = (host, port).to_socket_addrs()
+        .to_indy(IndyErrorKind::IOError, format!("Can't resolve host of {}", url))?;
+
+    let mut res = Err(err_msg(IndyErrorKind::IOError, format!("Can't resolve host of {}", url)));
+
+    for addr in addrs {
+        res = TcpStream::connect_timeout(&addr, Duration::from_secs(CONNECT_TIMEOUT_SEC))
+            .to_indy(IndyErrorKind::IOError, format!("Can't connect to {}", url));
+
+        if res.is_ok() {
+            break;
+        }
+    }
+
+    res
+}
+
+fn _parse_url(url: &str) -> IndyResult<(String, u16, String)> {
+    if !url.starts_with("http://") {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Unsupported tails location {:?}: only http URLs are supported", url)));
+    }
+
+    let url = &url["http://".len()..];
+
+    let (authority, path) = match url.find('/') {
+        Some(pos) => (&url[..pos], &url[pos..]),
+        None => (url, "/")
+    };
+
+    let (host, port) = match authority.rfind(':') {
+        Some(pos) => (&authority[..pos], authority[pos + 1..].parse::<u16>()
+            .to_indy(IndyErrorKind::InvalidStructure, format!("Invalid port in tails location {:?}", url))?),
+        None => (authority, 80)
+    };
+
+    if host.is_empty() {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Host not found in tails location {:?}", url)));
+    }
+
+    Ok((host.to_string(), port, path.to_string()))
+}
+
+fn _parse_response(url: &str, mut response: Vec<u8>, size: usize) -> IndyResult<Vec<u8>> {
+    let headers_end = response.windows(4).position(|window| window == b"\r\n\r\n")
+        .ok_or(err_msg(IndyErrorKind::IOError, format!("Invalid HTTP response from {}", url)))?;
+
+    let body = response.split_off(headers_end + 4);
+    let headers = String::from_utf8_lossy(&response[..headers_end]).to_lowercase();
+    let headers = headers.split("\r\n").collect::<Vec<&str>>();
+
+    let status = headers.first()
+        .and_then(|status_line| status_line.split_whitespace().nth(1))
+        .ok_or(err_msg(IndyErrorKind::IOError, format!("Invalid HTTP response from {}", url)))?;
+
+    let header = |name: &str| headers[1..].iter()
+        .filter_map(|header| {
+            let mut parts = header.splitn(2, ':');
+            match (parts.next(), parts.next()) {
+                (Some(header_name), Some(value)) if header_name.trim() == name => Some(value.trim()),
+                _ => None
+            }
+        })
+        .next();
+
+    if status.starts_with('3') {
+        return Err(err_msg(IndyErrorKind::IOError, format!("Tails file can't be downloaded from {}: redirects aren't supported (HTTP status {}, location {:?})",
+                                                            url, status, header("location").unwrap_or(""))));
+    }
+
+    if status != "200" {
+        return Err(err_msg(IndyErrorKind::IOError, format!("Tails file can't be downloaded from {}: HTTP status {}", url, status)));
+    }
+
+    if body.len() > size {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Tails file downloaded from {} exceeds expected size {}", url, size)));
+    }
+
+    let content_length = header("content-length").and_then(|value| value.parse::<usize>().ok());
+
+    if content_length.map(|content_length| content_length != body.len()).unwrap_or(false) {
+        return Err(err_msg(IndyErrorKind::IOError, format!("Tails file downloaded from {} is incomplete", url)));
+    }
+
+    Ok(body)
+}
+
+pub struct HttpReaderType {}
+
+impl HttpReaderType {
+    pub fn new() -> Self {
+        HttpReaderType {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::net::TcpListener;
+    use std::thread;
+
+    use utils::environment;
+
+    const TAILS: &'static [u8] = b"tails file content";
+
+    // Local HTTP stand-in that answers the only request
+    fn _serve_once(status: &'static str, body: &'static [u8]) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+
+            let mut request = Vec::new();
+            let mut buf = [0u8; 1024];
+
+            while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+                let size = stream.read(&mut buf).unwrap();
+                if size == 0 { break; }
+                request.extend_from_slice(&buf[..size]);
+            }
+
+            write!(stream, "HTTP/1.0 {}\r\nContent-Length: {}\r\n\r\n", status, body.len()).unwrap();
+            stream.write_all(body).unwrap();
+        });
+
+        format!("http://{}/tails", addr)
+    }
+
+    fn _hash(bytes: &[u8]) -> Vec<u8> {
+        let mut hasher = Sha256::default();
+        hasher.process(bytes);
+        hasher.fixed_result().to_vec()
+    }
+
+    fn _reader(name: &str) -> Box<Reader> {
+        let base_dir = environment::tmp_path().join(name);
+        fs::remove_dir_all(&base_dir).ok();
+
+        HttpReaderType::new().open(&json!({"base_dir": base_dir}).to_string()).unwrap()
+    }
+
+    #[test]
+    fn http_reader_works() {
+        let reader = _reader("http_reader_works");
+        let location = _serve_once("200 OK", TAILS);
+
+        let mut blob = reader.open(&_hash(TAILS), &location, TAILS.len()).unwrap();
+        assert_eq!(TAILS.to_vec(), blob.read(TAILS.len(), 0).unwrap());
+        assert!(blob.verify().unwrap());
+
+        // stand-in is gone, so tails file is read from cache
+        let mut blob = reader.open(&_hash(TAILS), &location, TAILS.len()).unwrap();
+        assert_eq!(b"file".to_vec(), blob.read(4, 6).unwrap());
+    }
+
+    #[test]
+    fn http_reader_works_for_hash_mismatch() {
+        let reader = _reader("http_reader_works_for_hash_mismatch");
+        let location = _serve_once("200 OK", b"other content");
+
+        let res = reader.open(&_hash(TAILS), &location, TAILS.len());
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn http_reader_works_for_not_found() {
+        let reader = _reader("http_reader_works_for_not_found");
+        let location = _serve_once("404 Not Found", b"");
+
+        let res = reader.open(&_hash(TAILS), &location, TAILS.len());
+        assert_kind!(IndyErrorKind::IOError, res);
+    }
+
+    #[test]
+    fn http_reader_works_for_redirect() {
+        let reader = _reader("http_reader_works_for_redirect");
+        let location = _serve_once("302 Found", b"");
+
+        let res = reader.open(&_hash(TAILS), &location, TAILS.len());
+        assert_kind!(IndyErrorKind::IOError, res);
+    }
+
+    #[test]
+    fn http_reader_works_for_oversized_tails() {
+        let reader = _reader("http_reader_works_for_oversized_tails");
+        let location = _serve_once("200 OK", TAILS);
+
+        let res = reader.open(&_hash(TAILS), &location, TAILS.len() - 1);
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn http_reader_works_for_unsupported_location() {
+        let reader = _reader("http_reader_works_for_unsupported_location");
+
+        let res = reader.open(&_hash(TAILS), "/tmp/tails", TAILS.len());
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn parse_url_works() {
+        assert_eq!(("localhost".to_string(), 8080, "/tails/abc".to_string()), _parse_url("http://localhost:8080/tails/abc").unwrap());
+        assert_eq!(("example.com".to_string(), 80, "/".to_string()), _parse_url("http://example.com").unwrap());
+        assert_kind!(IndyErrorKind::InvalidStructure, _parse_url("https://example.com/tails"));
+        assert_kind!(IndyErrorKind::InvalidStructure, _parse_url("http://example.com:port/tails"));
+    }
+}
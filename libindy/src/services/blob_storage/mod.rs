@@ -5,6 +5,7 @@ extern crate sha2;
 use std::cell::RefCell;
 use std::collections::HashMap;
 
+use api::blob_storage::*;
 use errors::prelude::*;
 use utils::sequence;
 
@@ -13,6 +14,8 @@ use self::sha2::Sha256;
 
 mod default_writer;
 mod default_reader;
+mod http_reader;
+mod plugged;
 
 trait WriterType {
     fn open(&self, config: &str) -> IndyResult<Box<Writer>>;
@@ -32,7 +35,7 @@ trait ReaderType {
 }
 
 trait Reader {
-    fn open(&self, hash: &[u8], location: &str) -> IndyResult<Box<ReadableBlob>>;
+    fn open(&self, hash: &[u8], location: &str, size: usize) -> IndyResult<Box<ReadableBlob>>;
 }
 
 trait ReadableBlob {
@@ -57,6 +60,7 @@ impl BlobStorageService {
         writer_types.insert("default".to_owned(), Box::new(default_writer::DefaultWriterType::new()));
         let mut reader_types: HashMap<String, Box<ReaderType>> = HashMap::new();
         reader_types.insert("default".to_owned(), Box::new(default_reader::DefaultReaderType::new()));
+        reader_types.insert("http".to_owned(), Box::new(http_reader::HttpReaderType::new()));
 
         BlobStorageService {
             writer_types: RefCell::new(writer_types),
@@ -70,6 +74,39 @@ impl BlobStorageService {
     }
 }
 
+impl BlobStorageService {
+    pub fn register_blob_storage(&self,
+                                 type_: &str,
+                                 open_writer: BlobStorageOpenWriter,
+                                 create_blob: BlobStorageCreateBlob,
+                                 append: BlobStorageAppend,
+                                 finalize: BlobStorageFinalize,
+                                 close_writer: BlobStorageCloseWriter,
+                                 open_reader: BlobStorageOpenReader,
+                                 open_blob: BlobStorageOpenBlob,
+                                 read: BlobStorageRead,
+                                 close_blob: BlobStorageCloseBlob,
+                                 close_reader: BlobStorageCloseReader) -> IndyResult<()> {
+        trace!("register_blob_storage >>> type_: {:?}", type_);
+
+        let mut writer_types = self.writer_types.try_borrow_mut()?;
+        let mut reader_types = self.reader_types.try_borrow_mut()?;
+
+        if writer_types.contains_key(type_) || reader_types.contains_key(type_) {
+            return Err(err_msg(IndyErrorKind::WalletStorageTypeAlreadyRegistered, format!("BlobStorage is already registered for type: {}", type_)));
+        }
+
+        let storage_type = plugged::PluggedBlobStorageType::new(open_writer, create_blob, append, finalize, close_writer,
+                                                                open_reader, open_blob, read, close_blob, close_reader);
+
+        writer_types.insert(type_.to_string(), Box::new(storage_type));
+        reader_types.insert(type_.to_string(), Box::new(storage_type));
+
+        trace!("register_blob_storage <<<");
+        Ok(())
+    }
+}
+
 /* Writer */
 impl BlobStorageService {
     pub fn open_writer(&self, type_: &str, config: &str) -> IndyResult<i32> {
@@ -128,10 +165,10 @@ impl BlobStorageService {
         Ok(config_handle)
     }
 
-    pub fn open_blob(&self, config_handle: i32, location: &str, hash: &[u8]) -> IndyResult<i32> {
+    pub fn open_blob(&self, config_handle: i32, location: &str, hash: &[u8], size: usize) -> IndyResult<i32> {
         let reader = self.reader_configs.try_borrow()?
             .get(&config_handle).ok_or(err_msg(IndyErrorKind::InvalidStructure, "Invalid BlobStorage config handle"))? // FIXME: Review error kind
-            .open(hash, location)?;
+            .open(hash, location, size)?;
 
         let reader_handle = sequence::get_next_id();
         self.reader_blobs.try_borrow_mut()?.insert(reader_handle, reader);
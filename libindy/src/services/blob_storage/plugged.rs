@@ -0,0 +1,379 @@
+extern crate digest;
+extern crate sha2;
+
+use self::digest::{FixedOutput, Input};
+use self::sha2::Sha256;
+
+use super::{ReadableBlob, Reader, ReaderType, WritableBlob, Writer, WriterType};
+use api::ErrorCode;
+use api::blob_storage::*;
+use errors::prelude::*;
+
+use libc::c_char;
+
+use std::ffi::{CStr, CString};
+use std::{ptr, slice};
+
+const VERIFY_CHUNK_SIZE: usize = 1024;
+
+/// Blob storage implemented by plugin through C callbacks (see indy_register_blob_storage).
+#[derive(Clone, Copy)]
+pub struct PluggedBlobStorageType {
+    open_writer_handler: BlobStorageOpenWriter,
+    create_blob_handler: BlobStorageCreateBlob,
+    append_handler: BlobStorageAppend,
+    finalize_handler: BlobStorageFinalize,
+    close_writer_handler: BlobStorageCloseWriter,
+    open_reader_handler: BlobStorageOpenReader,
+    open_blob_handler: BlobStorageOpenBlob,
+    read_handler: BlobStorageRead,
+    close_blob_handler: BlobStorageCloseBlob,
+    close_reader_handler: BlobStorageCloseReader,
+}
+
+impl PluggedBlobStorageType {
+    pub fn new(open_writer_handler: BlobStorageOpenWriter,
+               create_blob_handler: BlobStorageCreateBlob,
+               append_handler: BlobStorageAppend,
+               finalize_handler: BlobStorageFinalize,
+               close_writer_handler: BlobStorageCloseWriter,
+               open_reader_handler: BlobStorageOpenReader,
+               open_blob_handler: BlobStorageOpenBlob,
+               read_handler: BlobStorageRead,
+               close_blob_handler: BlobStorageCloseBlob,
+               close_reader_handler: BlobStorageCloseReader) -> PluggedBlobStorageType {
+        PluggedBlobStorageType {
+            open_writer_handler,
+            create_blob_handler,
+            append_handler,
+            finalize_handler,
+            close_writer_handler,
+            open_reader_handler,
+            open_blob_handler,
+            read_handler,
+            close_blob_handler,
+            close_reader_handler,
+        }
+    }
+}
+
+struct PluggedWriter {
+    storage_type: PluggedBlobStorageType,
+    writer_handle: i32,
+}
+
+struct PluggedWritableBlob {
+    storage_type: PluggedBlobStorageType,
+    blob_handle: i32,
+}
+
+struct PluggedReader {
+    storage_type: PluggedBlobStorageType,
+    reader_handle: i32,
+}
+
+struct PluggedReadableBlob {
+    storage_type: PluggedBlobStorageType,
+    blob_handle: i32,
+    hash: Vec<u8>,
+}
+
+impl WriterType for PluggedBlobStorageType {
+    fn open(&self, config: &str) -> IndyResult<Box<Writer>> {
+        let config = CString::new(config)
+            .to_indy(IndyErrorKind::InvalidStructure, "BlobStorage Writer config contains null symbol")?;
+
+        let mut writer_handle = -1;
+
+        let err = (self.open_writer_handler)(config.as_ptr(), &mut writer_handle);
+
+        if err != ErrorCode::Success {
+            return Err(err.into());
+        }
+
+        Ok(Box::new(PluggedWriter {
+            storage_type: *self,
+            writer_handle,
+        }))
+    }
+}
+
+impl Writer for PluggedWriter {
+    fn create(&self, _id: i32) -> IndyResult<Box<WritableBlob>> {
+        let mut blob_handle = -1;
+
+        let err = (self.storage_type.create_blob_handler)(self.writer_handle, &mut blob_handle);
+
+        if err != ErrorCode::Success {
+            return Err(err.into());
+        }
+
+        Ok(Box::new(PluggedWritableBlob {
+            storage_type: self.storage_type,
+            blob_handle,
+        }))
+    }
+}
+
+impl PluggedWriter {
+    fn close(&mut self) -> IndyResult<()> {
+        let err = (self.storage_type.close_writer_handler)(self.writer_handle);
+
+        if err != ErrorCode::Success {
+            return Err(err.into());
+        }
+
+        // invalidate the handle, just in case.
+        self.writer_handle = -1;
+
+        Ok(())
+    }
+}
+
+impl Drop for PluggedWriter {
+    fn drop(&mut self) {
+        // writer is closed when its config is released by BlobStorageService
+        if self.writer_handle >= 0 {
+            #[allow(unused_must_use)]
+                {
+                    self.close()
+                        .map_err(map_err_err!());
+                }
+        }
+    }
+}
+
+impl WritableBlob for PluggedWritableBlob {
+    fn append(&mut self, bytes: &[u8]) -> IndyResult<usize> {
+        let mut written_len: usize = 0;
+
+        let err = (self.storage_type.append_handler)(self.blob_handle, bytes.as_ptr(), bytes.len(), &mut written_len);
+
+        if err != ErrorCode::Success {
+            return Err(err.into());
+        }
+
+        Ok(written_len)
+    }
+
+    fn finalize(&mut self, hash: &[u8]) -> IndyResult<String> {
+        let mut location_ptr: *const c_char = ptr::null();
+
+        let err = (self.storage_type.finalize_handler)(self.blob_handle, hash.as_ptr(), hash.len(), &mut location_ptr);
+
+        let location = if err == ErrorCode::Success && !location_ptr.is_null() {
+            unsafe { CStr::from_ptr(location_ptr) }.to_str()
+                .map(String::from)
+                .to_indy(IndyErrorKind::InvalidState, "Blob location contains non-utf8 symbol")
+        } else if err == ErrorCode::Success {
+            Err(err_msg(IndyErrorKind::InvalidState, "Blob location isn't returned by BlobStorage plugin"))
+        } else {
+            Err(err.into())
+        };
+
+        let err = (self.storage_type.close_blob_handler)(self.blob_handle);
+
+        let location = location?;
+
+        if err != ErrorCode::Success {
+            return Err(err.into());
+        }
+
+        Ok(location)
+    }
+}
+
+impl ReaderType for PluggedBlobStorageType {
+    fn open(&self, config: &str) -> IndyResult<Box<Reader>> {
+        let config = CString::new(config)
+            .to_indy(IndyErrorKind::InvalidStructure, "BlobStorage Reader config contains null symbol")?;
+
+        let mut reader_handle = -1;
+
+        let err = (self.open_reader_handler)(config.as_ptr(), &mut reader_handle);
+
+        if err != ErrorCode::Success {
+            return Err(err.into());
+        }
+
+        Ok(Box::new(PluggedReader {
+            storage_type: *self,
+            reader_handle,
+        }))
+    }
+}
+
+impl Reader for PluggedReader {
+    fn open(&self, hash: &[u8], location: &str, _size: usize) -> IndyResult<Box<ReadableBlob>> {
+        let location = CString::new(location)
+            .to_indy(IndyErrorKind::InvalidStructure, "Blob location contains null symbol")?;
+
+        let mut blob_handle = -1;
+
+        let err = (self.storage_type.open_blob_handler)(self.reader_handle, hash.as_ptr(), hash.len(), location.as_ptr(), &mut blob_handle);
+
+        if err != ErrorCode::Success {
+            return Err(err.into());
+        }
+
+        Ok(Box::new(PluggedReadableBlob {
+            storage_type: self.storage_type,
+            blob_handle,
+            hash: hash.to_owned(),
+        }))
+    }
+}
+
+impl PluggedReader {
+    fn close(&mut self) -> IndyResult<()> {
+        let err = (self.storage_type.close_reader_handler)(self.reader_handle);
+
+        if err != ErrorCode::Success {
+            return Err(err.into());
+        }
+
+        // invalidate the handle, just in case.
+        self.reader_handle = -1;
+
+        Ok(())
+    }
+}
+
+impl Drop for PluggedReader {
+    fn drop(&mut self) {
+        // reader is closed when its config is released by BlobStorageService
+        if self.reader_handle >= 0 {
+            #[allow(unused_must_use)]
+                {
+                    self.close()
+                        .map_err(map_err_err!());
+                }
+        }
+    }
+}
+
+impl ReadableBlob for PluggedReadableBlob {
+    fn read(&mut self, size: usize, offset: usize) -> IndyResult<Vec<u8>> {
+        let mut bytes_ptr: *const u8 = ptr::null();
+        let mut bytes_len: usize = 0;
+
+        let err = (self.storage_type.read_handler)(self.blob_handle, size, offset, &mut bytes_ptr, &mut bytes_len);
+
+        if err != ErrorCode::Success {
+            return Err(err.into());
+        }
+
+        if bytes_ptr.is_null() || bytes_len == 0 {
+            return Ok(Vec::new());
+        }
+
+        Ok(unsafe { slice::from_raw_parts(bytes_ptr, bytes_len) }.to_vec())
+    }
+
+    fn verify(&mut self) -> IndyResult<bool> {
+        let mut hasher = Sha256::default();
+        let mut offset = 0;
+
+        loop {
+            let bytes = self.read(VERIFY_CHUNK_SIZE, offset)?;
+
+            if bytes.is_empty() {
+                return Ok(hasher.fixed_result().as_slice().eq(self.hash.as_slice()));
+            }
+
+            hasher.process(&bytes);
+            offset += bytes.len();
+        }
+    }
+
+    fn close(&self) -> IndyResult<()> {
+        let err = (self.storage_type.close_blob_handler)(self.blob_handle);
+
+        if err != ErrorCode::Success {
+            return Err(err.into());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::BlobStorageService;
+
+    use utils::inmem_blob_storage::{InmemBlobStorage, INMEM_BLOB_LOCATION_PREFIX};
+
+    const TAILS: &'static [u8] = b"tails file content";
+
+    fn _blob_storage_service() -> BlobStorageService {
+        let service = BlobStorageService::new();
+
+        service.register_blob_storage("inmem",
+                                      InmemBlobStorage::open_writer,
+                                      InmemBlobStorage::create_blob,
+                                      InmemBlobStorage::append,
+                                      InmemBlobStorage::finalize,
+                                      InmemBlobStorage::close_writer,
+                                      InmemBlobStorage::open_reader,
+                                      InmemBlobStorage::open_blob,
+                                      InmemBlobStorage::read,
+                                      InmemBlobStorage::close_blob,
+                                      InmemBlobStorage::close_reader).unwrap();
+
+        service
+    }
+
+    #[test]
+    fn plugged_blob_storage_works() {
+        let service = _blob_storage_service();
+
+        let writer_handle = service.open_writer("inmem", "{}").unwrap();
+        let blob_handle = service.create_blob(writer_handle).unwrap();
+        assert_eq!(TAILS.len(), service.append(blob_handle, TAILS).unwrap());
+
+        let (location, hash) = service.finalize(blob_handle).unwrap();
+        assert!(location.starts_with(INMEM_BLOB_LOCATION_PREFIX));
+
+        let reader_handle = service.open_reader("inmem", "{}").unwrap();
+        let blob_handle = service.open_blob(reader_handle, &location, &hash, TAILS.len()).unwrap();
+
+        assert_eq!(b"file".to_vec(), service.read(blob_handle, 4, 6).unwrap());
+        assert!(service._verify(blob_handle).unwrap());
+
+        service.close(blob_handle).unwrap();
+    }
+
+    #[test]
+    fn plugged_blob_storage_works_for_hash_mismatch() {
+        let service = _blob_storage_service();
+
+        let writer_handle = service.open_writer("inmem", "{}").unwrap();
+        let blob_handle = service.create_blob(writer_handle).unwrap();
+        service.append(blob_handle, TAILS).unwrap();
+        let (location, _) = service.finalize(blob_handle).unwrap();
+
+        let reader_handle = service.open_reader("inmem", "{}").unwrap();
+        let blob_handle = service.open_blob(reader_handle, &location, &[0u8; 32], TAILS.len()).unwrap();
+
+        assert!(!service._verify(blob_handle).unwrap());
+    }
+
+    #[test]
+    fn register_blob_storage_works_for_already_registered_type() {
+        let service = _blob_storage_service();
+
+        let res = service.register_blob_storage("default",
+                                                InmemBlobStorage::open_writer,
+                                                InmemBlobStorage::create_blob,
+                                                InmemBlobStorage::append,
+                                                InmemBlobStorage::finalize,
+                                                InmemBlobStorage::close_writer,
+                                                InmemBlobStorage::open_reader,
+                                                InmemBlobStorage::open_blob,
+                                                InmemBlobStorage::read,
+                                                InmemBlobStorage::close_blob,
+                                                InmemBlobStorage::close_reader);
+        assert_kind!(IndyErrorKind::WalletStorageTypeAlreadyRegistered, res);
+    }
+}
@@ -26,6 +26,7 @@ use domain::ledger::schema::{GetSchemaOperation, GetSchemaOperationData, GetSche
 use domain::ledger::txn::{GetTxnOperation, LedgerType};
 use domain::ledger::validator_info::GetValidatorInfoOperation;
 use errors::prelude::*;
+use utils::qualifier;
 
 pub mod merkletree;
 
@@ -46,7 +47,7 @@ impl LedgerService {
 
         let mut operation: Value = Value::Object(serde_json::map::Map::new());
         operation["type"] = Value::String(NYM.to_string());
-        operation["dest"] = Value::String(dest.to_string());
+        operation["dest"] = Value::String(qualifier::unqualify_did(dest));
 
         if let Some(v) = verkey {
             operation["verkey"] = Value::String(v.to_string());
@@ -81,7 +82,7 @@ impl LedgerService {
     pub fn build_get_nym_request(&self, identifier: Option<&str>, dest: &str) -> IndyResult<String> {
         info!("build_get_nym_request >>> identifier: {:?}, dest: {:?}", identifier, dest);
 
-        let operation = GetNymOperation::new(dest.to_string());
+        let operation = GetNymOperation::new(qualifier::unqualify_did(dest));
 
         let request = Request::build_request(identifier, operation)
             .to_indy(IndyErrorKind::InvalidState, "GET_NYM request json is invalid")?;
@@ -94,7 +95,7 @@ impl LedgerService {
     pub fn build_get_ddo_request(&self, identifier: Option<&str>, dest: &str) -> IndyResult<String> {
         info!("build_get_ddo_request >>> identifier: {:?}, dest: {:?}", identifier, dest);
 
-        let operation = GetDdoOperation::new(dest.to_string());
+        let operation = GetDdoOperation::new(qualifier::unqualify_did(dest));
 
         let request = Request::build_request(identifier, operation)
             .to_indy(IndyErrorKind::InvalidState, "GET_DDO request json is invalid")?;
@@ -117,7 +118,7 @@ impl LedgerService {
                 .to_indy(IndyErrorKind::InvalidStructure, "Can not deserialize Raw Attribute")?;
         }
 
-        let operation = AttribOperation::new(dest.to_string(),
+        let operation = AttribOperation::new(qualifier::unqualify_did(dest),
                                              hash.map(String::from),
                                              raw.map(String::from),
                                              enc.map(String::from));
@@ -138,7 +139,7 @@ impl LedgerService {
             return Err(err_msg(IndyErrorKind::InvalidStructure, "Either raw or hash or enc must be specified"));
         }
 
-        let operation = GetAttribOperation::new(dest.to_string(), raw, hash, enc);
+        let operation = GetAttribOperation::new(qualifier::unqualify_did(dest), raw, hash, enc);
 
         let request = Request::build_request(identifier, operation)
             .to_indy(IndyErrorKind::InvalidState, "GET_ATTRIB request json is invalid")?;
@@ -156,7 +157,7 @@ impl LedgerService {
                                format!("The number of Schema attributes {} cannot be greater than {}", schema.attr_names.len(), MAX_ATTRIBUTES_COUNT)));
         }
 
-        let schema_data = SchemaOperationData::new(schema.name, schema.version, schema.attr_names);
+        let schema_data = SchemaOperationData::new(schema.name, schema.version, schema.attr_names, schema.attr_types);
 
         let operation = SchemaOperation::new(schema_data);
 
@@ -171,6 +172,7 @@ impl LedgerService {
     pub fn build_get_schema_request(&self, identifier: Option<&str>, id: &str) -> IndyResult<String> {
         info!("build_get_schema_request >>> identifier: {:?}, id: {:?}", identifier, id);
 
+        let id = qualifier::unqualify(id);
         let parts: Vec<&str> = id.split_terminator(DELIMITER).collect::<Vec<&str>>();
 
         let dest = parts.get(0)
@@ -209,6 +211,7 @@ impl LedgerService {
     pub fn build_get_cred_def_request(&self, identifier: Option<&str>, id: &str) -> IndyResult<String> {
         info!("build_get_cred_def_request >>> identifier: {:?}, id {:?}", identifier, id);
 
+        let id = qualifier::unqualify(id);
         let parts: Vec<&str> = id.split_terminator(DELIMITER).collect::<Vec<&str>>();
 
         let origin = parts.get(0)
@@ -433,6 +436,7 @@ impl LedgerService {
                 attr_names: res.data.attr_names,
                 id: Schema::schema_id(&res.dest, &res.data.name, &res.data.version),
                 seq_no: Some(res.seq_no),
+                attr_types: res.data.attr_types,
             },
             GetSchemaReplyResult::GetSchemaReplyResultV1(res) => SchemaV1 {
                 name: res.txn.data.schema_name,
@@ -440,6 +444,7 @@ impl LedgerService {
                 attr_names: res.txn.data.value.attr_names,
                 id: res.txn.data.id,
                 seq_no: Some(res.txn_metadata.seq_no),
+                attr_types: res.txn.data.value.attr_types,
             }
         };
 
@@ -588,7 +593,7 @@ impl LedgerService {
 
 #[cfg(test)]
 mod tests {
-    use domain::anoncreds::schema::AttributeNames;
+    use domain::anoncreds::schema::{AttributeNames, AttributeType, AttributeTypes};
     use domain::ledger::constants::*;
     use domain::ledger::node::Services;
     use domain::ledger::request::ProtocolVersion;
@@ -745,6 +750,7 @@ mod tests {
             version: "1.0".to_string(),
             attr_names,
             seq_no: None,
+            attr_types: None,
         };
 
         let expected_result = json!({
@@ -760,6 +766,91 @@ mod tests {
         check_request(&request, expected_result);
     }
 
+    #[test]
+    fn build_schema_request_works_for_attr_types() {
+        let ledger_service = LedgerService::new();
+
+        let mut attr_names: AttributeNames = AttributeNames::new();
+        attr_names.insert("age".to_string());
+
+        let mut attr_types: AttributeTypes = AttributeTypes::new();
+        attr_types.insert("age".to_string(), AttributeType::Int);
+
+        let data = SchemaV1 {
+            id: Schema::schema_id(IDENTIFIER, "name", "1.0"),
+            name: "name".to_string(),
+            version: "1.0".to_string(),
+            attr_names,
+            seq_no: None,
+            attr_types: Some(attr_types),
+        };
+
+        let expected_result = json!({
+            "type": SCHEMA,
+            "data": {
+                "name": "name",
+                "version": "1.0",
+                "attr_names": ["age"],
+                "attr_types": {"age": {"type": "int"}}
+            }
+        });
+
+        let request = ledger_service.build_schema_request(IDENTIFIER, data).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn parse_get_schema_response_works_for_attr_types() {
+        let ledger_service = LedgerService::new();
+
+        let get_schema_response = json!({
+            "op": "REPLY",
+            "result": {
+                "type": GET_SCHEMA,
+                "seqNo": 10,
+                "dest": IDENTIFIER,
+                "data": {
+                    "name": "name",
+                    "version": "1.0",
+                    "attr_names": ["age", "birthdate"],
+                    "attr_types": {"age": {"type": "int"}, "birthdate": {"type": "date"}}
+                }
+            }
+        }).to_string();
+
+        let (schema_id, schema_json) = ledger_service.parse_get_schema_response(&get_schema_response).unwrap();
+        assert_eq!(Schema::schema_id(IDENTIFIER, "name", "1.0"), schema_id);
+
+        let schema: SchemaV1 = SchemaV1::from(serde_json::from_str::<Schema>(&schema_json).unwrap());
+        let attr_types = schema.attr_types.unwrap();
+        assert_eq!(Some(&AttributeType::Int), attr_types.get("age"));
+        assert_eq!(Some(&AttributeType::Date), attr_types.get("birthdate"));
+    }
+
+    #[test]
+    fn parse_get_schema_response_works_without_attr_types() {
+        let ledger_service = LedgerService::new();
+
+        let get_schema_response = json!({
+            "op": "REPLY",
+            "result": {
+                "type": GET_SCHEMA,
+                "seqNo": 10,
+                "dest": IDENTIFIER,
+                "data": {
+                    "name": "name",
+                    "version": "1.0",
+                    "attr_names": ["age"]
+                }
+            }
+        }).to_string();
+
+        let (_, schema_json) = ledger_service.parse_get_schema_response(&get_schema_response).unwrap();
+
+        let schema: SchemaV1 = SchemaV1::from(serde_json::from_str::<Schema>(&schema_json).unwrap());
+        assert!(schema.attr_types.is_none());
+    }
+
     #[test]
     fn build_schema_request_works_for_attrs_count_more_than_acceptable() {
         let ledger_service = LedgerService::new();
@@ -772,6 +863,7 @@ mod tests {
             version: "1.0".to_string(),
             attr_names,
             seq_no: None,
+            attr_types: None,
         };
 
         let res = ledger_service.build_schema_request(IDENTIFIER, data);
@@ -284,6 +284,10 @@ impl WalletService {
         }
     }
 
+    pub fn add_indy_record_tags<T>(&self, wallet_handle: i32, name: &str, tags: &Tags) -> IndyResult<()> where T: NamedType {
+        self.add_record_tags(wallet_handle, &self.add_prefix(T::short_type_name()), name, tags)
+    }
+
     pub fn update_record_tags(&self, wallet_handle: i32, type_: &str, name: &str, tags: &Tags) -> IndyResult<()> {
         match self.wallets.borrow_mut().get_mut(&wallet_handle) {
             Some(wallet) => wallet.update_tags(type_, name, tags),
@@ -291,6 +295,10 @@ impl WalletService {
         }
     }
 
+    pub fn update_indy_record_tags<T>(&self, wallet_handle: i32, name: &str, tags: &Tags) -> IndyResult<()> where T: NamedType {
+        self.update_record_tags(wallet_handle, &self.add_prefix(T::short_type_name()), name, tags)
+    }
+
     pub fn delete_record_tags(&self, wallet_handle: i32, type_: &str, name: &str, tag_names: &[&str]) -> IndyResult<()> {
         match self.wallets.borrow().get(&wallet_handle) {
             Some(wallet) => wallet.delete_tags(type_, name, tag_names),
@@ -298,6 +306,10 @@ impl WalletService {
         }
     }
 
+    pub fn delete_indy_record_tags<T>(&self, wallet_handle: i32, name: &str, tag_names: &[&str]) -> IndyResult<()> where T: NamedType {
+        self.delete_record_tags(wallet_handle, &self.add_prefix(T::short_type_name()), name, tag_names)
+    }
+
     pub fn delete_record(&self, wallet_handle: i32, type_: &str, name: &str) -> IndyResult<()> {
         match self.wallets.borrow().get(&wallet_handle) {
             Some(wallet) => wallet.delete(type_, name),
@@ -698,6 +710,18 @@ impl SearchOptions {
 
         serde_json::to_string(&options).unwrap()
     }
+
+    pub fn id_value_tags() -> String {
+        let options = SearchOptions {
+            retrieve_records: true,
+            retrieve_total_count: true,
+            retrieve_type: true,
+            retrieve_value: true,
+            retrieve_tags: true,
+        };
+
+        serde_json::to_string(&options).unwrap()
+    }
 }
 
 impl Default for SearchOptions {
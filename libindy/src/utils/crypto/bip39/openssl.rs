@@ -0,0 +1,151 @@
+extern crate openssl;
+
+use errors::prelude::*;
+use self::openssl::hash::{hash, MessageDigest};
+use self::openssl::pkcs5::pbkdf2_hmac;
+
+const WORDLIST: &'static str = include_str!("english.txt");
+const PBKDF2_ROUNDS: usize = 2048;
+pub const SEEDBYTES: usize = 64;
+
+lazy_static! {
+    static ref WORDS: Vec<&'static str> = WORDLIST.lines().collect();
+}
+
+/// Encodes entropy (16, 20, 24, 28 or 32 bytes) as BIP-39 mnemonic sentence.
+pub fn entropy_to_mnemonic(entropy: &[u8]) -> IndyResult<String> {
+    if entropy.len() < 16 || entropy.len() > 32 || entropy.len() % 4 != 0 {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid entropy length: {}", entropy.len())));
+    }
+
+    let checksum = hash(MessageDigest::sha256(), entropy)?;
+    let checksum_bits = entropy.len() / 4;
+
+    let mut bits: Vec<bool> = Vec::with_capacity(entropy.len() * 8 + checksum_bits);
+    bits.extend(_bytes_to_bits(entropy));
+    bits.extend(_bytes_to_bits(&checksum[..1]).into_iter().take(checksum_bits));
+
+    let words: Vec<&str> = bits
+        .chunks(11)
+        .map(|chunk| WORDS[chunk.iter().fold(0usize, |acc, bit| (acc << 1) | (*bit as usize))])
+        .collect();
+
+    Ok(words.join(" "))
+}
+
+/// Decodes BIP-39 mnemonic sentence back to entropy and validates its checksum.
+pub fn mnemonic_to_entropy(mnemonic: &str) -> IndyResult<Vec<u8>> {
+    let words: Vec<&str> = mnemonic.split_whitespace().collect();
+
+    if words.len() < 12 || words.len() > 24 || words.len() % 3 != 0 {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid mnemonic words count: {}", words.len())));
+    }
+
+    let mut bits: Vec<bool> = Vec::with_capacity(words.len() * 11);
+
+    for word in words.iter() {
+        let index = WORDS.binary_search(word)
+            .map_err(|_| err_msg(IndyErrorKind::InvalidStructure, format!("Unknown mnemonic word: {}", word)))?;
+
+        bits.extend((0..11).rev().map(|i| (index >> i) & 1 == 1));
+    }
+
+    let checksum_bits = bits.len() / 33;
+    let entropy_bits = bits.len() - checksum_bits;
+
+    let entropy: Vec<u8> = bits[..entropy_bits]
+        .chunks(8)
+        .map(|chunk| chunk.iter().fold(0u8, |acc, bit| (acc << 1) | (*bit as u8)))
+        .collect();
+
+    let checksum = hash(MessageDigest::sha256(), &entropy)?;
+
+    if _bytes_to_bits(&checksum[..1])[..checksum_bits] != bits[entropy_bits..] {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, "Invalid mnemonic checksum"));
+    }
+
+    Ok(entropy)
+}
+
+/// Calculates BIP-39 seed for the given mnemonic sentence and optional passphrase.
+pub fn mnemonic_to_seed(mnemonic: &str, passphrase: Option<&str>) -> IndyResult<Vec<u8>> {
+    mnemonic_to_entropy(mnemonic)?;
+
+    let mnemonic = mnemonic.split_whitespace().collect::<Vec<&str>>().join(" ");
+    let salt = format!("mnemonic{}", passphrase.unwrap_or(""));
+
+    let mut seed = vec![0u8; SEEDBYTES];
+    pbkdf2_hmac(mnemonic.as_bytes(), salt.as_bytes(), PBKDF2_ROUNDS, MessageDigest::sha512(), &mut seed)?;
+
+    Ok(seed)
+}
+
+fn _bytes_to_bits(bytes: &[u8]) -> Vec<bool> {
+    bytes
+        .iter()
+        .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern crate hex;
+
+    use self::hex::FromHex;
+
+    // Test vectors from https://github.com/trezor/python-mnemonic/blob/master/vectors.json
+    const ENTROPY: &'static str = "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f";
+    const MNEMONIC: &'static str = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+    const SEED: &'static str = "2e8905819b8723fe2c1d161860e5ee1830318dbf49a83bd451cfb8440c28bd6fa457fe1296106559a3c80937a1c1069be3a3a5bd381ee6260e8d9739fce1f607";
+
+    #[test]
+    fn wordlist_is_sorted_and_complete() {
+        assert_eq!(2048, WORDS.len());
+        assert!(WORDS.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn entropy_to_mnemonic_works() {
+        let entropy = Vec::from_hex(ENTROPY).unwrap();
+        assert_eq!(MNEMONIC, entropy_to_mnemonic(&entropy).unwrap());
+    }
+
+    #[test]
+    fn entropy_to_mnemonic_works_for_zero_entropy() {
+        let mnemonic = entropy_to_mnemonic(&[0u8; 32]).unwrap();
+        assert_eq!("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+                    abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art", mnemonic);
+    }
+
+    #[test]
+    fn entropy_to_mnemonic_fails_for_invalid_length() {
+        let res = entropy_to_mnemonic(&[0u8; 15]);
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn mnemonic_to_entropy_works() {
+        let entropy = Vec::from_hex(ENTROPY).unwrap();
+        assert_eq!(entropy, mnemonic_to_entropy(MNEMONIC).unwrap());
+    }
+
+    #[test]
+    fn mnemonic_to_entropy_fails_for_invalid_checksum() {
+        let res = mnemonic_to_entropy("legal winner thank year wave sausage worth useful legal winner thank thank");
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn mnemonic_to_entropy_fails_for_unknown_word() {
+        let res = mnemonic_to_entropy("legal winner thank year wave sausage worth useful legal winner thank indy");
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn mnemonic_to_seed_works() {
+        let seed = mnemonic_to_seed(MNEMONIC, Some("TREZOR")).unwrap();
+        assert_eq!(Vec::from_hex(SEED).unwrap(), seed);
+    }
+}
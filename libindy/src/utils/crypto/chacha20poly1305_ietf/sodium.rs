@@ -68,6 +68,18 @@ pub fn gen_nonce_and_encrypt_detached(data: &[u8], aad: &[u8], key: &Key) -> (Ve
     (plain.to_vec(), nonce, Tag(tag))
 }
 
+pub fn encrypt_detached(data: &[u8], aad: &[u8], key: &Key, nonce: &Nonce) -> (Vec<u8>, Tag) {
+    let mut plain = data.to_vec();
+    let tag = chacha20poly1305_ietf::seal_detached(
+        plain.as_mut_slice(),
+        Some(aad),
+        &nonce.0,
+        &key.0
+    );
+
+    (plain, Tag(tag))
+}
+
 
 pub fn decrypt_detached(data: &[u8], key: &Key, nonce: &Nonce, tag: &Tag, ad: Option<&[u8]>) -> Result<Vec<u8>, IndyError> {
     let mut plain = data.to_vec();
@@ -306,6 +318,18 @@ mod tests {
         assert_eq!(data, u);
 }
 
+    #[test]
+    fn encrypt_detached_decrypt_detached_works_for_nonce() {
+        let data = randombytes(100);
+        let key = gen_key();
+        let nonce = gen_nonce();
+        let aad = randombytes(100);
+
+        let (c, tag) = encrypt_detached(&data, aad.as_slice(), &key, &nonce);
+        let u = decrypt_detached(&c, &key, &nonce, &tag, Some(aad.as_slice())).unwrap();
+        assert_eq!(data, u);
+    }
+
     #[test]
     fn encrypt_decrypt_works_for_nonce() {
         let data = randombytes(16);
@@ -0,0 +1,141 @@
+use errors::prelude::*;
+use utils::crypto::base58;
+
+pub const DID_KEY_PREFIX: &'static str = "did:key:";
+pub const DID_PEER_PREFIX: &'static str = "did:peer:";
+
+// Multicodec prefix of ed25519 public key (varint encoded 0xed)
+const ED25519_PUB_MULTICODEC: [u8; 2] = [0xed, 0x01];
+const ED25519_PUB_LEN: usize = 32;
+const MULTIBASE_BASE58BTC: char = 'z';
+const PEER_PURPOSE_VERIFICATION: char = 'V';
+
+/// Returns true if DID's key is encoded in the identifier itself (did:key and did:peer).
+pub fn is_key_based_did(did: &str) -> bool {
+    did.starts_with(DID_KEY_PREFIX) || did.starts_with(DID_PEER_PREFIX)
+}
+
+/// Encodes ed25519 verkey as multibase (base58btc) multicodec key, e.g. "z6Mk...".
+pub fn encode_multikey(verkey: &str) -> IndyResult<String> {
+    if verkey.contains(':') {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Only ed25519 keys can be used in key based DIDs: {}", verkey)));
+    }
+
+    let mut bytes = ED25519_PUB_MULTICODEC.to_vec();
+    bytes.extend(base58::decode(verkey)?);
+
+    Ok(format!("{}{}", MULTIBASE_BASE58BTC, base58::encode(&bytes)))
+}
+
+/// Decodes multibase (base58btc) multicodec ed25519 key to base58 verkey.
+pub fn decode_multikey(multikey: &str) -> IndyResult<String> {
+    if !multikey.starts_with(MULTIBASE_BASE58BTC) {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Only base58btc multibase keys are supported: {}", multikey)));
+    }
+
+    let bytes = base58::decode(&multikey[1..])?;
+
+    if bytes.len() != ED25519_PUB_MULTICODEC.len() + ED25519_PUB_LEN || bytes[..2] != ED25519_PUB_MULTICODEC {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Only ed25519 multicodec keys are supported: {}", multikey)));
+    }
+
+    Ok(base58::encode(&bytes[2..]))
+}
+
+pub fn build_did_key(verkey: &str) -> IndyResult<String> {
+    Ok(format!("{}{}", DID_KEY_PREFIX, encode_multikey(verkey)?))
+}
+
+/// Builds did:peer with numalgo 0 (inception key) or 2 (single verification key).
+pub fn build_did_peer(verkey: &str, numalgo: u8) -> IndyResult<String> {
+    let multikey = encode_multikey(verkey)?;
+
+    match numalgo {
+        0 => Ok(format!("{}0{}", DID_PEER_PREFIX, multikey)),
+        2 => Ok(format!("{}2.{}{}", DID_PEER_PREFIX, PEER_PURPOSE_VERIFICATION, multikey)),
+        _ => Err(err_msg(IndyErrorKind::InvalidStructure, format!("Unsupported did:peer numalgo: {}", numalgo)))
+    }
+}
+
+/// Resolves verkey encoded in did:key or did:peer (numalgo 0 or 2) identifier.
+/// For numalgo 2 the first verification key is used.
+pub fn verkey_for_key_based_did(did: &str) -> IndyResult<String> {
+    if did.starts_with(DID_KEY_PREFIX) {
+        return decode_multikey(&did[DID_KEY_PREFIX.len()..]);
+    }
+
+    if did.starts_with(DID_PEER_PREFIX) {
+        let id = &did[DID_PEER_PREFIX.len()..];
+
+        if id.starts_with('0') {
+            return decode_multikey(&id[1..]);
+        }
+
+        if id.starts_with("2.") {
+            return id[2..]
+                .split('.')
+                .find(|element| element.starts_with(PEER_PURPOSE_VERIFICATION))
+                .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("did:peer doesn't contain verification key: {}", did)))
+                .and_then(|element| decode_multikey(&element[1..]));
+        }
+
+        return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Unsupported did:peer numalgo: {}", did)));
+    }
+
+    Err(err_msg(IndyErrorKind::InvalidStructure, format!("DID isn't key based: {}", did)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vector from https://w3c-ccg.github.io/did-method-key/
+    const VERKEY: &'static str = "B12NYF8RrR3h41TDCTJojY59usg3mbtbjnFs7Eud1Y6u";
+    const MULTIKEY: &'static str = "z6MkpTHR8VNsBxYAAWHut2Geadd9jSwuBV8xRoAnwWsdvktH";
+
+    #[test]
+    fn encode_multikey_works() {
+        assert_eq!(MULTIKEY, encode_multikey(VERKEY).unwrap());
+    }
+
+    #[test]
+    fn decode_multikey_works() {
+        assert_eq!(VERKEY, decode_multikey(MULTIKEY).unwrap());
+    }
+
+    #[test]
+    fn decode_multikey_fails_for_other_codec() {
+        let res = decode_multikey("zQ3shokFTS3brHcDQrn82RUDfCZESWL1ZdCEJwekUDPQiYBme");
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn build_did_key_works() {
+        assert_eq!(format!("did:key:{}", MULTIKEY), build_did_key(VERKEY).unwrap());
+    }
+
+    #[test]
+    fn build_did_peer_works() {
+        assert_eq!(format!("did:peer:0{}", MULTIKEY), build_did_peer(VERKEY, 0).unwrap());
+        assert_eq!(format!("did:peer:2.V{}", MULTIKEY), build_did_peer(VERKEY, 2).unwrap());
+    }
+
+    #[test]
+    fn build_did_peer_fails_for_unsupported_numalgo() {
+        let res = build_did_peer(VERKEY, 1);
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn verkey_for_key_based_did_works() {
+        assert_eq!(VERKEY, verkey_for_key_based_did(&format!("did:key:{}", MULTIKEY)).unwrap());
+        assert_eq!(VERKEY, verkey_for_key_based_did(&format!("did:peer:0{}", MULTIKEY)).unwrap());
+        assert_eq!(VERKEY, verkey_for_key_based_did(&format!("did:peer:2.Ez6LSbysY2xFMRpGMhb7tFTLMpeuPRaqaWM1yECx2AtzE3KCc.V{}.SeyJ0IjoiZG0ifQ", MULTIKEY)).unwrap());
+    }
+
+    #[test]
+    fn verkey_for_key_based_did_fails_for_unqualified_did() {
+        let res = verkey_for_key_based_did("8wZcEriaNLNKtteJvx7f8i");
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+}
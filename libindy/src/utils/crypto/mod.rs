@@ -34,6 +34,8 @@ pub mod signature_serializer;
 
 pub mod verkey_builder;
 
+pub mod did_methods;
+
 #[cfg(feature = "sealedbox_sodium")]
 #[path = "sealedbox/sodium.rs"]
 pub mod sealedbox;
@@ -46,6 +48,14 @@ pub mod pwhash_argon2i13;
 #[path = "hmacsha256/sodium.rs"]
 pub mod hmacsha256;
 
+#[cfg(feature = "bip39_openssl")]
+#[path = "bip39/openssl.rs"]
+pub mod bip39;
+
+#[cfg(feature = "slip10_openssl")]
+#[path = "slip10/openssl.rs"]
+pub mod slip10;
+
 #[cfg(feature = "randombytes_sodium")]
 #[path = "randombytes/sodium.rs"]
 pub mod randombytes;
\ No newline at end of file
@@ -0,0 +1,125 @@
+extern crate openssl;
+
+use byteorder::{BigEndian, WriteBytesExt};
+
+use errors::prelude::*;
+use self::openssl::hash::MessageDigest;
+use self::openssl::pkey::PKey;
+use self::openssl::sign::Signer;
+
+const ED25519_CURVE: &'static [u8] = b"ed25519 seed";
+const HARDENED_OFFSET: u32 = 0x80000000;
+pub const KEYBYTES: usize = 32;
+
+/// Derives ed25519 private key seed from the master seed along the path as described in SLIP-0010.
+///
+/// Path has form "m/44'/0'/1'". As ed25519 supports only hardened derivation every index must be hardened.
+pub fn derive_ed25519_key(master_seed: &[u8], path: &str) -> IndyResult<Vec<u8>> {
+    if master_seed.len() < 16 || master_seed.len() > 64 {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid master seed length: {}", master_seed.len())));
+    }
+
+    let indexes = parse_path(path)?;
+
+    let (mut key, mut chain_code) = _split(_hmac_sha512(ED25519_CURVE, master_seed)?);
+
+    for index in indexes {
+        let mut data: Vec<u8> = Vec::with_capacity(1 + KEYBYTES + 4);
+        data.push(0u8);
+        data.extend_from_slice(&key);
+        data.write_u32::<BigEndian>(index)?;
+
+        let (child_key, child_chain_code) = _split(_hmac_sha512(&chain_code, &data)?);
+        key = child_key;
+        chain_code = child_chain_code;
+    }
+
+    Ok(key)
+}
+
+pub fn parse_path(path: &str) -> IndyResult<Vec<u32>> {
+    let mut parts = path.split('/');
+
+    if parts.next() != Some("m") {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Derivation path must start with \"m\": {}", path)));
+    }
+
+    parts
+        .map(|part| {
+            let index = if part.ends_with('\'') || part.ends_with('H') || part.ends_with('h') {
+                &part[..part.len() - 1]
+            } else {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Only hardened derivation is supported for ed25519 keys: {}", path)));
+            };
+
+            let index = index.parse::<u32>()
+                .to_indy(IndyErrorKind::InvalidStructure, format!("Invalid derivation path index: {}", part))?;
+
+            if index >= HARDENED_OFFSET {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Derivation path index is too big: {}", part)));
+            }
+
+            Ok(index + HARDENED_OFFSET)
+        })
+        .collect()
+}
+
+fn _hmac_sha512(key: &[u8], data: &[u8]) -> IndyResult<Vec<u8>> {
+    let key = PKey::hmac(key)?;
+    let mut signer = Signer::new(MessageDigest::sha512(), &key)?;
+    signer.update(data)?;
+    Ok(signer.sign_to_vec()?)
+}
+
+fn _split(mut bytes: Vec<u8>) -> (Vec<u8>, Vec<u8>) {
+    let chain_code = bytes.split_off(KEYBYTES);
+    (bytes, chain_code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern crate hex;
+
+    use self::hex::FromHex;
+
+    // Test vector 1 from https://github.com/satoshilabs/slips/blob/master/slip-0010.md
+    const SEED: &'static str = "000102030405060708090a0b0c0d0e0f";
+
+    #[test]
+    fn derive_ed25519_key_works_for_master() {
+        let key = derive_ed25519_key(&Vec::from_hex(SEED).unwrap(), "m").unwrap();
+        assert_eq!(Vec::from_hex("2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7").unwrap(), key);
+    }
+
+    #[test]
+    fn derive_ed25519_key_works_for_path() {
+        let key = derive_ed25519_key(&Vec::from_hex(SEED).unwrap(), "m/0'/1'").unwrap();
+        assert_eq!(Vec::from_hex("b1d0bad404bf35da785a64ca1ac54b2617211d2777696fbffaf208f746ae84f2").unwrap(), key);
+    }
+
+    #[test]
+    fn derive_ed25519_key_works_for_h_notation() {
+        let key = derive_ed25519_key(&Vec::from_hex(SEED).unwrap(), "m/0H").unwrap();
+        assert_eq!(Vec::from_hex("68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3").unwrap(), key);
+    }
+
+    #[test]
+    fn derive_ed25519_key_fails_for_not_hardened_index() {
+        let res = derive_ed25519_key(&Vec::from_hex(SEED).unwrap(), "m/0'/1");
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn parse_path_fails_for_invalid_prefix() {
+        let res = parse_path("0'/1'");
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn parse_path_fails_for_invalid_index() {
+        let res = parse_path("m/a'");
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+}
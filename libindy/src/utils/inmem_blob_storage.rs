@@ -0,0 +1,194 @@
+extern crate libc;
+
+use api::ErrorCode;
+use utils::sequence;
+
+use self::libc::c_char;
+
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+use std::ptr;
+use std::slice;
+use std::sync::Mutex;
+
+pub const INMEM_BLOB_LOCATION_PREFIX: &'static str = "inmem://";
+
+#[derive(Debug)]
+struct InmemBlob {
+    location: String,
+    // bytes of the last read that must stay alive until the next call
+    chunk: Vec<u8>,
+}
+
+lazy_static! {
+    static ref INMEM_BLOBS: Mutex<HashMap<String, Vec<u8>>> = Default::default();
+}
+
+lazy_static! {
+    static ref ACTIVE_WRITERS: Mutex<HashMap<i32, ()>> = Default::default();
+}
+
+lazy_static! {
+    static ref ACTIVE_READERS: Mutex<HashMap<i32, ()>> = Default::default();
+}
+
+lazy_static! {
+    static ref WRITABLE_BLOBS: Mutex<HashMap<i32, (Vec<u8>, Option<CString>)>> = Default::default();
+}
+
+lazy_static! {
+    static ref READABLE_BLOBS: Mutex<HashMap<i32, InmemBlob>> = Default::default();
+}
+
+/// Reference in-memory implementation of blob storage plugin (see indy_register_blob_storage).
+/// Blobs are kept in the process memory with "inmem://<hex hash>" locations.
+pub struct InmemBlobStorage {}
+
+impl InmemBlobStorage {
+    pub extern "C" fn open_writer(_config: *const c_char, writer_handle_p: *mut i32) -> ErrorCode {
+        let handle = sequence::get_next_id();
+        ACTIVE_WRITERS.lock().unwrap().insert(handle, ());
+
+        unsafe { *writer_handle_p = handle };
+        ErrorCode::Success
+    }
+
+    pub extern "C" fn create_blob(writer_handle: i32, blob_handle_p: *mut i32) -> ErrorCode {
+        if !ACTIVE_WRITERS.lock().unwrap().contains_key(&writer_handle) {
+            return ErrorCode::CommonInvalidState;
+        }
+
+        let handle = sequence::get_next_id();
+        WRITABLE_BLOBS.lock().unwrap().insert(handle, (Vec::new(), None));
+
+        unsafe { *blob_handle_p = handle };
+        ErrorCode::Success
+    }
+
+    pub extern "C" fn append(blob_handle: i32, bytes: *const u8, bytes_len: usize, written_len_p: *mut usize) -> ErrorCode {
+        let mut blobs = WRITABLE_BLOBS.lock().unwrap();
+
+        let &mut (ref mut content, _) = match blobs.get_mut(&blob_handle) {
+            Some(blob) => blob,
+            None => return ErrorCode::CommonInvalidState
+        };
+
+        if bytes_len > 0 {
+            content.extend_from_slice(unsafe { slice::from_raw_parts(bytes, bytes_len) });
+        }
+
+        unsafe { *written_len_p = bytes_len };
+        ErrorCode::Success
+    }
+
+    pub extern "C" fn finalize(blob_handle: i32, hash: *const u8, hash_len: usize, location_p: *mut *const c_char) -> ErrorCode {
+        let mut blobs = WRITABLE_BLOBS.lock().unwrap();
+
+        let &mut (ref content, ref mut location) = match blobs.get_mut(&blob_handle) {
+            Some(blob) => blob,
+            None => return ErrorCode::CommonInvalidState
+        };
+
+        let hash = unsafe { slice::from_raw_parts(hash, hash_len) };
+        let hash_hex: String = hash.iter().map(|byte| format!("{:02x}", byte)).collect();
+        let blob_location = format!("{}{}", INMEM_BLOB_LOCATION_PREFIX, hash_hex);
+
+        INMEM_BLOBS.lock().unwrap().insert(blob_location.clone(), content.clone());
+
+        let blob_location = CString::new(blob_location).unwrap();
+        unsafe { *location_p = blob_location.as_ptr() };
+        *location = Some(blob_location);
+
+        ErrorCode::Success
+    }
+
+    pub extern "C" fn close_writer(writer_handle: i32) -> ErrorCode {
+        match ACTIVE_WRITERS.lock().unwrap().remove(&writer_handle) {
+            Some(_) => ErrorCode::Success,
+            None => ErrorCode::CommonInvalidState
+        }
+    }
+
+    pub extern "C" fn open_reader(_config: *const c_char, reader_handle_p: *mut i32) -> ErrorCode {
+        let handle = sequence::get_next_id();
+        ACTIVE_READERS.lock().unwrap().insert(handle, ());
+
+        unsafe { *reader_handle_p = handle };
+        ErrorCode::Success
+    }
+
+    pub extern "C" fn open_blob(reader_handle: i32, _hash: *const u8, _hash_len: usize, location: *const c_char, blob_handle_p: *mut i32) -> ErrorCode {
+        if !ACTIVE_READERS.lock().unwrap().contains_key(&reader_handle) {
+            return ErrorCode::CommonInvalidState;
+        }
+
+        let location = match unsafe { CStr::from_ptr(location) }.to_str() {
+            Ok(location) => location.to_string(),
+            Err(_) => return ErrorCode::CommonInvalidStructure
+        };
+
+        if !INMEM_BLOBS.lock().unwrap().contains_key(&location) {
+            return ErrorCode::CommonIOError;
+        }
+
+        let handle = sequence::get_next_id();
+        READABLE_BLOBS.lock().unwrap().insert(handle, InmemBlob { location, chunk: Vec::new() });
+
+        unsafe { *blob_handle_p = handle };
+        ErrorCode::Success
+    }
+
+    pub extern "C" fn read(blob_handle: i32, size: usize, offset: usize, bytes_p: *mut *const u8, bytes_len_p: *mut usize) -> ErrorCode {
+        let mut blobs = READABLE_BLOBS.lock().unwrap();
+
+        let blob = match blobs.get_mut(&blob_handle) {
+            Some(blob) => blob,
+            None => return ErrorCode::CommonInvalidState
+        };
+
+        let inmem_blobs = INMEM_BLOBS.lock().unwrap();
+
+        let content = match inmem_blobs.get(&blob.location) {
+            Some(content) => content,
+            None => return ErrorCode::CommonIOError
+        };
+
+        let start = ::std::cmp::min(offset, content.len());
+        let end = ::std::cmp::min(offset.saturating_add(size), content.len());
+
+        blob.chunk = content[start..end].to_vec();
+
+        unsafe {
+            *bytes_p = if blob.chunk.is_empty() { ptr::null() } else { blob.chunk.as_ptr() };
+            *bytes_len_p = blob.chunk.len();
+        }
+
+        ErrorCode::Success
+    }
+
+    pub extern "C" fn close_blob(blob_handle: i32) -> ErrorCode {
+        let writable = WRITABLE_BLOBS.lock().unwrap().remove(&blob_handle).is_some();
+        let readable = READABLE_BLOBS.lock().unwrap().remove(&blob_handle).is_some();
+
+        if writable || readable {
+            ErrorCode::Success
+        } else {
+            ErrorCode::CommonInvalidState
+        }
+    }
+
+    pub extern "C" fn close_reader(reader_handle: i32) -> ErrorCode {
+        match ACTIVE_READERS.lock().unwrap().remove(&reader_handle) {
+            Some(_) => ErrorCode::Success,
+            None => ErrorCode::CommonInvalidState
+        }
+    }
+
+    pub fn cleanup() {
+        INMEM_BLOBS.lock().unwrap().clear();
+        ACTIVE_WRITERS.lock().unwrap().clear();
+        ACTIVE_READERS.lock().unwrap().clear();
+        WRITABLE_BLOBS.lock().unwrap().clear();
+        READABLE_BLOBS.lock().unwrap().clear();
+    }
+}
@@ -16,6 +16,9 @@ pub mod logger;
 #[cfg(test)]
 pub mod inmem_wallet;
 
+#[cfg(test)]
+pub mod inmem_blob_storage;
+
 #[allow(unused_macros)]
 #[macro_use]
 pub mod result;
@@ -31,3 +34,5 @@ pub mod test;
 pub mod try;
 
 pub mod option;
+
+pub mod qualifier;
@@ -0,0 +1,252 @@
+use errors::prelude::*;
+use utils::crypto::did_methods;
+
+pub const DID_PREFIX: &'static str = "did";
+pub const SCHEMA_PREFIX: &'static str = "schema";
+pub const CRED_DEF_PREFIX: &'static str = "creddef";
+pub const REV_REG_PREFIX: &'static str = "revreg";
+
+const DELIMITER: char = ':';
+const SCHEMA_MARKER: &'static str = "2";
+const CRED_DEF_MARKER: &'static str = "3";
+const REV_REG_MARKER: &'static str = "4";
+
+/// Returns true if the entity (DID, schema, credential definition or revocation registry id)
+/// is in fully qualified form, e.g. "did:sov:..." or "schema:sov:did:sov:...".
+pub fn is_fully_qualified(entity: &str) -> bool {
+    let mut parts = entity.splitn(3, DELIMITER);
+
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(prefix), Some(method), Some(_)) =>
+            [DID_PREFIX, SCHEMA_PREFIX, CRED_DEF_PREFIX, REV_REG_PREFIX].contains(&prefix) && _is_method_name(method),
+        _ => false
+    }
+}
+
+/// Returns the method of a fully qualified entity, e.g. "sov" for "did:sov:NcYxiDXkpYi6ov5FcYDi1e".
+pub fn method(entity: &str) -> Option<&str> {
+    if !is_fully_qualified(entity) {
+        return None;
+    }
+
+    entity.split(DELIMITER).nth(1)
+}
+
+/// Builds fully qualified form of the unqualified entity (DID, schema, credential definition
+/// or revocation registry id). Already qualified entity is returned as is.
+pub fn qualify(entity: &str, method: &str) -> IndyResult<String> {
+    if !_is_method_name(method) {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid DID method name: {}", method)));
+    }
+
+    if is_fully_qualified(entity) {
+        return Ok(entity.to_string());
+    }
+
+    let parts: Vec<&str> = entity.split(DELIMITER).collect();
+
+    let res = match parts.get(1) {
+        None => qualify_did(entity, method),
+        Some(&SCHEMA_MARKER) =>
+            format!("{}:{}:{}:{}", SCHEMA_PREFIX, method, qualify_did(parts[0], method), parts[1..].join(":")),
+        Some(&CRED_DEF_MARKER) if parts.len() >= 4 => {
+            // Schema reference can be either sequence number or schema id
+            let (schema_ref, tag) = _split_cred_def_tail(&parts[3..]);
+            let schema_ref = if schema_ref.contains(DELIMITER) { qualify(&schema_ref, method)? } else { schema_ref };
+            let tag = tag.map(|tag| format!(":{}", tag)).unwrap_or_default();
+            format!("{}:{}:{}:{}:{}:{}{}", CRED_DEF_PREFIX, method, qualify_did(parts[0], method), parts[1], parts[2], schema_ref, tag)
+        }
+        Some(&REV_REG_MARKER) if parts.len() >= 5 => {
+            let (cred_def_id, tail) = _split_rev_reg_tail(&parts[2..]);
+            format!("{}:{}:{}:{}:{}:{}", REV_REG_PREFIX, method, qualify_did(parts[0], method), parts[1], qualify(&cred_def_id, method)?, tail)
+        }
+        _ => return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Unknown identifier format: {}", entity)))
+    };
+
+    Ok(res)
+}
+
+/// Converts fully qualified entity (DID, schema, credential definition or revocation registry id)
+/// to unqualified form used by the Indy ledger. Unqualified entity is returned as is.
+///
+/// Key based DIDs (did:key, did:peer) have no unqualified form and are returned as is too.
+pub fn unqualify(entity: &str) -> String {
+    let (prefix, rest) = match _strip_prefix(entity) {
+        Some(res) => res,
+        None => return entity.to_string()
+    };
+
+    let parts: Vec<&str> = rest.split(DELIMITER).collect();
+
+    let marker = match prefix {
+        DID_PREFIX => return unqualify_did(entity),
+        SCHEMA_PREFIX => SCHEMA_MARKER,
+        CRED_DEF_PREFIX => CRED_DEF_MARKER,
+        REV_REG_PREFIX => REV_REG_MARKER,
+        _ => return entity.to_string()
+    };
+
+    // Qualified DID of the issuer goes first and is followed by the marker
+    let marker_pos = match parts.iter().skip(2).position(|part| *part == marker) {
+        Some(pos) => pos + 2,
+        None => return entity.to_string()
+    };
+
+    let did = unqualify_did(&parts[..marker_pos].join(":"));
+    let tail = &parts[marker_pos + 1..];
+
+    match prefix {
+        SCHEMA_PREFIX => format!("{}:{}:{}", did, marker, tail.join(":")),
+        CRED_DEF_PREFIX if tail.len() >= 2 => {
+            let (schema_ref, tag) = _split_cred_def_tail(&tail[1..]);
+            let tag = tag.map(|tag| format!(":{}", tag)).unwrap_or_default();
+            format!("{}:{}:{}:{}{}", did, marker, tail[0], unqualify(&schema_ref), tag)
+        }
+        REV_REG_PREFIX if tail.len() >= 3 => {
+            let (cred_def_id, tail) = _split_rev_reg_tail(tail);
+            format!("{}:{}:{}:{}", did, marker, unqualify(&cred_def_id), tail)
+        }
+        _ => entity.to_string()
+    }
+}
+
+pub fn qualify_did(did: &str, method: &str) -> String {
+    if is_fully_qualified(did) {
+        did.to_string()
+    } else {
+        format!("{}:{}:{}", DID_PREFIX, method, did)
+    }
+}
+
+/// Unqualified DID is the last part of qualified one, e.g. "did:sov:NcYxiDXkpYi6ov5FcYDi1e"
+/// and network qualified "did:sov:builder:NcYxiDXkpYi6ov5FcYDi1e" are both "NcYxiDXkpYi6ov5FcYDi1e".
+pub fn unqualify_did(did: &str) -> String {
+    if !did.starts_with(&format!("{}{}", DID_PREFIX, DELIMITER)) || !is_fully_qualified(did) || did_methods::is_key_based_did(did) {
+        return did.to_string();
+    }
+
+    did.rsplit(DELIMITER).next().unwrap_or(did).to_string()
+}
+
+// Method name must start with a letter, so unqualified ids of DIDs like "did" aren't treated as qualified
+fn _is_method_name(method: &str) -> bool {
+    method.chars().next().map(|c| c.is_ascii_lowercase()).unwrap_or(false)
+        && method.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+}
+
+fn _strip_prefix(entity: &str) -> Option<(&str, &str)> {
+    let mut parts = entity.splitn(3, DELIMITER);
+
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(prefix), Some(_method), Some(rest)) if is_fully_qualified(entity) => Some((prefix, rest)),
+        _ => None
+    }
+}
+
+// Splits "<schema ref>:<tag>" where schema ref is either sequence number or (possibly qualified) schema id
+fn _split_cred_def_tail(parts: &[&str]) -> (String, Option<String>) {
+    match parts.len() {
+        0 => (String::new(), None),
+        1 => (parts[0].to_string(), None),
+        len if parts[0].parse::<u32>().is_ok() && len == 2 => (parts[0].to_string(), Some(parts[1].to_string())),
+        len if parts[0].parse::<u32>().is_ok() => (parts[0].to_string(), Some(parts[1..len].join(":"))),
+        len if parts[0] == SCHEMA_PREFIX || len > 4 => (parts[..len - 1].join(":"), Some(parts[len - 1].to_string())),
+        _ => (parts.join(":"), None)
+    }
+}
+
+// Splits "<cred def id>:<type>:<tag>" of the revocation registry id
+fn _split_rev_reg_tail(parts: &[&str]) -> (String, String) {
+    let len = parts.len();
+    (parts[..len - 2].join(":"), parts[len - 2..].join(":"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DID: &'static str = "NcYxiDXkpYi6ov5FcYDi1e";
+    const QUALIFIED_DID: &'static str = "did:sov:NcYxiDXkpYi6ov5FcYDi1e";
+    const SCHEMA_ID: &'static str = "NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0";
+    const QUALIFIED_SCHEMA_ID: &'static str = "schema:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0";
+    const CRED_DEF_ID: &'static str = "NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:tag";
+    const QUALIFIED_CRED_DEF_ID: &'static str = "creddef:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:tag";
+    const REV_REG_ID: &'static str = "NcYxiDXkpYi6ov5FcYDi1e:4:NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:tag:CL_ACCUM:TAG_1";
+    const QUALIFIED_REV_REG_ID: &'static str = "revreg:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:4:creddef:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:tag:CL_ACCUM:TAG_1";
+
+    #[test]
+    fn is_fully_qualified_works() {
+        assert!(is_fully_qualified(QUALIFIED_DID));
+        assert!(is_fully_qualified(QUALIFIED_SCHEMA_ID));
+        assert!(!is_fully_qualified(DID));
+        assert!(!is_fully_qualified(SCHEMA_ID));
+    }
+
+    #[test]
+    fn method_works() {
+        assert_eq!(Some("sov"), method(QUALIFIED_DID));
+        assert_eq!(Some("sov"), method(QUALIFIED_CRED_DEF_ID));
+        assert_eq!(None, method(DID));
+    }
+
+    #[test]
+    fn qualify_works() {
+        assert_eq!(QUALIFIED_DID, qualify(DID, "sov").unwrap());
+        assert_eq!(QUALIFIED_SCHEMA_ID, qualify(SCHEMA_ID, "sov").unwrap());
+        assert_eq!(QUALIFIED_CRED_DEF_ID, qualify(CRED_DEF_ID, "sov").unwrap());
+        assert_eq!(QUALIFIED_REV_REG_ID, qualify(REV_REG_ID, "sov").unwrap());
+        assert_eq!(QUALIFIED_DID, qualify(QUALIFIED_DID, "sov").unwrap());
+    }
+
+    #[test]
+    fn qualify_works_for_cred_def_id_with_schema_id() {
+        assert_eq!("creddef:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:3:CL:schema:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0:tag",
+                   qualify("NcYxiDXkpYi6ov5FcYDi1e:3:CL:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0:tag", "sov").unwrap());
+    }
+
+    #[test]
+    fn qualify_fails_for_invalid_method() {
+        let res = qualify(DID, "So:v");
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn unqualify_works() {
+        assert_eq!(DID, unqualify(QUALIFIED_DID));
+        assert_eq!(SCHEMA_ID, unqualify(QUALIFIED_SCHEMA_ID));
+        assert_eq!(CRED_DEF_ID, unqualify(QUALIFIED_CRED_DEF_ID));
+        assert_eq!(REV_REG_ID, unqualify(QUALIFIED_REV_REG_ID));
+    }
+
+    #[test]
+    fn unqualify_works_for_unqualified() {
+        assert_eq!(DID, unqualify(DID));
+        assert_eq!(SCHEMA_ID, unqualify(SCHEMA_ID));
+        assert_eq!(CRED_DEF_ID, unqualify(CRED_DEF_ID));
+        assert_eq!(REV_REG_ID, unqualify(REV_REG_ID));
+    }
+
+    #[test]
+    fn unqualify_works_for_network_qualified() {
+        assert_eq!(DID, unqualify("did:sov:builder:NcYxiDXkpYi6ov5FcYDi1e"));
+        assert_eq!(SCHEMA_ID, unqualify("schema:sov:did:sov:builder:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0"));
+    }
+
+    #[test]
+    fn unqualify_works_for_cred_def_id_with_schema_id() {
+        assert_eq!("NcYxiDXkpYi6ov5FcYDi1e:3:CL:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0:tag",
+                   unqualify("creddef:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:3:CL:schema:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0:tag"));
+    }
+
+    #[test]
+    fn unqualify_works_for_did_named_as_prefix() {
+        assert_eq!("did:2:gvt:1.0", unqualify("did:2:gvt:1.0"));
+        assert_eq!("did:3:CL:did:2:gvt:1.0", unqualify("did:3:CL:did:2:gvt:1.0"));
+    }
+
+    #[test]
+    fn unqualify_keeps_key_based_did() {
+        let did = "did:key:z6MkpTHR8VNsBxYAAWHut2Geadd9jSwuBV8xRoAnwWsdvktH";
+        assert_eq!(did, unqualify(did));
+    }
+}
@@ -30,14 +30,14 @@ mod utils;
 use utils::{wallet, anoncreds, blob_storage};
 use utils::anoncreds::{COMMON_MASTER_SECRET, CREDENTIAL1_ID, CREDENTIAL2_ID, CREDENTIAL3_ID, ANONCREDS_WALLET_CONFIG};
 
-use indy::ErrorCode;
+use indy::{ErrorCode, IndyError};
 use utils::inmem_wallet::InmemWallet;
 use utils::constants::*;
 
 use utils::domain::anoncreds::schema::{Schema, AttributeNames, MAX_ATTRIBUTES_COUNT};
 use utils::domain::anoncreds::credential_definition::CredentialDefinition;
 use utils::domain::anoncreds::revocation_registry_definition::RevocationRegistryDefinition;
-use utils::domain::anoncreds::credential::CredentialInfo;
+use utils::domain::anoncreds::credential::{CredentialInfo, IssuedCredentialInfo};
 use utils::domain::anoncreds::credential_for_proof_request::{CredentialsForProofRequest, RequestedCredential};
 use utils::domain::anoncreds::proof::Proof;
 use utils::domain::anoncreds::revocation_state::RevocationState;
@@ -88,6 +88,116 @@ mod high_cases {
         }
     }
 
+    mod issuer_rotate_credential_def {
+        use super::*;
+
+        const ROTATED_TAG: &'static str = "TAG_ROTATED";
+
+        fn _create_gvt_cred_def(wallet_handle: i32) -> String {
+            let (cred_def_id, _) = anoncreds::issuer_create_credential_definition(wallet_handle,
+                                                                                 ISSUER_DID,
+                                                                                 &anoncreds::gvt_schema_json(),
+                                                                                 TAG_1,
+                                                                                 None,
+                                                                                 Some(&anoncreds::default_cred_def_config())).unwrap();
+            cred_def_id
+        }
+
+        #[test]
+        fn issuer_rotate_credential_def_works() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let cred_def_id = _create_gvt_cred_def(wallet_handle);
+
+            let (rotated_cred_def_id, rotated_cred_def_json) =
+                anoncreds::issuer_rotate_credential_def(wallet_handle, ISSUER_DID, &cred_def_id, &anoncreds::gvt_schema_json(), ROTATED_TAG, None).unwrap();
+
+            assert_ne!(cred_def_id, rotated_cred_def_id);
+
+            let rotated_cred_def: CredentialDefinition = serde_json::from_str(&rotated_cred_def_json).unwrap();
+            match rotated_cred_def {
+                CredentialDefinition::CredentialDefinitionV1(rotated_cred_def) => {
+                    assert_eq!(rotated_cred_def_id, rotated_cred_def.id);
+                    assert_eq!(ROTATED_TAG, rotated_cred_def.tag);
+                    assert!(rotated_cred_def.value.revocation.is_none());
+                }
+            }
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn issuer_rotate_credential_def_works_for_offer_by_predecessor_id() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let cred_def_id = _create_gvt_cred_def(wallet_handle);
+
+            let (rotated_cred_def_id, _) =
+                anoncreds::issuer_rotate_credential_def(wallet_handle, ISSUER_DID, &cred_def_id, &anoncreds::gvt_schema_json(), ROTATED_TAG, None).unwrap();
+
+            let cred_offer_json = anoncreds::issuer_create_credential_offer(wallet_handle, &cred_def_id).unwrap();
+            let cred_offer: serde_json::Value = serde_json::from_str(&cred_offer_json).unwrap();
+            assert_eq!(rotated_cred_def_id, cred_offer["cred_def_id"].as_str().unwrap());
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn issuer_rotate_credential_def_works_for_offer_by_chain_of_predecessors() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let cred_def_id = _create_gvt_cred_def(wallet_handle);
+
+            let (rotated_cred_def_id, _) =
+                anoncreds::issuer_rotate_credential_def(wallet_handle, ISSUER_DID, &cred_def_id, &anoncreds::gvt_schema_json(), ROTATED_TAG, None).unwrap();
+
+            let (twice_rotated_cred_def_id, _) =
+                anoncreds::issuer_rotate_credential_def(wallet_handle, ISSUER_DID, &rotated_cred_def_id, &anoncreds::gvt_schema_json(), "TAG_ROTATED_TWICE", None).unwrap();
+
+            let cred_offer_json = anoncreds::issuer_create_credential_offer(wallet_handle, &cred_def_id).unwrap();
+            let cred_offer: serde_json::Value = serde_json::from_str(&cred_offer_json).unwrap();
+            assert_eq!(twice_rotated_cred_def_id, cred_offer["cred_def_id"].as_str().unwrap());
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn issuer_rotate_credential_def_works_for_already_rotated() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let cred_def_id = _create_gvt_cred_def(wallet_handle);
+
+            anoncreds::issuer_rotate_credential_def(wallet_handle, ISSUER_DID, &cred_def_id, &anoncreds::gvt_schema_json(), ROTATED_TAG, None).unwrap();
+
+            let res = anoncreds::issuer_rotate_credential_def(wallet_handle, ISSUER_DID, &cred_def_id, &anoncreds::gvt_schema_json(), "TAG_ROTATED_TWICE", None);
+            assert_eq!(ErrorCode::CommonInvalidState, res.unwrap_err());
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn issuer_rotate_credential_def_works_for_other_schema() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let cred_def_id = _create_gvt_cred_def(wallet_handle);
+
+            let res = anoncreds::issuer_rotate_credential_def(wallet_handle, ISSUER_DID, &cred_def_id, &anoncreds::xyz_schema_json(), ROTATED_TAG, None);
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn issuer_rotate_credential_def_works_for_unknown_cred_def() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let res = anoncreds::issuer_rotate_credential_def(wallet_handle, ISSUER_DID, &anoncreds::issuer_1_gvt_cred_def_id(), &anoncreds::gvt_schema_json(), ROTATED_TAG, None);
+            assert_eq!(ErrorCode::WalletItemNotFound, res.unwrap_err());
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+    }
+
     mod issuer_create_credential_offer {
         use super::*;
 
@@ -266,6 +376,140 @@ mod high_cases {
             wallet::close_wallet(wallet_handle).unwrap();
             wallet::close_wallet(prover_wallet_handle).unwrap();
         }
+
+        #[test]
+        fn prover_store_credential_works_for_verify_credential_on_store() {
+            let (credential_def_json, credential_offer, _, _) = anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+            let prover_wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+
+            anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+            let (credential_req, credential_req_meta) = anoncreds::prover_create_credential_req(prover_wallet_handle,
+                                                                                                DID_MY1,
+                                                                                                &credential_offer,
+                                                                                                credential_def_json,
+                                                                                                COMMON_MASTER_SECRET).unwrap();
+
+            let (credential_json, _, _) = anoncreds::issuer_create_credential(wallet_handle,
+                                                                              &credential_offer,
+                                                                              &credential_req,
+                                                                              &anoncreds::gvt_credential_values_json(),
+                                                                              None,
+                                                                              None).unwrap();
+
+            // signature doesn't cover revocation registry id, so only verification on store notices it
+            let mut credential: serde_json::Value = serde_json::from_str(&credential_json).unwrap();
+            credential["rev_reg_id"] = json!(format!("{}:4:{}:{}:{}", ISSUER_DID, anoncreds::issuer_1_gvt_cred_def_id(), REVOC_REG_TYPE, TAG_1));
+            let credential_json = credential.to_string();
+
+            indy::set_runtime_config(r#"{"verify_credential_on_store": true}"#);
+
+            let res = anoncreds::prover_store_credential(prover_wallet_handle,
+                                                         CREDENTIAL1_ID,
+                                                         &credential_req_meta,
+                                                         &credential_json,
+                                                         &credential_def_json,
+                                                         None);
+
+            indy::set_runtime_config(r#"{"verify_credential_on_store": false}"#);
+
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            anoncreds::prover_store_credential(prover_wallet_handle,
+                                               CREDENTIAL1_ID,
+                                               &credential_req_meta,
+                                               &credential_json,
+                                               &credential_def_json,
+                                               None).unwrap();
+
+            wallet::close_wallet(wallet_handle).unwrap();
+            wallet::close_wallet(prover_wallet_handle).unwrap();
+        }
+    }
+
+    mod prover_verify_credential {
+        use super::*;
+
+        fn _verify_credential(modify: fn(&mut serde_json::Value), schema_json: &str) -> serde_json::Value {
+            let (credential_def_json, credential_offer, _, _) = anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+            let prover_wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+
+            anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+            let (credential_req, credential_req_meta) = anoncreds::prover_create_credential_req(prover_wallet_handle,
+                                                                                                DID_MY1,
+                                                                                                &credential_offer,
+                                                                                                credential_def_json,
+                                                                                                COMMON_MASTER_SECRET).unwrap();
+
+            let (credential_json, _, _) = anoncreds::issuer_create_credential(wallet_handle,
+                                                                              &credential_offer,
+                                                                              &credential_req,
+                                                                              &anoncreds::gvt_credential_values_json(),
+                                                                              None,
+                                                                              None).unwrap();
+
+            let mut credential: serde_json::Value = serde_json::from_str(&credential_json).unwrap();
+            modify(&mut credential);
+
+            let report_json = anoncreds::prover_verify_credential(prover_wallet_handle,
+                                                                  &credential_req_meta,
+                                                                  &credential.to_string(),
+                                                                  schema_json,
+                                                                  credential_def_json,
+                                                                  None).unwrap();
+
+            wallet::close_wallet(wallet_handle).unwrap();
+            wallet::close_wallet(prover_wallet_handle).unwrap();
+
+            serde_json::from_str(&report_json).unwrap()
+        }
+
+        #[test]
+        fn prover_verify_credential_works() {
+            let report = _verify_credential(|_| {}, &anoncreds::gvt_schema_json());
+
+            assert_eq!(json!(true), report["valid"]);
+            assert_eq!(json!(true), report["schema"]["valid"]);
+            assert_eq!(json!(true), report["values"]["valid"]);
+            assert_eq!(json!(true), report["cred_def"]["valid"]);
+            assert_eq!(json!(true), report["rev_reg"]["valid"]);
+            assert_eq!(json!(true), report["signature"]["valid"]);
+        }
+
+        #[test]
+        fn prover_verify_credential_works_for_tampered_raw_value() {
+            let report = _verify_credential(|credential| credential["values"]["age"]["raw"] = json!("18"), &anoncreds::gvt_schema_json());
+
+            assert_eq!(json!(false), report["valid"]);
+            assert_eq!(json!(false), report["values"]["valid"]);
+            assert_eq!(json!(true), report["signature"]["valid"]);
+        }
+
+        #[test]
+        fn prover_verify_credential_works_for_tampered_encoded_value() {
+            let report = _verify_credential(|credential| {
+                credential["values"]["age"]["raw"] = json!("18");
+                credential["values"]["age"]["encoded"] = json!("18");
+            }, &anoncreds::gvt_schema_json());
+
+            assert_eq!(json!(false), report["valid"]);
+            assert_eq!(json!(true), report["values"]["valid"]);
+            assert_eq!(json!(false), report["signature"]["valid"]);
+        }
+
+        #[test]
+        fn prover_verify_credential_works_for_other_schema() {
+            let report = _verify_credential(|_| {}, &anoncreds::xyz_schema_json());
+
+            assert_eq!(json!(false), report["valid"]);
+            assert_eq!(json!(false), report["schema"]["valid"]);
+            assert_eq!(json!(false), report["cred_def"]["valid"]);
+        }
     }
 
     mod prover_get_credentials {
@@ -449,6 +693,254 @@ mod high_cases {
         }
     }
 
+    mod prover_delete_credential {
+        use super::*;
+
+        #[test]
+        fn prover_delete_credential_works() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let (_, _, cred_def_id, cred_def_json) = anoncreds::multi_steps_issuer_preparation(wallet_handle,
+                                                                                               ISSUER_DID,
+                                                                                               GVT_SCHEMA_NAME,
+                                                                                               GVT_SCHEMA_ATTRIBUTES);
+
+            anoncreds::prover_create_master_secret(wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+            anoncreds::multi_steps_create_credential(COMMON_MASTER_SECRET,
+                                                     wallet_handle,
+                                                     wallet_handle,
+                                                     CREDENTIAL1_ID,
+                                                     &anoncreds::gvt_credential_values_json(),
+                                                     &cred_def_id,
+                                                     &cred_def_json);
+
+            anoncreds::prover_get_credential(wallet_handle, CREDENTIAL1_ID).unwrap();
+
+            anoncreds::prover_delete_credential(wallet_handle, CREDENTIAL1_ID).unwrap();
+
+            let res = anoncreds::prover_get_credential(wallet_handle, CREDENTIAL1_ID);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn prover_delete_credential_works_for_not_found() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let res = anoncreds::prover_delete_credential(wallet_handle, "other_cred_id");
+            assert_eq!(ErrorCode::WalletItemNotFound, res.unwrap_err());
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[cfg(feature = "revocation_tests")]
+        #[test]
+        fn prover_delete_credential_works_for_cached_revocation_state() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let (_, _, cred_def_id, cred_def_json, rev_reg_id, revoc_reg_def_json, _, blob_storage_reader_handle) =
+                anoncreds::multi_steps_issuer_revocation_preparation(wallet_handle,
+                                                                     ISSUER_DID,
+                                                                     GVT_SCHEMA_NAME,
+                                                                     GVT_SCHEMA_ATTRIBUTES,
+                                                                     r#"{"max_cred_num":5, "issuance_type":"ISSUANCE_ON_DEMAND"}"#);
+
+            anoncreds::prover_create_master_secret(wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+            let cred_offer_json = anoncreds::issuer_create_credential_offer(wallet_handle, &cred_def_id).unwrap();
+
+            let (cred_req_json, cred_req_metadata_json) = anoncreds::prover_create_credential_req(wallet_handle,
+                                                                                                 DID_MY1,
+                                                                                                 &cred_offer_json,
+                                                                                                 &cred_def_json,
+                                                                                                 COMMON_MASTER_SECRET).unwrap();
+
+            let (cred_json, _, revoc_reg_delta_json) = anoncreds::issuer_create_credential(wallet_handle,
+                                                                                           &cred_offer_json,
+                                                                                           &cred_req_json,
+                                                                                           &anoncreds::gvt_credential_values_json(),
+                                                                                           Some(&rev_reg_id),
+                                                                                           Some(blob_storage_reader_handle)).unwrap();
+            let revoc_reg_delta_json = revoc_reg_delta_json.unwrap();
+
+            anoncreds::prover_store_credential(wallet_handle,
+                                               CREDENTIAL1_ID,
+                                               &cred_req_metadata_json,
+                                               &cred_json,
+                                               &cred_def_json,
+                                               Some(&revoc_reg_def_json)).unwrap();
+
+            // RevocationState is cached for the credential at 200, so it can't be updated for earlier timestamp
+            anoncreds::prover_update_credential_revocation_state(wallet_handle,
+                                                                 blob_storage_reader_handle,
+                                                                 CREDENTIAL1_ID,
+                                                                 &revoc_reg_def_json,
+                                                                 &revoc_reg_delta_json,
+                                                                 200).unwrap();
+
+            let res = anoncreds::prover_update_credential_revocation_state(wallet_handle,
+                                                                           blob_storage_reader_handle,
+                                                                           CREDENTIAL1_ID,
+                                                                           &revoc_reg_def_json,
+                                                                           &revoc_reg_delta_json,
+                                                                           100);
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            anoncreds::prover_delete_credential(wallet_handle, CREDENTIAL1_ID).unwrap();
+
+            // the same credential stored again doesn't get RevocationState cached for the deleted one
+            anoncreds::prover_store_credential(wallet_handle,
+                                               CREDENTIAL1_ID,
+                                               &cred_req_metadata_json,
+                                               &cred_json,
+                                               &cred_def_json,
+                                               Some(&revoc_reg_def_json)).unwrap();
+
+            anoncreds::prover_update_credential_revocation_state(wallet_handle,
+                                                                 blob_storage_reader_handle,
+                                                                 CREDENTIAL1_ID,
+                                                                 &revoc_reg_def_json,
+                                                                 &revoc_reg_delta_json,
+                                                                 100).unwrap();
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+    }
+
+    mod prover_credential_tags {
+        use super::*;
+
+        fn _proof_req() -> String {
+            json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({
+                   "attr1_referent": json!({ "name":"name" })
+               }),
+               "requested_predicates": json!({}),
+            }).to_string()
+        }
+
+        fn _search_by_tag(wallet_handle: i32, tag_query: serde_json::Value) -> Vec<RequestedCredential> {
+            let extra_query = json!({ "attr1_referent": tag_query }).to_string();
+
+            let search_handle = anoncreds::prover_search_credentials_for_proof_req(wallet_handle, &_proof_req(), Some(&extra_query)).unwrap();
+
+            let credentials_json = anoncreds::prover_fetch_next_credentials_for_proof_req(search_handle, "attr1_referent", 100).unwrap();
+
+            anoncreds::prover_close_credentials_search_for_proof_req(search_handle).unwrap();
+
+            serde_json::from_str(&credentials_json).unwrap()
+        }
+
+        fn _prepare_credentials(wallet_handle: i32) {
+            let (_, _, cred_def_id, cred_def_json) = anoncreds::multi_steps_issuer_preparation(wallet_handle,
+                                                                                               ISSUER_DID,
+                                                                                               GVT_SCHEMA_NAME,
+                                                                                               GVT_SCHEMA_ATTRIBUTES);
+
+            anoncreds::prover_create_master_secret(wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+            anoncreds::multi_steps_create_credential(COMMON_MASTER_SECRET,
+                                                     wallet_handle,
+                                                     wallet_handle,
+                                                     CREDENTIAL1_ID,
+                                                     &anoncreds::gvt_credential_values_json(),
+                                                     &cred_def_id,
+                                                     &cred_def_json);
+
+            anoncreds::multi_steps_create_credential(COMMON_MASTER_SECRET,
+                                                     wallet_handle,
+                                                     wallet_handle,
+                                                     CREDENTIAL2_ID,
+                                                     &anoncreds::gvt2_credential_values_json(),
+                                                     &cred_def_id,
+                                                     &cred_def_json);
+        }
+
+        #[test]
+        fn prover_add_credential_tags_works() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            _prepare_credentials(wallet_handle);
+
+            anoncreds::prover_add_credential_tags(wallet_handle, CREDENTIAL1_ID, r#"{"favorite": "yes", "~label": "work"}"#).unwrap();
+
+            let credentials = _search_by_tag(wallet_handle, json!({ "favorite": "yes" }));
+            assert_eq!(1, credentials.len());
+            assert_eq!(CREDENTIAL1_ID, credentials[0].cred_info.referent);
+
+            let credentials = _search_by_tag(wallet_handle, json!({ "~label": "work" }));
+            assert_eq!(1, credentials.len());
+            assert_eq!(CREDENTIAL1_ID, credentials[0].cred_info.referent);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn prover_add_credential_tags_works_for_reserved_tags() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            _prepare_credentials(wallet_handle);
+
+            let res = anoncreds::prover_add_credential_tags(wallet_handle, CREDENTIAL1_ID, r#"{"schema_id": "other_schema_id"}"#);
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            let res = anoncreds::prover_add_credential_tags(wallet_handle, CREDENTIAL1_ID, r#"{"attr::name::value": "Alexander"}"#);
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            // system tags stay untouched
+            let credentials = _search_by_tag(wallet_handle, json!({ "attr::name::value": "Alex" }));
+            assert_eq!(1, credentials.len());
+            assert_eq!(CREDENTIAL1_ID, credentials[0].cred_info.referent);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn prover_add_credential_tags_works_for_not_found() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let res = anoncreds::prover_add_credential_tags(wallet_handle, "other_cred_id", r#"{"favorite": "yes"}"#);
+            assert_eq!(ErrorCode::WalletItemNotFound, res.unwrap_err());
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn prover_delete_credential_tags_works() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            _prepare_credentials(wallet_handle);
+
+            anoncreds::prover_add_credential_tags(wallet_handle, CREDENTIAL1_ID, r#"{"favorite": "yes"}"#).unwrap();
+            anoncreds::prover_add_credential_tags(wallet_handle, CREDENTIAL2_ID, r#"{"favorite": "yes"}"#).unwrap();
+
+            anoncreds::prover_delete_credential_tags(wallet_handle, CREDENTIAL1_ID, r#"["favorite"]"#).unwrap();
+
+            let credentials = _search_by_tag(wallet_handle, json!({ "favorite": "yes" }));
+            assert_eq!(1, credentials.len());
+            assert_eq!(CREDENTIAL2_ID, credentials[0].cred_info.referent);
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn prover_delete_credential_tags_works_for_reserved_tags() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            _prepare_credentials(wallet_handle);
+
+            let res = anoncreds::prover_delete_credential_tags(wallet_handle, CREDENTIAL1_ID, r#"["cred_def_id"]"#);
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+    }
+
     mod prover_credentials_search {
         use super::*;
 
@@ -3110,7 +3602,7 @@ mod high_cases {
 
         #[test]
         fn verifier_verify_proof_works_for_wrong_proof() {
-            let proof_json = anoncreds::proof_json().replace("1139481716457488690172217916278103335", "1111111111111111111111111111111111111");
+            let proof_json = anoncreds::proof_json().replace("99262857098057710338306967609588410025648622308394250666849665532448612202874", "1111111111111111111111111111111111111");
 
             let valid = anoncreds::verifier_verify_proof(&anoncreds::proof_request_attr(),
                                                          &proof_json,
@@ -3120,38 +3612,338 @@ mod high_cases {
                                                          "{}").unwrap();
             assert!(!valid);
         }
-    }
-}
-
-mod medium_cases {
-    use super::*;
 
-    mod issuer_create_schema {
-        use super::*;
+        fn _proof_request_attr_with_restrictions(restrictions: serde_json::Value) -> String {
+            json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({
+                   "attr1_referent": json!({
+                       "name":"name",
+                       "restrictions": restrictions
+                   })
+               }),
+               "requested_predicates": json!({}),
+            }).to_string()
+        }
 
         #[test]
-        fn issuer_create_schema_works_for_empty_attrs() {
-            let res = anoncreds::issuer_create_schema(ISSUER_DID,
-                                                      GVT_SCHEMA_NAME,
-                                                      SCHEMA_VERSION,
-                                                      "[]");
-            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        fn verifier_verify_proof_works_for_satisfied_restrictions() {
+            let proof_req = _proof_request_attr_with_restrictions(json!({"issuer_did": ISSUER_DID, "attr::name::value": "Alex"}));
+
+            let valid = anoncreds::verifier_verify_proof(&proof_req,
+                                                         &anoncreds::proof_json(),
+                                                         &anoncreds::schemas_for_proof(),
+                                                         &anoncreds::cred_defs_for_proof(),
+                                                         "{}",
+                                                         "{}").unwrap();
+            assert!(valid);
         }
 
         #[test]
-        fn issuer_create_schema_works_for_invalid_issuer_did() {
-            let res = anoncreds::issuer_create_schema(INVALID_BASE58_DID,
-                                                      GVT_SCHEMA_NAME,
-                                                      SCHEMA_VERSION,
-                                                      GVT_SCHEMA_ATTRIBUTES);
-            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        fn verifier_verify_proof_works_for_not_satisfied_restrictions() {
+            let proof_req = _proof_request_attr_with_restrictions(json!({"issuer_did": ISSUER_DID_2}));
+
+            let res = anoncreds::verifier_verify_proof(&proof_req,
+                                                       &anoncreds::proof_json(),
+                                                       &anoncreds::schemas_for_proof(),
+                                                       &anoncreds::cred_defs_for_proof(),
+                                                       "{}",
+                                                       "{}");
+            assert_code!(ErrorCode::AnoncredsProofRejected, res);
         }
 
         #[test]
-        fn issuer_create_schema_works_for_attrs_count_more_than_acceptable() {
-            let attr_names: AttributeNames = (0..MAX_ATTRIBUTES_COUNT + 1).map(|i| i.to_string()).collect();
+        fn verifier_verify_proof_works_for_not_satisfied_revealed_value_restriction() {
+            let proof_req = _proof_request_attr_with_restrictions(json!({"attr::name::value": "Alexander"}));
 
-            let res = anoncreds::issuer_create_schema(ISSUER_DID,
+            let res = anoncreds::verifier_verify_proof(&proof_req,
+                                                       &anoncreds::proof_json(),
+                                                       &anoncreds::schemas_for_proof(),
+                                                       &anoncreds::cred_defs_for_proof(),
+                                                       "{}",
+                                                       "{}");
+            assert_code!(ErrorCode::AnoncredsProofRejected, res);
+        }
+
+        fn _proof_request_attr_group() -> String {
+            json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({
+                   "attr_group_referent": json!({
+                       "names": ["name", "sex"]
+                   })
+               }),
+               "requested_predicates": json!({}),
+            }).to_string()
+        }
+
+        fn _proof_for_attr_group() -> String {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let requested_credentials_json = json!({
+                 "self_attested_attributes": json!({}),
+                 "requested_attributes": json!({
+                    "attr_group_referent": json!({ "cred_id": CREDENTIAL1_ID, "revealed":true })
+                 }),
+                 "requested_predicates": json!({})
+            }).to_string();
+
+            let proof_json = anoncreds::prover_create_proof(wallet_handle,
+                                                            &_proof_request_attr_group(),
+                                                            &requested_credentials_json,
+                                                            COMMON_MASTER_SECRET,
+                                                            &anoncreds::schemas_for_proof(),
+                                                            &anoncreds::cred_defs_for_proof(),
+                                                            "{}").unwrap();
+
+            wallet::close_wallet(wallet_handle).unwrap();
+
+            proof_json
+        }
+
+        #[test]
+        fn verifier_verify_proof_works_for_attribute_group() {
+            let proof_json = _proof_for_attr_group();
+
+            let proof: serde_json::Value = serde_json::from_str(&proof_json).unwrap();
+            let revealed_group = &proof["requested_proof"]["revealed_attr_groups"]["attr_group_referent"]["values"];
+            assert_eq!(json!("Alex"), revealed_group["name"]["raw"]);
+            assert_eq!(json!("male"), revealed_group["sex"]["raw"]);
+
+            let valid = anoncreds::verifier_verify_proof(&_proof_request_attr_group(),
+                                                         &proof_json,
+                                                         &anoncreds::schemas_for_proof(),
+                                                         &anoncreds::cred_defs_for_proof(),
+                                                         "{}",
+                                                         "{}").unwrap();
+            assert!(valid);
+        }
+
+        #[test]
+        fn verifier_verify_proof_works_for_attribute_group_with_replaced_raw_value() {
+            let proof_json = _proof_for_attr_group().replace(r#""raw":"male""#, r#""raw":"female""#);
+
+            let valid = anoncreds::verifier_verify_proof(&_proof_request_attr_group(),
+                                                         &proof_json,
+                                                         &anoncreds::schemas_for_proof(),
+                                                         &anoncreds::cred_defs_for_proof(),
+                                                         "{}",
+                                                         "{}").unwrap();
+            assert!(!valid);
+        }
+    }
+
+    mod verifier_verify_proof_verbose {
+        use super::*;
+
+        fn _verify_verbose(proof_req: &str, proof_json: &str) -> serde_json::Value {
+            let report_json = anoncreds::verifier_verify_proof_verbose(proof_req,
+                                                                       proof_json,
+                                                                       &anoncreds::schemas_for_proof(),
+                                                                       &anoncreds::cred_defs_for_proof(),
+                                                                       "{}",
+                                                                       "{}").unwrap();
+            serde_json::from_str(&report_json).unwrap()
+        }
+
+        #[test]
+        fn verifier_verify_proof_verbose_works_for_correct_proof() {
+            let report = _verify_verbose(&anoncreds::proof_request_attr(), &anoncreds::proof_json());
+
+            assert_eq!(json!(true), report["valid"]);
+            assert_eq!(json!(true), report["proof_verified"]);
+            assert_eq!(json!("not_applicable"), report["sub_proofs"][0]["revocation"]);
+            assert_eq!(json!("attr1_referent"), report["sub_proofs"][0]["referents"][0]["referent"]);
+            assert_eq!(json!(true), report["sub_proofs"][0]["referents"][0]["verified"]);
+        }
+
+        #[test]
+        fn verifier_verify_proof_verbose_works_for_wrong_proof() {
+            let proof_json = anoncreds::proof_json().replace("99262857098057710338306967609588410025648622308394250666849665532448612202874", "1111111111111111111111111111111111111");
+
+            let report = _verify_verbose(&anoncreds::proof_request_attr(), &proof_json);
+
+            assert_eq!(json!(false), report["valid"]);
+            assert_eq!(json!(false), report["proof_verified"]);
+            // failure of CL proof for non revocable credential isn't reported as revocation failure
+            assert_eq!(json!("not_applicable"), report["sub_proofs"][0]["revocation"]);
+        }
+
+        #[test]
+        fn verifier_verify_proof_verbose_works_for_missing_referent() {
+            let proof_req = json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({
+                   "attr1_referent": json!({
+                       "name":"name"
+                   }),
+                   "attr2_referent": json!({
+                       "name":"sex"
+                   })
+               }),
+               "requested_predicates": json!({}),
+            }).to_string();
+
+            let proof_json = anoncreds::proof_json();
+
+            let res = anoncreds::verifier_verify_proof(&proof_req,
+                                                       &proof_json,
+                                                       &anoncreds::schemas_for_proof(),
+                                                       &anoncreds::cred_defs_for_proof(),
+                                                       "{}",
+                                                       "{}");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            let report = _verify_verbose(&proof_req, &proof_json);
+
+            // CL proof of the referents the proof contains is still correct
+            assert_eq!(json!(true), report["proof_verified"]);
+            assert_eq!(json!(false), report["valid"]);
+            assert_eq!(json!(["attr2_referent"]), report["missing_referents"]);
+            assert_eq!(json!([]), report["unexpected_referents"]);
+        }
+
+        #[test]
+        fn verifier_verify_proof_verbose_works_for_not_satisfied_restrictions() {
+            let proof_req = json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({
+                   "attr1_referent": json!({
+                       "name":"name",
+                       "restrictions": json!({"issuer_did": ISSUER_DID_2})
+                   })
+               }),
+               "requested_predicates": json!({}),
+            }).to_string();
+
+            let report = _verify_verbose(&proof_req, &anoncreds::proof_json());
+
+            assert_eq!(json!(false), report["valid"]);
+            assert_eq!(json!(true), report["proof_verified"]);
+            assert_eq!(json!(false), report["sub_proofs"][0]["referents"][0]["verified"]);
+            assert!(report["sub_proofs"][0]["referents"][0]["error"].is_string());
+        }
+    }
+
+    mod predicates {
+        use super::*;
+
+        fn _proof_req_for_predicate(p_type: &str, p_value: i32) -> String {
+            json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({}),
+               "requested_predicates": json!({
+                   "predicate1_referent": json!({ "name":"age", "p_type":p_type, "p_value":p_value })
+               }),
+            }).to_string()
+        }
+
+        fn _create_proof_for_predicate(p_type: &str, p_value: i32) -> Result<String, IndyError> {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let requested_credentials_json = json!({
+                 "self_attested_attributes": json!({}),
+                 "requested_attributes": json!({}),
+                 "requested_predicates": json!({
+                    "predicate1_referent": json!({ "cred_id": CREDENTIAL1_ID })
+                 })
+            }).to_string();
+
+            let res = anoncreds::prover_create_proof(wallet_handle,
+                                                     &_proof_req_for_predicate(p_type, p_value),
+                                                     &requested_credentials_json,
+                                                     COMMON_MASTER_SECRET,
+                                                     &anoncreds::schemas_for_proof(),
+                                                     &anoncreds::cred_defs_for_proof(),
+                                                     "{}");
+
+            wallet::close_wallet(wallet_handle).unwrap();
+
+            res
+        }
+
+        fn _verify_proof_for_predicate(p_type: &str, p_value: i32, proof_json: &str) -> Result<bool, IndyError> {
+            anoncreds::verifier_verify_proof(&_proof_req_for_predicate(p_type, p_value),
+                                             proof_json,
+                                             &anoncreds::schemas_for_proof(),
+                                             &anoncreds::cred_defs_for_proof(),
+                                             "{}",
+                                             "{}")
+        }
+
+        // credential attribute "age" is 28
+        fn _check_predicate(p_type: &str, satisfied_value: i32, failed_value: i32) {
+            let proof_json = _create_proof_for_predicate(p_type, satisfied_value).unwrap();
+            assert!(_verify_proof_for_predicate(p_type, satisfied_value, &proof_json).unwrap());
+
+            let res = _create_proof_for_predicate(p_type, failed_value);
+            assert!(res.is_err());
+
+            // proof created for satisfied bound can't be passed off for the failed one
+            let res = _verify_proof_for_predicate(p_type, failed_value, &proof_json);
+            assert!(!res.unwrap_or(false));
+        }
+
+        #[test]
+        fn anoncreds_works_for_le_predicate() {
+            _check_predicate("<=", 28, 27);
+        }
+
+        #[test]
+        fn anoncreds_works_for_lt_predicate() {
+            _check_predicate("<", 29, 28);
+        }
+
+        #[test]
+        fn anoncreds_works_for_gt_predicate() {
+            _check_predicate(">", 27, 28);
+        }
+    }
+}
+
+mod medium_cases {
+    use super::*;
+
+    mod issuer_create_schema {
+        use super::*;
+
+        #[test]
+        fn issuer_create_schema_works_for_empty_attrs() {
+            let res = anoncreds::issuer_create_schema(ISSUER_DID,
+                                                      GVT_SCHEMA_NAME,
+                                                      SCHEMA_VERSION,
+                                                      "[]");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn issuer_create_schema_works_for_invalid_issuer_did() {
+            let res = anoncreds::issuer_create_schema(INVALID_BASE58_DID,
+                                                      GVT_SCHEMA_NAME,
+                                                      SCHEMA_VERSION,
+                                                      GVT_SCHEMA_ATTRIBUTES);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn issuer_create_schema_works_for_attrs_count_more_than_acceptable() {
+            let attr_names: AttributeNames = (0..MAX_ATTRIBUTES_COUNT + 1).map(|i| i.to_string()).collect();
+
+            let res = anoncreds::issuer_create_schema(ISSUER_DID,
                                                       GVT_SCHEMA_NAME,
                                                       SCHEMA_VERSION,
                                                       &serde_json::to_string(&attr_names).unwrap());
@@ -3665,7 +4457,7 @@ mod medium_cases {
 
         #[test]
         fn verifier_verify_proof_works_for_invalid_proof_json_format() {
-            let proof_json = r#"{"proof":{"proofs":{"credential::58479554-187f-40d9-b0a5-a95cfb0338c3":{"primary_proof":{"eq_proof":{"revealed_attrs":{"name":"1139481716457488690172217916278103335"},"a_prime":"80401564260558483983794628158664845806393125691167675024527906210615204776868092566789307767601325086260531777605457298059939671624755239928848057947875953445797869574854365751051663611984607735255307096920094357120779812375573500489773454634756645206823074153240319316758529163584251907107473703779754778699279153037094140428648169418133281187947677937472972061954089873405836249023133445286756991574802740614183730141450546881449500189789970102133738133443822618072337620343825908790734460412932921199267304555521397418007577171242880211812703320270140386219809818196744216958369397014610013338422295772654405475023","e":"31151798717381512709903464053695613005379725796031086912986270617392167764097422442809244590980303622977555221812111085160553241592792901","v":"524407431684833626723631303096063196973911986967748096669183384949467719053669910411426601230736351335262754473490498825342793551112426427823428399937548938048089615644972537564428344526295733169691240937176356626523864731701111189536269488496019586818879697981955044502664124964896796783428945944075084807859935155837238670987272778459356531608865162828109489758902085206073584532002909678902616210042778963974064479140826712481297584040209095459963718975102750913306565864485279810056629704077428898739021040190774575868853629858297299392839284660771662690107106553362040805152261505268111067408422298806905178826507224233050991301274817252924123120887017757639206512015559321675322509820081151404696713509158685022511201565062671933414307463988209696457343022378430051265752251403461414881325357657438328740471164157220698425309006894962942640219890219594168419276308074677144722217081026358892787770650248878952483621","m":{"age":"10477979077744818183854012231360633424177093192344587159214818537659704987539982653663361680650769087122324965941845552897155693994859927792964720675888893623940580527766661802170","sex":"15368219775809326116045200104269422566086585069798988383076685221700842794654771075432385446820819836777771517356551059931242867733879324915651894894695726945279462946826404864068","height":"268172143999991481637372321419290603042446269013750825098514042757459298040087626745653681785038933035820421862976371452111736537699176931068992453946771945552540798204580069806"},"m1":"119095745403940293668103184388411799541118279558928018597628509118163496000813590825371995586347826189221837428823000332905316924389185590810015031744029496470545254805993327676570037596326743185389101389800942263689809725968264069601565478411709555274081560719927118853299543998608664701485475703881376151770","m2":"3166313665375815600922385342096456465402430622944571045536207479553790085339726549928012930073803465171492637049498407367742103524723152099973753540483894420905314750248333232361"},"ge_proofs":[{"u":{"2":"6494171529848192644197417834173236605253723188808961394289041396341136802965710957759175642924978223517091081898946519122412445399638640485278379079647638538597635045303985779767","0":"7739508859260491061487569748588091139318989278758566530899756574128579312557203413565436003310787878172471425996601979342157451689172171025305431595131816910273398879776841751855","3":"9424758820140378077609053635383940574362083113571024891496206162696034958494400871955445981458978146571146602763357500412840538526390475379772903513687358736287298159312524034159","1":"9011979414559555265454106061917684716953356440811838475257096756618761731111646531136628099710567973381801256908067529269805992222342928842825929421929485785888403149296320711642"},"r":{"DELTA":"2119857977629302693157808821351328058251440215802746362450951329352726877165815663955490999790457576333458830301801261754696823614762123890412904169206391143688952648566814660498520188221060505840151491403269696751525874990487604723445355651918681212361562384420233903265612599812725766212744963540390806334870022328290970137051148373040320927100063898502086531019924715927190306801273252711777648467224661735618842887006436195147540705753550974655689586750013569294343535843195025962867299786380033532422131203367401906988124836294104501525520053613392691214421562815044433237816093079784307397782961917892254668290115653012265908717124278607660504580036193346698672079435538219972121355893074219968755049500875222141","2":"879097501989202140886939888802566536179834329508897124489020677433754766947767937608431979796722207676629625451150104784909666168153917345813160237337412296010679353735699663083287427507870244565918756969618964144516025526404618052053542009438548457492400344119561349471929199757453154204191407620539220514897529346602664135146454509169680801061111878075145734123580343470361019624175036825631373890661124315134340427076598351080893567995392248394683875116715114577054906406649006122102488431184007790011073389768061904597267545895265921673106871142463561948479668876241841045522543174660428236658891636170119227855493059358614089146415798861053408542832475696099851160385105386001523305465829676723036394820593263477","0":"1724016272047416140958096373304304971004826284109046259544344355102178044512441391364907122486655755929044720001281832600729467778103556397960700809066582436321515744527550472324028227472294258045699756170293405547851344921626775854114063087070898499913846456795761213291925373770081490280103876827479351849800210782799381740073719081199000612284788683993320623339686128531187019125095700122135094060470612862911102824801065698176788174959069186600426519872015152034176356923049531650418553748519941342115963599848111324793380438600664408464987023646615003553912544410140730587797458882329021327455905737414352355326238028222782957735440607899424838572541602600159016542488644761584240884783618700311735467659132540546","3":"2317535203964314926167241523636020444600002667629517624482931328850422196008281300859516069440995466415138723103558631951648519232327284208990029010060986032518946759289078833125920310350676484457972303378558158127406345804560689086460633931717939234025886786468170219981598030245042011840614339386724945679531091642132820284896626191109974537171662283750959028046143650291367908660204201563611944187723824430780626387525165408619587771059635528553832034409311888615502905143628507219523591091412192645348525327725381323865648645828460581593542176351568614465903523790649219812666979685223535464526901006270478687017672202058914176692964406859722580270696925877498058525086810338471380117323227744481903228027847825795","1":"1119193929864813751243160041764170298897380522230946444206167281178657213260394833843687899872857393015947283159245092452814155776571829885921814072299525859857844030379558685168895306445277750249341844789101670896570226707650318347992386244538723699686941887792682779028216548922683313576597384354842537728667739985216662699631842296096507821667149950956179957306177525178260912379909156360834120816956949271530622510333943914411903103069247646327625753995178999023427645468623522280255892736633780185163496867644317005801241786702434621502492159672660131289312665511793827552317714835658019088880972220344126692027952749318018900669839090109361161616086319604439015851316798257015063653414161203599184730094765941653"},"mj":"10477979077744818183854012231360633424177093192344587159214818537659704987539982653663361680650769087122324965941845552897155693994859927792964720675888893623940580527766661802170","alpha":"46280660038407959140964701167450659223532556136388451390393713283900546119670373626221864441898929302821705811144923685080534692512705456699843367809872982836890616398604933641265111106644805368974824737276965928297120628041257593166650593538539384316563258781595629888673792430276007730792093088812056156937735120078929629310611907731935101448992312370312134173482115524436767558802102266208152808607480693236511858269018733175523724309089010048330044458187371675333889670055578652283806685440133357512406700879353713629795062705271430695988191782837658895477702634883214188598350625843489120361660836956958750828038278027538830855628653513539929730230905015331221220017847248793929813230252015802389329428995718799619565984669228143200627972926117282688854152516298117476837960100343260648687249027349308513966440386556698667484082658689","t":{"DELTA":"46814992964714978733007076702016837564951956529003697497847838781899848384824991374342901164708655443686022921583406187082133141084994843502230809550055933825660668160300304112671478218513259983054489597176651737200716259733573469298437873515151377206364940530308167934399245072298875358347931404742292788785586833114480704138718996633638362933821933388459210678374952072108333767698704767907612549860590824123780096225591372365712106060039646448181221691765233478768574198237963457485496438076793333937013217675591500849193742006533651525421426481898699626618796271544860105422331629265388419155909716261466161258430","2":"59423006413504086085782234600502410213751379553855471973440165009200961757474676407242673622935614782362911290590560535490636029324125251850583605745046201217673654522625983661578962623803698461459190578519097656221453474955879823750445359506290522280566225253310030053812918275525607874059407284653434046369835156477189219911810464401689041140506062300317020407969423270374033482533711564673658146930272487464489365713112043565257807490520178903336328210031106311280471651300486164966423437275272281777742004535722142265580037959473078313965482591454009972765788975683031385823798895914265841131145707278751512534120","0":"56510878078818710798555570103060159621941668074271797077206591818472978018558098567975838757566260370093327989369045722406190165972775356924844244889146946158949660988214890388299203816110339909687790860564719380865809705044646711632599987968183128514431910561478715003212633874423067294596323864121737000450543142072142652163818450299889830999149821558252183477517484127000480272695698860647674027831262149565273068850774090998356019534296579838685977022988536930596918054160990243868372150609770079720240227817149126735182138479851227052696211125454858584118346950878092387488482897777914362341820607560926173967363","3":"63511079416489489495396586813126304469185174450150717746314545118902972011091412254834718868134635251731510764117528579641756327883640004345178347120290107941107152421856942264968771810665927914509411385404403747487862696526824127219640807008235054362138760656969613951620938020257273816713908815343872804442748694361381399025862438391456307852482826748664499083370705834755863016895566228300904018909174673301643617543662527772400085378252706897979609427451977654028887889811453690146157824251379525221390697200211891556653698308665831075787991412401737090471273439878635073797691350863566834141222438011402987450926","1":"30348838247529448929141877305241172943867610065951047292188826263950046630912426030349276970628525991007036685038199133783991618544554063310358191845473212966131475853690378885426974792306638181168558731807811629973716711132134244797541560013139884391800841941607502149630914097258613821336239993125960064136287579351403225717114920758719152701696123905042695943045383536065833292374624566478931465135875411483860059753175449604448434619593495399051968638830805689355610877075130302742512428461286121237297212174164897833936610857614962734658136750299346971377383141235020438750748045568800723867413392427848651081274"},"predicate":{"name":"age","p_type":"GE","p_value":18}}]},"non_revoc_proof":null}},"aggregated_proof":{"c_hash":"81135772044295974649282368084258333955993271555081206390568996949836231116301","c_list":[[2,124,231,47,189,36,247,160,61,220,165,35,97,165,203,185,133,253,81,239,67,127,156,49,189,16,140,30,177,161,221,54,154,0,127,143,98,212,114,193,188,85,206,171,198,140,9,192,10,254,218,120,201,182,40,141,80,35,81,148,204,192,41,5,186,33,50,77,211,163,124,130,32,219,193,167,79,43,181,76,19,249,53,79,70,221,205,36,180,50,120,255,161,227,196,204,71,106,221,131,220,7,73,86,128,208,48,58,123,63,82,24,170,141,143,56,221,96,151,108,105,38,185,243,224,112,177,101,195,87,208,201,39,123,165,125,92,104,234,188,54,92,31,158,178,152,52,205,26,156,237,241,23,15,76,220,168,32,175,230,157,197,225,70,57,237,8,81,13,17,95,70,143,56,162,223,203,8,48,153,51,51,118,116,32,139,187,222,146,86,165,111,125,107,203,18,212,28,168,22,62,69,204,207,122,148,25,30,92,120,83,214,116,221,204,120,230,70,128,139,181,110,69,93,253,240,69,16,113,224,246,41,142,0,83,237,186,4,50,156,206,199,89,74,96,168,249,240,101,16,103,234,162,219,52,218,207],[1,191,167,2,151,36,61,136,184,172,120,86,127,88,109,119,56,21,167,171,217,221,24,64,246,237,255,152,81,183,201,191,59,234,213,101,254,91,33,205,120,71,215,144,160,243,145,109,19,151,241,46,135,132,50,143,219,207,197,35,89,103,83,212,96,83,222,101,55,57,220,161,252,115,39,62,46,160,30,138,221,89,125,66,114,150,5,95,63,10,55,107,102,73,40,69,41,6,57,0,64,226,152,66,181,149,251,50,28,53,18,26,221,5,188,67,125,184,190,200,56,92,132,201,242,211,37,2,43,6,146,88,228,120,204,190,4,118,134,106,118,110,249,145,175,165,116,197,200,183,207,215,197,79,207,203,29,182,231,151,248,233,107,41,79,234,250,27,33,33,107,102,240,47,37,230,243,185,93,192,52,31,73,211,11,173,150,92,194,154,172,247,221,206,129,85,193,105,172,140,201,40,240,200,28,94,1,96,204,175,113,170,46,134,229,111,215,208,237,252,84,50,249,41,214,79,38,194,23,212,7,164,153,217,23,252,32,114,145,58,189,118,104,131,84,184,115,175,199,227,219,117,23,113,113,180,3],[240,104,187,71,84,144,129,123,12,181,215,233,27,55,56,54,94,57,17,42,111,42,112,234,192,23,226,103,118,198,189,175,175,1,102,64,128,100,221,201,134,106,83,239,69,43,150,172,95,206,145,224,207,239,39,193,30,200,90,125,175,125,59,47,250,224,193,21,64,112,101,131,128,249,96,165,73,33,174,64,69,252,209,158,130,53,23,158,217,173,69,51,12,145,70,174,15,206,13,181,50,246,50,110,223,65,250,44,39,33,8,47,169,242,147,3,190,164,110,20,68,5,142,133,38,198,151,161,167,0,219,128,126,120,190,23,153,22,250,78,114,241,252,181,74,142,65,123,225,153,75,159,78,84,28,110,203,105,231,238,75,138,121,233,75,163,221,69,106,143,1,217,251,43,147,252,189,122,19,124,189,180,206,91,165,199,41,172,233,102,14,91,162,254,16,142,60,230,39,200,208,236,101,69,101,152,233,217,100,206,31,120,211,191,90,56,205,40,180,120,47,210,224,86,153,34,86,237,204,11,183,227,0,224,15,201,32,228,4,210,43,156,68,246,137,150,103,197,191,150,155,181,78,5,134,58],[1,214,184,139,205,251,132,131,8,186,140,58,211,242,134,120,121,253,128,192,10,252,172,101,44,26,119,56,212,8,248,71,19,96,59,12,233,191,63,187,217,35,191,160,127,247,189,247,229,111,252,101,126,10,142,252,238,215,211,137,137,164,114,186,255,199,183,50,103,9,158,63,134,140,162,154,188,109,52,31,92,78,38,228,0,60,225,100,239,88,114,95,48,71,7,117,168,45,45,177,178,62,87,197,98,174,123,249,26,237,179,12,63,182,46,218,183,148,163,222,179,159,146,56,142,190,122,100,211,6,86,237,10,7,111,186,27,66,95,252,108,247,203,1,111,60,13,218,104,63,128,125,197,11,201,138,33,122,37,31,163,123,120,132,65,122,208,60,80,87,113,183,28,31,74,106,18,79,52,245,113,184,94,202,72,223,8,128,209,43,77,237,119,208,255,144,26,76,223,77,177,131,237,49,150,251,53,150,115,33,254,237,185,15,140,234,205,99,248,252,171,245,192,104,151,194,190,186,249,180,246,9,169,165,0,221,7,107,39,67,58,178,176,99,212,40,247,49,127,7,94,5,170,65,154,28,104],[1,247,26,202,244,120,131,95,151,52,56,38,141,232,178,50,61,45,235,61,12,68,11,180,174,222,110,211,141,253,198,204,248,192,40,99,237,1,45,170,79,208,3,13,135,89,195,65,3,228,224,146,181,198,14,79,78,237,168,81,108,151,68,12,88,242,120,200,120,193,253,51,167,140,43,175,59,18,160,190,233,21,213,135,162,76,38,48,163,110,155,197,97,93,211,183,95,42,172,249,98,59,161,136,70,39,142,48,242,44,154,103,186,161,214,215,0,254,166,150,111,71,242,102,209,125,25,65,144,223,211,137,223,239,50,96,185,171,120,155,171,98,204,23,102,253,68,141,91,240,127,170,199,249,217,165,164,37,174,212,159,232,140,196,216,140,205,102,84,104,220,223,9,249,75,245,78,157,245,203,235,154,73,34,77,12,227,138,93,105,178,114,255,210,88,216,202,64,69,128,220,211,113,51,15,185,103,236,52,187,49,29,162,20,35,21,65,188,33,46,11,172,59,15,221,36,33,213,14,121,36,218,76,80,97,197,83,64,145,73,194,43,233,144,251,86,112,209,230,67,234,116,172,219,123,50,46],[1,114,216,159,37,214,198,117,230,153,15,176,95,20,29,134,179,207,209,35,101,193,47,54,130,141,78,213,54,167,31,73,105,177,129,135,6,135,45,107,103,16,133,187,74,217,42,40,1,214,60,70,78,245,86,82,150,75,91,235,181,249,129,147,202,15,86,250,222,240,203,236,102,39,53,147,79,178,124,184,97,73,65,136,74,29,219,182,83,167,221,203,32,200,243,130,65,234,133,181,203,35,86,21,123,170,74,174,5,132,1,149,77,141,158,193,249,130,37,53,253,234,228,144,66,152,232,246,26,193,6,53,139,45,231,173,115,87,89,61,197,9,96,73,229,189,49,44,203,214,156,139,58,153,77,13,90,35,157,130,184,150,161,69,145,157,4,206,52,216,227,233,113,202,54,154,153,100,83,97,135,88,197,227,42,52,28,221,91,117,56,183,198,102,231,37,232,226,136,142,115,218,175,45,221,143,130,215,184,39,102,172,126,253,152,108,254,241,17,98,70,223,191,138,251,227,243,32,180,190,223,69,135,0,97,105,115,189,221,134,26,159,32,210,172,233,7,65,238,77,203,159,181,188,203,159,190]]}},"requested_proof":{"revealed_attrs":{"attr1_referent":["credential::58479554-187f-40d9-b0a5-a95cfb0338c3","Alex","1139481716457488690172217916278103335"]},"unrevealed_attrs":{},"self_attested_attrs":{},"predicates":{"predicate1_referent":"credential::58479554-187f-40d9-b0a5-a95cfb0338c3"}}}"#;
+            let proof_json = r#"{"proof":{"proofs":{"credential::58479554-187f-40d9-b0a5-a95cfb0338c3":{"primary_proof":{"eq_proof":{"revealed_attrs":{"name":"99262857098057710338306967609588410025648622308394250666849665532448612202874"},"a_prime":"80401564260558483983794628158664845806393125691167675024527906210615204776868092566789307767601325086260531777605457298059939671624755239928848057947875953445797869574854365751051663611984607735255307096920094357120779812375573500489773454634756645206823074153240319316758529163584251907107473703779754778699279153037094140428648169418133281187947677937472972061954089873405836249023133445286756991574802740614183730141450546881449500189789970102133738133443822618072337620343825908790734460412932921199267304555521397418007577171242880211812703320270140386219809818196744216958369397014610013338422295772654405475023","e":"31151798717381512709903464053695613005379725796031086912986270617392167764097422442809244590980303622977555221812111085160553241592792901","v":"524407431684833626723631303096063196973911986967748096669183384949467719053669910411426601230736351335262754473490498825342793551112426427823428399937548938048089615644972537564428344526295733169691240937176356626523864731701111189536269488496019586818879697981955044502664124964896796783428945944075084807859935155837238670987272778459356531608865162828109489758902085206073584532002909678902616210042778963974064479140826712481297584040209095459963718975102750913306565864485279810056629704077428898739021040190774575868853629858297299392839284660771662690107106553362040805152261505268111067408422298806905178826507224233050991301274817252924123120887017757639206512015559321675322509820081151404696713509158685022511201565062671933414307463988209696457343022378430051265752251403461414881325357657438328740471164157220698425309006894962942640219890219594168419276308074677144722217081026358892787770650248878952483621","m":{"age":"10477979077744818183854012231360633424177093192344587159214818537659704987539982653663361680650769087122324965941845552897155693994859927792964720675888893623940580527766661802170","sex":"15368219775809326116045200104269422566086585069798988383076685221700842794654771075432385446820819836777771517356551059931242867733879324915651894894695726945279462946826404864068","height":"268172143999991481637372321419290603042446269013750825098514042757459298040087626745653681785038933035820421862976371452111736537699176931068992453946771945552540798204580069806"},"m1":"119095745403940293668103184388411799541118279558928018597628509118163496000813590825371995586347826189221837428823000332905316924389185590810015031744029496470545254805993327676570037596326743185389101389800942263689809725968264069601565478411709555274081560719927118853299543998608664701485475703881376151770","m2":"3166313665375815600922385342096456465402430622944571045536207479553790085339726549928012930073803465171492637049498407367742103524723152099973753540483894420905314750248333232361"},"ge_proofs":[{"u":{"2":"6494171529848192644197417834173236605253723188808961394289041396341136802965710957759175642924978223517091081898946519122412445399638640485278379079647638538597635045303985779767","0":"7739508859260491061487569748588091139318989278758566530899756574128579312557203413565436003310787878172471425996601979342157451689172171025305431595131816910273398879776841751855","3":"9424758820140378077609053635383940574362083113571024891496206162696034958494400871955445981458978146571146602763357500412840538526390475379772903513687358736287298159312524034159","1":"9011979414559555265454106061917684716953356440811838475257096756618761731111646531136628099710567973381801256908067529269805992222342928842825929421929485785888403149296320711642"},"r":{"DELTA":"2119857977629302693157808821351328058251440215802746362450951329352726877165815663955490999790457576333458830301801261754696823614762123890412904169206391143688952648566814660498520188221060505840151491403269696751525874990487604723445355651918681212361562384420233903265612599812725766212744963540390806334870022328290970137051148373040320927100063898502086531019924715927190306801273252711777648467224661735618842887006436195147540705753550974655689586750013569294343535843195025962867299786380033532422131203367401906988124836294104501525520053613392691214421562815044433237816093079784307397782961917892254668290115653012265908717124278607660504580036193346698672079435538219972121355893074219968755049500875222141","2":"879097501989202140886939888802566536179834329508897124489020677433754766947767937608431979796722207676629625451150104784909666168153917345813160237337412296010679353735699663083287427507870244565918756969618964144516025526404618052053542009438548457492400344119561349471929199757453154204191407620539220514897529346602664135146454509169680801061111878075145734123580343470361019624175036825631373890661124315134340427076598351080893567995392248394683875116715114577054906406649006122102488431184007790011073389768061904597267545895265921673106871142463561948479668876241841045522543174660428236658891636170119227855493059358614089146415798861053408542832475696099851160385105386001523305465829676723036394820593263477","0":"1724016272047416140958096373304304971004826284109046259544344355102178044512441391364907122486655755929044720001281832600729467778103556397960700809066582436321515744527550472324028227472294258045699756170293405547851344921626775854114063087070898499913846456795761213291925373770081490280103876827479351849800210782799381740073719081199000612284788683993320623339686128531187019125095700122135094060470612862911102824801065698176788174959069186600426519872015152034176356923049531650418553748519941342115963599848111324793380438600664408464987023646615003553912544410140730587797458882329021327455905737414352355326238028222782957735440607899424838572541602600159016542488644761584240884783618700311735467659132540546","3":"2317535203964314926167241523636020444600002667629517624482931328850422196008281300859516069440995466415138723103558631951648519232327284208990029010060986032518946759289078833125920310350676484457972303378558158127406345804560689086460633931717939234025886786468170219981598030245042011840614339386724945679531091642132820284896626191109974537171662283750959028046143650291367908660204201563611944187723824430780626387525165408619587771059635528553832034409311888615502905143628507219523591091412192645348525327725381323865648645828460581593542176351568614465903523790649219812666979685223535464526901006270478687017672202058914176692964406859722580270696925877498058525086810338471380117323227744481903228027847825795","1":"1119193929864813751243160041764170298897380522230946444206167281178657213260394833843687899872857393015947283159245092452814155776571829885921814072299525859857844030379558685168895306445277750249341844789101670896570226707650318347992386244538723699686941887792682779028216548922683313576597384354842537728667739985216662699631842296096507821667149950956179957306177525178260912379909156360834120816956949271530622510333943914411903103069247646327625753995178999023427645468623522280255892736633780185163496867644317005801241786702434621502492159672660131289312665511793827552317714835658019088880972220344126692027952749318018900669839090109361161616086319604439015851316798257015063653414161203599184730094765941653"},"mj":"10477979077744818183854012231360633424177093192344587159214818537659704987539982653663361680650769087122324965941845552897155693994859927792964720675888893623940580527766661802170","alpha":"46280660038407959140964701167450659223532556136388451390393713283900546119670373626221864441898929302821705811144923685080534692512705456699843367809872982836890616398604933641265111106644805368974824737276965928297120628041257593166650593538539384316563258781595629888673792430276007730792093088812056156937735120078929629310611907731935101448992312370312134173482115524436767558802102266208152808607480693236511858269018733175523724309089010048330044458187371675333889670055578652283806685440133357512406700879353713629795062705271430695988191782837658895477702634883214188598350625843489120361660836956958750828038278027538830855628653513539929730230905015331221220017847248793929813230252015802389329428995718799619565984669228143200627972926117282688854152516298117476837960100343260648687249027349308513966440386556698667484082658689","t":{"DELTA":"46814992964714978733007076702016837564951956529003697497847838781899848384824991374342901164708655443686022921583406187082133141084994843502230809550055933825660668160300304112671478218513259983054489597176651737200716259733573469298437873515151377206364940530308167934399245072298875358347931404742292788785586833114480704138718996633638362933821933388459210678374952072108333767698704767907612549860590824123780096225591372365712106060039646448181221691765233478768574198237963457485496438076793333937013217675591500849193742006533651525421426481898699626618796271544860105422331629265388419155909716261466161258430","2":"59423006413504086085782234600502410213751379553855471973440165009200961757474676407242673622935614782362911290590560535490636029324125251850583605745046201217673654522625983661578962623803698461459190578519097656221453474955879823750445359506290522280566225253310030053812918275525607874059407284653434046369835156477189219911810464401689041140506062300317020407969423270374033482533711564673658146930272487464489365713112043565257807490520178903336328210031106311280471651300486164966423437275272281777742004535722142265580037959473078313965482591454009972765788975683031385823798895914265841131145707278751512534120","0":"56510878078818710798555570103060159621941668074271797077206591818472978018558098567975838757566260370093327989369045722406190165972775356924844244889146946158949660988214890388299203816110339909687790860564719380865809705044646711632599987968183128514431910561478715003212633874423067294596323864121737000450543142072142652163818450299889830999149821558252183477517484127000480272695698860647674027831262149565273068850774090998356019534296579838685977022988536930596918054160990243868372150609770079720240227817149126735182138479851227052696211125454858584118346950878092387488482897777914362341820607560926173967363","3":"63511079416489489495396586813126304469185174450150717746314545118902972011091412254834718868134635251731510764117528579641756327883640004345178347120290107941107152421856942264968771810665927914509411385404403747487862696526824127219640807008235054362138760656969613951620938020257273816713908815343872804442748694361381399025862438391456307852482826748664499083370705834755863016895566228300904018909174673301643617543662527772400085378252706897979609427451977654028887889811453690146157824251379525221390697200211891556653698308665831075787991412401737090471273439878635073797691350863566834141222438011402987450926","1":"30348838247529448929141877305241172943867610065951047292188826263950046630912426030349276970628525991007036685038199133783991618544554063310358191845473212966131475853690378885426974792306638181168558731807811629973716711132134244797541560013139884391800841941607502149630914097258613821336239993125960064136287579351403225717114920758719152701696123905042695943045383536065833292374624566478931465135875411483860059753175449604448434619593495399051968638830805689355610877075130302742512428461286121237297212174164897833936610857614962734658136750299346971377383141235020438750748045568800723867413392427848651081274"},"predicate":{"name":"age","p_type":"GE","p_value":18}}]},"non_revoc_proof":null}},"aggregated_proof":{"c_hash":"81135772044295974649282368084258333955993271555081206390568996949836231116301","c_list":[[2,124,231,47,189,36,247,160,61,220,165,35,97,165,203,185,133,253,81,239,67,127,156,49,189,16,140,30,177,161,221,54,154,0,127,143,98,212,114,193,188,85,206,171,198,140,9,192,10,254,218,120,201,182,40,141,80,35,81,148,204,192,41,5,186,33,50,77,211,163,124,130,32,219,193,167,79,43,181,76,19,249,53,79,70,221,205,36,180,50,120,255,161,227,196,204,71,106,221,131,220,7,73,86,128,208,48,58,123,63,82,24,170,141,143,56,221,96,151,108,105,38,185,243,224,112,177,101,195,87,208,201,39,123,165,125,92,104,234,188,54,92,31,158,178,152,52,205,26,156,237,241,23,15,76,220,168,32,175,230,157,197,225,70,57,237,8,81,13,17,95,70,143,56,162,223,203,8,48,153,51,51,118,116,32,139,187,222,146,86,165,111,125,107,203,18,212,28,168,22,62,69,204,207,122,148,25,30,92,120,83,214,116,221,204,120,230,70,128,139,181,110,69,93,253,240,69,16,113,224,246,41,142,0,83,237,186,4,50,156,206,199,89,74,96,168,249,240,101,16,103,234,162,219,52,218,207],[1,191,167,2,151,36,61,136,184,172,120,86,127,88,109,119,56,21,167,171,217,221,24,64,246,237,255,152,81,183,201,191,59,234,213,101,254,91,33,205,120,71,215,144,160,243,145,109,19,151,241,46,135,132,50,143,219,207,197,35,89,103,83,212,96,83,222,101,55,57,220,161,252,115,39,62,46,160,30,138,221,89,125,66,114,150,5,95,63,10,55,107,102,73,40,69,41,6,57,0,64,226,152,66,181,149,251,50,28,53,18,26,221,5,188,67,125,184,190,200,56,92,132,201,242,211,37,2,43,6,146,88,228,120,204,190,4,118,134,106,118,110,249,145,175,165,116,197,200,183,207,215,197,79,207,203,29,182,231,151,248,233,107,41,79,234,250,27,33,33,107,102,240,47,37,230,243,185,93,192,52,31,73,211,11,173,150,92,194,154,172,247,221,206,129,85,193,105,172,140,201,40,240,200,28,94,1,96,204,175,113,170,46,134,229,111,215,208,237,252,84,50,249,41,214,79,38,194,23,212,7,164,153,217,23,252,32,114,145,58,189,118,104,131,84,184,115,175,199,227,219,117,23,113,113,180,3],[240,104,187,71,84,144,129,123,12,181,215,233,27,55,56,54,94,57,17,42,111,42,112,234,192,23,226,103,118,198,189,175,175,1,102,64,128,100,221,201,134,106,83,239,69,43,150,172,95,206,145,224,207,239,39,193,30,200,90,125,175,125,59,47,250,224,193,21,64,112,101,131,128,249,96,165,73,33,174,64,69,252,209,158,130,53,23,158,217,173,69,51,12,145,70,174,15,206,13,181,50,246,50,110,223,65,250,44,39,33,8,47,169,242,147,3,190,164,110,20,68,5,142,133,38,198,151,161,167,0,219,128,126,120,190,23,153,22,250,78,114,241,252,181,74,142,65,123,225,153,75,159,78,84,28,110,203,105,231,238,75,138,121,233,75,163,221,69,106,143,1,217,251,43,147,252,189,122,19,124,189,180,206,91,165,199,41,172,233,102,14,91,162,254,16,142,60,230,39,200,208,236,101,69,101,152,233,217,100,206,31,120,211,191,90,56,205,40,180,120,47,210,224,86,153,34,86,237,204,11,183,227,0,224,15,201,32,228,4,210,43,156,68,246,137,150,103,197,191,150,155,181,78,5,134,58],[1,214,184,139,205,251,132,131,8,186,140,58,211,242,134,120,121,253,128,192,10,252,172,101,44,26,119,56,212,8,248,71,19,96,59,12,233,191,63,187,217,35,191,160,127,247,189,247,229,111,252,101,126,10,142,252,238,215,211,137,137,164,114,186,255,199,183,50,103,9,158,63,134,140,162,154,188,109,52,31,92,78,38,228,0,60,225,100,239,88,114,95,48,71,7,117,168,45,45,177,178,62,87,197,98,174,123,249,26,237,179,12,63,182,46,218,183,148,163,222,179,159,146,56,142,190,122,100,211,6,86,237,10,7,111,186,27,66,95,252,108,247,203,1,111,60,13,218,104,63,128,125,197,11,201,138,33,122,37,31,163,123,120,132,65,122,208,60,80,87,113,183,28,31,74,106,18,79,52,245,113,184,94,202,72,223,8,128,209,43,77,237,119,208,255,144,26,76,223,77,177,131,237,49,150,251,53,150,115,33,254,237,185,15,140,234,205,99,248,252,171,245,192,104,151,194,190,186,249,180,246,9,169,165,0,221,7,107,39,67,58,178,176,99,212,40,247,49,127,7,94,5,170,65,154,28,104],[1,247,26,202,244,120,131,95,151,52,56,38,141,232,178,50,61,45,235,61,12,68,11,180,174,222,110,211,141,253,198,204,248,192,40,99,237,1,45,170,79,208,3,13,135,89,195,65,3,228,224,146,181,198,14,79,78,237,168,81,108,151,68,12,88,242,120,200,120,193,253,51,167,140,43,175,59,18,160,190,233,21,213,135,162,76,38,48,163,110,155,197,97,93,211,183,95,42,172,249,98,59,161,136,70,39,142,48,242,44,154,103,186,161,214,215,0,254,166,150,111,71,242,102,209,125,25,65,144,223,211,137,223,239,50,96,185,171,120,155,171,98,204,23,102,253,68,141,91,240,127,170,199,249,217,165,164,37,174,212,159,232,140,196,216,140,205,102,84,104,220,223,9,249,75,245,78,157,245,203,235,154,73,34,77,12,227,138,93,105,178,114,255,210,88,216,202,64,69,128,220,211,113,51,15,185,103,236,52,187,49,29,162,20,35,21,65,188,33,46,11,172,59,15,221,36,33,213,14,121,36,218,76,80,97,197,83,64,145,73,194,43,233,144,251,86,112,209,230,67,234,116,172,219,123,50,46],[1,114,216,159,37,214,198,117,230,153,15,176,95,20,29,134,179,207,209,35,101,193,47,54,130,141,78,213,54,167,31,73,105,177,129,135,6,135,45,107,103,16,133,187,74,217,42,40,1,214,60,70,78,245,86,82,150,75,91,235,181,249,129,147,202,15,86,250,222,240,203,236,102,39,53,147,79,178,124,184,97,73,65,136,74,29,219,182,83,167,221,203,32,200,243,130,65,234,133,181,203,35,86,21,123,170,74,174,5,132,1,149,77,141,158,193,249,130,37,53,253,234,228,144,66,152,232,246,26,193,6,53,139,45,231,173,115,87,89,61,197,9,96,73,229,189,49,44,203,214,156,139,58,153,77,13,90,35,157,130,184,150,161,69,145,157,4,206,52,216,227,233,113,202,54,154,153,100,83,97,135,88,197,227,42,52,28,221,91,117,56,183,198,102,231,37,232,226,136,142,115,218,175,45,221,143,130,215,184,39,102,172,126,253,152,108,254,241,17,98,70,223,191,138,251,227,243,32,180,190,223,69,135,0,97,105,115,189,221,134,26,159,32,210,172,233,7,65,238,77,203,159,181,188,203,159,190]]}},"requested_proof":{"revealed_attrs":{"attr1_referent":["credential::58479554-187f-40d9-b0a5-a95cfb0338c3","Alex","99262857098057710338306967609588410025648622308394250666849665532448612202874"]},"unrevealed_attrs":{},"self_attested_attrs":{},"predicates":{"predicate1_referent":"credential::58479554-187f-40d9-b0a5-a95cfb0338c3"}}}"#;
 
             let res = anoncreds::verifier_verify_proof(&anoncreds::proof_request_attr_and_predicate(),
                                                        &proof_json,
@@ -3805,6 +4597,401 @@ mod demos {
         utils::tear_down();
     }
 
+    #[test]
+    fn anoncreds_works_for_issuance_log() {
+        utils::setup();
+
+        //1. Create Issuer wallet, gets wallet handle
+        let issuer_wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+
+        //2. Create Prover wallet, gets wallet handle
+        let prover_wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+
+        //3. Issuer creates Schema and Credential Definition
+        let (schema_id, _, cred_def_id, cred_def_json) = anoncreds::multi_steps_issuer_preparation(issuer_wallet_handle,
+                                                                                                   ISSUER_DID,
+                                                                                                   GVT_SCHEMA_NAME,
+                                                                                                   GVT_SCHEMA_ATTRIBUTES);
+
+        //4. Prover creates Master Secret
+        anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+        //5. Issuer issues two credentials with the same values and records them into the issuance log
+        for order in &["1", "2"] {
+            let cred_offer_json = anoncreds::issuer_create_credential_offer(issuer_wallet_handle, &cred_def_id).unwrap();
+
+            let (cred_req_json, _) = anoncreds::prover_create_credential_req(prover_wallet_handle,
+                                                                            DID_MY1,
+                                                                            &cred_offer_json,
+                                                                            &cred_def_json,
+                                                                            COMMON_MASTER_SECRET).unwrap();
+
+            anoncreds::issuer_create_credential_with_log(issuer_wallet_handle,
+                                                         &cred_offer_json,
+                                                         &cred_req_json,
+                                                         &anoncreds::gvt_credential_values_json(),
+                                                         None,
+                                                         None,
+                                                         Some(&json!({"~order": order}).to_string())).unwrap();
+        }
+
+        //6. Issuer fetches all entries of the issuance log by small batches
+        let (search_handle, total_count) = anoncreds::issuer_search_issued_credentials(issuer_wallet_handle, None).unwrap();
+        assert_eq!(2, total_count);
+
+        let issued_credentials = anoncreds::issuer_fetch_issued_credentials(search_handle, 1).unwrap();
+        let first: Vec<IssuedCredentialInfo> = serde_json::from_str(&issued_credentials).unwrap();
+        assert_eq!(1, first.len());
+
+        let issued_credentials = anoncreds::issuer_fetch_issued_credentials(search_handle, 2).unwrap();
+        let second: Vec<IssuedCredentialInfo> = serde_json::from_str(&issued_credentials).unwrap();
+        assert_eq!(1, second.len());
+
+        assert_eq!(schema_id, first[0].schema_id);
+        assert_eq!(cred_def_id, first[0].cred_def_id);
+        assert_eq!(None, first[0].rev_reg_id);
+        assert!(!first[0].revoked);
+        assert_eq!(first[0].attrs_hash, second[0].attrs_hash);
+        assert_ne!(first[0].referent, second[0].referent);
+
+        anoncreds::issuer_close_issued_credentials_search(search_handle).unwrap();
+
+        let res = anoncreds::issuer_fetch_issued_credentials(search_handle, 1);
+        assert_eq!(ErrorCode::WalletInvalidHandle, res.unwrap_err());
+
+        //7. Issuer finds the entry by caller's tag
+        let (search_handle, total_count) = anoncreds::issuer_search_issued_credentials(issuer_wallet_handle, Some(r#"{"~order": "2"}"#)).unwrap();
+        assert_eq!(1, total_count);
+
+        let issued_credentials = anoncreds::issuer_fetch_issued_credentials(search_handle, total_count).unwrap();
+        let issued_credentials: Vec<IssuedCredentialInfo> = serde_json::from_str(&issued_credentials).unwrap();
+        assert_eq!("2", issued_credentials[0].tags["~order"]);
+
+        anoncreds::issuer_close_issued_credentials_search(search_handle).unwrap();
+
+        //8. Issuer finds both entries by hash of the same attribute values
+        let query_json = json!({"attrs_hash": first[0].attrs_hash}).to_string();
+        let (search_handle, total_count) = anoncreds::issuer_search_issued_credentials(issuer_wallet_handle, Some(&query_json)).unwrap();
+        assert_eq!(2, total_count);
+
+        anoncreds::issuer_close_issued_credentials_search(search_handle).unwrap();
+
+        //9. Hash of attribute values is keyed by the wallet, so other issuer gets other hash for the same values
+        let other_issuer_wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+
+        let (_, _, other_cred_def_id, other_cred_def_json) = anoncreds::multi_steps_issuer_preparation(other_issuer_wallet_handle,
+                                                                                                       ISSUER_DID,
+                                                                                                       GVT_SCHEMA_NAME,
+                                                                                                       GVT_SCHEMA_ATTRIBUTES);
+
+        let cred_offer_json = anoncreds::issuer_create_credential_offer(other_issuer_wallet_handle, &other_cred_def_id).unwrap();
+        let (cred_req_json, _) = anoncreds::prover_create_credential_req(prover_wallet_handle,
+                                                                        DID_MY1,
+                                                                        &cred_offer_json,
+                                                                        &other_cred_def_json,
+                                                                        COMMON_MASTER_SECRET).unwrap();
+        anoncreds::issuer_create_credential_with_log(other_issuer_wallet_handle,
+                                                     &cred_offer_json,
+                                                     &cred_req_json,
+                                                     &anoncreds::gvt_credential_values_json(),
+                                                     None,
+                                                     None,
+                                                     None).unwrap();
+
+        let (search_handle, _) = anoncreds::issuer_search_issued_credentials(other_issuer_wallet_handle, None).unwrap();
+        let issued_credentials = anoncreds::issuer_fetch_issued_credentials(search_handle, 1).unwrap();
+        let issued_credentials: Vec<IssuedCredentialInfo> = serde_json::from_str(&issued_credentials).unwrap();
+        assert_ne!(first[0].attrs_hash, issued_credentials[0].attrs_hash);
+
+        anoncreds::issuer_close_issued_credentials_search(search_handle).unwrap();
+
+        wallet::close_wallet(other_issuer_wallet_handle).unwrap();
+        wallet::close_wallet(issuer_wallet_handle).unwrap();
+        wallet::close_wallet(prover_wallet_handle).unwrap();
+
+        utils::tear_down();
+    }
+
+    #[test]
+    fn anoncreds_works_for_credential_definition_rotation() {
+        utils::setup();
+
+        //1. Create Issuer wallet, gets wallet handle
+        let issuer_wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+
+        //2. Create Prover wallet, gets wallet handle
+        let prover_wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+
+        //3. Issuer creates Schema and Credential Definition
+        let (schema_id, schema_json, cred_def_id, cred_def_json) = anoncreds::multi_steps_issuer_preparation(issuer_wallet_handle,
+                                                                                                             ISSUER_DID,
+                                                                                                             GVT_SCHEMA_NAME,
+                                                                                                             GVT_SCHEMA_ATTRIBUTES);
+
+        //4. Prover creates Master Secret
+        anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+        //5. Issuance credential for Prover before rotation
+        anoncreds::multi_steps_create_credential(COMMON_MASTER_SECRET,
+                                                 prover_wallet_handle,
+                                                 issuer_wallet_handle,
+                                                 CREDENTIAL1_ID,
+                                                 &anoncreds::gvt_credential_values_json(),
+                                                 &cred_def_id,
+                                                 &cred_def_json);
+
+        //6. Issuer rotates keys of Credential Definition
+        let (rotated_cred_def_id, rotated_cred_def_json) = anoncreds::issuer_rotate_credential_def(issuer_wallet_handle,
+                                                                                                   ISSUER_DID,
+                                                                                                   &cred_def_id,
+                                                                                                   &schema_json,
+                                                                                                   "TAG_ROTATED",
+                                                                                                   None).unwrap();
+
+        //7. Issuance credential for Prover by predecessor id is done under successor
+        anoncreds::multi_steps_create_credential(COMMON_MASTER_SECRET,
+                                                 prover_wallet_handle,
+                                                 issuer_wallet_handle,
+                                                 CREDENTIAL2_ID,
+                                                 &anoncreds::gvt2_credential_values_json(),
+                                                 &cred_def_id,
+                                                 &rotated_cred_def_json);
+
+        let credential_json = anoncreds::prover_get_credential(prover_wallet_handle, CREDENTIAL2_ID).unwrap();
+        let credential: CredentialInfo = serde_json::from_str(&credential_json).unwrap();
+        assert_eq!(rotated_cred_def_id, credential.cred_def_id);
+
+        let credential_json = anoncreds::prover_get_credential(prover_wallet_handle, CREDENTIAL1_ID).unwrap();
+        let credential: CredentialInfo = serde_json::from_str(&credential_json).unwrap();
+        assert_eq!(cred_def_id, credential.cred_def_id);
+
+        //8. Prover proves both credentials: issued before rotation and under successor
+        let proof_req_json = json!({
+           "nonce":"123432421212",
+           "name":"proof_req_1",
+           "version":"0.1",
+           "requested_attributes": json!({
+               "attr1_referent": json!({
+                   "name":"name",
+                   "restrictions": json!({ "cred_def_id": cred_def_id })
+               }),
+               "attr2_referent": json!({
+                   "name":"name",
+                   "restrictions": json!({ "cred_def_id": rotated_cred_def_id })
+               })
+           }),
+           "requested_predicates": json!({}),
+        }).to_string();
+
+        let requested_credentials_json = json!({
+             "self_attested_attributes": json!({}),
+             "requested_attributes": json!({
+                "attr1_referent": json!({ "cred_id": CREDENTIAL1_ID, "revealed":true }),
+                "attr2_referent": json!({ "cred_id": CREDENTIAL2_ID, "revealed":true })
+             }),
+             "requested_predicates": json!({})
+        }).to_string();
+
+        let schemas_json = json!({schema_id: serde_json::from_str::<Schema>(&schema_json).unwrap()}).to_string();
+        let cred_defs_json = json!({
+            cred_def_id.as_str(): serde_json::from_str::<CredentialDefinition>(&cred_def_json).unwrap(),
+            rotated_cred_def_id.as_str(): serde_json::from_str::<CredentialDefinition>(&rotated_cred_def_json).unwrap(),
+        }).to_string();
+        let rev_states_json = json!({}).to_string();
+
+        let proof_json = anoncreds::prover_create_proof(prover_wallet_handle,
+                                                        &proof_req_json,
+                                                        &requested_credentials_json,
+                                                        COMMON_MASTER_SECRET,
+                                                        &schemas_json,
+                                                        &cred_defs_json,
+                                                        &rev_states_json).unwrap();
+
+        let proof: Proof = serde_json::from_str(&proof_json).unwrap();
+        assert_eq!("Alex", proof.requested_proof.revealed_attrs.get("attr1_referent").unwrap().raw);
+        assert_eq!("Alexander", proof.requested_proof.revealed_attrs.get("attr2_referent").unwrap().raw);
+
+        //9. Verifier verifies proof
+        let rev_reg_defs_json = json!({}).to_string();
+        let rev_regs_json = json!({}).to_string();
+
+        let valid = anoncreds::verifier_verify_proof(&proof_req_json,
+                                                     &proof_json,
+                                                     &schemas_json,
+                                                     &cred_defs_json,
+                                                     &rev_reg_defs_json,
+                                                     &rev_regs_json).unwrap();
+        assert!(valid);
+
+        wallet::close_wallet(issuer_wallet_handle).unwrap();
+        wallet::close_wallet(prover_wallet_handle).unwrap();
+
+        utils::tear_down();
+    }
+
+    #[test]
+    fn anoncreds_works_for_typed_attributes() {
+        utils::setup();
+
+        //1. Create Issuer wallet, gets wallet handle
+        let issuer_wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+
+        //2. Create Prover wallet, gets wallet handle
+        let prover_wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+
+        //3. Issuer creates Schema with typed attributes and Credential Definition
+        let (schema_id, schema_json) = anoncreds::issuer_create_schema_with_types(ISSUER_DID,
+                                                                                  GVT_SCHEMA_NAME,
+                                                                                  SCHEMA_VERSION,
+                                                                                  r#"{"name": {"type": "string"}, "birthdate": {"type": "date"}, "age": {"type": "int"}}"#).unwrap();
+
+        let (cred_def_id, cred_def_json) = anoncreds::issuer_create_credential_definition(issuer_wallet_handle,
+                                                                                         ISSUER_DID,
+                                                                                         &schema_json,
+                                                                                         TAG_1,
+                                                                                         None,
+                                                                                         Some(&anoncreds::default_cred_def_config())).unwrap();
+
+        //4. Prover creates Master Secret
+        anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+        //5. Issuance of Credential with values encoded by attribute types
+        let cred_offer_json = anoncreds::issuer_create_credential_offer(issuer_wallet_handle, &cred_def_id).unwrap();
+
+        let (cred_req_json, cred_req_metadata_json) = anoncreds::prover_create_credential_req(prover_wallet_handle,
+                                                                                             DID_MY1,
+                                                                                             &cred_offer_json,
+                                                                                             &cred_def_json,
+                                                                                             COMMON_MASTER_SECRET).unwrap();
+
+        let cred_values_json = json!({
+            "name": {"raw": "Alex", "encoded": ""},
+            "birthdate": {"raw": "2000-02-29", "encoded": ""},
+            "age": {"raw": "028", "encoded": ""}
+        }).to_string();
+
+        let (cred_json, _, _) = anoncreds::issuer_create_credential(issuer_wallet_handle,
+                                                                    &cred_offer_json,
+                                                                    &cred_req_json,
+                                                                    &cred_values_json,
+                                                                    None,
+                                                                    None).unwrap();
+
+        //6. Prover verifies Credential against Schema with types and stores it with verification on store
+        let report_json = anoncreds::prover_verify_credential(prover_wallet_handle,
+                                                              &cred_req_metadata_json,
+                                                              &cred_json,
+                                                              &schema_json,
+                                                              &cred_def_json,
+                                                              None).unwrap();
+        let report: serde_json::Value = serde_json::from_str(&report_json).unwrap();
+        assert_eq!(json!(true), report["valid"]);
+
+        indy::set_runtime_config(r#"{"verify_credential_on_store": true}"#);
+
+        let res = anoncreds::prover_store_credential(prover_wallet_handle,
+                                                     CREDENTIAL1_ID,
+                                                     &cred_req_metadata_json,
+                                                     &cred_json,
+                                                     &cred_def_json,
+                                                     None);
+
+        indy::set_runtime_config(r#"{"verify_credential_on_store": false}"#);
+
+        res.unwrap();
+
+        //7. Prover creates Proof revealing typed attributes
+        let proof_req_json = json!({
+           "nonce":"123432421212",
+           "name":"proof_req_1",
+           "version":"0.1",
+           "requested_attributes": json!({
+               "attr1_referent": json!({ "name":"birthdate" }),
+               "attr2_referent": json!({ "name":"age" }),
+               "attr3_referent": json!({ "names": ["name", "birthdate"] })
+           }),
+           "requested_predicates": json!({
+               "predicate1_referent": json!({ "name":"age", "p_type":">=", "p_value":18 })
+           }),
+        }).to_string();
+
+        let requested_credentials_json = json!({
+             "self_attested_attributes": json!({}),
+             "requested_attributes": json!({
+                "attr1_referent": json!({ "cred_id": CREDENTIAL1_ID, "revealed":true }),
+                "attr2_referent": json!({ "cred_id": CREDENTIAL1_ID, "revealed":true }),
+                "attr3_referent": json!({ "cred_id": CREDENTIAL1_ID, "revealed":true })
+             }),
+             "requested_predicates": json!({
+                "predicate1_referent": json!({ "cred_id": CREDENTIAL1_ID })
+             })
+        }).to_string();
+
+        let schemas_json = json!({schema_id.clone(): serde_json::from_str::<Schema>(&schema_json).unwrap()}).to_string();
+        let cred_defs_json = json!({cred_def_id.clone(): serde_json::from_str::<CredentialDefinition>(&cred_def_json).unwrap()}).to_string();
+        let rev_states_json = json!({}).to_string();
+
+        let proof_json = anoncreds::prover_create_proof(prover_wallet_handle,
+                                                        &proof_req_json,
+                                                        &requested_credentials_json,
+                                                        COMMON_MASTER_SECRET,
+                                                        &schemas_json,
+                                                        &cred_defs_json,
+                                                        &rev_states_json).unwrap();
+
+        let proof: Proof = serde_json::from_str(&proof_json).unwrap();
+        let revealed_birthdate = proof.requested_proof.revealed_attrs.get("attr1_referent").unwrap();
+        assert_eq!("2000-02-29", revealed_birthdate.raw);
+        assert_eq!("11016", revealed_birthdate.encoded);
+        let revealed_age = proof.requested_proof.revealed_attrs.get("attr2_referent").unwrap();
+        assert_eq!("028", revealed_age.raw);
+        assert_eq!("28", revealed_age.encoded);
+
+        //8. Verifier verifies Proof with Schema containing attribute types
+        let rev_reg_defs_json = json!({}).to_string();
+        let rev_regs_json = json!({}).to_string();
+
+        let valid = anoncreds::verifier_verify_proof(&proof_req_json,
+                                                     &proof_json,
+                                                     &schemas_json,
+                                                     &cred_defs_json,
+                                                     &rev_reg_defs_json,
+                                                     &rev_regs_json).unwrap();
+        assert!(valid);
+
+        //9. Verifier rejects Proof with Schema without attribute types as values must have standard encoding then
+        let mut untyped_schema: serde_json::Value = serde_json::from_str(&schema_json).unwrap();
+        untyped_schema.as_object_mut().unwrap().remove("attrTypes");
+        let untyped_schemas_json = json!({schema_id.clone(): untyped_schema}).to_string();
+
+        let res = anoncreds::verifier_verify_proof(&proof_req_json,
+                                                   &proof_json,
+                                                   &untyped_schemas_json,
+                                                   &cred_defs_json,
+                                                   &rev_reg_defs_json,
+                                                   &rev_regs_json);
+        assert_code!(ErrorCode::AnoncredsProofRejected, res);
+
+        //10. Verifier rejects Proof with revealed raw value not matching type encoding
+        for &(referent, raw) in [("attr1_referent", "2000-03-01"), ("attr2_referent", "0028"), ("attr2_referent", "2000-02-29")].iter() {
+            let mut tampered_proof: serde_json::Value = serde_json::from_str(&proof_json).unwrap();
+            tampered_proof["requested_proof"]["revealed_attrs"][referent]["raw"] = json!(raw);
+
+            let res = anoncreds::verifier_verify_proof(&proof_req_json,
+                                                       &tampered_proof.to_string(),
+                                                       &schemas_json,
+                                                       &cred_defs_json,
+                                                       &rev_reg_defs_json,
+                                                       &rev_regs_json);
+            assert_code!(ErrorCode::AnoncredsProofRejected, res);
+        }
+
+        wallet::close_wallet(issuer_wallet_handle).unwrap();
+        wallet::close_wallet(prover_wallet_handle).unwrap();
+
+        utils::tear_down();
+    }
+
     #[test]
     fn anoncreds_works_for_plugged_wallet() {
         utils::setup();
@@ -4138,10 +5325,133 @@ mod demos {
             gvt_cred_def_id: serde_json::from_str::<CredentialDefinition>(&gvt_cred_def_json).unwrap(),
             xyz_cred_def_id: serde_json::from_str::<CredentialDefinition>(&xyz_cred_def_json).unwrap()
         }).to_string();
-        let rev_states_json = json!({}).to_string();
+        let rev_states_json = json!({}).to_string();
+
+        let proof_json = anoncreds::prover_create_proof(prover_wallet_handle,
+                                                        &proof_req_json,
+                                                        &requested_credentials_json,
+                                                        COMMON_MASTER_SECRET,
+                                                        &schemas_json,
+                                                        &credential_defs_json,
+                                                        &rev_states_json).unwrap();
+        let proof: Proof = serde_json::from_str(&proof_json).unwrap();
+
+        //11. Verifier verifies proof
+        assert_eq!("Alex", proof.requested_proof.revealed_attrs.get("attr1_referent").unwrap().raw);
+        assert_eq!("partial", proof.requested_proof.revealed_attrs.get("attr2_referent").unwrap().raw);
+
+        let rev_reg_defs_json = json!({}).to_string();
+        let rev_regs_json = json!({}).to_string();
+
+        let valid = anoncreds::verifier_verify_proof(&proof_req_json,
+                                                     &proof_json,
+                                                     &schemas_json,
+                                                     &credential_defs_json,
+                                                     &rev_reg_defs_json,
+                                                     &rev_regs_json).unwrap();
+        assert!(valid);
+
+        wallet::close_wallet(prover_wallet_handle).unwrap();
+        wallet::close_wallet(issuer_wallet_handle).unwrap();
+
+        utils::tear_down();
+    }
+
+    #[cfg(feature = "revocation_tests")]
+    #[test]
+    fn anoncreds_works_for_revocation_proof_issuance_by_demand() {
+        utils::setup();
+
+        //1. Issuer creates wallet, gets wallet handle
+        let issuer_wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+
+        //2. Prover creates wallet, gets wallet handle
+        let prover_wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+
+        //3 Issuer creates Schema, Credential Definition and Revocation Registry
+        let (schema_id, schema_json,
+            cred_def_id, cred_def_json,
+            rev_reg_id, revoc_reg_def_json, _,
+            blob_storage_reader_handle) = anoncreds::multi_steps_issuer_revocation_preparation(issuer_wallet_handle,
+                                                                                               ISSUER_DID,
+                                                                                               GVT_SCHEMA_NAME,
+                                                                                               GVT_SCHEMA_ATTRIBUTES,
+                                                                                               r#"{"max_cred_num":5, "issuance_type":"ISSUANCE_ON_DEMAND"}"#);
+
+        //4. Prover creates Master Secret
+        anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+
+        //5. Issuance Credential
+        let (cred_rev_id, revoc_reg_delta_json) = anoncreds::multi_steps_create_revocation_credential(
+            COMMON_MASTER_SECRET,
+            prover_wallet_handle,
+            issuer_wallet_handle,
+            CREDENTIAL1_ID,
+            &anoncreds::gvt_credential_values_json(),
+            &cred_def_id,
+            &cred_def_json,
+            &rev_reg_id,
+            &revoc_reg_def_json,
+            blob_storage_reader_handle,
+        );
+        let revoc_reg_delta_json = revoc_reg_delta_json.unwrap();
+
+        //6. Prover gets Credentials for Proof Request
+        let proof_request = json!({
+           "nonce":"123432421212",
+           "name":"proof_req_1",
+           "version":"0.1",
+           "requested_attributes": json!({
+               "attr1_referent": json!({
+                   "name":"name"
+               })
+           }),
+           "requested_predicates": json!({
+               "predicate1_referent": json!({ "name":"age", "p_type":">=", "p_value":18 })
+           }),
+           "non_revoked": json!({ "from":80, "to":100 })
+        }).to_string();
+
+        //7. Prover gets Credentials for Proof Request
+        let credentials_json = anoncreds::prover_get_credentials_for_proof_req(prover_wallet_handle, &proof_request).unwrap();
+        let credential = anoncreds::get_credential_for_attr_referent(&credentials_json, "attr1_referent");
+
+        //8. Prover creates RevocationState
+        let timestamp = 100;
+        let rev_state_json = anoncreds::create_revocation_state(blob_storage_reader_handle,
+                                                                &revoc_reg_def_json,
+                                                                &revoc_reg_delta_json,
+                                                                timestamp,
+                                                                &cred_rev_id).unwrap();
+
+        //9. Prover creates Proof
+        let requested_credentials_json = json!({
+             "self_attested_attributes": json!({}),
+             "requested_attributes": json!({
+                "attr1_referent": json!({ "cred_id": credential.referent, "timestamp": timestamp,  "revealed":true })
+             }),
+             "requested_predicates": json!({
+                "predicate1_referent": json!({ "cred_id": credential.referent, "timestamp": timestamp })
+             })
+        }).to_string();
+
+        let schemas_json = json!({
+            schema_id.clone(): serde_json::from_str::<Schema>(&schema_json).unwrap()
+        }).to_string();
+
+        let credential_defs_json = json!({
+            cred_def_id.clone(): serde_json::from_str::<CredentialDefinition>(&cred_def_json).unwrap()
+        }).to_string();
+
+        let rev_states_json = json!({
+            rev_reg_id.clone(): json!({
+                timestamp.to_string(): serde_json::from_str::<RevocationState>(&rev_state_json).unwrap()
+            })
+        }).to_string();
 
         let proof_json = anoncreds::prover_create_proof(prover_wallet_handle,
-                                                        &proof_req_json,
+                                                        &proof_request,
                                                         &requested_credentials_json,
                                                         COMMON_MASTER_SECRET,
                                                         &schemas_json,
@@ -4149,14 +5459,20 @@ mod demos {
                                                         &rev_states_json).unwrap();
         let proof: Proof = serde_json::from_str(&proof_json).unwrap();
 
-        //11. Verifier verifies proof
+        //10. Verifier verifies proof
         assert_eq!("Alex", proof.requested_proof.revealed_attrs.get("attr1_referent").unwrap().raw);
-        assert_eq!("partial", proof.requested_proof.revealed_attrs.get("attr2_referent").unwrap().raw);
 
-        let rev_reg_defs_json = json!({}).to_string();
-        let rev_regs_json = json!({}).to_string();
+        let rev_reg_defs_json = json!({
+            rev_reg_id.clone(): serde_json::from_str::<RevocationRegistryDefinition>(&revoc_reg_def_json).unwrap()
+        }).to_string();
 
-        let valid = anoncreds::verifier_verify_proof(&proof_req_json,
+        let rev_regs_json = json!({
+            rev_reg_id.clone(): json!({
+                timestamp.to_string(): serde_json::from_str::<RevocationRegistry>(&revoc_reg_delta_json).unwrap()
+            })
+        }).to_string();
+
+        let valid = anoncreds::verifier_verify_proof(&proof_request,
                                                      &proof_json,
                                                      &schemas_json,
                                                      &credential_defs_json,
@@ -4164,15 +5480,49 @@ mod demos {
                                                      &rev_regs_json).unwrap();
         assert!(valid);
 
-        wallet::close_wallet(prover_wallet_handle).unwrap();
+        //11. Verifier rejects proof for timestamp out of requested non_revoked interval
+        let mut later_proof_request: serde_json::Value = serde_json::from_str(&proof_request).unwrap();
+        later_proof_request["non_revoked"] = json!({ "from":timestamp + 1, "to":timestamp + 20 });
+
+        let res = anoncreds::verifier_verify_proof(&later_proof_request.to_string(),
+                                                   &proof_json,
+                                                   &schemas_json,
+                                                   &credential_defs_json,
+                                                   &rev_reg_defs_json,
+                                                   &rev_regs_json);
+        assert_code!(ErrorCode::AnoncredsProofRejected, res);
+
+        //12. Verifier gets revocation status of the sub proof for both intervals
+        let report_json = anoncreds::verifier_verify_proof_verbose(&proof_request,
+                                                                   &proof_json,
+                                                                   &schemas_json,
+                                                                   &credential_defs_json,
+                                                                   &rev_reg_defs_json,
+                                                                   &rev_regs_json).unwrap();
+        let report: serde_json::Value = serde_json::from_str(&report_json).unwrap();
+        assert_eq!(json!(true), report["valid"]);
+        assert_eq!(json!("passed"), report["sub_proofs"][0]["revocation"]);
+
+        let report_json = anoncreds::verifier_verify_proof_verbose(&later_proof_request.to_string(),
+                                                                   &proof_json,
+                                                                   &schemas_json,
+                                                                   &credential_defs_json,
+                                                                   &rev_reg_defs_json,
+                                                                   &rev_regs_json).unwrap();
+        let report: serde_json::Value = serde_json::from_str(&report_json).unwrap();
+        assert_eq!(json!(false), report["valid"]);
+        assert_eq!(json!(true), report["proof_verified"]);
+        assert_eq!(json!("failed"), report["sub_proofs"][0]["revocation"]);
+
         wallet::close_wallet(issuer_wallet_handle).unwrap();
+        wallet::close_wallet(prover_wallet_handle).unwrap();
 
         utils::tear_down();
     }
 
     #[cfg(feature = "revocation_tests")]
     #[test]
-    fn anoncreds_works_for_revocation_proof_issuance_by_demand() {
+    fn anoncreds_works_for_revocation_proof_with_cached_revocation_state() {
         utils::setup();
 
         //1. Issuer creates wallet, gets wallet handle
@@ -4194,9 +5544,8 @@ mod demos {
         //4. Prover creates Master Secret
         anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
 
-
         //5. Issuance Credential
-        let (cred_rev_id, revoc_reg_delta_json) = anoncreds::multi_steps_create_revocation_credential(
+        let (_, revoc_reg_delta_json) = anoncreds::multi_steps_create_revocation_credential(
             COMMON_MASTER_SECRET,
             prover_wallet_handle,
             issuer_wallet_handle,
@@ -4210,7 +5559,17 @@ mod demos {
         );
         let revoc_reg_delta_json = revoc_reg_delta_json.unwrap();
 
-        //6. Prover gets Credentials for Proof Request
+        //6. Prover creates RevocationState of the stored Credential and caches it in the wallet
+        let timestamp = 100;
+        let rev_state_json = anoncreds::prover_update_credential_revocation_state(prover_wallet_handle,
+                                                                                  blob_storage_reader_handle,
+                                                                                  CREDENTIAL1_ID,
+                                                                                  &revoc_reg_def_json,
+                                                                                  &revoc_reg_delta_json,
+                                                                                  timestamp).unwrap();
+        let rev_state: RevocationState = serde_json::from_str(&rev_state_json).unwrap();
+        assert_eq!(timestamp, rev_state.timestamp);
+
         let proof_request = json!({
            "nonce":"123432421212",
            "name":"proof_req_1",
@@ -4223,29 +5582,225 @@ mod demos {
            "requested_predicates": json!({
                "predicate1_referent": json!({ "name":"age", "p_type":">=", "p_value":18 })
            }),
-           "non_revoked": json!({ "from":80, "to":100 })
+           "non_revoked": json!({ "from":80, "to":300 })
         }).to_string();
 
-        //7. Prover gets Credentials for Proof Request
-        let credentials_json = anoncreds::prover_get_credentials_for_proof_req(prover_wallet_handle, &proof_request).unwrap();
-        let credential = anoncreds::get_credential_for_attr_referent(&credentials_json, "attr1_referent");
+        let schemas_json = json!({
+            schema_id.clone(): serde_json::from_str::<Schema>(&schema_json).unwrap()
+        }).to_string();
 
-        //8. Prover creates RevocationState
+        let credential_defs_json = json!({
+            cred_def_id.clone(): serde_json::from_str::<CredentialDefinition>(&cred_def_json).unwrap()
+        }).to_string();
+
+        let rev_reg_defs_json = json!({
+            rev_reg_id.clone(): serde_json::from_str::<RevocationRegistryDefinition>(&revoc_reg_def_json).unwrap()
+        }).to_string();
+
+        let prove_and_verify = |timestamp: u64, rev_reg_delta_json: &str| {
+            let requested_credentials_json = json!({
+                 "self_attested_attributes": json!({}),
+                 "requested_attributes": json!({
+                    "attr1_referent": json!({ "cred_id": CREDENTIAL1_ID, "timestamp": timestamp,  "revealed":true })
+                 }),
+                 "requested_predicates": json!({
+                    "predicate1_referent": json!({ "cred_id": CREDENTIAL1_ID, "timestamp": timestamp })
+                 })
+            }).to_string();
+
+            // Revocation state is taken from the wallet
+            let proof_json = anoncreds::prover_create_proof(prover_wallet_handle,
+                                                            &proof_request,
+                                                            &requested_credentials_json,
+                                                            COMMON_MASTER_SECRET,
+                                                            &schemas_json,
+                                                            &credential_defs_json,
+                                                            "{}").unwrap();
+
+            let rev_regs_json = json!({
+                rev_reg_id.clone(): json!({
+                    timestamp.to_string(): serde_json::from_str::<RevocationRegistry>(rev_reg_delta_json).unwrap()
+                })
+            }).to_string();
+
+            anoncreds::verifier_verify_proof(&proof_request,
+                                             &proof_json,
+                                             &schemas_json,
+                                             &credential_defs_json,
+                                             &rev_reg_defs_json,
+                                             &rev_regs_json).unwrap()
+        };
+
+        //7. Prover proves with cached RevocationState for its timestamp
+        assert!(prove_and_verify(timestamp, &revoc_reg_delta_json));
+
+        //8. Prover proves with cached RevocationState for later timestamp while registry is unchanged
+        assert!(prove_and_verify(timestamp + 10, &revoc_reg_delta_json));
+
+        //9. Issuer issues other Credential, so registry is changed
+        let (_, revoc_reg_delta_2_json) = anoncreds::multi_steps_create_revocation_credential(
+            COMMON_MASTER_SECRET,
+            prover_wallet_handle,
+            issuer_wallet_handle,
+            CREDENTIAL2_ID,
+            &anoncreds::gvt2_credential_values_json(),
+            &cred_def_id,
+            &cred_def_json,
+            &rev_reg_id,
+            &revoc_reg_def_json,
+            blob_storage_reader_handle,
+        );
+        let revoc_reg_delta_2_json = revoc_reg_delta_2_json.unwrap();
+
+        //10. Prover updates cached RevocationState incrementally and proves with it
+        let timestamp_2 = 200;
+        anoncreds::prover_update_credential_revocation_state(prover_wallet_handle,
+                                                             blob_storage_reader_handle,
+                                                             CREDENTIAL1_ID,
+                                                             &revoc_reg_def_json,
+                                                             &revoc_reg_delta_2_json,
+                                                             timestamp_2).unwrap();
+
+        assert!(prove_and_verify(timestamp_2, &revoc_reg_delta_2_json));
+
+        //11. Prover can't update cached RevocationState to earlier timestamp
+        let res = anoncreds::prover_update_credential_revocation_state(prover_wallet_handle,
+                                                                       blob_storage_reader_handle,
+                                                                       CREDENTIAL1_ID,
+                                                                       &revoc_reg_def_json,
+                                                                       &revoc_reg_delta_2_json,
+                                                                       timestamp);
+        assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+        //12. Prover can't update RevocationState by definition of other registry
+        let mut other_revoc_reg_def: serde_json::Value = serde_json::from_str(&revoc_reg_def_json).unwrap();
+        other_revoc_reg_def["id"] = json!(format!("{}:4:{}:{}:{}", ISSUER_DID, cred_def_id, REVOC_REG_TYPE, "OTHER_TAG"));
+
+        let res = anoncreds::prover_update_credential_revocation_state(prover_wallet_handle,
+                                                                       blob_storage_reader_handle,
+                                                                       CREDENTIAL1_ID,
+                                                                       &other_revoc_reg_def.to_string(),
+                                                                       &revoc_reg_delta_2_json,
+                                                                       timestamp_2);
+        assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+        //13. Prover can't create proof for timestamp earlier than cached RevocationState
+        let requested_credentials_json = json!({
+             "self_attested_attributes": json!({}),
+             "requested_attributes": json!({
+                "attr1_referent": json!({ "cred_id": CREDENTIAL1_ID, "timestamp": timestamp,  "revealed":true })
+             }),
+             "requested_predicates": json!({})
+        }).to_string();
+
+        let res = anoncreds::prover_create_proof(prover_wallet_handle,
+                                                 &proof_request,
+                                                 &requested_credentials_json,
+                                                 COMMON_MASTER_SECRET,
+                                                 &schemas_json,
+                                                 &credential_defs_json,
+                                                 "{}");
+        assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+        wallet::close_wallet(issuer_wallet_handle).unwrap();
+        wallet::close_wallet(prover_wallet_handle).unwrap();
+
+        utils::tear_down();
+    }
+
+    #[cfg(feature = "revocation_tests")]
+    #[test]
+    fn anoncreds_works_for_revocation_with_plugged_blob_storage() {
+        utils::setup();
+
+        //1. Register in-memory blob storage
+        blob_storage::register_blob_storage(INMEM_TYPE).unwrap();
+
+        //2. Issuer and Prover create wallets, gets wallet handles
+        let issuer_wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+        let prover_wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+
+        //3. Issuer creates Schema and Credential Definition
+        let (schema_id, schema_json) = anoncreds::issuer_create_schema(ISSUER_DID,
+                                                                       GVT_SCHEMA_NAME,
+                                                                       SCHEMA_VERSION,
+                                                                       GVT_SCHEMA_ATTRIBUTES).unwrap();
+
+        let (cred_def_id, cred_def_json) = anoncreds::issuer_create_credential_definition(issuer_wallet_handle,
+                                                                                          ISSUER_DID,
+                                                                                          &schema_json,
+                                                                                          TAG_1,
+                                                                                          None,
+                                                                                          Some(&anoncreds::revocation_cred_def_config())).unwrap();
+
+        //4. Issuer creates Revocation Registry with tails file stored by plugged blob storage
+        let tails_writer_handle = blob_storage::open_writer(INMEM_TYPE, "{}").unwrap();
+
+        let (rev_reg_id, revoc_reg_def_json, _) =
+            anoncreds::issuer_create_and_store_revoc_reg(issuer_wallet_handle,
+                                                         ISSUER_DID,
+                                                         None,
+                                                         TAG_1,
+                                                         &cred_def_id,
+                                                         r#"{"max_cred_num":5, "issuance_type":"ISSUANCE_ON_DEMAND"}"#,
+                                                         tails_writer_handle).unwrap();
+
+        let revoc_reg_def: RevocationRegistryDefinition = serde_json::from_str(&revoc_reg_def_json).unwrap();
+        let RevocationRegistryDefinition::RevocationRegistryDefinitionV1(revoc_reg_def) = revoc_reg_def;
+        assert!(revoc_reg_def.value.tails_location.starts_with("inmem://"));
+
+        let blob_storage_reader_handle = blob_storage::open_reader(INMEM_TYPE, "{}").unwrap();
+
+        //5. Prover creates Master Secret
+        anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+        //6. Issuance Credential
+        let (cred_rev_id, revoc_reg_delta_json) = anoncreds::multi_steps_create_revocation_credential(
+            COMMON_MASTER_SECRET,
+            prover_wallet_handle,
+            issuer_wallet_handle,
+            CREDENTIAL1_ID,
+            &anoncreds::gvt_credential_values_json(),
+            &cred_def_id,
+            &cred_def_json,
+            &rev_reg_id,
+            &revoc_reg_def_json,
+            blob_storage_reader_handle,
+        );
+
+        //7. Prover creates RevocationState reading tails from plugged blob storage
+        let revoc_reg_delta_json = revoc_reg_delta_json.unwrap();
         let timestamp = 100;
+
         let rev_state_json = anoncreds::create_revocation_state(blob_storage_reader_handle,
                                                                 &revoc_reg_def_json,
                                                                 &revoc_reg_delta_json,
                                                                 timestamp,
                                                                 &cred_rev_id).unwrap();
 
-        //9. Prover creates Proof
+        //8. Prover creates Proof
+        let proof_request = json!({
+           "nonce":"123432421212",
+           "name":"proof_req_1",
+           "version":"0.1",
+           "requested_attributes": json!({
+               "attr1_referent": json!({
+                   "name":"name"
+               })
+           }),
+           "requested_predicates": json!({
+               "predicate1_referent": json!({ "name":"age", "p_type":">=", "p_value":18 })
+           }),
+           "non_revoked": json!({ "from":80, "to":100 })
+        }).to_string();
+
         let requested_credentials_json = json!({
              "self_attested_attributes": json!({}),
              "requested_attributes": json!({
-                "attr1_referent": json!({ "cred_id": credential.referent, "timestamp": timestamp,  "revealed":true })
+                "attr1_referent": json!({ "cred_id": CREDENTIAL1_ID, "timestamp": timestamp,  "revealed":true })
              }),
              "requested_predicates": json!({
-                "predicate1_referent": json!({ "cred_id": credential.referent, "timestamp": timestamp })
+                "predicate1_referent": json!({ "cred_id": CREDENTIAL1_ID, "timestamp": timestamp })
              })
         }).to_string();
 
@@ -4270,11 +5825,8 @@ mod demos {
                                                         &schemas_json,
                                                         &credential_defs_json,
                                                         &rev_states_json).unwrap();
-        let proof: Proof = serde_json::from_str(&proof_json).unwrap();
-
-        //10. Verifier verifies proof
-        assert_eq!("Alex", proof.requested_proof.revealed_attrs.get("attr1_referent").unwrap().raw);
 
+        //9. Verifier verifies Proof
         let rev_reg_defs_json = json!({
             rev_reg_id.clone(): serde_json::from_str::<RevocationRegistryDefinition>(&revoc_reg_def_json).unwrap()
         }).to_string();
@@ -4293,6 +5845,27 @@ mod demos {
                                                      &rev_regs_json).unwrap();
         assert!(valid);
 
+        //10. Issuer revokes Credential
+        let revoc_reg_delta_json = anoncreds::issuer_revoke_credential(issuer_wallet_handle,
+                                                                       blob_storage_reader_handle,
+                                                                       &rev_reg_id,
+                                                                       &cred_rev_id).unwrap();
+
+        //11. Verifier verifies Proof after Credential was revoked
+        let rev_regs_json = json!({
+            rev_reg_id.clone(): json!({
+                timestamp.to_string(): serde_json::from_str::<RevocationRegistry>(&revoc_reg_delta_json).unwrap()
+            })
+        }).to_string();
+
+        let valid = anoncreds::verifier_verify_proof(&proof_request,
+                                                     &proof_json,
+                                                     &schemas_json,
+                                                     &credential_defs_json,
+                                                     &rev_reg_defs_json,
+                                                     &rev_regs_json).unwrap();
+        assert!(!valid);
+
         wallet::close_wallet(issuer_wallet_handle).unwrap();
         wallet::close_wallet(prover_wallet_handle).unwrap();
 
@@ -4959,8 +6532,8 @@ mod demos {
         //8. Issuer2 issue ABC Credential for Prover
         //   note that encoding is not standardized by Indy except that 32-bit integers are encoded as themselves. IS-786
         let abc_cred_values = r#"{
-            "name": {"raw":"Alexander", "encoded": "126328542632549235769221"},
-            "second_name": {"raw":"Park", "encoded": "42935129364832492914638245934"},
+            "name": {"raw":"Alexander", "encoded": "99363921290711255957660407514291096682589087904667529759842645133073147484933"},
+            "second_name": {"raw":"Park", "encoded": "45863450696068714786335678411396868862099937553022511523906445574212994705970"},
             "experience": {"raw":"5", "encoded": "5"}
         }"#;
 
@@ -5358,6 +6931,16 @@ mod demos {
                                                      &rev_regs_json).unwrap();
         assert!(valid);
 
+        let report_json = anoncreds::verifier_verify_proof_verbose(&proof_request,
+                                                                   &proof_json,
+                                                                   &schemas_json,
+                                                                   &credential_defs_json,
+                                                                   &rev_reg_defs_json,
+                                                                   &rev_regs_json).unwrap();
+        let report: serde_json::Value = serde_json::from_str(&report_json).unwrap();
+        assert_eq!(json!(true), report["valid"]);
+        assert_eq!(json!("passed"), report["sub_proofs"][0]["revocation"]);
+
         //9. Issuer revokes credential
         let revoc_reg_delta_json = anoncreds::issuer_revoke_credential(issuer_wallet_handle,
                                                                        blob_storage_reader_handle,
@@ -5383,6 +6966,18 @@ mod demos {
                                                      &rev_regs_json).unwrap();
         assert!(!valid);
 
+        //11. Verifier gets verbose report for revoked credential
+        let report_json = anoncreds::verifier_verify_proof_verbose(&proof_request,
+                                                                   &proof_json,
+                                                                   &schemas_json,
+                                                                   &credential_defs_json,
+                                                                   &rev_reg_defs_json,
+                                                                   &rev_regs_json).unwrap();
+        let report: serde_json::Value = serde_json::from_str(&report_json).unwrap();
+        assert_eq!(json!(false), report["valid"]);
+        assert_eq!(json!(false), report["proof_verified"]);
+        assert_eq!(json!("unknown"), report["sub_proofs"][0]["revocation"]);
+
         wallet::close_wallet(issuer_wallet_handle).unwrap();
         wallet::close_wallet(prover_wallet_handle).unwrap();
 
@@ -5786,6 +7381,171 @@ mod demos {
     }
 
 
+    #[cfg(feature = "revocation_tests")]
+    #[test]
+    fn anoncreds_works_for_issuance_by_demand_revocation_strategy_revoke_credentials_batch() {
+        utils::setup();
+
+        //1. Issuer and Prover create wallets, gets wallet handles
+        let issuer_wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+        let prover_wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+
+        //2. Issuer creates Schema, Credential Definition and Revocation Registry
+        let (_, _,
+            cred_def_id, cred_def_json,
+            rev_reg_id, revoc_reg_def_json, _,
+            blob_storage_reader_handle) = anoncreds::multi_steps_issuer_revocation_preparation(issuer_wallet_handle,
+                                                                                               ISSUER_DID,
+                                                                                               GVT_SCHEMA_NAME,
+                                                                                               GVT_SCHEMA_ATTRIBUTES,
+                                                                                               r#"{"max_cred_num":5, "issuance_type":"ISSUANCE_ON_DEMAND"}"#);
+
+        //3. Issuer issues two Credentials
+        anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+        let (cred_rev_id_1, _) = anoncreds::multi_steps_create_revocation_credential(COMMON_MASTER_SECRET,
+                                                                                   prover_wallet_handle,
+                                                                                   issuer_wallet_handle,
+                                                                                   CREDENTIAL1_ID,
+                                                                                   &anoncreds::gvt_credential_values_json(),
+                                                                                   &cred_def_id,
+                                                                                   &cred_def_json,
+                                                                                   &rev_reg_id,
+                                                                                   &revoc_reg_def_json,
+                                                                                   blob_storage_reader_handle);
+
+        let (cred_rev_id_2, _) = anoncreds::multi_steps_create_revocation_credential(COMMON_MASTER_SECRET,
+                                                                                   prover_wallet_handle,
+                                                                                   issuer_wallet_handle,
+                                                                                   CREDENTIAL2_ID,
+                                                                                   &anoncreds::gvt2_credential_values_json(),
+                                                                                   &cred_def_id,
+                                                                                   &cred_def_json,
+                                                                                   &rev_reg_id,
+                                                                                   &revoc_reg_def_json,
+                                                                                   blob_storage_reader_handle);
+
+        //4. Issuer can't revoke empty batch
+        let res = anoncreds::issuer_revoke_credentials(issuer_wallet_handle, blob_storage_reader_handle, &rev_reg_id, "[]");
+        assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+        //5. Batch with not issued or repeated id is rejected and doesn't revoke valid ids
+        let res = anoncreds::issuer_revoke_credentials(issuer_wallet_handle, blob_storage_reader_handle, &rev_reg_id,
+                                                       &json!([cred_rev_id_1, "100"]).to_string());
+        assert_eq!(ErrorCode::AnoncredsInvalidUserRevocId, res.unwrap_err());
+
+        let res = anoncreds::issuer_revoke_credentials(issuer_wallet_handle, blob_storage_reader_handle, &rev_reg_id,
+                                                       &json!([cred_rev_id_1, cred_rev_id_1]).to_string());
+        assert_eq!(ErrorCode::AnoncredsInvalidUserRevocId, res.unwrap_err());
+
+        //6. Issuer revokes both Credentials by one call
+        let rev_reg_delta_json = anoncreds::issuer_revoke_credentials(issuer_wallet_handle, blob_storage_reader_handle, &rev_reg_id,
+                                                                      &json!([cred_rev_id_1, cred_rev_id_2]).to_string()).unwrap();
+        serde_json::from_str::<RevocationRegistry>(&rev_reg_delta_json).unwrap();
+
+        //7. Revoked Credentials can't be revoked again
+        let res = anoncreds::issuer_revoke_credential(issuer_wallet_handle, blob_storage_reader_handle, &rev_reg_id, &cred_rev_id_1);
+        assert_code!(ErrorCode::AnoncredsInvalidUserRevocId, res);
+
+        let res = anoncreds::issuer_revoke_credential(issuer_wallet_handle, blob_storage_reader_handle, &rev_reg_id, &cred_rev_id_2);
+        assert_code!(ErrorCode::AnoncredsInvalidUserRevocId, res);
+
+        wallet::close_wallet(issuer_wallet_handle).unwrap();
+        wallet::close_wallet(prover_wallet_handle).unwrap();
+
+        utils::tear_down();
+    }
+
+    #[cfg(feature = "revocation_tests")]
+    #[test]
+    fn anoncreds_works_for_revocation_registry_rotation() {
+        utils::setup();
+
+        //1. Issuer and Prover create wallets, gets wallet handles
+        let issuer_wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+        let prover_wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+
+        //2. Issuer creates Schema, Credential Definition and Revocation Registry for 1 Credential
+        let (_, _,
+            cred_def_id, cred_def_json,
+            rev_reg_id, revoc_reg_def_json, _,
+            blob_storage_reader_handle) = anoncreds::multi_steps_issuer_revocation_preparation(issuer_wallet_handle,
+                                                                                               ISSUER_DID,
+                                                                                               GVT_SCHEMA_NAME,
+                                                                                               GVT_SCHEMA_ATTRIBUTES,
+                                                                                               r#"{"max_cred_num":1, "issuance_type":"ISSUANCE_ON_DEMAND"}"#);
+
+        let rev_reg_id_for_tag = |tag: &str| format!("{}:4:{}:{}:{}", ISSUER_DID, cred_def_id, REVOC_REG_TYPE, tag);
+
+        let tails_writer_handle = blob_storage::open_writer("default", &anoncreds::tails_writer_config()).unwrap();
+
+        let rotate = |config: &str| -> serde_json::Value {
+            let rotation_json = anoncreds::issuer_rotate_revoc_reg(issuer_wallet_handle, ISSUER_DID, &cred_def_id, config, tails_writer_handle).unwrap();
+            serde_json::from_str(&rotation_json).unwrap()
+        };
+
+        //3. Rotation does nothing while active registry is below threshold
+        let rotation = rotate("{}");
+        assert_eq!(json!(rev_reg_id), rotation["active_rev_reg_id"]);
+        assert_eq!(json!(0), rotation["active_issued"]);
+        assert_eq!(serde_json::Value::Null, rotation["next_rev_reg_id"]);
+        assert_eq!(json!([]), rotation["ledger_requests"]);
+
+        //4. Issuer issues Credential which fills active registry
+        anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+        anoncreds::multi_steps_create_revocation_credential(COMMON_MASTER_SECRET,
+                                                            prover_wallet_handle,
+                                                            issuer_wallet_handle,
+                                                            CREDENTIAL1_ID,
+                                                            &anoncreds::gvt_credential_values_json(),
+                                                            &cred_def_id,
+                                                            &cred_def_json,
+                                                            &rev_reg_id,
+                                                            &revoc_reg_def_json,
+                                                            blob_storage_reader_handle);
+
+        //5. Rotation creates the next registry and switches to it as active one is full
+        let rotation = rotate("{}");
+        let next_rev_reg_id = rev_reg_id_for_tag("1");
+        assert_eq!(json!(next_rev_reg_id), rotation["active_rev_reg_id"]);
+        assert_eq!(json!(0), rotation["active_issued"]);
+        assert_eq!(serde_json::Value::Null, rotation["next_rev_reg_id"]);
+        assert_eq!(json!(next_rev_reg_id), rotation["rev_reg_def"]["id"]);
+        assert_eq!(2, rotation["ledger_requests"].as_array().unwrap().len());
+
+        //6. Issuer issues Credential from the next registry
+        anoncreds::multi_steps_create_revocation_credential(COMMON_MASTER_SECRET,
+                                                            prover_wallet_handle,
+                                                            issuer_wallet_handle,
+                                                            CREDENTIAL2_ID,
+                                                            &anoncreds::gvt2_credential_values_json(),
+                                                            &cred_def_id,
+                                                            &cred_def_json,
+                                                            &next_rev_reg_id,
+                                                            &rotation["rev_reg_def"].to_string(),
+                                                            blob_storage_reader_handle);
+
+        //7. Rotation rejects tag of existing registry
+        let res = anoncreds::issuer_rotate_revoc_reg(issuer_wallet_handle, ISSUER_DID, &cred_def_id,
+                                                     &json!({"tag": TAG_1}).to_string(), tails_writer_handle);
+        assert_eq!(ErrorCode::WalletItemAlreadyExists, res.unwrap_err());
+
+        //8. Rotation creates registry with explicit tag
+        let rotation = rotate(r#"{"tag": "3"}"#);
+        assert_eq!(json!(rev_reg_id_for_tag("3")), rotation["active_rev_reg_id"]);
+
+        //9. Default tag skips tag taken by explicitly tagged registry
+        let rotation = rotate(r#"{"threshold": 0}"#);
+        assert_eq!(json!(rev_reg_id_for_tag("3")), rotation["active_rev_reg_id"]);
+        assert_eq!(json!(rev_reg_id_for_tag("4")), rotation["next_rev_reg_id"]);
+
+        wallet::close_wallet(issuer_wallet_handle).unwrap();
+        wallet::close_wallet(prover_wallet_handle).unwrap();
+
+        utils::tear_down();
+    }
+
     #[test]
     fn anoncreds_works_for_multiple_requested_predicates_from_one_credential() {
         utils::setup();
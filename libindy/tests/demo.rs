@@ -255,7 +255,7 @@ fn anoncreds_demo_works() {
     // note that encoding is not standardized by Indy except that 32-bit integers are encoded as themselves. IS-786
     let credential_json = json!({
         "sex": { "raw": "male", "encoded": "5944657099558967239210949258394887428692050081607692519917050011144233115103" },
-        "name": { "raw": "Alex", "encoded": "1139481716457488690172217916278103335" },
+        "name": { "raw": "Alex", "encoded": "99262857098057710338306967609588410025648622308394250666849665532448612202874" },
         "height": { "raw": "175", "encoded": "175" },
         "age": { "raw": "28", "encoded": "28" }
     }).to_string();
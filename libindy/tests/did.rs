@@ -27,7 +27,7 @@ extern crate serde;
 #[macro_use]
 mod utils;
 
-use utils::{wallet, did, pool, ledger};
+use utils::{wallet, did, pool, ledger, pairwise};
 use utils::constants::*;
 use utils::types::ResponseType;
 
@@ -698,6 +698,136 @@ mod high_cases {
         }
     }
 
+    mod key_rotation_notice {
+        use super::*;
+        use rust_base58::ToBase58;
+        use utils::crypto;
+
+        fn setup_pairwise() -> (i32, i32, String) {
+            let my_wallet_handle = utils::setup_with_wallet();
+            let their_wallet_handle = wallet::create_and_open_default_wallet().unwrap();
+
+            let (my_did, my_verkey) = did::create_and_store_my_did(my_wallet_handle, None).unwrap();
+            let (their_my_did, _) = did::create_and_store_my_did(their_wallet_handle, None).unwrap();
+
+            did::store_their_did_from_parts(their_wallet_handle, &my_did, &my_verkey).unwrap();
+            pairwise::create_pairwise(their_wallet_handle, &my_did, &their_my_did, None).unwrap();
+
+            (my_wallet_handle, their_wallet_handle, my_did)
+        }
+
+        fn tear_down_pairwise(my_wallet_handle: i32, their_wallet_handle: i32) {
+            wallet::close_wallet(their_wallet_handle).unwrap();
+            utils::tear_down_with_wallet(my_wallet_handle);
+        }
+
+        #[test]
+        fn indy_apply_key_rotation_notice_works() {
+            let (my_wallet_handle, their_wallet_handle, my_did) = setup_pairwise();
+
+            let new_verkey = did::replace_keys_start(my_wallet_handle, &my_did, "{}").unwrap();
+            let notice_json = did::create_key_rotation_notice(my_wallet_handle, &my_did).unwrap();
+
+            did::apply_key_rotation_notice(their_wallet_handle, &notice_json).unwrap();
+
+            let verkey = did::key_for_local_did(their_wallet_handle, &my_did).unwrap();
+            assert_eq!(new_verkey, verkey);
+
+            tear_down_pairwise(my_wallet_handle, their_wallet_handle);
+        }
+
+        #[test]
+        fn indy_apply_key_rotation_notice_works_for_invalid_signature() {
+            let (my_wallet_handle, their_wallet_handle, my_did) = setup_pairwise();
+
+            did::replace_keys_start(my_wallet_handle, &my_did, "{}").unwrap();
+            let notice_json = did::create_key_rotation_notice(my_wallet_handle, &my_did).unwrap();
+
+            let (_, other_verkey) = did::create_and_store_my_did(my_wallet_handle, None).unwrap();
+            let mut notice: serde_json::Value = serde_json::from_str(&notice_json).unwrap();
+            notice["new_verkey"] = json!(other_verkey);
+
+            let res = did::apply_key_rotation_notice(their_wallet_handle, &notice.to_string());
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            let verkey = did::key_for_local_did(their_wallet_handle, &my_did).unwrap();
+            assert_ne!(other_verkey, verkey);
+
+            tear_down_pairwise(my_wallet_handle, their_wallet_handle);
+        }
+
+        #[test]
+        fn indy_apply_key_rotation_notice_works_for_replayed_notice() {
+            let (my_wallet_handle, their_wallet_handle, my_did) = setup_pairwise();
+
+            let new_verkey = did::replace_keys_start(my_wallet_handle, &my_did, "{}").unwrap();
+            let notice_json = did::create_key_rotation_notice(my_wallet_handle, &my_did).unwrap();
+
+            did::apply_key_rotation_notice(their_wallet_handle, &notice_json).unwrap();
+
+            let res = did::apply_key_rotation_notice(their_wallet_handle, &notice_json);
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            let verkey = did::key_for_local_did(their_wallet_handle, &my_did).unwrap();
+            assert_eq!(new_verkey, verkey);
+
+            tear_down_pairwise(my_wallet_handle, their_wallet_handle);
+        }
+
+        #[test]
+        fn indy_apply_key_rotation_notice_works_for_pairwise_update() {
+            let (my_wallet_handle, their_wallet_handle, my_did) = setup_pairwise();
+
+            let pairwise_json = pairwise::get_pairwise(their_wallet_handle, &my_did).unwrap();
+            let pairwise_info: serde_json::Value = serde_json::from_str(&pairwise_json).unwrap();
+            assert!(pairwise_info["their_key_rotated_at"].is_null());
+
+            did::replace_keys_start(my_wallet_handle, &my_did, "{}").unwrap();
+            let notice_json = did::create_key_rotation_notice(my_wallet_handle, &my_did).unwrap();
+            let notice: serde_json::Value = serde_json::from_str(&notice_json).unwrap();
+
+            did::apply_key_rotation_notice(their_wallet_handle, &notice_json).unwrap();
+
+            let pairwise_json = pairwise::get_pairwise(their_wallet_handle, &my_did).unwrap();
+            let pairwise_info: serde_json::Value = serde_json::from_str(&pairwise_json).unwrap();
+            assert_eq!(notice["timestamp"], pairwise_info["their_key_rotated_at"]);
+            assert!(pairwise_info["my_did"].is_string());
+
+            tear_down_pairwise(my_wallet_handle, their_wallet_handle);
+        }
+
+        #[test]
+        fn indy_apply_key_rotation_notice_works_for_stale_notice() {
+            let (my_wallet_handle, their_wallet_handle, my_did) = setup_pairwise();
+
+            did::replace_keys_start(my_wallet_handle, &my_did, "{}").unwrap();
+            let notice_json = did::create_key_rotation_notice(my_wallet_handle, &my_did).unwrap();
+            did::apply_key_rotation_notice(their_wallet_handle, &notice_json).unwrap();
+            did::replace_keys_apply(my_wallet_handle, &my_did).unwrap();
+
+            // notice signed by the rotated key but dated before the applied one
+            let (_, stale_verkey) = did::create_and_store_my_did(my_wallet_handle, None).unwrap();
+            let mut notice: serde_json::Value = serde_json::from_str(&notice_json).unwrap();
+            let timestamp = notice["timestamp"].as_u64().unwrap() - 1;
+            let rotated_verkey = notice["new_verkey"].as_str().unwrap().to_string();
+            notice["verkey"] = json!(rotated_verkey);
+            notice["new_verkey"] = json!(stale_verkey);
+            notice["timestamp"] = json!(timestamp);
+
+            let signature_data = format!("{}|{}|{}|{}", my_did, rotated_verkey, stale_verkey, timestamp);
+            let signature = crypto::sign(my_wallet_handle, &rotated_verkey, signature_data.as_bytes()).unwrap();
+            notice["signature"] = json!(signature.to_base58());
+
+            let res = did::apply_key_rotation_notice(their_wallet_handle, &notice.to_string());
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            let verkey = did::key_for_local_did(their_wallet_handle, &my_did).unwrap();
+            assert_eq!(rotated_verkey, verkey);
+
+            tear_down_pairwise(my_wallet_handle, their_wallet_handle);
+        }
+    }
+
     mod store_their_did {
         use super::*;
 
@@ -308,5 +308,83 @@ mod high_cases {
             utils::tear_down_with_wallet(wallet_handle);
         }
     }
-}
 
+    mod search_pairwise {
+        use super::*;
+
+        fn setup_pairwise_with_tags() -> i32 {
+            let (wallet_handle, my_did) = utils::setup_did();
+
+            did::store_their_did_from_parts(wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            did::store_their_did_from_parts(wallet_handle, DID_MY2, VERKEY_MY2).unwrap();
+
+            pairwise::create_pairwise(wallet_handle, DID_TRUSTEE, &my_did, Some(METADATA)).unwrap();
+            pairwise::create_pairwise(wallet_handle, DID_MY2, &my_did, None).unwrap();
+
+            pairwise::set_pairwise_tags(wallet_handle, DID_TRUSTEE, r#"{"label":"trustee","~state":"active"}"#).unwrap();
+            pairwise::set_pairwise_tags(wallet_handle, DID_MY2, r#"{"label":"my2","~state":"inactive"}"#).unwrap();
+
+            wallet_handle
+        }
+
+        fn search_all(wallet_handle: i32, query_json: &str) -> Vec<serde_json::Value> {
+            let (search_handle, total_count) = pairwise::search_pairwise(wallet_handle, query_json).unwrap();
+
+            let pairwise_list_json = pairwise::fetch_pairwise_search(search_handle, 10).unwrap();
+            pairwise::close_pairwise_search(search_handle).unwrap();
+
+            let pairwise_list: Vec<serde_json::Value> = serde_json::from_str(&pairwise_list_json).unwrap();
+            assert_eq!(total_count, pairwise_list.len());
+            pairwise_list
+        }
+
+        #[test]
+        fn indy_search_pairwise_works_for_tag() {
+            let wallet_handle = setup_pairwise_with_tags();
+
+            let pairwise_list = search_all(wallet_handle, r#"{"label":"trustee"}"#);
+            assert_eq!(1, pairwise_list.len());
+            assert_eq!(DID_TRUSTEE, pairwise_list[0]["their_did"].as_str().unwrap());
+            assert_eq!(METADATA, pairwise_list[0]["metadata"].as_str().unwrap());
+            assert_eq!(json!({"label": "trustee", "~state": "active"}), pairwise_list[0]["tags"]);
+
+            let pairwise_list = search_all(wallet_handle, r#"{"~state":"inactive"}"#);
+            assert_eq!(1, pairwise_list.len());
+            assert_eq!(DID_MY2, pairwise_list[0]["their_did"].as_str().unwrap());
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_search_pairwise_works_for_replaced_tags() {
+            let wallet_handle = setup_pairwise_with_tags();
+
+            pairwise::set_pairwise_tags(wallet_handle, DID_MY2, r#"{"label":"my2","~state":"active"}"#).unwrap();
+
+            let pairwise_list = search_all(wallet_handle, r#"{"~state":"active"}"#);
+            assert_eq!(2, pairwise_list.len());
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_search_pairwise_works_for_no_match() {
+            let wallet_handle = setup_pairwise_with_tags();
+
+            let pairwise_list = search_all(wallet_handle, r#"{"label":"unknown"}"#);
+            assert!(pairwise_list.is_empty());
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+
+        #[test]
+        fn indy_set_pairwise_tags_works_for_not_created_pairwise() {
+            let wallet_handle = utils::setup_with_wallet();
+
+            let res = pairwise::set_pairwise_tags(wallet_handle, DID_TRUSTEE, r#"{"label":"trustee"}"#);
+            assert_eq!(ErrorCode::WalletItemNotFound, res.unwrap_err());
+
+            utils::tear_down_with_wallet(wallet_handle);
+        }
+    }
+}
@@ -1,12 +1,14 @@
 extern crate futures;
+extern crate libc;
 
-use indy::IndyError;
+use indy::{ErrorCode, IndyError};
 use indy::anoncreds;
 use self::futures::Future;
+use self::libc::c_char;
 
 use serde_json;
 
-use utils::{environment, wallet, blob_storage, test, pool};
+use utils::{callback, environment, wallet, blob_storage, test, pool};
 use utils::types::CredentialOfferInfo;
 
 use std::sync::{Once, ONCE_INIT};
@@ -14,6 +16,8 @@ use std::mem;
 use utils::constants::*;
 
 use std::collections::{HashSet, HashMap};
+use std::ffi::CString;
+use std::ptr::null;
 
 use utils::domain::anoncreds::schema::{Schema, SchemaV1};
 use utils::domain::anoncreds::credential_definition::{CredentialDefinition, CredentialDefinitionConfig};
@@ -50,17 +54,101 @@ pub fn issuer_create_schema(issuer_did: &str, name: &str, version: &str, attr_na
     anoncreds::issuer_create_schema(issuer_did, name, version, attr_names).wait()
 }
 
+pub fn issuer_create_schema_with_types(issuer_did: &str, name: &str, version: &str, attr_types: &str) -> Result<(String, String), ErrorCode> {
+    let (receiver, command_handle, cb) = callback::_closure_to_cb_ec_string_string();
+
+    let issuer_did = CString::new(issuer_did).unwrap();
+    let name = CString::new(name).unwrap();
+    let version = CString::new(version).unwrap();
+    let attr_types = CString::new(attr_types).unwrap();
+
+    let err = unsafe {
+        indy_issuer_create_schema_with_types(command_handle,
+                                             issuer_did.as_ptr(),
+                                             name.as_ptr(),
+                                             version.as_ptr(),
+                                             attr_types.as_ptr(),
+                                             cb)
+    };
+
+    super::results::result_to_string_string(err as i32, receiver)
+}
+
 pub fn issuer_create_credential_definition(wallet_handle: i32, issuer_did: &str, schema: &str, tag: &str,
                                            signature_type: Option<&str>, config: Option<&str>) -> Result<(String, String), IndyError> {
     anoncreds::issuer_create_and_store_credential_def(wallet_handle, issuer_did, schema, tag, signature_type, config.unwrap_or("{}")).wait() // TODO: FIXME OPTIONAL CONFIG
 }
 
+pub fn issuer_rotate_credential_def(wallet_handle: i32, issuer_did: &str, cred_def_id: &str, schema: &str, tag: &str,
+                                    config: Option<&str>) -> Result<(String, String), ErrorCode> {
+    let (receiver, command_handle, cb) = callback::_closure_to_cb_ec_string_string();
+
+    let issuer_did = CString::new(issuer_did).unwrap();
+    let cred_def_id = CString::new(cred_def_id).unwrap();
+    let schema = CString::new(schema).unwrap();
+    let tag = CString::new(tag).unwrap();
+    let config_str = config.map(|s| CString::new(s).unwrap());
+
+    let err = unsafe {
+        indy_issuer_rotate_credential_def(command_handle,
+                                          wallet_handle,
+                                          issuer_did.as_ptr(),
+                                          cred_def_id.as_ptr(),
+                                          schema.as_ptr(),
+                                          tag.as_ptr(),
+                                          config_str.as_ref().map(|s| s.as_ptr()).unwrap_or(null()),
+                                          cb)
+    };
+
+    super::results::result_to_string_string(err as i32, receiver)
+}
+
 pub fn issuer_create_and_store_revoc_reg(wallet_handle: i32, issuer_did: &str, type_: Option<&str>, tag: &str,
                                          cred_def_id: &str, config_json: &str, tails_writer_handle: i32)
                                          -> Result<(String, String, String), IndyError> {
     anoncreds::issuer_create_and_store_revoc_reg(wallet_handle, issuer_did, type_, tag, cred_def_id, config_json, tails_writer_handle).wait()
 }
 
+pub fn issuer_rotate_revoc_reg(wallet_handle: i32, issuer_did: &str, cred_def_id: &str, config_json: &str,
+                               tails_writer_handle: i32) -> Result<String, ErrorCode> {
+    let (receiver, command_handle, cb) = callback::_closure_to_cb_ec_string();
+
+    let issuer_did = CString::new(issuer_did).unwrap();
+    let cred_def_id = CString::new(cred_def_id).unwrap();
+    let config_json = CString::new(config_json).unwrap();
+
+    let err = unsafe {
+        indy_issuer_rotate_revoc_reg(command_handle,
+                                     wallet_handle,
+                                     issuer_did.as_ptr(),
+                                     cred_def_id.as_ptr(),
+                                     config_json.as_ptr(),
+                                     tails_writer_handle,
+                                     cb)
+    };
+
+    super::results::result_to_string(err as i32, receiver)
+}
+
+pub fn issuer_revoke_credentials(wallet_handle: i32, blob_storage_reader_handle: i32, rev_reg_id: &str,
+                                 cred_revoc_ids_json: &str) -> Result<String, ErrorCode> {
+    let (receiver, command_handle, cb) = callback::_closure_to_cb_ec_string();
+
+    let rev_reg_id = CString::new(rev_reg_id).unwrap();
+    let cred_revoc_ids_json = CString::new(cred_revoc_ids_json).unwrap();
+
+    let err = unsafe {
+        indy_issuer_revoke_credentials(command_handle,
+                                       wallet_handle,
+                                       blob_storage_reader_handle,
+                                       rev_reg_id.as_ptr(),
+                                       cred_revoc_ids_json.as_ptr(),
+                                       cb)
+    };
+
+    super::results::result_to_string(err as i32, receiver)
+}
+
 pub fn issuer_create_credential_offer(wallet_handle: i32, cred_def_id: &str) -> Result<String, IndyError> {
     anoncreds::issuer_create_credential_offer(wallet_handle, cred_def_id).wait()
 }
@@ -70,6 +158,63 @@ pub fn issuer_create_credential(wallet_handle: i32, cred_offer_json: &str, cred_
     anoncreds::issuer_create_credential(wallet_handle, cred_offer_json, cred_req_json, cred_values_json, rev_reg_id, blob_storage_reader_handle.unwrap_or(-1)).wait() // TODO OPTIONAL blob_storage_reader_handle
 }
 
+pub fn issuer_create_credential_with_log(wallet_handle: i32, cred_offer_json: &str, cred_req_json: &str, cred_values_json: &str,
+                                         rev_reg_id: Option<&str>, blob_storage_reader_handle: Option<i32>,
+                                         tags_json: Option<&str>) -> Result<(String, Option<String>, Option<String>), ErrorCode> {
+    let (receiver, command_handle, cb) = callback::_closure_to_cb_ec_string_opt_string_opt_string();
+
+    let cred_offer_json = CString::new(cred_offer_json).unwrap();
+    let cred_req_json = CString::new(cred_req_json).unwrap();
+    let cred_values_json = CString::new(cred_values_json).unwrap();
+    let rev_reg_id_str = rev_reg_id.map(|s| CString::new(s).unwrap());
+    let tags_json_str = tags_json.map(|s| CString::new(s).unwrap());
+
+    let err = unsafe {
+        indy_issuer_create_credential_with_log(command_handle,
+                                               wallet_handle,
+                                               cred_offer_json.as_ptr(),
+                                               cred_req_json.as_ptr(),
+                                               cred_values_json.as_ptr(),
+                                               rev_reg_id_str.as_ref().map(|s| s.as_ptr()).unwrap_or(null()),
+                                               blob_storage_reader_handle.unwrap_or(-1),
+                                               tags_json_str.as_ref().map(|s| s.as_ptr()).unwrap_or(null()),
+                                               cb)
+    };
+
+    super::results::result_to_string_opt_string_opt_string(err as i32, receiver)
+}
+
+pub fn issuer_search_issued_credentials(wallet_handle: i32, query_json: Option<&str>) -> Result<(i32, usize), ErrorCode> {
+    let (receiver, command_handle, cb) = callback::_closure_to_cb_ec_i32_usize();
+
+    let query_json_str = query_json.map(|s| CString::new(s).unwrap());
+
+    let err = unsafe {
+        indy_issuer_search_issued_credentials(command_handle,
+                                              wallet_handle,
+                                              query_json_str.as_ref().map(|s| s.as_ptr()).unwrap_or(null()),
+                                              cb)
+    };
+
+    super::results::result_to_int_usize(err as i32, receiver)
+}
+
+pub fn issuer_fetch_issued_credentials(search_handle: i32, count: usize) -> Result<String, ErrorCode> {
+    let (receiver, command_handle, cb) = callback::_closure_to_cb_ec_string();
+
+    let err = unsafe { indy_issuer_fetch_issued_credentials(command_handle, search_handle, count, cb) };
+
+    super::results::result_to_string(err as i32, receiver)
+}
+
+pub fn issuer_close_issued_credentials_search(search_handle: i32) -> Result<(), ErrorCode> {
+    let (receiver, command_handle, cb) = callback::_closure_to_cb_ec();
+
+    let err = unsafe { indy_issuer_close_issued_credentials_search(command_handle, search_handle, cb) };
+
+    super::results::result_to_empty(err as i32, receiver)
+}
+
 pub fn issuer_revoke_credential(wallet_handle: i32, blob_storage_reader_handle: i32, rev_reg_id: &str, cred_revoc_id: &str) -> Result<String, IndyError> {
     anoncreds::issuer_revoke_credential(wallet_handle, blob_storage_reader_handle, rev_reg_id, cred_revoc_id).wait()
 }
@@ -92,6 +237,52 @@ pub fn prover_store_credential(wallet_handle: i32, cred_id: &str, cred_req_metad
     anoncreds::prover_store_credential(wallet_handle, Some(cred_id), cred_req_metadata_json, cred_json, cred_def_json, rev_reg_def_json).wait()
 }
 
+pub fn prover_update_credential_revocation_state(wallet_handle: i32, blob_storage_reader_handle: i32, cred_id: &str,
+                                                 rev_reg_def_json: &str, rev_reg_delta_json: &str, timestamp: u64) -> Result<String, ErrorCode> {
+    let (receiver, command_handle, cb) = callback::_closure_to_cb_ec_string();
+
+    let cred_id = CString::new(cred_id).unwrap();
+    let rev_reg_def_json = CString::new(rev_reg_def_json).unwrap();
+    let rev_reg_delta_json = CString::new(rev_reg_delta_json).unwrap();
+
+    let err = unsafe {
+        indy_prover_update_credential_revocation_state(command_handle,
+                                                       wallet_handle,
+                                                       blob_storage_reader_handle,
+                                                       cred_id.as_ptr(),
+                                                       rev_reg_def_json.as_ptr(),
+                                                       rev_reg_delta_json.as_ptr(),
+                                                       timestamp,
+                                                       cb)
+    };
+
+    super::results::result_to_string(err as i32, receiver)
+}
+
+pub fn prover_verify_credential(wallet_handle: i32, cred_req_metadata_json: &str, cred_json: &str, schema_json: &str,
+                                cred_def_json: &str, rev_reg_def_json: Option<&str>) -> Result<String, ErrorCode> {
+    let (receiver, command_handle, cb) = callback::_closure_to_cb_ec_string();
+
+    let cred_req_metadata_json = CString::new(cred_req_metadata_json).unwrap();
+    let cred_json = CString::new(cred_json).unwrap();
+    let schema_json = CString::new(schema_json).unwrap();
+    let cred_def_json = CString::new(cred_def_json).unwrap();
+    let rev_reg_def_json = rev_reg_def_json.map(|json| CString::new(json).unwrap());
+
+    let err = unsafe {
+        indy_prover_verify_credential(command_handle,
+                                      wallet_handle,
+                                      cred_req_metadata_json.as_ptr(),
+                                      cred_json.as_ptr(),
+                                      schema_json.as_ptr(),
+                                      cred_def_json.as_ptr(),
+                                      rev_reg_def_json.as_ref().map(|json| json.as_ptr()).unwrap_or(::std::ptr::null()),
+                                      cb)
+    };
+
+    super::results::result_to_string(err as i32, receiver)
+}
+
 //TODO mark as depricated and use only in target tests
 pub fn prover_get_credentials(wallet_handle: i32, filter_json: &str) -> Result<String, IndyError> {
     anoncreds::prover_get_credentials(wallet_handle, Some(filter_json)).wait()
@@ -101,6 +292,38 @@ pub fn prover_get_credential(wallet_handle: i32, cred_id: &str) -> Result<String
     anoncreds::prover_get_credential(wallet_handle, cred_id).wait()
 }
 
+pub fn prover_delete_credential(wallet_handle: i32, cred_id: &str) -> Result<(), ErrorCode> {
+    let (receiver, command_handle, cb) = callback::_closure_to_cb_ec();
+
+    let cred_id = CString::new(cred_id).unwrap();
+
+    let err = unsafe { indy_prover_delete_credential(command_handle, wallet_handle, cred_id.as_ptr(), cb) };
+
+    super::results::result_to_empty(err as i32, receiver)
+}
+
+pub fn prover_add_credential_tags(wallet_handle: i32, cred_id: &str, tags_json: &str) -> Result<(), ErrorCode> {
+    let (receiver, command_handle, cb) = callback::_closure_to_cb_ec();
+
+    let cred_id = CString::new(cred_id).unwrap();
+    let tags_json = CString::new(tags_json).unwrap();
+
+    let err = unsafe { indy_prover_add_credential_tags(command_handle, wallet_handle, cred_id.as_ptr(), tags_json.as_ptr(), cb) };
+
+    super::results::result_to_empty(err as i32, receiver)
+}
+
+pub fn prover_delete_credential_tags(wallet_handle: i32, cred_id: &str, tag_names_json: &str) -> Result<(), ErrorCode> {
+    let (receiver, command_handle, cb) = callback::_closure_to_cb_ec();
+
+    let cred_id = CString::new(cred_id).unwrap();
+    let tag_names_json = CString::new(tag_names_json).unwrap();
+
+    let err = unsafe { indy_prover_delete_credential_tags(command_handle, wallet_handle, cred_id.as_ptr(), tag_names_json.as_ptr(), cb) };
+
+    super::results::result_to_empty(err as i32, receiver)
+}
+
 pub fn prover_search_credentials(wallet_handle: i32, filter_json: &str) -> Result<(i32, usize), IndyError> {
     anoncreds::prover_search_credentials(wallet_handle, Some(filter_json)).wait()
 }
@@ -142,6 +365,31 @@ pub fn verifier_verify_proof(proof_request_json: &str, proof_json: &str, schemas
     anoncreds::verifier_verify_proof(proof_request_json, proof_json, schemas_json, cred_defs_json, rev_reg_defs_json, rev_regs_json).wait()
 }
 
+pub fn verifier_verify_proof_verbose(proof_request_json: &str, proof_json: &str, schemas_json: &str,
+                                     cred_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str) -> Result<String, ErrorCode> {
+    let (receiver, command_handle, cb) = callback::_closure_to_cb_ec_string();
+
+    let proof_request_json = CString::new(proof_request_json).unwrap();
+    let proof_json = CString::new(proof_json).unwrap();
+    let schemas_json = CString::new(schemas_json).unwrap();
+    let cred_defs_json = CString::new(cred_defs_json).unwrap();
+    let rev_reg_defs_json = CString::new(rev_reg_defs_json).unwrap();
+    let rev_regs_json = CString::new(rev_regs_json).unwrap();
+
+    let err = unsafe {
+        indy_verifier_verify_proof_verbose(command_handle,
+                                           proof_request_json.as_ptr(),
+                                           proof_json.as_ptr(),
+                                           schemas_json.as_ptr(),
+                                           cred_defs_json.as_ptr(),
+                                           rev_reg_defs_json.as_ptr(),
+                                           rev_regs_json.as_ptr(),
+                                           cb)
+    };
+
+    super::results::result_to_string(err as i32, receiver)
+}
+
 pub fn create_revocation_state(blob_storage_reader_handle: i32, rev_reg_def_json: &str,
                                rev_reg_delta_json: &str, timestamp: u64, cred_rev_id: &str) -> Result<String, IndyError> {
     anoncreds::create_revocation_state(blob_storage_reader_handle, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_rev_id).wait()
@@ -232,11 +480,11 @@ pub fn issuer_2_gvt_cred_offer_info() -> CredentialOfferInfo {
     CredentialOfferInfo { cred_def_id: issuer_2_gvt_cred_def_id() }
 }
 
-// note that encoding is not standardized by Indy except that 32-bit integers are encoded as themselves. IS-786
+// values are encoded the standard way (see indy_encode_credential_attribute): 32-bit integers as themselves, other values as SHA-256 hash
 pub fn gvt_credential_values() -> HashMap<String, AttributeValues> {
     map! {
             "sex".to_string() => AttributeValues {raw: "male".to_string(), encoded: "5944657099558967239210949258394887428692050081607692519917050011144233115103".to_string()},
-            "name".to_string() => AttributeValues {raw: "Alex".to_string(), encoded: "1139481716457488690172217916278103335".to_string()},
+            "name".to_string() => AttributeValues {raw: "Alex".to_string(), encoded: "99262857098057710338306967609588410025648622308394250666849665532448612202874".to_string()},
             "height".to_string() => AttributeValues {raw: "175".to_string(), encoded: "175".to_string()},
             "age".to_string() => AttributeValues {raw: "28".to_string(), encoded: "28".to_string()}
           }
@@ -248,7 +496,7 @@ pub fn gvt_credential_values_json() -> String {
 
 pub fn xyz_credential_values() -> HashMap<String, AttributeValues> {
     map! {
-            "status".to_string() => AttributeValues {raw: "partial".to_string(), encoded: "51792877103171595686471452153480627530895".to_string()},
+            "status".to_string() => AttributeValues {raw: "partial".to_string(), encoded: "68844542227783239616914631962666354018596081780892056673977817439867175591325".to_string()},
             "period".to_string() => AttributeValues {raw: "8".to_string(), encoded: "8".to_string()}
           }
 }
@@ -259,8 +507,8 @@ pub fn xyz_credential_values_json() -> String {
 
 pub fn gvt2_credential_values() -> HashMap<String, AttributeValues> {
     map! {
-            "sex".to_string() => AttributeValues {raw: "male".to_string(), encoded: "2142657394558967239210949258394838228692050081607692519917028371144233115103".to_string()},
-            "name".to_string() => AttributeValues {raw: "Alexander".to_string(), encoded: "21332817548165488690172217217278169335".to_string()},
+            "sex".to_string() => AttributeValues {raw: "male".to_string(), encoded: "5944657099558967239210949258394887428692050081607692519917050011144233115103".to_string()},
+            "name".to_string() => AttributeValues {raw: "Alexander".to_string(), encoded: "99363921290711255957660407514291096682589087904667529759842645133073147484933".to_string()},
             "height".to_string() => AttributeValues {raw: "170".to_string(), encoded: "170".to_string()},
             "Age".to_string() => AttributeValues {raw: "28".to_string(), encoded: "28".to_string()}
           }
@@ -272,8 +520,8 @@ pub fn gvt2_credential_values_json() -> String {
 
 pub fn gvt3_credential_values() -> HashMap<String, AttributeValues> {
     map! {
-            "sex".to_string() => AttributeValues {raw: "male".to_string(), encoded: "1234567890442222223345678958394838228692050081607692519917028371144233115103".to_string()},
-            "name".to_string() => AttributeValues {raw: "Artem".to_string(), encoded: "12356325715837025980172217217278169335".to_string()},
+            "sex".to_string() => AttributeValues {raw: "male".to_string(), encoded: "5944657099558967239210949258394887428692050081607692519917050011144233115103".to_string()},
+            "name".to_string() => AttributeValues {raw: "Artem".to_string(), encoded: "45966993882451240096075526826515069198253634658736010384036786617531474905229".to_string()},
             "height".to_string() => AttributeValues {raw: "180".to_string(), encoded: "180".to_string()},
             "age".to_string() => AttributeValues {raw: "25".to_string(), encoded: "25".to_string()}
           }
@@ -385,37 +633,32 @@ pub fn proof_request_attr() -> String {
         }).to_string()
 }
 
+// proof for proof_request_attr() created from the credential issued in the common wallet,
+// so it stays consistent with the current attribute encoding and cred_defs_for_proof()
 pub fn proof_json() -> String {
-    r#"{
-            "proof":{
-                "proofs":[
-                    {
-                        "primary_proof":{
-                            "eq_proof":{"revealed_attrs":{"name":"1139481716457488690172217916278103335"},"a_prime":"73051896986344783783621559954466052240337632808477729510525777007534198657123370460809453476237905269777928500034476888078179811369103091702326392092669222868996323974762333077146800752404116534730748685092400106417894776122280960547391515814302192999142386455183675790870578615457141270148590712693325301185445330992767208427208215818892089082206123243055148017865514286222759353929656015594529211154843197464055996993778878163967106658629893439206203941596066380562586058713924055616953462170537040600604826428201808405436865130230174790116739542071871153581967170346076628186863101926791732126528122264782281465094","e":"26894279258848531841414955598838798345606055130059418263879278878511424413654641307014787224496208858379991228288791608261549931755104416","v":"769593829417540943566687651216000708099616242062220026508500847265211856977241087739974159673381844796906987056271685312217722655254322996792650873775611656861273544234724432321045515309211146266498852589181986850053751764534235454974453901933962390148609111520973909072559803423360526975061164422239685006387576029266210201929872373313392190241424322333321394922891207577033519614434276723347140746548441162607411616008633618021962845423830579218345578253882839612570986096830936195064001459565147361336597305783767484298283647710212770870573787603073109857430854719681849489345098539472090186844042540487233617799636327572785715912348265648433678177765454231546725849288046905854444755145184654162149010359429569273734847400697627028832950969890252877892391103230391674009825009176344665382964776819962789472959504523580584494299815960094679820651071251157496967617834816772303813309035759721203718921501821175528106375","m":{"age":"1143281854280323408461665818853228702279803847691030529301464848501919856277927436364331044530711281448694432838145799412204154542183613877104383361274202256495017144684827419222","sex":"13123681697669364600723785784083768668401173003182555407713667959884184961072036088391942098105496874381346284841774772987179772727928471347011107103459387881602408580853389973314","height":"5824877563809831190436025794795529331411852203759926644567286594845018041324472260994302109635777382645241758582661313361940262319244084725507113643699421966391425299602530147274","master_secret":"8583218861046444624186479147396651631579156942204850397797096661516116684243552483174250620744158944865553535495733571632663325011575249979223204777745326895517953843420687756433"},"m2":"5731555078708393357614629066851705238802823277918949054467378429261691189252606979808518037016695141384783224302687321866277811431449642994233365265728281815807346591371594096297"},
-                            "ge_proofs":[]
-                        },
-                        "non_revoc_proof":null
-                    }
-                ],
-                "aggregated_proof":{"c_hash":"83823592657816121785961198553253620031199104930943156818597639614860312075063","c_list":[[2,66,174,183,214,178,122,180,186,63,14,80,155,85,150,14,217,66,149,176,133,171,1,26,238,182,223,250,20,5,23,250,187,84,179,207,13,147,67,92,135,47,152,151,93,9,90,133,13,250,155,255,236,150,10,32,56,173,28,213,29,208,126,57,225,129,173,51,233,189,32,201,139,82,153,42,8,222,131,35,246,39,85,114,168,183,150,197,192,212,171,99,158,9,192,212,61,24,7,95,188,144,164,79,43,149,163,156,241,105,34,114,197,160,90,232,244,72,122,177,186,233,82,107,1,66,231,153,178,57,101,174,240,63,7,50,168,21,134,165,133,105,244,106,115,4,93,227,249,77,58,24,219,122,95,128,87,249,247,119,163,1,197,94,230,66,56,58,203,213,201,219,52,134,122,200,20,210,10,225,231,124,232,0,34,112,168,133,157,202,13,47,132,162,140,159,133,104,24,133,150,66,116,106,250,18,9,84,4,249,4,184,75,216,144,55,119,233,139,217,138,27,215,38,114,20,34,209,179,90,237,184,124,207,14,59,104,25,219,37,162,82,5,24,12,20,94,208,227,162,61,76,247,121,109,93,6]]}
-            },
-            "requested_proof":{
-                "revealed_attrs":{
-                    "attr1_referent":{"sub_proof_index":0,"raw":"Alex","encoded":"1139481716457488690172217916278103335"}
-                },
-                "self_attested_attrs":{},
-                "unrevealed_attrs":{},
-                "predicates":{}
-            },
-            "identifiers":[
-                {
-                    "schema_id":"NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0",
-                    "cred_def_id":"NcYxiDXkpYi6ov5FcYDi1e:3:CL:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0:TAG_1",
-                    "rev_reg_id":null,
-                    "timestamp":null
-                }
-            ]
-        }"#.to_string()
+    init_common_wallet();
+
+    let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+    let requested_credentials_json = json!({
+         "self_attested_attributes": json!({}),
+         "requested_attributes": json!({
+            "attr1_referent": json!({ "cred_id": CREDENTIAL1_ID, "revealed":true })
+         }),
+         "requested_predicates": json!({})
+    }).to_string();
+
+    let proof_json = prover_create_proof(wallet_handle,
+                                         &proof_request_attr(),
+                                         &requested_credentials_json,
+                                         COMMON_MASTER_SECRET,
+                                         &schemas_for_proof(),
+                                         &cred_defs_for_proof(),
+                                         "{}").unwrap();
+
+    wallet::close_wallet(wallet_handle).unwrap();
+
+    proof_json
 }
 
 pub fn schemas_for_proof() -> String {
@@ -426,8 +669,9 @@ pub fn schemas_for_proof() -> String {
 }
 
 pub fn cred_defs_for_proof() -> String {
+    let (credential_def_json, _, _, _) = init_common_wallet();
     json!({
-            issuer_1_gvt_cred_def_id(): serde_json::from_str::<CredentialDefinition>(&credential_def_json()).unwrap()
+            issuer_1_gvt_cred_def_id(): serde_json::from_str::<CredentialDefinition>(credential_def_json).unwrap()
         }).to_string()
 }
 
@@ -733,3 +977,147 @@ pub fn multi_steps_create_revocation_credential(prover_master_secret_id: &str,
 
     (prover1_cred_rev_id, revoc_reg_delta1_json)
 }
+
+extern {
+    #[no_mangle]
+    fn indy_issuer_create_schema_with_types(command_handle: i32,
+                                            issuer_did: *const c_char,
+                                            name: *const c_char,
+                                            version: *const c_char,
+                                            attr_types_json: *const c_char,
+                                            cb: Option<extern fn(command_handle_: i32,
+                                                                 err: i32,
+                                                                 schema_id: *const c_char,
+                                                                 schema_json: *const c_char)>) -> ErrorCode;
+
+    #[no_mangle]
+    fn indy_issuer_rotate_credential_def(command_handle: i32,
+                                         wallet_handle: i32,
+                                         issuer_did: *const c_char,
+                                         cred_def_id: *const c_char,
+                                         schema_json: *const c_char,
+                                         tag: *const c_char,
+                                         config_json: *const c_char,
+                                         cb: Option<extern fn(command_handle_: i32,
+                                                              err: i32,
+                                                              cred_def_id: *const c_char,
+                                                              cred_def_json: *const c_char)>) -> ErrorCode;
+
+    #[no_mangle]
+    fn indy_issuer_create_credential_with_log(command_handle: i32,
+                                              wallet_handle: i32,
+                                              cred_offer_json: *const c_char,
+                                              cred_req_json: *const c_char,
+                                              cred_values_json: *const c_char,
+                                              rev_reg_id: *const c_char,
+                                              blob_storage_reader_handle: i32,
+                                              tags_json: *const c_char,
+                                              cb: Option<extern fn(command_handle_: i32,
+                                                                   err: i32,
+                                                                   cred_json: *const c_char,
+                                                                   cred_revoc_id: *const c_char,
+                                                                   revoc_reg_delta_json: *const c_char)>) -> ErrorCode;
+
+    #[no_mangle]
+    fn indy_issuer_search_issued_credentials(command_handle: i32,
+                                             wallet_handle: i32,
+                                             query_json: *const c_char,
+                                             cb: Option<extern fn(command_handle_: i32,
+                                                                  err: i32,
+                                                                  search_handle: i32,
+                                                                  total_count: usize)>) -> ErrorCode;
+
+    #[no_mangle]
+    fn indy_issuer_fetch_issued_credentials(command_handle: i32,
+                                            search_handle: i32,
+                                            count: usize,
+                                            cb: Option<extern fn(command_handle_: i32,
+                                                                 err: i32,
+                                                                 issued_credentials_json: *const c_char)>) -> ErrorCode;
+
+    #[no_mangle]
+    fn indy_issuer_close_issued_credentials_search(command_handle: i32,
+                                                   search_handle: i32,
+                                                   cb: Option<extern fn(command_handle_: i32,
+                                                                        err: i32)>) -> ErrorCode;
+
+    #[no_mangle]
+    fn indy_prover_delete_credential(command_handle: i32,
+                                     wallet_handle: i32,
+                                     cred_id: *const c_char,
+                                     cb: Option<extern fn(command_handle_: i32,
+                                                          err: i32)>) -> ErrorCode;
+
+    #[no_mangle]
+    fn indy_prover_add_credential_tags(command_handle: i32,
+                                       wallet_handle: i32,
+                                       cred_id: *const c_char,
+                                       tags_json: *const c_char,
+                                       cb: Option<extern fn(command_handle_: i32,
+                                                            err: i32)>) -> ErrorCode;
+
+    #[no_mangle]
+    fn indy_prover_delete_credential_tags(command_handle: i32,
+                                          wallet_handle: i32,
+                                          cred_id: *const c_char,
+                                          tag_names_json: *const c_char,
+                                          cb: Option<extern fn(command_handle_: i32,
+                                                               err: i32)>) -> ErrorCode;
+
+    #[no_mangle]
+    fn indy_issuer_revoke_credentials(command_handle: i32,
+                                      wallet_handle: i32,
+                                      blob_storage_reader_handle: i32,
+                                      rev_reg_id: *const c_char,
+                                      cred_revoc_ids_json: *const c_char,
+                                      cb: Option<extern fn(command_handle_: i32,
+                                                           err: i32,
+                                                           revoc_reg_delta_json: *const c_char)>) -> ErrorCode;
+
+    #[no_mangle]
+    fn indy_issuer_rotate_revoc_reg(command_handle: i32,
+                                    wallet_handle: i32,
+                                    issuer_did: *const c_char,
+                                    cred_def_id: *const c_char,
+                                    config_json: *const c_char,
+                                    tails_writer_handle: i32,
+                                    cb: Option<extern fn(command_handle_: i32,
+                                                         err: i32,
+                                                         rotation_json: *const c_char)>) -> ErrorCode;
+
+    #[no_mangle]
+    fn indy_prover_update_credential_revocation_state(command_handle: i32,
+                                                      wallet_handle: i32,
+                                                      blob_storage_reader_handle: i32,
+                                                      cred_id: *const c_char,
+                                                      rev_reg_def_json: *const c_char,
+                                                      rev_reg_delta_json: *const c_char,
+                                                      timestamp: u64,
+                                                      cb: Option<extern fn(command_handle_: i32,
+                                                                           err: i32,
+                                                                           rev_state_json: *const c_char)>) -> ErrorCode;
+
+    #[no_mangle]
+    fn indy_prover_verify_credential(command_handle: i32,
+                                     wallet_handle: i32,
+                                     cred_req_metadata_json: *const c_char,
+                                     cred_json: *const c_char,
+                                     schema_json: *const c_char,
+                                     cred_def_json: *const c_char,
+                                     rev_reg_def_json: *const c_char,
+                                     cb: Option<extern fn(command_handle_: i32,
+                                                          err: i32,
+                                                          report_json: *const c_char)>) -> ErrorCode;
+
+    #[no_mangle]
+    fn indy_verifier_verify_proof_verbose(command_handle: i32,
+                                          proof_request_json: *const c_char,
+                                          proof_json: *const c_char,
+                                          schemas_json: *const c_char,
+                                          credential_defs_json: *const c_char,
+                                          rev_reg_defs_json: *const c_char,
+                                          rev_regs_json: *const c_char,
+                                          cb: Option<extern fn(command_handle_: i32,
+                                                               err: i32,
+                                                               report_json: *const c_char)>) -> ErrorCode;
+}
@@ -1,9 +1,18 @@
 extern crate futures;
+extern crate libc;
 
-use indy::IndyError;
+use indy::{ErrorCode, IndyError};
 use indy::blob_storage;
 
 use self::futures::Future;
+use self::libc::c_char;
+
+use utils::callback;
+use utils::inmem_blob_storage::InmemBlobStorage;
+
+use std::collections::HashSet;
+use std::ffi::CString;
+use std::sync::Mutex;
 
 pub fn open_reader(type_: &str, config_json: &str) -> Result<i32, IndyError> {
     blob_storage::open_reader(type_, config_json).wait()
@@ -11,4 +20,88 @@ pub fn open_reader(type_: &str, config_json: &str) -> Result<i32, IndyError> {
 
 pub fn open_writer(type_: &str, config_json: &str) -> Result<i32, IndyError> {
     blob_storage::open_writer(type_, config_json).wait()
-}
\ No newline at end of file
+}
+
+pub fn register_blob_storage(xtype: &str) -> Result<(), ErrorCode> {
+    lazy_static! {
+        static ref REGISTERED_BLOB_STORAGES: Mutex<HashSet<String>> = Default::default();
+    }
+
+    let mut blob_storages = REGISTERED_BLOB_STORAGES.lock().unwrap();
+
+    if blob_storages.contains(xtype) {
+        // blob storage type can be registered only once per process
+        return Ok(());
+    }
+
+    let (receiver, command_handle, cb) = callback::_closure_to_cb_ec();
+
+    let xxtype = CString::new(xtype).unwrap();
+
+    let err = unsafe {
+        indy_register_blob_storage(
+            command_handle,
+            xxtype.as_ptr(),
+            Some(InmemBlobStorage::open_writer),
+            Some(InmemBlobStorage::create_blob),
+            Some(InmemBlobStorage::append),
+            Some(InmemBlobStorage::finalize),
+            Some(InmemBlobStorage::close_writer),
+            Some(InmemBlobStorage::open_reader),
+            Some(InmemBlobStorage::open_blob),
+            Some(InmemBlobStorage::read),
+            Some(InmemBlobStorage::close_blob),
+            Some(InmemBlobStorage::close_reader),
+            cb
+        )
+    };
+
+    blob_storages.insert(xtype.to_string());
+
+    super::results::result_to_empty(err as i32, receiver)
+}
+
+extern {
+    #[no_mangle]
+    pub fn indy_register_blob_storage(command_handle: i32,
+                                      type_: *const c_char,
+                                      open_writer: Option<BlobStorageOpenWriter>,
+                                      create_blob: Option<BlobStorageCreateBlob>,
+                                      append: Option<BlobStorageAppend>,
+                                      finalize: Option<BlobStorageFinalize>,
+                                      close_writer: Option<BlobStorageCloseWriter>,
+                                      open_reader: Option<BlobStorageOpenReader>,
+                                      open_blob: Option<BlobStorageOpenBlob>,
+                                      read: Option<BlobStorageRead>,
+                                      close_blob: Option<BlobStorageCloseBlob>,
+                                      close_reader: Option<BlobStorageCloseReader>,
+                                      cb: Option<extern fn(command_handle_: i32, err: i32)>) -> ErrorCode;
+}
+
+pub type BlobStorageOpenWriter = extern fn(config: *const c_char,
+                                           writer_handle_p: *mut i32) -> ErrorCode;
+pub type BlobStorageCreateBlob = extern fn(writer_handle: i32,
+                                           blob_handle_p: *mut i32) -> ErrorCode;
+pub type BlobStorageAppend = extern fn(blob_handle: i32,
+                                       bytes: *const u8,
+                                       bytes_len: usize,
+                                       written_len_p: *mut usize) -> ErrorCode;
+pub type BlobStorageFinalize = extern fn(blob_handle: i32,
+                                         hash: *const u8,
+                                         hash_len: usize,
+                                         location_p: *mut *const c_char) -> ErrorCode;
+pub type BlobStorageCloseWriter = extern fn(writer_handle: i32) -> ErrorCode;
+pub type BlobStorageOpenReader = extern fn(config: *const c_char,
+                                           reader_handle_p: *mut i32) -> ErrorCode;
+pub type BlobStorageOpenBlob = extern fn(reader_handle: i32,
+                                         hash: *const u8,
+                                         hash_len: usize,
+                                         location: *const c_char,
+                                         blob_handle_p: *mut i32) -> ErrorCode;
+pub type BlobStorageRead = extern fn(blob_handle: i32,
+                                     size: usize,
+                                     offset: usize,
+                                     bytes_p: *mut *const u8,
+                                     bytes_len_p: *mut usize) -> ErrorCode;
+pub type BlobStorageCloseBlob = extern fn(blob_handle: i32) -> ErrorCode;
+pub type BlobStorageCloseReader = extern fn(reader_handle: i32) -> ErrorCode;
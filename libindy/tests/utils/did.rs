@@ -1,12 +1,16 @@
 extern crate futures;
+extern crate libc;
 
 use indy::did;
-use indy::IndyError;
+use indy::{ErrorCode, IndyError};
 use self::futures::Future;
+use self::libc::c_char;
 
-use utils::{ledger, pool};
+use utils::{callback, ledger, pool};
 use utils::types::ResponseType;
 
+use std::ffi::CString;
+
 
 pub fn create_store_and_publish_my_did_from_trustee(wallet_handle: i32, pool_handle: i32) -> Result<(String, String), IndyError> {
     let (trustee_did, _) = create_and_store_my_did(wallet_handle, Some(::utils::constants::TRUSTEE_SEED))?;
@@ -93,4 +97,41 @@ pub fn get_my_did_with_metadata(wallet_handle: i32, did: &str) -> Result<String,
 
 pub fn abbreviate_verkey(did: &str, verkey: &str) -> Result<String, IndyError> {
     did::abbreviate_verkey(did, verkey).wait()
-}
\ No newline at end of file
+}
+
+pub fn create_key_rotation_notice(wallet_handle: i32, did: &str) -> Result<String, ErrorCode> {
+    let (receiver, command_handle, cb) = callback::_closure_to_cb_ec_string();
+
+    let did = CString::new(did).unwrap();
+
+    let err = unsafe { indy_create_key_rotation_notice(command_handle, wallet_handle, did.as_ptr(), cb) };
+
+    super::results::result_to_string(err as i32, receiver)
+}
+
+pub fn apply_key_rotation_notice(wallet_handle: i32, notice_json: &str) -> Result<(), ErrorCode> {
+    let (receiver, command_handle, cb) = callback::_closure_to_cb_ec();
+
+    let notice_json = CString::new(notice_json).unwrap();
+
+    let err = unsafe { indy_apply_key_rotation_notice(command_handle, wallet_handle, notice_json.as_ptr(), cb) };
+
+    super::results::result_to_empty(err as i32, receiver)
+}
+
+extern {
+    #[no_mangle]
+    fn indy_create_key_rotation_notice(command_handle: i32,
+                                       wallet_handle: i32,
+                                       did: *const c_char,
+                                       cb: Option<extern fn(command_handle_: i32,
+                                                            err: i32,
+                                                            notice_json: *const c_char)>) -> ErrorCode;
+
+    #[no_mangle]
+    fn indy_apply_key_rotation_notice(command_handle: i32,
+                                      wallet_handle: i32,
+                                      notice_json: *const c_char,
+                                      cb: Option<extern fn(command_handle_: i32,
+                                                           err: i32)>) -> ErrorCode;
+}
@@ -43,6 +43,9 @@ pub mod ctypes;
 #[path = "../../src/utils/inmem_wallet.rs"]
 pub mod inmem_wallet;
 
+#[path = "../../src/utils/inmem_blob_storage.rs"]
+pub mod inmem_blob_storage;
+
 #[path = "../../src/domain/mod.rs"]
 pub mod domain;
 
@@ -1,8 +1,14 @@
 extern crate futures;
+extern crate libc;
 
-use indy::IndyError;
+use indy::{ErrorCode, IndyError};
 use indy::pairwise;
 use self::futures::Future;
+use self::libc::c_char;
+
+use utils::callback;
+
+use std::ffi::CString;
 
 pub fn pairwise_exists(wallet_handle: i32, their_did: &str) -> Result<bool, IndyError> {
     pairwise::is_pairwise_exists(wallet_handle, their_did).wait()
@@ -22,4 +28,74 @@ pub fn get_pairwise(wallet_handle: i32, their_did: &str) -> Result<String, IndyE
 
 pub fn set_pairwise_metadata(wallet_handle: i32, their_did: &str, metadata: Option<&str>) -> Result<(), IndyError> {
     pairwise::set_pairwise_metadata(wallet_handle, their_did, metadata).wait()
-}
\ No newline at end of file
+}
+
+pub fn set_pairwise_tags(wallet_handle: i32, their_did: &str, tags_json: &str) -> Result<(), ErrorCode> {
+    let (receiver, command_handle, cb) = callback::_closure_to_cb_ec();
+
+    let their_did = CString::new(their_did).unwrap();
+    let tags_json = CString::new(tags_json).unwrap();
+
+    let err = unsafe { indy_set_pairwise_tags(command_handle, wallet_handle, their_did.as_ptr(), tags_json.as_ptr(), cb) };
+
+    super::results::result_to_empty(err as i32, receiver)
+}
+
+pub fn search_pairwise(wallet_handle: i32, query_json: &str) -> Result<(i32, usize), ErrorCode> {
+    let (receiver, command_handle, cb) = callback::_closure_to_cb_ec_i32_usize();
+
+    let query_json = CString::new(query_json).unwrap();
+
+    let err = unsafe { indy_search_pairwise(command_handle, wallet_handle, query_json.as_ptr(), cb) };
+
+    super::results::result_to_int_usize(err as i32, receiver)
+}
+
+pub fn fetch_pairwise_search(search_handle: i32, count: usize) -> Result<String, ErrorCode> {
+    let (receiver, command_handle, cb) = callback::_closure_to_cb_ec_string();
+
+    let err = unsafe { indy_fetch_pairwise_search(command_handle, search_handle, count, cb) };
+
+    super::results::result_to_string(err as i32, receiver)
+}
+
+pub fn close_pairwise_search(search_handle: i32) -> Result<(), ErrorCode> {
+    let (receiver, command_handle, cb) = callback::_closure_to_cb_ec();
+
+    let err = unsafe { indy_close_pairwise_search(command_handle, search_handle, cb) };
+
+    super::results::result_to_empty(err as i32, receiver)
+}
+
+extern {
+    #[no_mangle]
+    fn indy_set_pairwise_tags(command_handle: i32,
+                              wallet_handle: i32,
+                              their_did: *const c_char,
+                              tags_json: *const c_char,
+                              cb: Option<extern fn(command_handle_: i32,
+                                                   err: i32)>) -> ErrorCode;
+
+    #[no_mangle]
+    fn indy_search_pairwise(command_handle: i32,
+                            wallet_handle: i32,
+                            query_json: *const c_char,
+                            cb: Option<extern fn(command_handle_: i32,
+                                                 err: i32,
+                                                 search_handle: i32,
+                                                 total_count: usize)>) -> ErrorCode;
+
+    #[no_mangle]
+    fn indy_fetch_pairwise_search(command_handle: i32,
+                                  search_handle: i32,
+                                  count: usize,
+                                  cb: Option<extern fn(command_handle_: i32,
+                                                       err: i32,
+                                                       pairwise_list_json: *const c_char)>) -> ErrorCode;
+
+    #[no_mangle]
+    fn indy_close_pairwise_search(command_handle: i32,
+                                  search_handle: i32,
+                                  cb: Option<extern fn(command_handle_: i32,
+                                                       err: i32)>) -> ErrorCode;
+}
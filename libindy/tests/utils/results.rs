@@ -36,13 +36,15 @@ pub fn result_to_int(err: ErrorCode, receiver: Receiver<(ErrorCode, i32)>) -> Re
     Ok(val)
 }
 
-pub fn result_to_int_usize(err: ErrorCode, receiver: Receiver<(ErrorCode, i32, usize)>) -> Result<(i32, usize), ErrorCode> {
+pub fn result_to_int_usize(err: Error, receiver: Receiver<(Error, i32, usize)>) -> Result<(i32, usize), ErrorCode> {
+    let err = ErrorCode::from(err as i32);
     if err != ErrorCode::Success {
         return Err(err);
     }
 
     let (err, val, val_2) = receiver.recv().unwrap();
 
+    let err = ErrorCode::from(err as i32);
     if err != ErrorCode::Success {
         return Err(err);
     }
@@ -64,13 +66,15 @@ pub fn result_to_bool(err: ErrorCode, receiver: Receiver<(ErrorCode, bool)>) ->
     Ok(val)
 }
 
-pub fn result_to_string(err: ErrorCode, receiver: Receiver<(ErrorCode, String)>) -> Result<String, ErrorCode> {
+pub fn result_to_string(err: Error, receiver: Receiver<(Error, String)>) -> Result<String, ErrorCode> {
+    let err = ErrorCode::from(err as i32);
     if err != ErrorCode::Success {
         return Err(err);
     }
 
     let (err, val) = receiver.recv().unwrap();
 
+    let err = ErrorCode::from(err as i32);
     if err != ErrorCode::Success {
         return Err(err);
     }
@@ -78,13 +82,15 @@ pub fn result_to_string(err: ErrorCode, receiver: Receiver<(ErrorCode, String)>)
     Ok(val)
 }
 
-pub fn result_to_string_string(err: ErrorCode, receiver: Receiver<(ErrorCode, String, String)>) -> Result<(String, String), ErrorCode> {
+pub fn result_to_string_string(err: Error, receiver: Receiver<(Error, String, String)>) -> Result<(String, String), ErrorCode> {
+    let err = ErrorCode::from(err as i32);
     if err != ErrorCode::Success {
         return Err(err);
     }
 
     let (err, val, val2) = receiver.recv().unwrap();
 
+    let err = ErrorCode::from(err as i32);
     if err != ErrorCode::Success {
         return Err(err);
     }
@@ -106,13 +112,15 @@ pub fn result_to_string_string_string(err: ErrorCode, receiver: Receiver<(ErrorC
     Ok((val, val2, val3))
 }
 
-pub fn result_to_string_opt_string_opt_string(err: ErrorCode, receiver: Receiver<(ErrorCode, String, Option<String>, Option<String>)>) -> Result<(String, Option<String>, Option<String>), ErrorCode> {
+pub fn result_to_string_opt_string_opt_string(err: Error, receiver: Receiver<(Error, String, Option<String>, Option<String>)>) -> Result<(String, Option<String>, Option<String>), ErrorCode> {
+    let err = ErrorCode::from(err as i32);
     if err != ErrorCode::Success {
         return Err(err);
     }
 
     let (err, val, val2, val3) = receiver.recv().unwrap();
 
+    let err = ErrorCode::from(err as i32);
     if err != ErrorCode::Success {
         return Err(err);
     }